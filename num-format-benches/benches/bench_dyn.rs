@@ -0,0 +1,66 @@
+#![allow(unused_variables)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use num_format::{AnyFormat, Buffer, Format, Locale};
+
+fn bench_dyn(c: &mut Criterion) {
+    let measurement_time = Duration::from_millis(5_000); // Default is 5 seconds
+    let sample_size = 100; // Default is 100
+    let warm_up_time = Duration::from_millis(3_000); // Default is 3 seconds
+    let mut group = c.benchmark_group("dyn");
+
+    // black_box keeps the compiler from seeing which format is behind each of these, as it could
+    // not if the format were chosen at runtime
+    let any_format = AnyFormat::from(Locale::en);
+    let any_format = black_box(&any_format);
+    let reference: &dyn Format = black_box(&Locale::en);
+    let boxed: Box<dyn Format> = black_box(Box::new(Locale::en));
+    let arc: Arc<dyn Format> = black_box(Arc::new(Locale::en));
+
+    for &(name, n) in &[("10_000", 10_000usize), ("MAX", usize::MAX)] {
+        group.bench_function(format!("static/{}", name), |b| {
+            b.iter(|| {
+                let mut buf = Buffer::default();
+                buf.write_formatted(&n, &Locale::en);
+            })
+        });
+        group.bench_function(format!("any_format/{}", name), |b| {
+            b.iter(|| {
+                let mut buf = Buffer::default();
+                buf.write_formatted(&n, any_format);
+            })
+        });
+        group.bench_function(format!("ref_dyn/{}", name), |b| {
+            b.iter(|| {
+                let mut buf = Buffer::default();
+                buf.write_formatted(&n, &reference);
+            })
+        });
+        group.bench_function(format!("box_dyn/{}", name), |b| {
+            b.iter(|| {
+                let mut buf = Buffer::default();
+                buf.write_formatted(&n, &boxed);
+            })
+        });
+        group.bench_function(format!("arc_dyn/{}", name), |b| {
+            b.iter(|| {
+                let mut buf = Buffer::default();
+                buf.write_formatted(&n, &arc);
+            })
+        });
+    }
+    group.measurement_time(measurement_time);
+    group.sample_size(sample_size);
+    group.warm_up_time(warm_up_time);
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default();
+    targets = bench_dyn
+}
+criterion_main!(benches);
@@ -1,103 +1,90 @@
 use indexmap::IndexMap;
-use proc_macro2::{Delimiter, Group, Ident, Literal, Span};
+use proc_macro2::{Delimiter, Group, Ident, Literal, Span, TokenStream};
 use quote::quote;
 
+use crate::error::DataErrors;
+use crate::locale_tags::LocaleTags;
+use crate::ordinals::{Ordinals, Piece, Rule};
+use crate::plural_rules::{Domain, PluralRules};
+use crate::plurals::Plurals;
 use crate::utils::Format;
+use crate::validate::validate;
+
+/// Languages with a locale set of their own. Locales of any other language are available unless
+/// the `NUM_FORMAT_LOCALES` build option of num-format restricts the set (see its `build.rs`).
+const LOCALE_SETS: &[(&str, &[&str])] = &[
+    ("locales_en", &["en"]),
+    (
+        "locales_eu",
+        // Official languages of the European Union other than English
+        &[
+            "bg", "cs", "da", "de", "el", "es", "et", "fi", "fr", "ga", "hr", "hu", "it", "lt",
+            "lv", "mt", "nl", "pl", "pt", "ro", "sk", "sl", "sv",
+        ],
+    ),
+];
+const OTHER_LOCALE_SET: &str = "locales_other";
+
+/// Takes the map returned from `parse_data`, the ordinals returned from `parse_ordinals`, the
+/// plurals returned from `parse_plurals` and the locale tag data returned from
+/// `parse_locale_tags` and turns them into a rust module.
+///
+/// Symbols are stored once per distinct set of symbols (most locales share one with others)
+/// and looked up through a per-locale index, as are plural rules and ordinal rule sets. Plural
+/// rules are generated as code. The items that make a locale available by name and its symbols
+/// in other numbering systems are marked with the `cfg` of the locale set the locale belongs to,
+/// so that num-format can compile in a subset of them. `Locale` variants and the tables indexed
+/// by them are never marked, so that every build has them whole.
+///
+/// Fails with [`DataErrors`] if a symbol is too long for num-format (see [`validate`]).
+pub fn create_module(
+    data: &IndexMap<String, Format>,
+    ordinals: &Ordinals,
+    plurals: &Plurals,
+    tags: &LocaleTags,
+) -> Result<String, anyhow::Error> {
+    let errors = validate(data);
+    if !errors.is_empty() {
+        return Err(DataErrors(errors).into());
+    }
 
-/// Takes the map returned from `parse_data` and turns it into a rust module.
-pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::Error> {
-    let variant_names = data.keys().map(|s| Ident::new(s, Span::call_site()));
-
-    let mut decimals = Vec::new();
-    let mut groupings = Vec::new();
-    let mut infinities = Vec::new();
-    let mut minus_signs = Vec::new();
-    let mut nans = Vec::new();
-    let mut plus_signs = Vec::new();
-    let mut separators = Vec::new();
+    let mut all = Vec::new();
+    let mut cardinal_plural_rules = Vec::new();
+    let mut cardinal_rules: IndexMap<_, usize> = IndexMap::new();
+    let mut english_names = Vec::new();
     let mut from_strs = Vec::new();
     let mut names = Vec::new();
     let mut names2 = Vec::new();
+    let mut native_names = Vec::new();
+    let mut numbering_system_symbols = Vec::new();
+    let mut ordinal_indices = Vec::new();
+    let mut ordinal_plural_rules = Vec::new();
+    let mut plural_rules: IndexMap<_, usize> = IndexMap::new();
+    let mut subtags = Vec::new();
+    let mut symbol_indices = Vec::new();
+    let mut symbols: IndexMap<_, usize> = IndexMap::new();
+    let mut variant_names = Vec::new();
     for (variant_name, format) in data.iter() {
         let key = Ident::new(variant_name, Span::call_site());
+        let set = locale_set(&format.language);
+        let cfg = quote! { #[cfg(#set)] };
 
-        // decimals
-        let value = Literal::string(&format.dec.to_string());
-        let group = Group::new(
-            Delimiter::None,
-            quote! {
-                #key => #value,
-            },
-        );
-        decimals.push(group);
+        // variant_names
+        variant_names.push(quote! { #key });
 
-        // groupings
-        let value = format.grp.to_ident();
-        let group = Group::new(
-            Delimiter::None,
-            quote! {
-                #key => #value,
-            },
-        );
-        groupings.push(group);
+        // all
+        all.push(quote! { Locale::#key });
 
-        // infinities
-        let value = Literal::string(&format.inf);
-        let group = Group::new(
-            Delimiter::None,
-            quote! {
-                #key => #value,
-            },
-        );
-        infinities.push(group);
-
-        // minus_signs
-        let value = Literal::string(&format.min);
-        let group = Group::new(
-            Delimiter::None,
-            quote! {
-                #key => #value,
-            },
-        );
-        minus_signs.push(group);
-
-        // nans
-        let value = Literal::string(&format.nan);
-        let group = Group::new(
-            Delimiter::None,
-            quote! {
-                #key => #value,
-            },
-        );
-        nans.push(group);
-
-        // plus_signs
-        let value = Literal::string(&format.pos);
-        let group = Group::new(
-            Delimiter::None,
-            quote! {
-                #key => #value,
-            },
-        );
-        plus_signs.push(group);
-
-        // separtors
-        let value = Literal::string(&format.sep.to_string());
-        let group = Group::new(
-            Delimiter::None,
-            quote! {
-                #key => #value,
-            },
-        );
-        separators.push(group);
+        // english_names
+        let value = Literal::string(&format.english_name);
+        english_names.push(quote! { #value });
 
         // from_strs
-        let value = key.clone();
         let key2 = Literal::string(&format.identifier);
         let group = Group::new(
             Delimiter::None,
             quote! {
-                #key2 => #value,
+                #cfg #key2 => #key,
             },
         );
         from_strs.push(group);
@@ -107,7 +94,7 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
             let group = Group::new(
                 Delimiter::None,
                 quote! {
-                    #key2 => #value,
+                    #cfg #key2 => #key,
                 },
             );
             from_strs.push(group);
@@ -115,21 +102,141 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
 
         // names
         let value = Literal::string(&format.identifier);
-        let group = Group::new(
-            Delimiter::None,
-            quote! {
-                #key => #value,
-            },
-        );
-        names.push(group);
+        names.push(quote! { #value });
 
         // names2
-        names2.push(&format.identifier);
+        names2.push((&format.identifier, cfg.clone()));
+
+        // native_names
+        let value = Literal::string(&format.native_name);
+        native_names.push(quote! { #value });
+
+        // subtags
+        let language = Literal::string(&format.language);
+        let script = option_literal(&format.script);
+        let region = option_literal(&format.region);
+        let variant = option_literal(&format.variant);
+        subtags.push(quote! {
+            Subtags {
+                language: #language,
+                script: #script,
+                region: #region,
+                variant: #variant,
+            }
+        });
+
+        // symbol_indices (symbols are deduplicated)
+        let len = symbols.len();
+        let index = *symbols.entry(format.symbols()).or_insert(len);
+        let value = Literal::u8_unsuffixed(u8_index(index, "sets of symbols")?);
+        symbol_indices.push(quote! { #value });
+
+        // numbering_system_symbols (in the order of the locales, then of the numbering systems)
+        for (numbering_system, other) in &format.numbering_systems {
+            let len = symbols.len();
+            let index = *symbols.entry(other.clone()).or_insert(len);
+            let value = Literal::u8_unsuffixed(u8_index(index, "sets of symbols")?);
+            let numbering_system = numbering_system_ident(numbering_system);
+            numbering_system_symbols.push(quote! {
+                #cfg (Locale::#key, NumberingSystem::#numbering_system, #value)
+            });
+        }
+
+        // ordinal_indices
+        let index = ordinals
+            .rule_set_indices
+            .get(variant_name)
+            .ok_or_else(|| anyhow::anyhow!("No ordinal rule set for {}", format.identifier))?;
+        let value = Literal::u8_unsuffixed(u8_index(*index, "ordinal rule sets")?);
+        ordinal_indices.push(quote! { #value });
+
+        // ordinal_plural_rules (plural rules are deduplicated)
+        let rules = ordinals.plural_rules.get(variant_name).cloned();
+        let len = plural_rules.len();
+        let index = *plural_rules.entry(rules.unwrap_or_default()).or_insert(len);
+        let value = Literal::u8_unsuffixed(u8_index(index, "ordinal plural rules")?);
+        ordinal_plural_rules.push(quote! { #value });
+
+        // cardinal_plural_rules (plural rules are deduplicated)
+        let rules = plurals.rules.get(variant_name).cloned();
+        let len = cardinal_rules.len();
+        let index = *cardinal_rules
+            .entry(rules.unwrap_or_default())
+            .or_insert(len);
+        let value = Literal::u8_unsuffixed(u8_index(index, "cardinal plural rules")?);
+        cardinal_plural_rules.push(quote! { #value });
     }
 
-    names2.sort();
-    let names2 = names2.into_iter().map(|s| Literal::string(s));
-    let names2_len = names2.len();
+    names2.sort_by(|a, b| a.0.cmp(b.0));
+    let names2 = names2.into_iter().map(|(s, cfg)| {
+        let s = Literal::string(s);
+        quote! { #cfg #s }
+    });
+
+    let symbols = symbols.keys().map(|symbols| {
+        let dec = Literal::string(&symbols.dec);
+        let grp = symbols.grp.to_ident();
+        let inf = Literal::string(&symbols.inf);
+        let min = Literal::string(&symbols.min);
+        let nan = Literal::string(&symbols.nan);
+        let pos = Literal::string(&symbols.pos);
+        let sep = Literal::string(&symbols.sep);
+        quote! {
+            Symbols {
+                decimal: #dec,
+                grouping: Grouping::#grp,
+                infinity: #inf,
+                minus_sign: #min,
+                nan: #nan,
+                plus_sign: #pos,
+                separator: #sep,
+            }
+        }
+    });
+
+    let ordinal_rule_sets = ordinals
+        .rule_sets
+        .iter()
+        .map(|rules| {
+            let rules = rules
+                .iter()
+                .map(rule_tokens)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(quote! { &[#(#rules),*] })
+        })
+        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+    // `n % 10 == 0` rather than `n.is_multiple_of(10)`, which is newer than num-format's MSRV
+    // (hence the `allow` on `ordinal_category` and `plural_category`). Relations on `n` of a
+    // decimal repeat the check that it is an integer, as CLDR repeats `n` (hence the other
+    // `allow` on `plural_category`)
+    let categories = |rules: &IndexMap<PluralRules, usize>, domain| {
+        rules
+            .keys()
+            .enumerate()
+            .filter(|(_, rules)| !rules.is_other())
+            .map(|(index, rules)| {
+                let index = Literal::usize_unsuffixed(index);
+                let rules = rules.to_tokens(domain);
+                quote! { #index => #rules, }
+            })
+            .collect::<Vec<_>>()
+    };
+    let language_aliases = tags.language_aliases.iter().map(|(alias, language)| {
+        quote! { (#alias, #language) }
+    });
+    let likely_scripts = tags
+        .likely_scripts
+        .iter()
+        .map(|(language, region, script)| {
+            quote! { (#language, #region, #script) }
+        });
+    let parent_locales = tags.parent_locales.iter().map(|(locale, parent)| {
+        quote! { (#locale, #parent) }
+    });
+
+    let ordinal_categories = categories(&plural_rules, Domain::Integers);
+    let cardinal_categories = categories(&cardinal_rules, Domain::Decimals);
 
     let token_stream = quote! {
         //!Note: This module was autogenerated by num-format-dev.
@@ -139,15 +246,113 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
         use crate::error::Error;
         use crate::format::Format;
         use crate::grouping::Grouping;
+        use crate::integer::Integer;
+        use crate::locale_tag;
+        use crate::numbering_system::NumberingSystem;
+        use crate::ordinal::{OrdinalPiece, OrdinalRule};
+        use crate::plural_category::PluralCategory;
+        use crate::plural_operands::{PluralOperands, ToPluralOperands};
         use crate::strings::{
             DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr
         };
 
-        const AVAILABLE_NAMES: [&str; #names2_len] = [#(#names2),*];
+        const AVAILABLE_NAMES: &[&str] = &[#(#names2),*];
+
+        const ALL: &[Locale] = &[#(#all),*];
+
+        const ENGLISH_NAMES: &[&str] = &[#(#english_names),*];
+
+        /// Deprecated language codes and their replacements, from CLDR's `aliases.json`.
+        pub(crate) const LANGUAGE_ALIASES: &[(&str, &str)] = &[#(#language_aliases),*];
+
+        /// Scripts implied by a language and region when a tag names no script, from CLDR's
+        /// `likelySubtags.json`. Only those that differ from the language's own likely script
+        /// are listed.
+        pub(crate) const LIKELY_SCRIPTS: &[(&str, &str, &str)] = &[#(#likely_scripts),*];
+
+        const NAMES: &[&str] = &[#(#names),*];
+
+        const NATIVE_NAMES: &[&str] = &[#(#native_names),*];
+
+        /// The symbols of locales in the other numbering systems of [`NumberingSystem`], where
+        /// they differ from those of the locale's default numbering system, as indices into
+        /// `SYMBOLS`. Sorted by locale, then numbering system.
+        const NUMBERING_SYSTEM_SYMBOLS: &[(Locale, NumberingSystem, u8)] = &[
+            #(#numbering_system_symbols),*
+        ];
+
+        const ORDINAL_INDICES: &[u8] = &[#(#ordinal_indices),*];
+
+        const ORDINAL_PLURAL_RULES: &[u8] = &[#(#ordinal_plural_rules),*];
+
+        const ORDINAL_RULE_SETS: &[&[OrdinalRule]] = &[#(#ordinal_rule_sets),*];
+
+        /// Parents that differ from what truncating a tag would give, from CLDR's
+        /// `parentLocales.json`. A parent of `"root"` means there is nothing to fall back to
+        /// (e.g. `az-Arab` must not fall back to `az`, which is written in Latin script).
+        pub(crate) const PARENT_LOCALES: &[(&str, &str)] = &[#(#parent_locales),*];
+
+        const PLURAL_RULES: &[u8] = &[#(#cardinal_plural_rules),*];
+
+        const SUBTAGS: &[Subtags] = &[#(#subtags),*];
+
+        const SYMBOL_INDICES: &[u8] = &[#(#symbol_indices),*];
+
+        const SYMBOLS: &[Symbols] = &[#(#symbols),*];
+
+        /// Subtags of a locale's identifier.
+        struct Subtags {
+            language: &'static str,
+            script: Option<&'static str>,
+            region: Option<&'static str>,
+            variant: Option<&'static str>,
+        }
+
+        /// Symbols of one or more locales that format numbers identically.
+        pub(crate) struct Symbols {
+            pub(crate) decimal: &'static str,
+            pub(crate) grouping: Grouping,
+            pub(crate) infinity: &'static str,
+            pub(crate) minus_sign: &'static str,
+            pub(crate) nan: &'static str,
+            pub(crate) plus_sign: &'static str,
+            pub(crate) separator: &'static str,
+        }
+
+        /// Returns the category of `n` under the ordinal plural rules with the given index.
+        #[allow(clippy::manual_is_multiple_of)]
+        fn ordinal_category(rules: u8, n: u128) -> PluralCategory {
+            match rules {
+                #(#ordinal_categories)*
+                _ => PluralCategory::Other,
+            }
+        }
+
+        /// Returns the category of a number under the cardinal plural rules with the given index.
+        #[allow(clippy::manual_is_multiple_of, clippy::nonminimal_bool)]
+        fn plural_category(rules: u8, o: &PluralOperands) -> PluralCategory {
+            match rules {
+                #(#cardinal_categories)*
+                _ => PluralCategory::Other,
+            }
+        }
+
+        /// Returns the ordinal rule set with the given index.
+        pub(crate) fn ordinal_rule_set(index: u8) -> &'static [OrdinalRule] {
+            ORDINAL_RULE_SETS[usize::from(index)]
+        }
 
         ///<b><u>A key type</u></b>. Represents formats from the [Unicode Consortium]'s
         ///[Common Locale Data Repository (CLDR)]. Implements [`Format`].
         ///
+        ///All of CLDR's locales can be looked up by name unless num-format is built with the
+        ///`NUM_FORMAT_LOCALES` environment variable set to `en` or `eu` (or both), in which case
+        ///only the English locales or the locales of the official languages of the European Union
+        ///(including English) can be. Every variant exists, is returned by [`iter`] and has the
+        ///same symbols, plural rules, ordinal rules and display names in every build; one that is
+        ///left out is only missing from [`available_names`] and cannot be returned by
+        ///[`from_name`] (or [`negotiate`], which resolves names the same way).
+        ///
         ///# Example
         ///```
         ///use num_format::{Buffer, Locale};
@@ -164,7 +369,11 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
         ///}
         ///```
         ///
+        /// [`available_names`]: enum.Locale.html#method.available_names
         /// [`Format`]: trait.Format.html
+        /// [`from_name`]: enum.Locale.html#method.from_name
+        /// [`iter`]: enum.Locale.html#method.iter
+        /// [`negotiate`]: enum.Locale.html#method.negotiate
         /// [Common Locale Data Repository (CLDR)]: https://en.wikipedia.org/wiki/Common_Locale_Data_Repository
         /// [Unicode Consortium]: https://en.wikipedia.org/wiki/Unicode_Consortium
         #[allow(non_camel_case_types, missing_docs)]
@@ -178,6 +387,14 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
             /// Constructs a [`Locale`] from its name. For a list of available names, see
             /// [`available_names`].
             ///
+            /// Besides the available names themselves, accepts BCP 47 language tags (e.g. `en-US`,
+            /// `zh-Hant-TW` or `pt-br`) and POSIX locale names (e.g. `en_US.UTF-8` or
+            /// `sr_RS@latin`), matched case-insensitively. A tag without an exact match falls back
+            /// to its closest available parent, as CLDR does (e.g. `en-US` to `en`, `zh-TW` to
+            /// `zh-Hant` and `de-DE-u-nu-latn` to `de`). To pick the best match from a list of
+            /// tags in order of preference, see [`negotiate`]. Unicode extension keywords (e.g.
+            /// `-u-nu-arab`) are ignored here; to honour them, see [`TaggedLocale`].
+            ///
             /// # Errors
             ///
             /// Returns an error if the name provided cannot be
@@ -185,82 +402,194 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
             ///
             /// [`available_names`]: enum.Locale.html#method.available_names
             /// [`Locale`]: enum.Locale.html
+            /// [`negotiate`]: enum.Locale.html#method.negotiate
+            /// [`TaggedLocale`]: struct.TaggedLocale.html
+            #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
             pub fn from_name<S>(name: S) -> Result<Locale, Error>
                 where S: AsRef<str>,
             {
                 let name = name.as_ref();
-                name.parse::<Locale>()
+                Locale::from_exact_name(name)
+                    .or_else(|| locale_tag::resolve(name))
+                    .ok_or_else(|| Error::parse_locale(name))
+            }
+
+            pub(crate) fn from_exact_name(s: &str) -> Option<Locale> {
+                use self::Locale::*;
+                let locale = match s {
+                    #(#from_strs)*
+                    _ => return None,
+                };
+                Some(locale)
             }
 
             /// Returns a static slice of all available names.
             pub fn available_names() -> &'static [&'static str] {
-                &AVAILABLE_NAMES
+                AVAILABLE_NAMES
             }
 
             /// Returns the locale's decimal representation.
             pub fn decimal(&self) -> &'static str {
-                use self::Locale::*;
-                match self {
-                    #(#decimals)*
-                }
+                self.symbols().decimal
+            }
+
+            /// Returns the locale's name in English, from CLDR's display names (e.g.
+            /// "Austrian German" for `de-AT`).
+            pub fn english_name(&self) -> &'static str {
+                ENGLISH_NAMES[*self as usize]
             }
 
             /// Returns the locale's [`Grouping`].
             ///
             /// [`Grouping`]: enum.Grouping.html
             pub fn grouping(&self) -> Grouping {
-                use self::Grouping::*;
-                use self::Locale::*;
-                match self {
-                    #(#groupings)*
-                }
+                self.symbols().grouping
             }
 
             /// Returns the locale's infinity representation.
             pub fn infinity(&self) -> &'static str {
-                use self::Locale::*;
-                match self {
-                    #(#infinities)*
-                }
+                self.symbols().infinity
+            }
+
+            /// Returns an iterator over all locales, in the order in which they are declared.
+            pub fn iter() -> impl DoubleEndedIterator<Item = Locale> + ExactSizeIterator {
+                ALL.iter().copied()
+            }
+
+            /// Returns the locale's language subtag (e.g. "sr" for `sr-Latn-BA`).
+            pub fn language(&self) -> &'static str {
+                self.subtags().language
             }
 
             /// Returns the locale's minus sign representation.
             pub fn minus_sign(&self) -> &'static str {
-                use self::Locale::*;
-                match self {
-                    #(#minus_signs)*
-                }
+                self.symbols().minus_sign
             }
 
             /// Returns the locale's name.
             pub fn name(&self) -> &'static str {
-                use self::Locale::*;
-                match self {
-                    #(#names)*
-                }
+                NAMES[*self as usize]
             }
 
             /// Returns the locale's NaN representation.
             pub fn nan(&self) -> &'static str {
-                use self::Locale::*;
-                match self {
-                    #(#nans)*
-                }
+                self.symbols().nan
+            }
+
+            /// Returns the locale's name in its own language, from CLDR's display names (e.g.
+            /// "Österreichisches Deutsch" for `de-AT`). Falls back to the English name for the
+            /// few locales CLDR has no display names for.
+            pub fn native_name(&self) -> &'static str {
+                NATIVE_NAMES[*self as usize]
+            }
+
+            /// Returns the ordinal plural category of `n` in the locale's language, from CLDR's
+            /// ordinal plural rules (e.g. [`PluralCategory::Two`] for 22 in English, which is why
+            /// it is written "22nd"). The sign of `n` is ignored.
+            ///
+            /// # Example
+            /// ```
+            /// use num_format::{Locale, PluralCategory};
+            ///
+            /// assert_eq!(Locale::en.ordinal_category(&22), PluralCategory::Two);
+            /// assert_eq!(Locale::en.ordinal_category(&12), PluralCategory::Other);
+            /// assert_eq!(Locale::fr.ordinal_category(&1), PluralCategory::One);
+            /// ```
+            ///
+            /// [`PluralCategory::Two`]: enum.PluralCategory.html#variant.Two
+            pub fn ordinal_category<N>(&self, n: &N) -> PluralCategory
+                where N: Integer,
+            {
+                self.ordinal_category_of(n.magnitude())
+            }
+
+            pub(crate) fn ordinal_category_of(&self, n: u128) -> PluralCategory {
+                ordinal_category(ORDINAL_PLURAL_RULES[*self as usize], n)
+            }
+
+            pub(crate) fn ordinal_rules(&self) -> &'static [OrdinalRule] {
+                ordinal_rule_set(ORDINAL_INDICES[*self as usize])
+            }
+
+            /// Returns the plural category of `n` in the locale's language, from CLDR's cardinal
+            /// plural rules (e.g. [`PluralCategory::One`] for 1 in English, which is why it is
+            /// "1 day" but "2 days"). The sign of `n` is ignored.
+            ///
+            /// `n` can be any integer (or, with the `with-num-bigint` feature, a `BigInt` or
+            /// `BigUint`) or a decimal given as [`PluralOperands`]. In many languages a decimal's
+            /// category depends on how many fraction digits it is written with, so those should
+            /// be the digits it is actually formatted with (e.g. "1.0" is [`PluralCategory::Other`]
+            /// in English).
+            ///
+            /// # Example
+            /// ```
+            /// use num_format::{Locale, PluralCategory, PluralOperands};
+            ///
+            /// assert_eq!(Locale::en.plural_category(&1), PluralCategory::One);
+            /// assert_eq!(Locale::en.plural_category(&2), PluralCategory::Other);
+            /// assert_eq!(Locale::fr.plural_category(&0), PluralCategory::One);
+            /// assert_eq!(Locale::ru.plural_category(&21), PluralCategory::One);
+            /// assert_eq!(Locale::ru.plural_category(&25), PluralCategory::Many);
+            ///
+            /// let one = "1.0".parse::<PluralOperands>().unwrap();
+            /// assert_eq!(Locale::en.plural_category(&one), PluralCategory::Other);
+            /// let one_and_a_half = PluralOperands::from_f64(1.5, 1).unwrap();
+            /// assert_eq!(Locale::ru.plural_category(&one_and_a_half), PluralCategory::Other);
+            /// ```
+            ///
+            /// [`PluralCategory::One`]: enum.PluralCategory.html#variant.One
+            /// [`PluralCategory::Other`]: enum.PluralCategory.html#variant.Other
+            /// [`PluralOperands`]: struct.PluralOperands.html
+            pub fn plural_category<N>(&self, n: &N) -> PluralCategory
+                where N: ToPluralOperands,
+            {
+                plural_category(PLURAL_RULES[*self as usize], &n.to_plural_operands())
             }
 
             /// Returns the locale's plus sign representation.
             pub fn plus_sign(&self) -> &'static str {
-                use self::Locale::*;
-                match self {
-                    #(#plus_signs)*
-                }
+                self.symbols().plus_sign
+            }
+
+            /// Returns the locale's region subtag, if any (e.g. "BA" for `sr-Latn-BA`).
+            pub fn region(&self) -> Option<&'static str> {
+                self.subtags().region
+            }
+
+            /// Returns the locale's script subtag, if any (e.g. "Latn" for `sr-Latn-BA`).
+            pub fn script(&self) -> Option<&'static str> {
+                self.subtags().script
             }
 
             /// Returns the locale's separator representation, if any.
             pub fn separator(&self) -> &'static str {
-                use self::Locale::*;
-                match self {
-                    #(#separators)*
+                self.symbols().separator
+            }
+
+            /// Returns the locale's variant subtag, if any (e.g. "POSIX" for `en-US-POSIX`).
+            pub fn variant(&self) -> Option<&'static str> {
+                self.subtags().variant
+            }
+
+            fn subtags(&self) -> &'static Subtags {
+                &SUBTAGS[*self as usize]
+            }
+
+            /// Returns the symbols of the locale's default numbering system.
+            pub(crate) fn symbols(&self) -> &'static Symbols {
+                &SYMBOLS[usize::from(SYMBOL_INDICES[*self as usize])]
+            }
+
+            /// Returns the symbols of the locale in the given numbering system, which are those
+            /// of its default numbering system unless CLDR has others.
+            pub(crate) fn symbols_in(&self, numbering_system: NumberingSystem) -> &'static Symbols {
+                let key = (*self, numbering_system as u8);
+                match NUMBERING_SYSTEM_SYMBOLS
+                    .binary_search_by_key(&key, |&(locale, numbering_system, _)| {
+                        (locale, numbering_system as u8)
+                    }) {
+                    Ok(i) => &SYMBOLS[usize::from(NUMBERING_SYSTEM_SYMBOLS[i].2)],
+                    Err(_) => self.symbols(),
                 }
             }
         }
@@ -309,12 +638,7 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
             ///
             /// [`from_name`]: enum.Locale.html#method.from_name
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                use self::Locale::*;
-                let locale = match s {
-                    #(#from_strs)*
-                    _ => return Err(Error::parse_locale(s)),
-                };
-                Ok(locale)
+                Locale::from_name(s)
             }
         }
     };
@@ -322,3 +646,118 @@ pub fn create_module(data: &IndexMap<String, Format>) -> Result<String, anyhow::
     let s = format!("{}", &token_stream);
     Ok(s)
 }
+
+fn rule_tokens(rule: &Rule) -> Result<TokenStream, anyhow::Error> {
+    let base = Literal::u64_unsuffixed(rule.base);
+    let pieces = rule
+        .pieces
+        .iter()
+        .map(|piece| {
+            let tokens = match piece {
+                Piece::Number => quote! { OrdinalPiece::Number },
+                Piece::Text(text) => {
+                    let text = Literal::string(text);
+                    quote! { OrdinalPiece::Text(#text) }
+                }
+                Piece::Plural(texts) => {
+                    let texts = texts.iter().map(|text| Literal::string(text));
+                    quote! { OrdinalPiece::Plural([#(#texts),*]) }
+                }
+                Piece::RuleSet(index) => {
+                    let index = Literal::u8_unsuffixed(u8_index(*index, "ordinal rule sets")?);
+                    quote! { OrdinalPiece::RuleSet(#index) }
+                }
+                Piece::Remainder { rule_set, divisor } => {
+                    let rule_set =
+                        Literal::u8_unsuffixed(u8_index(*rule_set, "ordinal rule sets")?);
+                    let divisor = Literal::u64_unsuffixed(*divisor);
+                    quote! { OrdinalPiece::Remainder { rule_set: #rule_set, divisor: #divisor } }
+                }
+            };
+            Ok(tokens)
+        })
+        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+    Ok(quote! { OrdinalRule { base: #base, pieces: &[#(#pieces),*] } })
+}
+
+/// Returns the identifier of the `NumberingSystem` variant with the given CLDR name (e.g. `Arab`
+/// for "arab").
+fn numbering_system_ident(name: &str) -> Ident {
+    let mut ident = name[..1].to_uppercase();
+    ident.push_str(&name[1..]);
+    Ident::new(&ident, Span::call_site())
+}
+
+/// Converts an index into a table of the generated module to a `u8`.
+fn u8_index(index: usize, table: &str) -> Result<u8, anyhow::Error> {
+    u8::try_from(index).map_err(|_| anyhow::anyhow!("More than 256 distinct {}", table))
+}
+
+/// Returns the `cfg` name of the locale set that locales of `language` belong to.
+fn locale_set(language: &str) -> Ident {
+    let set = LOCALE_SETS
+        .iter()
+        .find(|(_, languages)| languages.contains(&language))
+        .map_or(OTHER_LOCALE_SET, |(set, _)| set);
+    Ident::new(set, Span::call_site())
+}
+
+fn option_literal(value: &Option<String>) -> TokenStream {
+    match value {
+        Some(value) => {
+            let value = Literal::string(value);
+            quote! { Some(#value) }
+        }
+        None => quote! { None },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_module_strings() {
+        let mut data = IndexMap::new();
+        let mut de = Format::test("de", ".", "-");
+        let mut arab = de.symbols();
+        arab.dec = "\u{66b}".to_string();
+        de.numbering_systems.insert("arab".to_string(), arab);
+        data.insert("de".to_string(), de);
+        data.insert(
+            "xx".to_string(),
+            Format::test("xx", "\u{2019}\u{2009}", "\u{200f}-"),
+        );
+        let s = create_module(
+            &data,
+            &Ordinals::test(&data),
+            &Plurals::test(&data),
+            &LocaleTags::default(),
+        )
+        .unwrap();
+        let separator = Literal::string("\u{2019}\u{2009}");
+        assert!(s.contains(&format!("separator : {}", separator)));
+        let minus_sign = Literal::string("\u{200f}-");
+        assert!(s.contains(&format!("minus_sign : {}", minus_sign)));
+        // the symbols of "de" in "arab" are the second set
+        assert!(s.contains("(Locale :: de , NumberingSystem :: Arab , 1)"));
+        // variants exist whichever locale sets are compiled in
+        assert!(s.contains("pub enum Locale { de , xx }"));
+        assert!(s.contains("# [cfg (locales_eu)] \"de\" => de"));
+
+        data.insert(
+            "yy".to_string(),
+            Format::test("yy", &"\u{a0}".repeat(5), "-"),
+        );
+        let e = create_module(
+            &data,
+            &Ordinals::test(&data),
+            &Plurals::test(&data),
+            &LocaleTags::default(),
+        )
+        .unwrap_err();
+        let errors = &e.downcast_ref::<DataErrors>().unwrap().0;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].identifier, "yy");
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+
+use crate::utils::{escape, Format, Symbols};
+
+/// A difference between the locale data of two CLDR versions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Change {
+    /// A locale only the new version has.
+    Added(String),
+    /// A locale only the old version has.
+    Removed(String),
+    /// A locale whose symbol, grouping or display name differs between the versions.
+    Changed {
+        /// The CLDR identifier of the locale (e.g. "de-AT").
+        identifier: String,
+        /// The name of what changed (e.g. "separator", or "arab separator" for a symbol of a
+        /// numbering system other than the locale's default).
+        field: String,
+        /// The old value.
+        old: String,
+        /// The new value.
+        new: String,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added(identifier) => write!(f, "+ {}", identifier),
+            Change::Removed(identifier) => write!(f, "- {}", identifier),
+            Change::Changed {
+                identifier,
+                field,
+                old,
+                new,
+            } => write!(
+                f,
+                "~ {}: {} \"{}\" -> \"{}\"",
+                identifier,
+                field,
+                escape(old),
+                escape(new)
+            ),
+        }
+    }
+}
+
+/// Compares the maps `parse_data` returns for two CLDR versions, listing the locales that were
+/// added or removed and, field by field, the ones whose data changed.
+pub fn diff(old: &IndexMap<String, Format>, new: &IndexMap<String, Format>) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for (variant_name, old) in old {
+        let new = match new.get(variant_name) {
+            Some(new) => new,
+            None => {
+                changes.push(Change::Removed(old.identifier.clone()));
+                continue;
+            }
+        };
+        let mut fields = symbol_fields(&old.symbols(), &new.symbols())
+            .into_iter()
+            .map(|(field, old_value, new_value)| (field.to_string(), old_value, new_value))
+            .collect::<Vec<_>>();
+        fields.push((
+            "english_name".to_string(),
+            old.english_name.clone(),
+            new.english_name.clone(),
+        ));
+        fields.push((
+            "native_name".to_string(),
+            old.native_name.clone(),
+            new.native_name.clone(),
+        ));
+        // a numbering system missing from `numbering_systems` has the default symbols
+        let numbering_systems = old.numbering_systems.keys().chain(
+            new.numbering_systems
+                .keys()
+                .filter(|name| !old.numbering_systems.contains_key(*name)),
+        );
+        for name in numbering_systems {
+            let old_symbols = old.numbering_systems.get(name).cloned();
+            let new_symbols = new.numbering_systems.get(name).cloned();
+            let old_symbols = old_symbols.unwrap_or_else(|| old.symbols());
+            let new_symbols = new_symbols.unwrap_or_else(|| new.symbols());
+            for (field, old_value, new_value) in symbol_fields(&old_symbols, &new_symbols) {
+                fields.push((format!("{} {}", name, field), old_value, new_value));
+            }
+        }
+        for (field, old_value, new_value) in fields {
+            if old_value != new_value {
+                changes.push(Change::Changed {
+                    identifier: old.identifier.clone(),
+                    field,
+                    old: old_value,
+                    new: new_value,
+                });
+            }
+        }
+    }
+    for (variant_name, new) in new {
+        if !old.contains_key(variant_name) {
+            changes.push(Change::Added(new.identifier.clone()));
+        }
+    }
+    changes
+}
+
+/// Returns the name, old value and new value of each symbol.
+fn symbol_fields(old: &Symbols, new: &Symbols) -> [(&'static str, String, String); 7] {
+    [
+        ("decimal", old.dec.clone(), new.dec.clone()),
+        ("grouping", old.grp.to_string(), new.grp.to_string()),
+        ("infinity", old.inf.clone(), new.inf.clone()),
+        ("minus_sign", old.min.clone(), new.min.clone()),
+        ("nan", old.nan.clone(), new.nan.clone()),
+        ("plus_sign", old.pos.clone(), new.pos.clone()),
+        ("separator", old.sep.clone(), new.sep.clone()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let mut old = IndexMap::new();
+        old.insert("de".to_string(), Format::test("de", ".", "-"));
+        old.insert("xx".to_string(), Format::test("xx", ",", "-"));
+        let mut new = IndexMap::new();
+        new.insert("de".to_string(), Format::test("de", "\u{a0}", "\u{2212}"));
+        new.insert("yy".to_string(), Format::test("yy", ",", "-"));
+
+        let changes = diff(&old, &new);
+        let lines = changes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                "~ de: minus_sign \"-\" -> \"\\u{2212}\"",
+                "~ de: separator \".\" -> \"\\u{a0}\"",
+                "- xx",
+                "+ yy",
+            ]
+        );
+        assert!(diff(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_diff_numbering_systems() {
+        let mut de = Format::test("de", ".", "-");
+        let mut arab = de.symbols();
+        arab.dec = "\u{66b}".to_string();
+        de.numbering_systems
+            .insert("arab".to_string(), arab.clone());
+        let mut old = IndexMap::new();
+        old.insert("de".to_string(), de.clone());
+
+        arab.sep = "\u{66c}".to_string();
+        de.numbering_systems.insert("arab".to_string(), arab);
+        let mut new = IndexMap::new();
+        new.insert("de".to_string(), de.clone());
+        let lines = diff(&old, &new)
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec!["~ de: arab separator \".\" -> \"\\u{66c}\""]);
+
+        // a numbering system whose symbols became those of the default one
+        de.numbering_systems.clear();
+        new.insert("de".to_string(), de);
+        let lines = diff(&old, &new)
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec!["~ de: arab decimal \"\\u{66b}\" -> \".\""]);
+    }
+}
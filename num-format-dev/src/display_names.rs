@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+/// The language, script, region and variant subtags of a CLDR locale identifier.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Subtags {
+    pub(crate) language: String,
+    pub(crate) script: Option<String>,
+    pub(crate) region: Option<String>,
+    pub(crate) variant: Option<String>,
+}
+
+impl Subtags {
+    /// Splits an identifier such as "sr-Latn-BA" or "ca-ES-VALENCIA" into its subtags.
+    pub(crate) fn parse(identifier: &str) -> Result<Subtags, anyhow::Error> {
+        let mut parts = identifier.split('-').peekable();
+        let language = parts.next().unwrap().to_string();
+        let script = parts
+            .next_if(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_alphabetic()))
+            .map(String::from);
+        let region = parts
+            .next_if(|s| {
+                (s.len() == 2 && s.chars().all(|c| c.is_ascii_uppercase()))
+                    || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit()))
+            })
+            .map(String::from);
+        let variant = parts.next().map(String::from);
+        if parts.next().is_some() {
+            anyhow::bail!("Could not parse {} into subtags", identifier);
+        }
+        Ok(Subtags {
+            language,
+            script,
+            region,
+            variant,
+        })
+    }
+}
+
+/// The localeDisplayNames data of one locale in the cldr-localenames-full package.
+#[derive(Debug, Default)]
+pub(crate) struct DisplayNames {
+    languages: HashMap<String, String>,
+    scripts: HashMap<String, String>,
+    territories: HashMap<String, String>,
+    variants: HashMap<String, String>,
+    pattern: Option<LocaleDisplayPattern>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LocaleDisplayPattern {
+    locale_pattern: String,
+    locale_separator: String,
+}
+
+impl DisplayNames {
+    /// Reads the display names of `identifier` from a cldr-localenames-full directory. Returns
+    /// `None` if the package has no data for that locale.
+    pub(crate) fn read<P>(data_dir: P, identifier: &str) -> Result<Option<Self>, anyhow::Error>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = data_dir.as_ref().join("main").join(identifier);
+        if !dir.is_dir() {
+            return Ok(None);
+        }
+        Ok(Some(DisplayNames {
+            languages: read_field(&dir, identifier, "languages")?.unwrap_or_default(),
+            scripts: read_field(&dir, identifier, "scripts")?.unwrap_or_default(),
+            territories: read_field(&dir, identifier, "territories")?.unwrap_or_default(),
+            variants: read_field(&dir, identifier, "variants")?.unwrap_or_default(),
+            pattern: read_field(&dir, identifier, "localeDisplayPattern")?,
+        }))
+    }
+
+    /// Composes the display name of a locale following the algorithm in UTS #35: the longest
+    /// combination of the language with its script and/or region that has a name of its own,
+    /// followed by the names of the remaining subtags in parentheses (e.g. "American English
+    /// (Computer)" for "en-US-POSIX"). Returns `None` if the language has no name.
+    pub(crate) fn display_name(&self, subtags: &Subtags) -> Option<String> {
+        let Subtags {
+            language,
+            script,
+            region,
+            variant,
+        } = subtags;
+
+        let mut candidates = Vec::new();
+        if let (Some(script), Some(region)) = (script, region) {
+            candidates.push((format!("{}-{}-{}", language, script, region), false, false));
+        }
+        if let Some(region) = region {
+            candidates.push((format!("{}-{}", language, region), script.is_some(), false));
+        }
+        if let Some(script) = script {
+            candidates.push((format!("{}-{}", language, script), false, region.is_some()));
+        }
+        candidates.push((language.clone(), script.is_some(), region.is_some()));
+
+        let (name, with_script, with_region) = candidates
+            .iter()
+            .find_map(|(key, s, r)| self.languages.get(key).map(|name| (name, *s, *r)))?;
+
+        let mut extras = Vec::new();
+        if let (true, Some(script)) = (with_script, script) {
+            extras.push(self.scripts.get(script).unwrap_or(script));
+        }
+        if let (true, Some(region)) = (with_region, region) {
+            extras.push(self.territories.get(region).unwrap_or(region));
+        }
+        if let Some(variant) = variant {
+            extras.push(self.variants.get(variant).unwrap_or(variant));
+        }
+        if extras.is_empty() {
+            return Some(name.clone());
+        }
+
+        let (pattern, separator) = match &self.pattern {
+            Some(pattern) => (&pattern.locale_pattern[..], &pattern.locale_separator[..]),
+            None => ("{0} ({1})", "{0}, {1}"),
+        };
+        let mut extras = extras.into_iter();
+        let first = extras.next().unwrap().clone();
+        let extras = extras.fold(first, |acc, extra| {
+            separator.replace("{0}", &acc).replace("{1}", extra)
+        });
+        Some(pattern.replace("{0}", name).replace("{1}", &extras))
+    }
+}
+
+fn read_field<T>(dir: &Path, identifier: &str, field: &str) -> Result<Option<T>, anyhow::Error>
+where
+    T: DeserializeOwned,
+{
+    let file = match field {
+        "localeDisplayPattern" => "localeDisplayNames.json".to_string(),
+        _ => format!("{}.json", field),
+    };
+    let path = dir.join(file);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(&path)?;
+    let value: serde_json::Value = serde_json::from_str(&contents)?;
+    let value = &value["main"][identifier]["localeDisplayNames"][field];
+    if value.is_null() {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_value(value.clone())?))
+}
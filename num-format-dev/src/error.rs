@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::utils::escape;
+
+/// A problem with the CLDR data of one locale (or one language, for its plural rules).
+#[derive(Debug)]
+pub struct DataError {
+    /// The CLDR identifier of the locale (e.g. "de-AT").
+    pub identifier: String,
+    /// What is wrong with it.
+    pub kind: DataErrorKind,
+}
+
+/// The kinds of [`DataError`].
+#[derive(Debug)]
+pub enum DataErrorKind {
+    /// Its cardinal plural rules could not be parsed, or disagree with their samples.
+    CardinalPluralRules(String),
+    /// Its display names could not be read.
+    DisplayNames(anyhow::Error),
+    /// Its identifier could not be split into subtags.
+    InvalidIdentifier,
+    /// Its `numbers.json` file could not be read.
+    Io(io::Error),
+    /// Its `numbers.json` file is not valid JSON, or a field has the wrong type.
+    Json(serde_json::Error),
+    /// A field num-format needs is missing (e.g. "numbers.defaultNumberingSystem").
+    MissingField(String),
+    /// CLDR has no English display name for it.
+    NoEnglishName,
+    /// Its ordinal plural rules could not be parsed, or disagree with their samples.
+    OrdinalPluralRules(String),
+    /// The ordinal rules of its RBNF file could not be parsed, or use features num-format does
+    /// not support.
+    OrdinalRules(String),
+    /// A symbol is longer than num-format can hold.
+    TooLong {
+        /// The name of the symbol (e.g. "separator").
+        symbol: &'static str,
+        /// The symbol itself.
+        value: String,
+        /// The maximum length of the symbol in bytes.
+        max_len: usize,
+    },
+    /// Its standard decimal pattern groups digits in a way num-format does not support.
+    UnsupportedGrouping(String),
+}
+
+impl DataError {
+    pub(crate) fn new(identifier: &str, kind: DataErrorKind) -> DataError {
+        DataError {
+            identifier: identifier.to_string(),
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use self::DataErrorKind::*;
+        write!(f, "{}: ", self.identifier)?;
+        match &self.kind {
+            CardinalPluralRules(e) => write!(f, "invalid cardinal plural rules: {}", e),
+            DisplayNames(e) => write!(f, "could not read display names: {:#}", e),
+            InvalidIdentifier => write!(f, "could not parse the identifier into subtags"),
+            Io(e) => write!(f, "could not read numbers.json: {}", e),
+            Json(e) => write!(f, "invalid numbers.json: {}", e),
+            MissingField(field) => write!(f, "missing {}", field),
+            NoEnglishName => write!(f, "no English display name"),
+            OrdinalPluralRules(e) => write!(f, "invalid ordinal plural rules: {}", e),
+            OrdinalRules(e) => write!(f, "invalid ordinal rules: {}", e),
+            TooLong {
+                symbol,
+                value,
+                max_len,
+            } => write!(
+                f,
+                "{} \"{}\" is {} bytes long, but num-format holds at most {}",
+                symbol,
+                escape(value),
+                value.len(),
+                max_len
+            ),
+            UnsupportedGrouping(pattern) => write!(f, "unsupported grouping in {:?}", pattern),
+        }
+    }
+}
+
+impl Error for DataError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            DataErrorKind::DisplayNames(e) => Some(e.as_ref()),
+            DataErrorKind::Io(e) => Some(e),
+            DataErrorKind::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// All the [`DataError`]s found in a set of CLDR data, one per line when displayed.
+#[derive(Debug)]
+pub struct DataErrors(pub Vec<DataError>);
+
+impl fmt::Display for DataErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let noun = if self.0.len() == 1 {
+            "problem"
+        } else {
+            "problems"
+        };
+        write!(f, "{} {} with the CLDR data:", self.0.len(), noun)?;
+        for error in &self.0 {
+            write!(f, "\n  {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for DataErrors {}
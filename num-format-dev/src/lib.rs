@@ -1,4 +1,8 @@
 //! Utility crate for programmatically generating a rust module / enum from CLDR json files.
+//!
+//! The `num-format-dev` binary regenerates num-format's `locale.rs` (`generate`), reports how
+//! the locale data of two CLDR versions differ (`diff`) and checks that every locale fits within
+//! num-format's limits (`validate`). See `num-format-dev --help`.
 
 #![deny(
     dead_code,
@@ -16,8 +20,23 @@
 #![recursion_limit = "256"]
 
 mod create_module;
+mod diff;
+mod display_names;
+mod error;
+mod locale_tags;
+mod ordinals;
 mod parse_data;
+mod plural_rules;
+mod plurals;
 mod utils;
+mod validate;
 
 pub use self::create_module::create_module;
+pub use self::diff::{diff, Change};
+pub use self::error::{DataError, DataErrorKind, DataErrors};
+pub use self::locale_tags::{parse_locale_tags, LocaleTags};
+pub use self::ordinals::{parse_ordinals, Ordinals};
 pub use self::parse_data::parse_data;
+pub use self::plurals::{parse_plurals, Plurals};
+pub use self::utils::{Format, Grouping};
+pub use self::validate::validate;
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::ordinals::read_parent_locales;
+use crate::utils::Format;
+
+/// The data num-format needs to resolve BCP 47 language tags and POSIX locale names to locales
+/// (e.g. `zh-TW` to `zh-Hant`), as read by [`parse_locale_tags`].
+#[derive(Debug, Default)]
+pub struct LocaleTags {
+    /// Deprecated language codes and their replacements (e.g. "iw" and "he"), sorted.
+    pub(crate) language_aliases: Vec<(String, String)>,
+    /// Languages, regions and the scripts they imply (e.g. "zh", "TW" and "Hant"), sorted.
+    pub(crate) likely_scripts: Vec<(String, String, String)>,
+    /// Locales and the parents they fall back to, where those differ from what truncating the
+    /// locale would give (e.g. "en-AU" and "en-001"), sorted.
+    pub(crate) parent_locales: Vec<(String, String)>,
+}
+
+/// Reads the language aliases of CLDR's `aliases.json`, the likely scripts of its
+/// `likelySubtags.json` and the parent locales of its `parentLocales.json` for the locales in
+/// `data`.
+///
+/// `core_dir` is a checkout of the cldr-core package. Only the aliases that replace one language
+/// subtag by the language of a locale in `data` are kept. Likely scripts are kept for the
+/// languages of the locales in `data`, and only for regions where they differ from the
+/// language's own (e.g. "az-IR" is written in Arabic script, so it must not fall back to `az`,
+/// which is written in Latin script).
+pub fn parse_locale_tags<P>(
+    core_dir: P,
+    data: &IndexMap<String, Format>,
+) -> Result<LocaleTags, anyhow::Error>
+where
+    P: AsRef<Path>,
+{
+    let supplemental = core_dir.as_ref().join("supplemental");
+
+    let aliases: AliasesJson = read_json(&supplemental.join("aliases.json"))?;
+    let mut language_aliases = aliases
+        .supplemental
+        .metadata
+        .alias
+        .language_alias
+        .into_iter()
+        .filter(|(alias, replacement)| {
+            is_language(alias)
+                && is_language(&replacement.replacement)
+                && !data.values().any(|format| &format.language == alias)
+                && data
+                    .values()
+                    .any(|format| format.language == replacement.replacement)
+        })
+        .map(|(alias, replacement)| (alias, replacement.replacement))
+        .collect::<Vec<_>>();
+    language_aliases.sort();
+
+    let likely: LikelySubtagsJson = read_json(&supplemental.join("likelySubtags.json"))?;
+    let likely = likely.supplemental.likely_subtags;
+    let script_of = |tag: &str| tag.split('-').nth(1).map(str::to_string);
+    let mut likely_scripts = Vec::new();
+    for (tag, likely_tag) in &likely {
+        let (language, region) = match tag.split_once('-') {
+            Some((language, region)) if is_region(region) => (language, region),
+            _ => continue,
+        };
+        let script = match script_of(likely_tag) {
+            Some(script) => script,
+            None => continue,
+        };
+        if likely.get(language).and_then(|tag| script_of(tag)).as_ref() == Some(&script) {
+            continue;
+        }
+        if !data.values().any(|format| format.language == language) {
+            continue;
+        }
+        likely_scripts.push((language.to_string(), region.to_string(), script));
+    }
+    likely_scripts.sort();
+
+    let mut parent_locales = read_parent_locales(&supplemental.join("parentLocales.json"))?
+        .into_iter()
+        .collect::<Vec<_>>();
+    parent_locales.sort();
+
+    Ok(LocaleTags {
+        language_aliases,
+        likely_scripts,
+        parent_locales,
+    })
+}
+
+fn read_json<T>(path: &Path) -> Result<T, anyhow::Error>
+where
+    T: DeserializeOwned,
+{
+    let contents = fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Could not read {:?}: {}", path, e))?;
+    serde_json::from_str(&contents).map_err(|e| anyhow::anyhow!("Invalid {:?}: {}", path, e))
+}
+
+/// Returns `true` if `s` is a language subtag alone (e.g. "iw", but not "sr_Latn").
+fn is_language(s: &str) -> bool {
+    (2..=8).contains(&s.len()) && s.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+/// Returns `true` if `s` is a region subtag (e.g. "TW" or "419").
+fn is_region(s: &str) -> bool {
+    (s.len() == 2 && s.bytes().all(|b| b.is_ascii_uppercase()))
+        || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit()))
+}
+
+#[derive(Deserialize)]
+struct AliasesJson {
+    supplemental: AliasesSupplemental,
+}
+
+#[derive(Deserialize)]
+struct AliasesSupplemental {
+    metadata: Metadata,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    alias: Alias,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Alias {
+    language_alias: HashMap<String, Replacement>,
+}
+
+#[derive(Deserialize)]
+struct Replacement {
+    #[serde(rename = "_replacement")]
+    replacement: String,
+}
+
+#[derive(Deserialize)]
+struct LikelySubtagsJson {
+    supplemental: LikelySubtagsSupplemental,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LikelySubtagsSupplemental {
+    likely_subtags: HashMap<String, String>,
+}
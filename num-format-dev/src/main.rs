@@ -1,15 +1,119 @@
 use std::fs::File;
 use std::io::Write;
+use std::path::PathBuf;
 
-use num_format_dev::{create_module, parse_data};
+use clap::{Parser, Subcommand};
+use indexmap::IndexMap;
+use num_format_dev::{
+    create_module, diff, parse_data, parse_locale_tags, parse_ordinals, parse_plurals, validate,
+    DataErrors, Format,
+};
 
+const CORE_DIR: &str = "./num-format-dev/cldr-core";
 const DATA_DIR: &str = "./num-format-dev/cldr-numbers-full";
+const NAMES_DIR: &str = "./num-format-dev/cldr-localenames-full";
+const RBNF_DIR: &str = "./num-format-dev/cldr-rbnf";
 const OUT_PATH: &str = "./num-format/src/locale.rs";
 
+/// Generates num-format's locale data from CLDR json files.
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Regenerates locale.rs (after checking the data as `validate` does).
+    Generate {
+        /// Checkout of the cldr-numbers-full package.
+        #[arg(long, default_value = DATA_DIR)]
+        data_dir: PathBuf,
+        /// Checkout of the cldr-localenames-full package.
+        #[arg(long, default_value = NAMES_DIR)]
+        names_dir: PathBuf,
+        /// Checkout of the cldr-core package (for plural rules, language aliases, likely
+        /// subtags and parent locales).
+        #[arg(long, default_value = CORE_DIR)]
+        core_dir: PathBuf,
+        /// Checkout of the cldr-rbnf package (for ordinal rules).
+        #[arg(long, default_value = RBNF_DIR)]
+        rbnf_dir: PathBuf,
+        /// Where to write the module.
+        #[arg(long, default_value = OUT_PATH)]
+        out: PathBuf,
+    },
+    /// Reports the locales whose symbols, grouping or display names differ between two
+    /// checkouts of the cldr-numbers-full package, and those that were added or removed.
+    Diff {
+        /// The old checkout of the cldr-numbers-full package.
+        old_data_dir: PathBuf,
+        /// The new checkout of the cldr-numbers-full package.
+        new_data_dir: PathBuf,
+        /// Checkout of the cldr-localenames-full package to go with the old data.
+        #[arg(long, default_value = NAMES_DIR)]
+        old_names_dir: PathBuf,
+        /// Checkout of the cldr-localenames-full package to go with the new data.
+        #[arg(long, default_value = NAMES_DIR)]
+        new_names_dir: PathBuf,
+    },
+    /// Checks that every symbol of every locale fits within num-format's limits.
+    Validate {
+        /// Checkout of the cldr-numbers-full package.
+        #[arg(long, default_value = DATA_DIR)]
+        data_dir: PathBuf,
+        /// Checkout of the cldr-localenames-full package.
+        #[arg(long, default_value = NAMES_DIR)]
+        names_dir: PathBuf,
+    },
+}
+
 fn main() -> Result<(), anyhow::Error> {
-    let data = parse_data(DATA_DIR)?;
-    let s = create_module(&data)?;
-    let mut f = File::create(OUT_PATH)?;
-    f.write_all(s.as_bytes())?;
+    match Cli::parse().command {
+        Command::Generate {
+            data_dir,
+            names_dir,
+            core_dir,
+            rbnf_dir,
+            out,
+        } => {
+            let data = parse_data(data_dir, names_dir)?;
+            let ordinals = parse_ordinals(&core_dir, rbnf_dir, &data)?;
+            let plurals = parse_plurals(&core_dir, &data)?;
+            let tags = parse_locale_tags(&core_dir, &data)?;
+            let s = create_module(&data, &ordinals, &plurals, &tags)?;
+            let mut f = File::create(out)?;
+            f.write_all(s.as_bytes())?;
+        }
+        Command::Diff {
+            old_data_dir,
+            new_data_dir,
+            old_names_dir,
+            new_names_dir,
+        } => {
+            let old = parse_data(old_data_dir, old_names_dir)?;
+            let new = parse_data(new_data_dir, new_names_dir)?;
+            for change in diff(&old, &new) {
+                println!("{}", change);
+            }
+        }
+        Command::Validate {
+            data_dir,
+            names_dir,
+        } => {
+            let data = parse_data(data_dir, names_dir)?;
+            check(&data)?;
+            println!("All {} locales are valid", data.len());
+        }
+    }
+    Ok(())
+}
+
+/// Fails, listing them, if any symbols are too long for num-format.
+fn check(data: &IndexMap<String, Format>) -> Result<(), anyhow::Error> {
+    let errors = validate(data);
+    if !errors.is_empty() {
+        return Err(DataErrors(errors).into());
+    }
     Ok(())
 }
@@ -0,0 +1,528 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::error::{DataError, DataErrorKind, DataErrors};
+use crate::plural_rules::{read_plural_rules, PluralRules, PluralType, CATEGORIES};
+use crate::utils::Format;
+
+/// The rule set of a CLDR RBNF file that formats numbers as ordinals written with digits.
+const DIGITS_ORDINAL: &str = "%digits-ordinal";
+
+/// How each locale writes ordinals (e.g. "1st" or "1."), as read by [`parse_ordinals`].
+#[derive(Debug, Default)]
+pub struct Ordinals {
+    /// The ordinal plural rules of each locale, keyed like the map returned by `parse_data`.
+    pub(crate) plural_rules: IndexMap<String, PluralRules>,
+    /// The index into `rule_sets` of the `%digits-ordinal` rule set of each locale, keyed like
+    /// the map returned by `parse_data`.
+    pub(crate) rule_set_indices: IndexMap<String, usize>,
+    /// The `%digits-ordinal` rule sets, and the rule sets they refer to.
+    pub(crate) rule_sets: Vec<Vec<Rule>>,
+}
+
+/// A rule of an RBNF rule set (e.g. `20: →→;`), which applies to the numbers from its base value
+/// up to the base value of the next rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Rule {
+    pub(crate) base: u64,
+    pub(crate) pieces: Vec<Piece>,
+}
+
+/// The parts of the text of a [`Rule`] that num-format supports.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Piece {
+    /// The number, formatted with the grouping of the locale (`=#,##0=`).
+    Number,
+    /// Literal text.
+    Text(String),
+    /// Text chosen by the ordinal plural category of the number, one per category in the
+    /// order of `CATEGORIES` (`$(ordinal,one{st}other{th})$`).
+    Plural([String; 6]),
+    /// The number formatted with another rule set (`=%%name=`).
+    RuleSet(usize),
+    /// The remainder of dividing the number by a power of ten formatted with a rule set
+    /// (`→→` or `→%%name→`).
+    Remainder { rule_set: usize, divisor: u64 },
+}
+
+/// Reads the ordinal plural rules of CLDR's `ordinals.json` and the `%digits-ordinal` rule sets
+/// of its RBNF data for every locale in `data`.
+///
+/// `core_dir` is a checkout of the cldr-core package and `rbnf_dir` one of the cldr-rbnf
+/// package. Like CLDR, a locale without RBNF data of its own inherits that of its parent locale
+/// and ultimately "root" (which writes ordinals as e.g. "1."), and one without plural rules of
+/// its own those of its language. Numbers are
+/// always written with the grouping of the locale and negative numbers with its minus sign, so
+/// the rules CLDR has for negative numbers and fractions are ignored.
+///
+/// Problems with the plural rules of a language or the rule sets of an RBNF file are collected
+/// and returned together as [`DataErrors`].
+pub fn parse_ordinals<P, Q>(
+    core_dir: P,
+    rbnf_dir: Q,
+    data: &IndexMap<String, Format>,
+) -> Result<Ordinals, anyhow::Error>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let supplemental = core_dir.as_ref().join("supplemental");
+    let parents = read_parent_locales(&supplemental.join("parentLocales.json"))?;
+
+    let mut errors = Vec::new();
+    let plural_rules =
+        read_plural_rules(core_dir.as_ref(), PluralType::Ordinal, data, &mut errors)?;
+
+    let mut rbnf = Rbnf {
+        dir: rbnf_dir.as_ref().join("rbnf"),
+        files: HashMap::new(),
+        indices: HashMap::new(),
+        rule_sets: Vec::new(),
+    };
+    let mut ordinals = Ordinals {
+        plural_rules,
+        ..Ordinals::default()
+    };
+    for (variant_name, format) in data {
+        for identifier in &fallback_chain(&format.identifier, &parents) {
+            match rbnf.digits_ordinal(identifier) {
+                Ok(Some(index)) => {
+                    let _ = ordinals
+                        .rule_set_indices
+                        .insert(variant_name.clone(), index);
+                    break;
+                }
+                Ok(None) => continue,
+                Err(kind) => {
+                    if !errors
+                        .iter()
+                        .any(|e: &DataError| &e.identifier == identifier)
+                    {
+                        errors.push(DataError::new(identifier, kind));
+                    }
+                    break;
+                }
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return Err(DataErrors(errors).into());
+    }
+    if let Some(variant_name) = data
+        .keys()
+        .find(|key| !ordinals.rule_set_indices.contains_key(*key))
+    {
+        anyhow::bail!(
+            "No {} rule set for {} or \"root\" in {:?}",
+            DIGITS_ORDINAL,
+            variant_name,
+            rbnf.dir
+        );
+    }
+    ordinals.rule_sets = rbnf
+        .rule_sets
+        .into_iter()
+        .map(Option::unwrap_or_default)
+        .collect();
+    Ok(ordinals)
+}
+
+#[cfg(test)]
+impl Ordinals {
+    /// Returns ordinals that write every locale in `data` as "root" does (e.g. "1.").
+    pub(crate) fn test(data: &IndexMap<String, Format>) -> Ordinals {
+        let rule = Rule {
+            base: 0,
+            pieces: vec![Piece::Number, Piece::Text(".".to_string())],
+        };
+        Ordinals {
+            plural_rules: data
+                .keys()
+                .map(|key| (key.clone(), PluralRules::default()))
+                .collect(),
+            rule_set_indices: data.keys().map(|key| (key.clone(), 0)).collect(),
+            rule_sets: vec![vec![rule]],
+        }
+    }
+}
+
+/// Returns `identifier` followed by the locales it inherits from, ending with "root" (e.g.
+/// "de-AT", "de", "root").
+pub(crate) fn fallback_chain(identifier: &str, parents: &HashMap<String, String>) -> Vec<String> {
+    let mut chain = vec![identifier.to_string()];
+    let mut current = identifier.to_string();
+    while current != "root" {
+        current = match parents.get(&current) {
+            Some(parent) => parent.clone(),
+            None => match current.rfind('-') {
+                Some(i) => current[..i].to_string(),
+                None => "root".to_string(),
+            },
+        };
+        chain.push(current.clone());
+    }
+    chain
+}
+
+pub(crate) fn read_parent_locales(path: &Path) -> Result<HashMap<String, String>, anyhow::Error> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Could not read {:?}: {}", path, e))?;
+    let json: ParentLocalesJson = serde_json::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("Invalid {:?}: {}", path, e))?;
+    Ok(json.supplemental.parent_locales.parent_locale)
+}
+
+/// The rules of the rule sets of an RBNF file by name, as pairs of a base value and a text.
+type RuleSets = HashMap<String, Vec<(String, String)>>;
+
+/// The RBNF files read so far and the rule sets found in them.
+struct Rbnf {
+    dir: PathBuf,
+    /// The ordinal rule sets of each file, by name, or `None` if there is no such file.
+    files: HashMap<String, Option<RuleSets>>,
+    /// The index into `rule_sets` of each rule set, by file and name.
+    indices: HashMap<(String, String), usize>,
+    /// The rule sets, `None` while being parsed (they can refer to each other).
+    rule_sets: Vec<Option<Vec<Rule>>>,
+}
+
+impl Rbnf {
+    /// Returns the index of the `%digits-ordinal` rule set of the RBNF file of `identifier`, if
+    /// it has one.
+    fn digits_ordinal(&mut self, identifier: &str) -> Result<Option<usize>, DataErrorKind> {
+        if !self.files.contains_key(identifier) {
+            let rule_sets = self.read(identifier)?;
+            let _ = self.files.insert(identifier.to_string(), rule_sets);
+        }
+        match &self.files[identifier] {
+            Some(rule_sets) if rule_sets.contains_key(DIGITS_ORDINAL) => self
+                .rule_set(identifier, DIGITS_ORDINAL)
+                .map(Some)
+                .map_err(DataErrorKind::OrdinalRules),
+            _ => Ok(None),
+        }
+    }
+
+    fn read(&self, identifier: &str) -> Result<Option<RuleSets>, DataErrorKind> {
+        let path = self.dir.join(format!("{}.json", identifier));
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(DataErrorKind::Io(e)),
+        };
+        let value: serde_json::Value =
+            serde_json::from_str(&contents).map_err(DataErrorKind::Json)?;
+        let rule_sets = &value["rbnf"]["rbnf"]["OrdinalRules"];
+        if rule_sets.is_null() {
+            return Ok(Some(HashMap::new()));
+        }
+        let rule_sets = RuleSets::deserialize(rule_sets).map_err(DataErrorKind::Json)?;
+        Ok(Some(rule_sets))
+    }
+
+    /// Returns the index of the rule set `name` of the RBNF file of `identifier`, parsing it
+    /// (and the rule sets it refers to) if it hasn't been already.
+    fn rule_set(&mut self, identifier: &str, name: &str) -> Result<usize, String> {
+        let key = (identifier.to_string(), name.to_string());
+        if let Some(index) = self.indices.get(&key) {
+            return Ok(*index);
+        }
+        let rules = self.files[identifier]
+            .as_ref()
+            .and_then(|rule_sets| rule_sets.get(name))
+            .cloned()
+            .ok_or_else(|| format!("no rule set {}", name))?;
+        let index = self.rule_sets.len();
+        self.rule_sets.push(None);
+        let _ = self.indices.insert(key, index);
+        let parsed = self
+            .parse_rules(identifier, index, &rules)
+            .map_err(|e| format!("{}: {}", name, e))?;
+        self.rule_sets[index] = Some(parsed);
+        Ok(index)
+    }
+
+    fn parse_rules(
+        &mut self,
+        identifier: &str,
+        index: usize,
+        rules: &[(String, String)],
+    ) -> Result<Vec<Rule>, String> {
+        let mut parsed = Vec::new();
+        for (base, text) in rules {
+            // Negative numbers and fractions are formatted by num-format itself
+            if matches!(base.as_str(), "-x" | "x.x" | "0.x" | "x.0" | "Inf" | "NaN") {
+                continue;
+            }
+            let base = base
+                .parse()
+                .map_err(|_| format!("unsupported base value {:?}", base))?;
+            let pieces = self
+                .parse_rule(identifier, index, base, text)
+                .map_err(|e| format!("{} in {:?}", e, text))?;
+            parsed.push(Rule { base, pieces });
+        }
+        if parsed.first().map(|rule| rule.base) != Some(0) {
+            return Err("no rule for 0".to_string());
+        }
+        Ok(parsed)
+    }
+
+    /// Parses the text of a rule of the rule set `rule_set` (e.g.
+    /// `=#,##0=$(ordinal,one{st}other{th})$;`).
+    fn parse_rule(
+        &mut self,
+        identifier: &str,
+        rule_set: usize,
+        base: u64,
+        text: &str,
+    ) -> Result<Vec<Piece>, String> {
+        let text = text.strip_suffix(';').unwrap_or(text);
+        // A leading apostrophe only serves to keep leading white space
+        let text = text.strip_prefix('\'').unwrap_or(text);
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let piece = if let Some(after) = rest.strip_prefix("$(") {
+                let end = after.find(")$").ok_or("unterminated \"$(\"")?;
+                rest = &after[end + 2..];
+                parse_plural(&after[..end])?
+            } else if c == '=' || c == '→' {
+                let after = &rest[c.len_utf8()..];
+                let end = after
+                    .find(c)
+                    .ok_or_else(|| format!("unterminated {:?}", c))?;
+                let inner = &after[..end];
+                rest = &after[end + c.len_utf8()..];
+                match (c, inner) {
+                    ('=', "#,##0" | "#,##,##0") => Piece::Number,
+                    ('=', name) if name.starts_with('%') => {
+                        Piece::RuleSet(self.rule_set(identifier, name)?)
+                    }
+                    ('→', "") => Piece::Remainder {
+                        rule_set,
+                        divisor: divisor(base),
+                    },
+                    ('→', name) if name.starts_with('%') => Piece::Remainder {
+                        rule_set: self.rule_set(identifier, name)?,
+                        divisor: divisor(base),
+                    },
+                    _ => return Err(format!("unsupported substitution {}{}{}", c, inner, c)),
+                }
+            } else if matches!(c, '←' | '[' | ']' | '>' | '<') {
+                return Err(format!("unsupported {:?}", c));
+            } else {
+                literal.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            };
+            if !literal.is_empty() {
+                pieces.push(Piece::Text(std::mem::take(&mut literal)));
+            }
+            pieces.push(piece);
+        }
+        if !literal.is_empty() {
+            pieces.push(Piece::Text(literal));
+        }
+        Ok(pieces)
+    }
+}
+
+/// Returns the power of ten that a `→→` substitution in a rule with the given base value divides
+/// the number by, which is the largest one not greater than the base value.
+fn divisor(base: u64) -> u64 {
+    let mut divisor = 1;
+    while divisor <= base / 10 {
+        divisor *= 10;
+    }
+    divisor
+}
+
+/// Parses the inside of `$(ordinal,one{st}two{nd}few{rd}other{th})$`.
+fn parse_plural(s: &str) -> Result<Piece, String> {
+    let rest = s
+        .strip_prefix("ordinal,")
+        .ok_or_else(|| format!("unsupported \"$({})$\"", s))?;
+    let mut texts: [Option<String>; 6] = Default::default();
+    for part in rest.split_terminator('}') {
+        let (category, text) = part
+            .split_once('{')
+            .ok_or_else(|| format!("invalid \"$({})$\"", s))?;
+        let index = CATEGORIES
+            .iter()
+            .position(|c| *c == category)
+            .ok_or_else(|| format!("unknown plural category {:?}", category))?;
+        texts[index] = Some(text.to_string());
+    }
+    let other = texts[5]
+        .clone()
+        .ok_or_else(|| format!("no \"other\" in \"$({})$\"", s))?;
+    Ok(Piece::Plural(
+        texts.map(|text| text.unwrap_or_else(|| other.clone())),
+    ))
+}
+
+#[derive(Deserialize)]
+struct ParentLocalesJson {
+    supplemental: ParentLocalesSupplemental,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParentLocalesSupplemental {
+    parent_locales: ParentLocales,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParentLocales {
+    parent_locale: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_rbnf(rule_sets: &[(&str, &[(&str, &str)])]) -> Rbnf {
+        let rule_sets = rule_sets
+            .iter()
+            .map(|(name, rules)| {
+                let rules = rules
+                    .iter()
+                    .map(|(base, text)| (base.to_string(), text.to_string()))
+                    .collect();
+                (name.to_string(), rules)
+            })
+            .collect();
+        let mut files = HashMap::new();
+        let _ = files.insert("xx".to_string(), Some(rule_sets));
+        Rbnf {
+            dir: PathBuf::new(),
+            files,
+            indices: HashMap::new(),
+            rule_sets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let mut rbnf = test_rbnf(&[
+            (
+                "%digits-ordinal",
+                &[
+                    ("-x", "−→→;"),
+                    ("0", "=#,##0=-=%%suffix=;"),
+                    ("1", "No. 1;"),
+                    ("2", "=#,##0=$(ordinal,one{st}other{th})$;"),
+                ],
+            ),
+            (
+                "%%suffix",
+                &[("0", "''inci;"), ("20", "→→;"), ("100", "→%%larger→;")],
+            ),
+            ("%%larger", &[("0", "тен;"), ("1", "→%%suffix→;")]),
+        ]);
+        assert_eq!(rbnf.digits_ordinal("xx").unwrap(), Some(0));
+        assert_eq!(rbnf.digits_ordinal("yy").unwrap(), None);
+        let rule_sets = rbnf.rule_sets.into_iter().flatten().collect::<Vec<_>>();
+        let th = || "th".to_string();
+        assert_eq!(
+            rule_sets,
+            vec![
+                vec![
+                    Rule {
+                        base: 0,
+                        pieces: vec![Piece::Number, Piece::Text("-".into()), Piece::RuleSet(1)],
+                    },
+                    Rule {
+                        base: 1,
+                        pieces: vec![Piece::Text("No. 1".into())],
+                    },
+                    Rule {
+                        base: 2,
+                        pieces: vec![
+                            Piece::Number,
+                            Piece::Plural([th(), "st".into(), th(), th(), th(), th()]),
+                        ],
+                    },
+                ],
+                vec![
+                    Rule {
+                        base: 0,
+                        pieces: vec![Piece::Text("'inci".into())],
+                    },
+                    Rule {
+                        base: 20,
+                        pieces: vec![Piece::Remainder {
+                            rule_set: 1,
+                            divisor: 10,
+                        }],
+                    },
+                    Rule {
+                        base: 100,
+                        pieces: vec![Piece::Remainder {
+                            rule_set: 2,
+                            divisor: 100,
+                        }],
+                    },
+                ],
+                vec![
+                    Rule {
+                        base: 0,
+                        pieces: vec![Piece::Text("тен".into())],
+                    },
+                    Rule {
+                        base: 1,
+                        pieces: vec![Piece::Remainder {
+                            rule_set: 1,
+                            divisor: 1,
+                        }],
+                    },
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rules_errors() {
+        let mut rbnf = test_rbnf(&[(
+            "%digits-ordinal",
+            &[("0", "=#,##0=$(cardinal,one{st}other{th})$;")],
+        )]);
+        let e = rbnf.digits_ordinal("xx").unwrap_err();
+        assert_eq!(
+            DataError::new("xx", e).to_string(),
+            "xx: invalid ordinal rules: %digits-ordinal: unsupported \"$(cardinal,one{st}other\
+             {th})$\" in \"=#,##0=$(cardinal,one{st}other{th})$;\""
+        );
+
+        let mut rbnf = test_rbnf(&[("%digits-ordinal", &[("1", "=#,##0=.;")])]);
+        let e = rbnf.digits_ordinal("xx").unwrap_err();
+        assert_eq!(
+            DataError::new("xx", e).to_string(),
+            "xx: invalid ordinal rules: %digits-ordinal: no rule for 0"
+        );
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let mut parents = HashMap::new();
+        let _ = parents.insert("en-GB".to_string(), "en-001".to_string());
+        let _ = parents.insert("az-Arab".to_string(), "root".to_string());
+        assert_eq!(
+            fallback_chain("en-GB", &parents),
+            ["en-GB", "en-001", "en", "root"]
+        );
+        assert_eq!(
+            fallback_chain("az-Arab-IR", &parents),
+            ["az-Arab-IR", "az-Arab", "root"]
+        );
+        assert_eq!(fallback_chain("root", &parents), ["root"]);
+    }
+}
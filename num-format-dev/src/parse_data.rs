@@ -6,89 +6,188 @@ use indexmap::IndexMap;
 use serde::Deserialize;
 use walkdir::WalkDir;
 
-use crate::utils::{Format, Grouping};
-
-const MAX_MIN_LEN: usize = 8;
-const MAX_POS_LEN: usize = 8;
+use crate::display_names::{DisplayNames, Subtags};
+use crate::error::{DataError, DataErrorKind, DataErrors};
+use crate::utils::{Format, Grouping, Symbols};
+use crate::validate::too_long;
+
+/// The decimal numbering systems of num-format's `NumberingSystem`, by CLDR name, in the order in
+/// which they are declared.
+pub(crate) const NUMBERING_SYSTEMS: &[&str] = &[
+    "arab", "arabext", "beng", "deva", "fullwide", "gujr", "guru", "khmr", "knda", "laoo", "latn",
+    "mlym", "mymr", "orya", "tamldec", "telu", "thai", "tibt",
+];
 
 /// Walks a directory containing CLDR json files and collects the data they contain into a map.
-pub fn parse_data<P>(data_dir: P) -> Result<IndexMap<String, Format>, anyhow::Error>
+///
+/// `data_dir` is a checkout of the cldr-numbers-full package and `names_dir` one of the
+/// cldr-localenames-full package, from which the English and native display names come. Besides
+/// the symbols of each locale's default numbering system, those of the other numbering systems in
+/// [`NUMBERING_SYSTEMS`] are read, and kept where they differ. Those with a symbol too long for
+/// num-format are left out, so that num-format uses the default numbering system's instead.
+///
+/// Problems with the data of individual locales are collected and returned together as
+/// [`DataErrors`]. Symbols are not checked against num-format's limits here; see
+/// [`validate`](crate::validate).
+pub fn parse_data<P, Q>(
+    data_dir: P,
+    names_dir: Q,
+) -> Result<IndexMap<String, Format>, anyhow::Error>
 where
     P: AsRef<Path>,
+    Q: AsRef<Path>,
 {
     let mut data: IndexMap<String, Format> = IndexMap::new();
+    let mut errors = Vec::new();
+    let english = DisplayNames::read(names_dir.as_ref(), "en")?
+        .ok_or_else(|| anyhow::anyhow!("No English display names in {:?}", names_dir.as_ref()))?;
 
     // Walk the data dir
     for entry in WalkDir::new(data_dir.as_ref()) {
-        let entry = entry.unwrap();
+        let entry = entry?;
 
         // Skip if we aren't dealing with a "numbers.json" file
         if !entry.file_type().is_file() || entry.file_name() != ffi::OsStr::new("numbers.json") {
             continue;
         }
 
-        // Read file
-        let contents = fs::read_to_string(entry.path()).unwrap();
-
-        // Get the variant name and identifier
+        // Get the identifier (the name of the directory the file is in)
         let identifier = entry
             .path()
             .parent()
-            .unwrap()
-            .file_name()
-            .unwrap()
-            .to_str()
-            .unwrap();
-        let variant_name = make_variant_name(identifier);
-
-        // Deserialize the json
-        let value: serde_json::Value = serde_json::from_str(&contents).unwrap();
-        let value = &value["main"][identifier];
-
-        // Get the symbols
-        let default_numbering_system =
-            &value["numbers"]["defaultNumberingSystem"].as_str().unwrap();
-        let symbols_lookup = format!("symbols-numberSystem-{}", default_numbering_system);
-        let symbols = &value["numbers"][&symbols_lookup].to_string();
-        let symbols: Symbols = serde_json::from_str(symbols).unwrap();
-
-        // Grouping
-        let decimal_formats_lookup =
-            format!("decimalFormats-numberSystem-{}", default_numbering_system);
-        let decimal_formats = &value["numbers"][&decimal_formats_lookup];
-        let grp = decimal_formats["standard"]
-            .as_str()
-            .unwrap()
-            .parse::<Grouping>()
-            .unwrap();
-
-        // Format
-        let format = Format {
-            identifier: identifier.to_string(),
-
-            dec: symbols.decimal,
-            grp,
-            inf: symbols.infinity,
-            min: {
-                let s = symbols.minus_sign.to_string();
-                assert!(s.len() <= MAX_MIN_LEN);
-                s
-            },
-            nan: symbols.nan,
-            pos: {
-                let s = symbols.plus_sign.to_string();
-                assert!(s.len() <= MAX_POS_LEN);
-                s
-            },
-            sep: symbols.group,
-        };
-
-        let _ = data.insert(variant_name, format);
+            .and_then(Path::file_name)
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        match parse_file(entry.path(), &identifier, &english, names_dir.as_ref()) {
+            Ok(format) => {
+                let variant_name = make_variant_name(&identifier);
+                let _ = data.insert(variant_name, format);
+            }
+            Err(kind) => errors.push(DataError::new(&identifier, kind)),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(DataErrors(errors).into());
+    }
+    if data.is_empty() {
+        anyhow::bail!("No numbers.json files in {:?}", data_dir.as_ref());
     }
     data.sort_by(|k1, _, k2, _| k1.cmp(k2));
     Ok(data)
 }
 
+/// Reads the `numbers.json` file of one locale.
+fn parse_file(
+    path: &Path,
+    identifier: &str,
+    english: &DisplayNames,
+    names_dir: &Path,
+) -> Result<Format, DataErrorKind> {
+    // Read and deserialize the json
+    let contents = fs::read_to_string(path).map_err(DataErrorKind::Io)?;
+    let value: serde_json::Value = serde_json::from_str(&contents).map_err(DataErrorKind::Json)?;
+    let value = &value["main"][identifier];
+
+    // Get the symbols of the default numbering system, and of the other numbering systems
+    // num-format supports where they differ (and fit)
+    let default_numbering_system = value["numbers"]["defaultNumberingSystem"]
+        .as_str()
+        .ok_or_else(|| missing(&["main", identifier, "numbers", "defaultNumberingSystem"]))?;
+    let symbols = parse_symbols(value, identifier, default_numbering_system)?.ok_or_else(|| {
+        let lookup = format!("symbols-numberSystem-{}", default_numbering_system);
+        missing(&["main", identifier, "numbers", &lookup])
+    })?;
+    let mut numbering_systems = IndexMap::new();
+    for &numbering_system in NUMBERING_SYSTEMS {
+        if numbering_system == default_numbering_system {
+            continue;
+        }
+        if let Some(other) = parse_symbols(value, identifier, numbering_system)? {
+            if other != symbols && too_long(&other).is_empty() {
+                let _ = numbering_systems.insert(numbering_system.to_string(), other);
+            }
+        }
+    }
+
+    // Display names (the native name falls back to the English one for locales that
+    // have no display names of their own, such as "root")
+    let subtags = Subtags::parse(identifier).map_err(|_| DataErrorKind::InvalidIdentifier)?;
+    let english_name = english
+        .display_name(&subtags)
+        .ok_or(DataErrorKind::NoEnglishName)?;
+    let native_name = DisplayNames::read(names_dir, identifier)
+        .map_err(DataErrorKind::DisplayNames)?
+        .and_then(|names| names.display_name(&subtags))
+        .unwrap_or_else(|| english_name.clone());
+
+    Ok(Format {
+        identifier: identifier.to_string(),
+        language: subtags.language,
+        script: subtags.script,
+        region: subtags.region,
+        variant: subtags.variant,
+        english_name,
+        native_name,
+
+        dec: symbols.dec,
+        grp: symbols.grp,
+        inf: symbols.inf,
+        min: symbols.min,
+        nan: symbols.nan,
+        pos: symbols.pos,
+        sep: symbols.sep,
+
+        numbering_systems,
+    })
+}
+
+/// Reads the symbols and grouping of a locale in a numbering system, or returns `None` if its
+/// `numbers.json` file has no symbols for the numbering system.
+fn parse_symbols(
+    value: &serde_json::Value,
+    identifier: &str,
+    numbering_system: &str,
+) -> Result<Option<Symbols>, DataErrorKind> {
+    let symbols_lookup = format!("symbols-numberSystem-{}", numbering_system);
+    let symbols = &value["numbers"][&symbols_lookup];
+    if symbols.is_null() {
+        return Ok(None);
+    }
+    let symbols = SymbolsJson::deserialize(symbols).map_err(DataErrorKind::Json)?;
+
+    // Grouping
+    let decimal_formats_lookup = format!("decimalFormats-numberSystem-{}", numbering_system);
+    let pattern = value["numbers"][&decimal_formats_lookup]["standard"]
+        .as_str()
+        .ok_or_else(|| {
+            missing(&[
+                "main",
+                identifier,
+                "numbers",
+                &decimal_formats_lookup,
+                "standard",
+            ])
+        })?;
+    let grp = pattern
+        .parse::<Grouping>()
+        .map_err(|_| DataErrorKind::UnsupportedGrouping(pattern.to_string()))?;
+
+    Ok(Some(Symbols {
+        dec: symbols.decimal,
+        grp,
+        inf: symbols.infinity,
+        min: symbols.minus_sign,
+        nan: symbols.nan,
+        pos: symbols.plus_sign,
+        sep: symbols.group,
+    }))
+}
+
+fn missing(field: &[&str]) -> DataErrorKind {
+    DataErrorKind::MissingField(field.join("."))
+}
+
 fn make_variant_name(identifier: &str) -> String {
     let mut buf = String::new();
 
@@ -114,10 +213,10 @@ fn make_variant_name(identifier: &str) -> String {
 
 #[derive(Debug, Eq, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct Symbols {
-    decimal: char,
+struct SymbolsJson {
+    decimal: String,
     exponential: String,
-    group: char,
+    group: String,
     infinity: String,
     minus_sign: String,
     nan: String,
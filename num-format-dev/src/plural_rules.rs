@@ -0,0 +1,633 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use indexmap::IndexMap;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+use serde::Deserialize;
+
+use crate::error::{DataError, DataErrorKind};
+use crate::ordinals::fallback_chain;
+use crate::utils::Format;
+
+/// CLDR's plural categories, in its order. "other" is last and applies when no rule does.
+pub(crate) const CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+const OTHER: usize = 5;
+
+/// The plural rules of a language, e.g. `one: n % 10 = 1 and n % 100 != 11` for the ordinals of
+/// English, as an index into [`CATEGORIES`] and a condition for each category but "other".
+///
+/// num-format never writes numbers with an exponent, so the operands `c` and `e` are always 0
+/// and the rules are evaluated (and generated) that way.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub(crate) struct PluralRules(Vec<(usize, Condition)>);
+
+/// Relations joined by "and", joined by "or".
+type Condition = Vec<Vec<Relation>>;
+
+/// A relation such as `n % 100 != 11..13,15`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Relation {
+    operand: char,
+    modulus: Option<u128>,
+    negated: bool,
+    ranges: Vec<(u128, u128)>,
+}
+
+/// Which of CLDR's plural rules to read: those for counting things (`plurals.json`) or those for
+/// ordinals (`ordinals.json`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum PluralType {
+    Cardinal,
+    Ordinal,
+}
+
+/// The numbers generated rules are evaluated for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Domain {
+    /// Integers, given as `n: u128` (all the operands for fractions are 0).
+    Integers,
+    /// Decimals, given as `o: &PluralOperands`.
+    Decimals,
+}
+
+/// The operands of a decimal number (e.g. "1.50") that plural rules test, as defined by
+/// [UTS #35](https://unicode.org/reports/tr35/tr35-numbers.html#Operands), but for `c` and `e`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Operands {
+    i: u128,
+    v: u128,
+    w: u128,
+    f: u128,
+    t: u128,
+}
+
+impl Operands {
+    /// Returns the operands of the number with the given digits and `v` fraction digits (e.g.
+    /// 150 and 2 for "1.50").
+    fn decimal(digits: u128, v: u32) -> Operands {
+        let divisor = 10u128.pow(v);
+        let f = digits % divisor;
+        let fraction = format!("{:0width$}", f, width = v as usize);
+        let significant = fraction.trim_end_matches('0');
+        Operands {
+            i: digits / divisor,
+            v: u128::from(v),
+            w: significant.len() as u128,
+            f,
+            t: significant.parse().unwrap_or(0),
+        }
+    }
+
+    /// Parses a decimal such as "1.50" into its digits and number of fraction digits.
+    fn parse(s: &str) -> Option<(u128, u32)> {
+        let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+        if integer.is_empty() || !s.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+            return None;
+        }
+        let digits = format!("{}{}", integer, fraction).parse().ok()?;
+        Some((digits, fraction.len() as u32))
+    }
+}
+
+/// Reads CLDR's plural rules of the given type from the cldr-core checkout `core_dir` and
+/// returns those of every locale in `data`, keyed like `data`. Plural rules belong to languages
+/// (and a few regional variants such as "pt-PT"), so a locale without rules of its own has those
+/// of its language, whatever `parentLocales.json` says about the rest of its data.
+///
+/// Problems with the rules of a language are added to `errors`.
+pub(crate) fn read_plural_rules(
+    core_dir: &Path,
+    plural_type: PluralType,
+    data: &IndexMap<String, Format>,
+    errors: &mut Vec<DataError>,
+) -> Result<IndexMap<String, PluralRules>, anyhow::Error> {
+    let file_name = match plural_type {
+        PluralType::Cardinal => "plurals.json",
+        PluralType::Ordinal => "ordinals.json",
+    };
+    let path = core_dir.join("supplemental").join(file_name);
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("Could not read {:?}: {}", path, e))?;
+    let json: PluralsJson = serde_json::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("Invalid {:?}: {}", path, e))?;
+    let languages = match plural_type {
+        PluralType::Cardinal => &json.supplemental.cardinal,
+        PluralType::Ordinal => &json.supplemental.ordinal,
+    };
+
+    let mut by_language = HashMap::new();
+    for (language, rules) in languages {
+        let rules = rules.iter().map(|(key, rule)| {
+            let category = key.trim_start_matches("pluralRule-count-");
+            (category, rule.as_str())
+        });
+        match PluralRules::parse(rules) {
+            Ok(rules) => {
+                let _ = by_language.insert(language.as_str(), rules);
+            }
+            Err(e) => {
+                let kind = match plural_type {
+                    PluralType::Cardinal => DataErrorKind::CardinalPluralRules(e),
+                    PluralType::Ordinal => DataErrorKind::OrdinalPluralRules(e),
+                };
+                errors.push(DataError::new(language, kind));
+            }
+        }
+    }
+    Ok(data
+        .iter()
+        .map(|(variant_name, format)| {
+            let rules = fallback_chain(&format.identifier, &HashMap::new())
+                .iter()
+                .find_map(|identifier| by_language.get(identifier.as_str()))
+                .cloned()
+                .unwrap_or_default();
+            (variant_name.clone(), rules)
+        })
+        .collect())
+}
+
+impl PluralRules {
+    /// Parses the rules of one language from CLDR's `plurals.json` or `ordinals.json`, given as
+    /// pairs of a category and a rule (e.g. `("one", "n = 1 @integer 1")`), checking each rule
+    /// against the samples that follow its `@integer` and `@decimal`.
+    pub(crate) fn parse<'a, I>(rules: I) -> Result<PluralRules, String>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut parsed = Vec::new();
+        let mut samples = Vec::new();
+        for (category, rule) in rules {
+            let index = CATEGORIES
+                .iter()
+                .position(|c| *c == category)
+                .ok_or_else(|| format!("unknown plural category {:?}", category))?;
+            let (condition, rest) = match rule.find('@') {
+                Some(at) => (&rule[..at], &rule[at..]),
+                None => (rule, ""),
+            };
+            if index != OTHER {
+                let condition = parse_condition(condition)
+                    .map_err(|e| format!("{} in the rule for {:?}: {:?}", e, category, rule))?;
+                parsed.push((index, condition));
+            }
+            samples.push((index, category, rest));
+        }
+        parsed.sort_by_key(|(index, _)| *index);
+        let rules = PluralRules(parsed);
+
+        for (index, category, rest) in samples {
+            for (sample, operands) in parse_samples(rest)? {
+                if rules.category(&operands) != index {
+                    return Err(format!(
+                        "{} is a sample of {:?}, but the rules put it in {:?}",
+                        sample,
+                        category,
+                        CATEGORIES[rules.category(&operands)]
+                    ));
+                }
+            }
+        }
+        Ok(rules)
+    }
+
+    /// Returns the index into [`CATEGORIES`] of the category of a number.
+    pub(crate) fn category(&self, operands: &Operands) -> usize {
+        self.0
+            .iter()
+            .find(|(_, condition)| {
+                condition
+                    .iter()
+                    .any(|and| and.iter().all(|relation| relation.holds(operands)))
+            })
+            .map_or(OTHER, |(index, _)| *index)
+    }
+
+    /// Returns the rules as an expression of type `PluralCategory` in terms of `n: u128` or
+    /// `o: &PluralOperands`, depending on `domain`.
+    pub(crate) fn to_tokens(&self, domain: Domain) -> TokenStream {
+        let mut conditions = Vec::new();
+        let mut categories = Vec::new();
+        let mut last = category_ident(OTHER);
+        for (index, condition) in &self.0 {
+            let ors = condition
+                .iter()
+                .filter_map(|and| {
+                    and.iter()
+                        .filter_map(|relation| match relation.to_tokens(domain) {
+                            Ok(tokens) => Some(Ok(tokens)),
+                            Err(true) => None,
+                            Err(false) => Some(Err(())),
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                        .ok()
+                })
+                .collect::<Vec<_>>();
+            if ors.is_empty() {
+                // Never holds in the domain
+                continue;
+            }
+            if ors.iter().any(Vec::is_empty) {
+                // Always holds in the domain
+                last = category_ident(*index);
+                break;
+            }
+            let ors = ors.iter().map(|and| {
+                // A relation on `n` of a decimal is a disjunction if negated
+                // (`(o.t != 0 || o.i != 1)`), which needs parentheses among others
+                let and = and.iter().map(|(tokens, disjunction)| {
+                    if *disjunction && and.len() > 1 {
+                        quote! { (#tokens) }
+                    } else {
+                        tokens.clone()
+                    }
+                });
+                quote! { #(#and)&&* }
+            });
+            conditions.push(quote! { #(#ors)||* });
+            categories.push(category_ident(*index));
+        }
+        quote! {
+            #(if #conditions { PluralCategory::#categories } else)* { PluralCategory::#last }
+        }
+    }
+
+    /// Returns whether every number is in the category "other".
+    pub(crate) fn is_other(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Returns the identifier of the variant of `PluralCategory` for an index into [`CATEGORIES`].
+fn category_ident(index: usize) -> Ident {
+    let name = CATEGORIES[index];
+    let name = name[..1].to_uppercase() + &name[1..];
+    Ident::new(&name, Span::call_site())
+}
+
+impl Relation {
+    fn holds(&self, operands: &Operands) -> bool {
+        let value = match self.operand {
+            // `n` is only equal to integers, so only integers are in its ranges
+            'n' if operands.t != 0 => return self.negated,
+            'n' | 'i' => operands.i,
+            'v' => operands.v,
+            'w' => operands.w,
+            'f' => operands.f,
+            't' => operands.t,
+            _ => 0,
+        };
+        let value = self.modulus.map_or(value, |modulus| value % modulus);
+        let contained = self
+            .ranges
+            .iter()
+            .any(|&(start, end)| (start..=end).contains(&value));
+        contained != self.negated
+    }
+
+    /// Returns the relation as a boolean expression and whether that is a disjunction, or
+    /// whether the relation always holds if its operand is always 0 in `domain`.
+    fn to_tokens(&self, domain: Domain) -> Result<(TokenStream, bool), bool> {
+        let operand = match (domain, self.operand) {
+            (Domain::Integers, 'n' | 'i') => quote! { n },
+            (Domain::Integers, _) | (Domain::Decimals, 'c' | 'e') => {
+                return Err(self.holds(&Operands::default()))
+            }
+            (Domain::Decimals, 'n' | 'i') => quote! { o.i },
+            (Domain::Decimals, operand) => {
+                let operand = Ident::new(&operand.to_string(), Span::call_site());
+                quote! { o.#operand }
+            }
+        };
+        let value = match self.modulus {
+            Some(modulus) => {
+                let modulus = Literal::u128_unsuffixed(modulus);
+                quote! { #operand % #modulus }
+            }
+            None => operand,
+        };
+        let tokens = match self.ranges[..] {
+            [(start, end)] if start == end => {
+                let start = Literal::u128_unsuffixed(start);
+                if self.negated {
+                    quote! { #value != #start }
+                } else {
+                    quote! { #value == #start }
+                }
+            }
+            _ => {
+                let patterns = self.ranges.iter().map(|&(start, end)| {
+                    let start_literal = Literal::u128_unsuffixed(start);
+                    if start == end {
+                        quote! { #start_literal }
+                    } else {
+                        let end = Literal::u128_unsuffixed(end);
+                        quote! { #start_literal..=#end }
+                    }
+                });
+                let negation = if self.negated {
+                    quote! { ! }
+                } else {
+                    quote! {}
+                };
+                quote! { #negation matches!(#value, #(#patterns)|*) }
+            }
+        };
+        if domain == Domain::Decimals && self.operand == 'n' {
+            // `n` is only equal to integers (those without nonzero fraction digits)
+            if self.negated {
+                return Ok((quote! { o.t != 0 || #tokens }, true));
+            }
+            return Ok((quote! { o.t == 0 && #tokens }, false));
+        }
+        Ok((tokens, false))
+    }
+}
+
+fn parse_condition(s: &str) -> Result<Condition, String> {
+    let tokens = tokenize(s)?;
+    let mut tokens = tokens.iter().map(String::as_str).peekable();
+    let mut condition = vec![vec![parse_relation(&mut tokens)?]];
+    while let Some(token) = tokens.next() {
+        match token {
+            "and" => condition
+                .last_mut()
+                .unwrap()
+                .push(parse_relation(&mut tokens)?),
+            "or" => condition.push(vec![parse_relation(&mut tokens)?]),
+            _ => return Err(format!("unexpected {:?}", token)),
+        }
+    }
+    Ok(condition)
+}
+
+fn parse_relation<'a, I>(tokens: &mut std::iter::Peekable<I>) -> Result<Relation, String>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let operand = match tokens.next() {
+        Some(token @ ("n" | "i" | "v" | "w" | "f" | "t" | "c" | "e")) => {
+            token.chars().next().unwrap()
+        }
+        token => return Err(format!("expected an operand, found {:?}", token)),
+    };
+    let mut modulus = None;
+    if tokens.peek() == Some(&"%") {
+        let _ = tokens.next();
+        modulus = Some(parse_value(tokens.next())?);
+    }
+    let negated = match tokens.next() {
+        Some("=") => false,
+        Some("!=") => true,
+        token => return Err(format!("expected \"=\" or \"!=\", found {:?}", token)),
+    };
+    let mut ranges = Vec::new();
+    loop {
+        let start = parse_value(tokens.next())?;
+        let end = if tokens.peek() == Some(&"..") {
+            let _ = tokens.next();
+            parse_value(tokens.next())?
+        } else {
+            start
+        };
+        ranges.push((start, end));
+        if tokens.peek() != Some(&",") {
+            break;
+        }
+        let _ = tokens.next();
+    }
+    // `1..=3` rather than `1 | 2 | 3` in the generated code
+    ranges.sort_unstable();
+    ranges.dedup_by(|next, range| {
+        if next.0 <= range.1.saturating_add(1) {
+            range.1 = range.1.max(next.1);
+            true
+        } else {
+            false
+        }
+    });
+    Ok(Relation {
+        operand,
+        modulus,
+        negated,
+        ranges,
+    })
+}
+
+fn parse_value(token: Option<&str>) -> Result<u128, String> {
+    token
+        .and_then(|token| token.parse().ok())
+        .ok_or_else(|| format!("expected a number, found {:?}", token))
+}
+
+/// Splits a condition into words, numbers and the symbols `%`, `=`, `!=`, `..` and `,`.
+fn tokenize(s: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            let _ = chars.next();
+        } else if c.is_ascii_alphanumeric() {
+            let mut token = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_alphanumeric()) {
+                token.push(c);
+                let _ = chars.next();
+            }
+            tokens.push(token);
+        } else {
+            let token = match (chars.next(), chars.peek()) {
+                (Some('!'), Some('=')) => "!=",
+                (Some('.'), Some('.')) => "..",
+                (Some('%'), _) => "%",
+                (Some('='), _) => "=",
+                (Some(','), _) => ",",
+                _ => return Err(format!("unexpected {:?}", c)),
+            };
+            if token.len() == 2 {
+                let _ = chars.next();
+            }
+            tokens.push(token.to_string());
+        }
+    }
+    Ok(tokens)
+}
+
+/// Returns the samples listed after `@integer` and `@decimal` in a rule (e.g.
+/// `@integer 0, 4~18, 100, … @decimal 0.0~1.5, 10.0, …`) and their operands, leaving out the
+/// open end (`…`) and those with an exponent (e.g. `1c6`).
+fn parse_samples(samples: &str) -> Result<Vec<(String, Operands)>, String> {
+    let mut parsed = Vec::new();
+    for list in samples.split('@').skip(1) {
+        let list = list
+            .strip_prefix("integer")
+            .or_else(|| list.strip_prefix("decimal"))
+            .ok_or_else(|| format!("invalid samples {:?}", list))?;
+        for sample in list.split(',').map(str::trim) {
+            if sample.is_empty() || sample == "…" || sample.contains(&['c', 'e'][..]) {
+                continue;
+            }
+            let invalid = || format!("invalid sample {:?}", sample);
+            let (start, end) = sample.split_once('~').unwrap_or((sample, sample));
+            let (start, v) = Operands::parse(start).ok_or_else(invalid)?;
+            let (end, end_v) = Operands::parse(end).ok_or_else(invalid)?;
+            if v != end_v || end < start || end - start > 10_000 {
+                return Err(invalid());
+            }
+            for digits in start..=end {
+                let operands = Operands::decimal(digits, v);
+                let sample = match v {
+                    0 => digits.to_string(),
+                    _ => format!("{}.{:0width$}", operands.i, operands.f, width = v as usize),
+                };
+                parsed.push((sample, operands));
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+#[derive(Deserialize)]
+struct PluralsJson {
+    supplemental: PluralsSupplemental,
+}
+
+#[derive(Deserialize)]
+struct PluralsSupplemental {
+    #[serde(rename = "plurals-type-cardinal", default)]
+    cardinal: BTreeMap<String, BTreeMap<String, String>>,
+    #[serde(rename = "plurals-type-ordinal", default)]
+    ordinal: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EN: &[(&str, &str)] = &[
+        (
+            "one",
+            "n % 10 = 1 and n % 100 != 11 @integer 1, 21, 31, 101, 1001, …",
+        ),
+        (
+            "two",
+            "n % 10 = 2 and n % 100 != 12 @integer 2, 22, 32, 102, 1002, …",
+        ),
+        (
+            "few",
+            "n % 10 = 3 and n % 100 != 13 @integer 3, 23, 33, 103, 1003, …",
+        ),
+        (
+            "other",
+            " @integer 0, 4~18, 100, 1000, 10000, 100000, 1000000, …",
+        ),
+    ];
+
+    /// Returns the category of a decimal such as "1.50" under `rules`.
+    fn category(rules: &PluralRules, s: &str) -> &'static str {
+        let (digits, v) = Operands::parse(s).unwrap();
+        CATEGORIES[rules.category(&Operands::decimal(digits, v))]
+    }
+
+    #[test]
+    fn test_plural_rules() {
+        let rules = PluralRules::parse(EN.iter().copied()).unwrap();
+        let categories = ["1", "2", "3", "4", "11", "12", "13", "21", "111", "1002"]
+            .iter()
+            .map(|n| category(&rules, n))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            categories,
+            ["one", "two", "few", "other", "other", "other", "other", "one", "other", "two"]
+        );
+
+        // Operands for fractions are 0 for integers, and the exponent is always 0
+        let rules = PluralRules::parse(vec![
+            ("one", "i = 1 and v = 0 @integer 1"),
+            (
+                "many",
+                "e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5 @integer 1000000, 1c6",
+            ),
+            (
+                "other",
+                " @integer 0, 2~16, 100, 1000, 10000, 100000, … @decimal 0.0~1.5, 1000000.0, …",
+            ),
+        ])
+        .unwrap();
+        assert_eq!(category(&rules, "1"), "one");
+        assert_eq!(category(&rules, "1.0"), "other");
+        assert_eq!(category(&rules, "2000000"), "many");
+        assert_eq!(category(&rules, "2000000.5"), "other");
+        let tokens = rules.to_tokens(Domain::Integers).to_string();
+        assert!(tokens.contains("n == 1"));
+        assert!(tokens.contains("n != 0 && n % 1000000 == 0"));
+        assert!(!tokens.contains(" e "));
+        let tokens = rules.to_tokens(Domain::Decimals).to_string();
+        assert!(tokens.contains("o . i == 1 && o . v == 0"));
+        assert!(tokens.contains("o . i != 0 && o . i % 1000000 == 0 && o . v == 0"));
+        assert!(!tokens.contains(" e "));
+
+        let tokens = PluralRules::parse(vec![("one", "n % 10 = 1,5 and n % 100 != 11..13,14")])
+            .unwrap()
+            .to_tokens(Domain::Integers)
+            .to_string();
+        assert!(tokens.contains("matches ! (n % 10 , 1 | 5) && ! matches ! (n % 100 , 11 ..= 14)"));
+    }
+
+    #[test]
+    fn test_plural_rules_decimals() {
+        // The cardinal rules of French, which put "1.5" in "one", and of Latvian
+        let rules = PluralRules::parse(vec![
+            ("one", "i = 0,1 @integer 0, 1 @decimal 0.0~1.5"),
+            ("other", " @integer 2~17, 100 @decimal 2.0~3.5, 10.0"),
+        ])
+        .unwrap();
+        assert_eq!(category(&rules, "1.99"), "one");
+        assert_eq!(category(&rules, "2.0"), "other");
+        let rules = PluralRules::parse(vec![
+            (
+                "zero",
+                "n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19 \
+                 @integer 0, 10~20, 30 @decimal 0.0, 10.0, 11.0, 0.00, 0.11, 0.19",
+            ),
+            (
+                "one",
+                "n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 \
+                 or v != 2 and f % 10 = 1 @integer 1, 21 @decimal 0.1, 1.0, 1.1, 2.1, 0.01",
+            ),
+            (
+                "other",
+                " @integer 2~9, 22~29 @decimal 0.2~0.9, 1.2~1.9, 10.2, 0.02",
+            ),
+        ])
+        .unwrap();
+        assert_eq!(category(&rules, "10.5"), "other");
+        assert_eq!(category(&rules, "21.11"), "zero");
+        let tokens = rules.to_tokens(Domain::Decimals).to_string();
+        assert!(tokens.contains("o . t == 0 && o . i % 10 == 0 || o . t == 0 &&"));
+        assert!(tokens.contains("&& (o . t != 0 || o . i % 100 != 11) ||"));
+    }
+
+    #[test]
+    fn test_plural_rules_errors() {
+        let e = PluralRules::parse(vec![("one", "n = 1 @integer 1, 11")]).unwrap_err();
+        assert_eq!(
+            e,
+            "11 is a sample of \"one\", but the rules put it in \"other\""
+        );
+        let e = PluralRules::parse(vec![("one", "i = 1 @decimal 1.0, 2.0")]).unwrap_err();
+        assert_eq!(
+            e,
+            "2.0 is a sample of \"one\", but the rules put it in \"other\""
+        );
+        let e = PluralRules::parse(vec![("one", "n = ")]).unwrap_err();
+        assert_eq!(
+            e,
+            "expected a number, found None in the rule for \"one\": \"n = \""
+        );
+        let e = PluralRules::parse(vec![("some", "n = 1")]).unwrap_err();
+        assert_eq!(e, "unknown plural category \"some\"");
+    }
+}
@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use indexmap::IndexMap;
+
+use crate::error::DataErrors;
+use crate::plural_rules::{read_plural_rules, PluralRules, PluralType};
+use crate::utils::Format;
+
+/// The cardinal plural rules of each locale (those for counting things, e.g. "1 day" but "2
+/// days"), as read by [`parse_plurals`].
+#[derive(Debug, Default)]
+pub struct Plurals {
+    /// The cardinal plural rules of each locale, keyed like the map returned by `parse_data`.
+    pub(crate) rules: IndexMap<String, PluralRules>,
+}
+
+/// Reads the cardinal plural rules of CLDR's `plurals.json` for every locale in `data`.
+///
+/// `core_dir` is a checkout of the cldr-core package. A locale without plural rules of its own
+/// has those of its language. Problems with the rules of a language are collected and returned
+/// together as [`DataErrors`].
+pub fn parse_plurals<P>(
+    core_dir: P,
+    data: &IndexMap<String, Format>,
+) -> Result<Plurals, anyhow::Error>
+where
+    P: AsRef<Path>,
+{
+    let mut errors = Vec::new();
+    let rules = read_plural_rules(core_dir.as_ref(), PluralType::Cardinal, data, &mut errors)?;
+    if !errors.is_empty() {
+        return Err(DataErrors(errors).into());
+    }
+    Ok(Plurals { rules })
+}
+
+#[cfg(test)]
+impl Plurals {
+    /// Returns plurals that put every number of every locale in `data` in "other".
+    pub(crate) fn test(data: &IndexMap<String, Format>) -> Plurals {
+        Plurals {
+            rules: data
+                .keys()
+                .map(|key| (key.clone(), PluralRules::default()))
+                .collect(),
+        }
+    }
+}
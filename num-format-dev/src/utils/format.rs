@@ -1,14 +1,80 @@
+use indexmap::IndexMap;
+
 use crate::utils::Grouping;
 
+/// The data of one CLDR locale, as read by `parse_data`.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Format {
     pub(crate) identifier: String,
+    pub(crate) language: String,
+    pub(crate) script: Option<String>,
+    pub(crate) region: Option<String>,
+    pub(crate) variant: Option<String>,
+    pub(crate) english_name: String,
+    pub(crate) native_name: String,
 
-    pub(crate) dec: char,
+    pub(crate) dec: String,
     pub(crate) grp: Grouping,
     pub(crate) inf: String,
     pub(crate) min: String,
     pub(crate) nan: String,
     pub(crate) pos: String,
-    pub(crate) sep: char,
+    pub(crate) sep: String,
+
+    /// The symbols of the locale in the other decimal numbering systems num-format supports
+    /// (keyed by their CLDR names, e.g. "arab"), where they differ from those above, which are
+    /// the symbols of its default numbering system.
+    pub(crate) numbering_systems: IndexMap<String, Symbols>,
+}
+
+/// The symbols and grouping of a locale in one numbering system.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct Symbols {
+    pub(crate) dec: String,
+    pub(crate) grp: Grouping,
+    pub(crate) inf: String,
+    pub(crate) min: String,
+    pub(crate) nan: String,
+    pub(crate) pos: String,
+    pub(crate) sep: String,
+}
+
+impl Format {
+    /// Returns the symbols of the locale's default numbering system.
+    pub(crate) fn symbols(&self) -> Symbols {
+        Symbols {
+            dec: self.dec.clone(),
+            grp: self.grp,
+            inf: self.inf.clone(),
+            min: self.min.clone(),
+            nan: self.nan.clone(),
+            pos: self.pos.clone(),
+            sep: self.sep.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Format {
+    /// Returns a format with the given identifier, separator and minus sign and otherwise the
+    /// symbols of "en".
+    pub(crate) fn test(identifier: &str, sep: &str, min: &str) -> Format {
+        Format {
+            identifier: identifier.to_string(),
+            language: identifier.to_string(),
+            script: None,
+            region: None,
+            variant: None,
+            english_name: identifier.to_string(),
+            native_name: identifier.to_string(),
+            dec: ".".to_string(),
+            grp: Grouping::Standard,
+            inf: "∞".to_string(),
+            min: min.to_string(),
+            nan: "NaN".to_string(),
+            pos: "+".to_string(),
+            sep: sep.to_string(),
+            numbering_systems: IndexMap::new(),
+        }
+    }
 }
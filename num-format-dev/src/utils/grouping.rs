@@ -3,10 +3,14 @@ use std::str::FromStr;
 
 use proc_macro2::{Ident, Span};
 
+/// How a locale groups the digits of a number, from its standard decimal pattern.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum Grouping {
+    /// Groups of three (e.g. "#,##0.###")
     Standard,
+    /// A group of three followed by groups of two (e.g. "#,##,##0.###")
     Indian,
+    /// No grouping (e.g. "0.######")
     Posix,
 }
 
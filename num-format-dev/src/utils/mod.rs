@@ -2,4 +2,19 @@ mod format;
 mod grouping;
 
 pub use self::format::Format;
+pub(crate) use self::format::Symbols;
 pub use self::grouping::Grouping;
+
+/// Returns `s` with every character that is neither alphanumeric nor visible ASCII replaced by
+/// its `\u{..}` escape, so that look-alike symbols (e.g. spaces) can be told apart.
+pub(crate) fn escape(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c.is_ascii_graphic() {
+                c.to_string()
+            } else {
+                c.escape_unicode().to_string()
+            }
+        })
+        .collect()
+}
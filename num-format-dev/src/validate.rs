@@ -0,0 +1,81 @@
+use indexmap::IndexMap;
+
+use crate::error::{DataError, DataErrorKind};
+use crate::utils::{Format, Symbols};
+
+// These must match the capacities in num-format/src/strings.rs
+const MAX_DEC_LEN: usize = 8;
+const MAX_INF_LEN: usize = 128;
+const MAX_MIN_LEN: usize = 8;
+const MAX_NAN_LEN: usize = 64;
+const MAX_POS_LEN: usize = 8;
+const MAX_SEP_LEN: usize = 8;
+
+/// Checks that every symbol of every locale fits within num-format's limits (`MAX_DEC_LEN`,
+/// `MAX_MIN_LEN`, `MAX_SEP_LEN` etc.), returning a [`DataErrorKind::TooLong`] error for each
+/// one that doesn't.
+///
+/// Only the symbols of each locale's default numbering system are checked; `parse_data` leaves
+/// out the symbols of other numbering systems that don't fit.
+pub fn validate(data: &IndexMap<String, Format>) -> Vec<DataError> {
+    let mut errors = Vec::new();
+    for format in data.values() {
+        for kind in too_long(&format.symbols()) {
+            errors.push(DataError::new(&format.identifier, kind));
+        }
+    }
+    errors
+}
+
+/// Returns a [`DataErrorKind::TooLong`] error for each of `symbols` that doesn't fit within
+/// num-format's limits.
+pub(crate) fn too_long(symbols: &Symbols) -> Vec<DataErrorKind> {
+    let mut errors = Vec::new();
+    let symbols = [
+        ("decimal", &symbols.dec, MAX_DEC_LEN),
+        ("infinity", &symbols.inf, MAX_INF_LEN),
+        ("minus_sign", &symbols.min, MAX_MIN_LEN),
+        ("nan", &symbols.nan, MAX_NAN_LEN),
+        ("plus_sign", &symbols.pos, MAX_POS_LEN),
+        ("separator", &symbols.sep, MAX_SEP_LEN),
+    ];
+    for (symbol, value, max_len) in symbols {
+        if value.len() > max_len {
+            let kind = DataErrorKind::TooLong {
+                symbol,
+                value: value.clone(),
+                max_len,
+            };
+            errors.push(kind);
+        }
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let mut data = IndexMap::new();
+        data.insert("de".to_string(), Format::test("de", ".", "-"));
+        data.insert(
+            "xx".to_string(),
+            Format::test("xx", "\u{2009}\u{2009}\u{2009}", "\u{200f}\u{200f}\u{2212}"),
+        );
+        let errors = validate(&data)
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                "xx: minus_sign \"\\u{200f}\\u{200f}\\u{2212}\" is 9 bytes long, but num-format \
+                 holds at most 8",
+                "xx: separator \"\\u{2009}\\u{2009}\\u{2009}\" is 9 bytes long, but num-format \
+                 holds at most 8",
+            ]
+        );
+    }
+}
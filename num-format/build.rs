@@ -0,0 +1,46 @@
+//! Chooses the locale data to compile in from the `NUM_FORMAT_LOCALES` environment variable.
+//!
+//! Every `Locale` variant exists in every build. The items of `src/locale.rs` that make a locale
+//! available by name (and its display names) are marked with the `cfg` of the locale set the
+//! locale belongs to. All sets are enabled unless `NUM_FORMAT_LOCALES` lists some of `en` and
+//! `eu` (e.g. `NUM_FORMAT_LOCALES=en,eu`), in which case only those (and English, which the crate
+//! itself uses) are. `all` enables every set.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=NUM_FORMAT_LOCALES");
+
+    let mut all = true;
+    let mut eu = false;
+    if let Ok(value) = env::var("NUM_FORMAT_LOCALES") {
+        if !value.trim().is_empty() {
+            all = false;
+            for set in value.split(',').map(str::trim) {
+                match set {
+                    "all" => all = true,
+                    "en" => {}
+                    "eu" => eu = true,
+                    _ => panic!(
+                        "Invalid NUM_FORMAT_LOCALES {:?}: expected a comma-separated list of \
+                         `all`, `en` and `eu`",
+                        value
+                    ),
+                }
+            }
+        }
+    }
+
+    let sets = [
+        ("locales_en", true),
+        ("locales_eu", all || eu),
+        ("locales_other", all),
+    ];
+    for (set, enabled) in sets {
+        println!("cargo:rustc-check-cfg=cfg({})", set);
+        if enabled {
+            println!("cargo:rustc-cfg={}", set);
+        }
+    }
+}
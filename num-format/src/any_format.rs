@@ -0,0 +1,146 @@
+use crate::custom_format::CustomFormat;
+use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::locale::Locale;
+use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
+#[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+use crate::system_locale::SystemLocale;
+use crate::tagged_locale::TaggedLocale;
+
+/// Any of the formats of this crate, chosen at runtime. Implements [`Format`] by dispatching
+/// to the format it holds, so a format chosen per request (e.g. from a user's settings or an
+/// `Accept-Language` header) can be kept in a struct without making it generic, and without the
+/// cost of a call through a trait object.
+///
+/// Other implementations of [`Format`] can be chosen at runtime as trait objects, since
+/// [`Format`] is implemented for `&F`, `Box<F>` and `Arc<F>` (the last two with the `std`
+/// feature), including for `F = dyn Format`.
+///
+/// # Example
+/// ```rust
+/// use num_format::{AnyFormat, Buffer, CustomFormat, Locale};
+///
+/// struct Request {
+///     format: AnyFormat,
+/// }
+///
+/// fn main() {
+///     let requests = [
+///         Request { format: Locale::de.into() },
+///         Request { format: "en-IN".parse::<AnyFormat>().unwrap() },
+///         Request { format: CustomFormat::builder().separator("_").build().unwrap().into() },
+///     ];
+///     let expected = ["1.000.000", "10,00,000", "1_000_000"];
+///     let mut buf = Buffer::new();
+///     for (request, expected) in requests.iter().zip(&expected) {
+///         buf.write_formatted(&1000000, &request.format);
+///         assert_eq!(buf.as_str(), *expected);
+///     }
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+// Boxing the larger formats would need the std feature and put them behind a pointer again
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum AnyFormat {
+    /// A [`CustomFormat`].
+    ///
+    /// [`CustomFormat`]: struct.CustomFormat.html
+    Custom(CustomFormat),
+    /// A [`Locale`].
+    ///
+    /// [`Locale`]: enum.Locale.html
+    Locale(Locale),
+    #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+    /// A [`SystemLocale`].
+    ///
+    /// [`SystemLocale`]: struct.SystemLocale.html
+    System(SystemLocale),
+    /// A [`TaggedLocale`].
+    ///
+    /// [`TaggedLocale`]: struct.TaggedLocale.html
+    Tagged(TaggedLocale),
+}
+
+impl From<CustomFormat> for AnyFormat {
+    fn from(format: CustomFormat) -> AnyFormat {
+        AnyFormat::Custom(format)
+    }
+}
+
+impl From<Locale> for AnyFormat {
+    fn from(locale: Locale) -> AnyFormat {
+        AnyFormat::Locale(locale)
+    }
+}
+
+#[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+impl From<SystemLocale> for AnyFormat {
+    fn from(locale: SystemLocale) -> AnyFormat {
+        AnyFormat::System(locale)
+    }
+}
+
+impl From<TaggedLocale> for AnyFormat {
+    fn from(locale: TaggedLocale) -> AnyFormat {
+        AnyFormat::Tagged(locale)
+    }
+}
+
+impl core::str::FromStr for AnyFormat {
+    type Err = crate::error::Error;
+
+    /// Parses a BCP 47 language tag or POSIX locale name into a [`TaggedLocale`], as
+    /// [`TaggedLocale::from_tag`] does.
+    ///
+    /// [`TaggedLocale`]: struct.TaggedLocale.html
+    /// [`TaggedLocale::from_tag`]: struct.TaggedLocale.html#method.from_tag
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<TaggedLocale>().map(AnyFormat::Tagged)
+    }
+}
+
+macro_rules! dispatch {
+    ($self:ident, $method:ident) => {
+        match $self {
+            AnyFormat::Custom(format) => Format::$method(format),
+            AnyFormat::Locale(locale) => Format::$method(locale),
+            #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+            AnyFormat::System(locale) => Format::$method(locale),
+            AnyFormat::Tagged(locale) => Format::$method(locale),
+        }
+    };
+}
+
+impl Format for AnyFormat {
+    #[inline(always)]
+    fn decimal(&self) -> DecimalStr<'_> {
+        dispatch!(self, decimal)
+    }
+    #[inline(always)]
+    fn grouping(&self) -> Grouping {
+        dispatch!(self, grouping)
+    }
+    #[inline(always)]
+    fn infinity(&self) -> InfinityStr<'_> {
+        dispatch!(self, infinity)
+    }
+    #[inline(always)]
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        dispatch!(self, minus_sign)
+    }
+    #[inline(always)]
+    fn nan(&self) -> NanStr<'_> {
+        dispatch!(self, nan)
+    }
+    #[inline(always)]
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        dispatch!(self, plus_sign)
+    }
+    #[inline(always)]
+    fn separator(&self) -> SeparatorStr<'_> {
+        dispatch!(self, separator)
+    }
+}
@@ -0,0 +1,137 @@
+#![cfg(all(feature = "with-system-locale", any(unix, windows)))]
+
+use std::ops::Deref;
+use std::sync::{Arc, RwLock};
+
+use crate::error::Error;
+use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
+use crate::system_locale::SystemLocale;
+
+lazy_static! {
+    // The process-wide default SystemLocale, or None until it is first needed
+    static ref CACHE: RwLock<Option<Arc<SystemLocale>>> = RwLock::new(None);
+}
+
+/// A cheap handle to a process-wide copy of your operating system's default [`SystemLocale`],
+/// which is queried once and then only again when you call [`refresh`]. Implements [`Format`]
+/// and dereferences to [`SystemLocale`].
+///
+/// Getting one with [`get`] takes a read lock and clones an [`Arc`]; it does not query the
+/// operating system, so it suits code that formats many numbers. A handle keeps the locale it
+/// was given: after a [`refresh`] (e.g. once a long-running program has changed `LC_ALL` or
+/// `LANG` as it reloads its configuration), handles from [`get`] have the new locale and older
+/// ones the old.
+///
+/// # Example
+/// ```rust
+/// use num_format::{CachedSystemLocale, SystemLocale, ToFormattedString};
+///
+/// fn main() {
+///     let locale = CachedSystemLocale::get().unwrap();
+///     assert_eq!(*locale, SystemLocale::default().unwrap());
+///     println!("{}", 1000000.to_formatted_string(&locale));
+///
+///     // ... after the program has changed its locale environment variables
+///     let refreshed = CachedSystemLocale::refresh().unwrap();
+///     assert_eq!(CachedSystemLocale::get().unwrap(), refreshed);
+/// }
+/// ```
+///
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [`Format`]: trait.Format.html
+/// [`get`]: struct.CachedSystemLocale.html#method.get
+/// [`refresh`]: struct.CachedSystemLocale.html#method.refresh
+/// [`SystemLocale`]: struct.SystemLocale.html
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CachedSystemLocale(Arc<SystemLocale>);
+
+impl CachedSystemLocale {
+    /// Returns a handle to the process-wide default [`SystemLocale`], which is constructed with
+    /// [`SystemLocale::default`] the first time it is needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process-wide locale has not been constructed yet and
+    /// [`SystemLocale::default`] fails, in which case the next call tries again.
+    ///
+    /// [`SystemLocale`]: struct.SystemLocale.html
+    /// [`SystemLocale::default`]: struct.SystemLocale.html#method.default
+    pub fn get() -> Result<CachedSystemLocale, Error> {
+        if let Some(ref locale) = *CACHE.read().unwrap_or_else(|e| e.into_inner()) {
+            return Ok(CachedSystemLocale(locale.clone()));
+        }
+        let mut cache = CACHE.write().unwrap_or_else(|e| e.into_inner());
+        // another thread may have constructed it while this one waited for the lock
+        let locale = match *cache {
+            Some(ref locale) => locale.clone(),
+            None => Arc::new(SystemLocale::default()?),
+        };
+        *cache = Some(locale.clone());
+        Ok(CachedSystemLocale(locale))
+    }
+
+    /// Queries the operating system's default locale again, as [`SystemLocale::default`] does,
+    /// replaces the process-wide [`SystemLocale`] with it and returns a handle to it. Handles
+    /// obtained before keep the previous locale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`SystemLocale::default`] fails, in which case the process-wide
+    /// locale is left as it was.
+    ///
+    /// [`SystemLocale`]: struct.SystemLocale.html
+    /// [`SystemLocale::default`]: struct.SystemLocale.html#method.default
+    pub fn refresh() -> Result<CachedSystemLocale, Error> {
+        let locale = Arc::new(SystemLocale::default()?);
+        *CACHE.write().unwrap_or_else(|e| e.into_inner()) = Some(locale.clone());
+        Ok(CachedSystemLocale(locale))
+    }
+
+    /// Returns the [`SystemLocale`] this handle refers to.
+    ///
+    /// [`SystemLocale`]: struct.SystemLocale.html
+    pub fn locale(&self) -> &SystemLocale {
+        &self.0
+    }
+}
+
+impl Deref for CachedSystemLocale {
+    type Target = SystemLocale;
+
+    fn deref(&self) -> &SystemLocale {
+        &self.0
+    }
+}
+
+impl Format for CachedSystemLocale {
+    #[inline(always)]
+    fn decimal(&self) -> DecimalStr<'_> {
+        Format::decimal(self.locale())
+    }
+    #[inline(always)]
+    fn grouping(&self) -> Grouping {
+        Format::grouping(self.locale())
+    }
+    #[inline(always)]
+    fn infinity(&self) -> InfinityStr<'_> {
+        Format::infinity(self.locale())
+    }
+    #[inline(always)]
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        Format::minus_sign(self.locale())
+    }
+    #[inline(always)]
+    fn nan(&self) -> NanStr<'_> {
+        Format::nan(self.locale())
+    }
+    #[inline(always)]
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        Format::plus_sign(self.locale())
+    }
+    #[inline(always)]
+    fn separator(&self) -> SeparatorStr<'_> {
+        Format::separator(self.locale())
+    }
+}
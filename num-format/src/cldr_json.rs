@@ -0,0 +1,271 @@
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::custom_format::CustomFormat;
+use crate::error::Error;
+use crate::grouping::Grouping;
+
+impl CustomFormat {
+    /// Constructs a [`CustomFormat`] from the contents of a CLDR `numbers.json` file, as found
+    /// in the [cldr-numbers-full] package, using the symbols and standard decimal pattern of the
+    /// locale's default numbering system. This makes locales added to CLDR after the snapshot
+    /// [`Locale`] was generated from usable without waiting for a new release.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` isn't the data of a single locale in the format of CLDR's
+    /// `numbers.json` files, if its standard decimal pattern groups digits other than as
+    /// [`Grouping`] does, or if one of its symbols is too long for a [`CustomFormat`].
+    ///
+    /// # Example
+    /// ```
+    /// use num_format::{CustomFormat, Grouping, ToFormattedString};
+    ///
+    /// fn main() {
+    ///     let json = r##"{
+    ///       "main": {
+    ///         "de-AT": {
+    ///           "numbers": {
+    ///             "defaultNumberingSystem": "latn",
+    ///             "symbols-numberSystem-latn": {
+    ///               "decimal": ",",
+    ///               "group": "\u00a0",
+    ///               "infinity": "∞",
+    ///               "minusSign": "-",
+    ///               "nan": "NaN",
+    ///               "plusSign": "+"
+    ///             },
+    ///             "decimalFormats-numberSystem-latn": {
+    ///               "standard": "#,##0.###"
+    ///             }
+    ///           }
+    ///         }
+    ///       }
+    ///     }"##;
+    ///
+    ///     let format = CustomFormat::from_cldr_json(json).unwrap();
+    ///     assert_eq!(format.grouping(), Grouping::Standard);
+    ///     assert_eq!(1_234_567.to_formatted_string(&format), "1\u{a0}234\u{a0}567");
+    /// }
+    /// ```
+    ///
+    /// [cldr-numbers-full]: https://github.com/unicode-org/cldr-json/tree/main/cldr-json/cldr-numbers-full
+    /// [`CustomFormat`]: struct.CustomFormat.html
+    /// [`Grouping`]: enum.Grouping.html
+    /// [`Locale`]: enum.Locale.html
+    pub fn from_cldr_json(json: &str) -> Result<CustomFormat, Error> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| Error::cldr_invalid_data(e.to_string()))?;
+        let locales = value["main"]
+            .as_object()
+            .ok_or_else(|| Error::cldr_invalid_data("missing \"main\""))?;
+        let (identifier, locale) = match locales.iter().next() {
+            Some(entry) if locales.len() == 1 => entry,
+            _ => {
+                return Err(Error::cldr_invalid_data(
+                    "\"main\" does not hold exactly one locale",
+                ))
+            }
+        };
+
+        let numbers = &locale["numbers"];
+        let numbering_system = numbers["defaultNumberingSystem"]
+            .as_str()
+            .ok_or_else(|| missing(identifier, "numbers.defaultNumberingSystem"))?;
+        let symbols_key = format!("symbols-numberSystem-{}", numbering_system);
+        let symbols = &numbers[&symbols_key];
+        let symbol = |name: &str| {
+            symbols[name]
+                .as_str()
+                .ok_or_else(|| missing(identifier, &format!("numbers.{}.{}", symbols_key, name)))
+        };
+        let formats_key = format!("decimalFormats-numberSystem-{}", numbering_system);
+        let pattern = numbers[&formats_key]["standard"]
+            .as_str()
+            .ok_or_else(|| missing(identifier, &format!("numbers.{}.standard", formats_key)))?;
+
+        CustomFormat::builder()
+            .decimal(symbol("decimal")?)
+            .grouping(grouping(pattern)?)
+            .infinity(symbol("infinity")?)
+            .minus_sign(symbol("minusSign")?)
+            .nan(symbol("nan")?)
+            .plus_sign(symbol("plusSign")?)
+            .separator(symbol("group")?)
+            .build()
+    }
+
+    /// Constructs a [`CustomFormat`] from the CLDR `numbers.json` file at `path`. See
+    /// [`from_cldr_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, or for any of the reasons
+    /// [`from_cldr_json`] does.
+    ///
+    /// [`CustomFormat`]: struct.CustomFormat.html
+    /// [`from_cldr_json`]: struct.CustomFormat.html#method.from_cldr_json
+    pub fn from_cldr_file<P>(path: P) -> Result<CustomFormat, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|e| Error::cldr_io(path.display().to_string(), e.to_string()))?;
+        CustomFormat::from_cldr_json(&json)
+    }
+
+    /// Constructs a [`CustomFormat`] for the locale `identifier` (e.g. `"de-AT"`) from `dir`, a
+    /// checkout of the [cldr-numbers-full] package, i.e. from `dir/main/{identifier}/numbers.json`.
+    /// See [`from_cldr_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, or for any of the reasons
+    /// [`from_cldr_json`] does.
+    ///
+    /// [cldr-numbers-full]: https://github.com/unicode-org/cldr-json/tree/main/cldr-json/cldr-numbers-full
+    /// [`CustomFormat`]: struct.CustomFormat.html
+    /// [`from_cldr_json`]: struct.CustomFormat.html#method.from_cldr_json
+    pub fn from_cldr_dir<P, S>(dir: P, identifier: S) -> Result<CustomFormat, Error>
+    where
+        P: AsRef<Path>,
+        S: AsRef<str>,
+    {
+        let path = dir
+            .as_ref()
+            .join("main")
+            .join(identifier.as_ref())
+            .join("numbers.json");
+        CustomFormat::from_cldr_file(path)
+    }
+}
+
+/// Returns the [`Grouping`] of a CLDR number pattern such as `"#,##,##0.###"`, judging by the
+/// sizes of its primary (rightmost) and secondary groups of digits.
+fn grouping(pattern: &str) -> Result<Grouping, Error> {
+    // Only the integer part of the positive subpattern tells how digits are grouped
+    let positive = pattern.split(';').next().unwrap_or_default();
+    let integer = positive.split('.').next().unwrap_or_default();
+    let groups = integer
+        .rsplit(',')
+        .map(|group| group.chars().filter(|c| *c == '#' || *c == '0').count())
+        .collect::<Vec<_>>();
+    match groups.as_slice() {
+        [_] => Ok(Grouping::Posix),
+        [3, _] | [3, 3, ..] => Ok(Grouping::Standard),
+        [3, 2, ..] => Ok(Grouping::Indian),
+        _ => Err(Error::cldr_unsupported_grouping(pattern)),
+    }
+}
+
+fn missing(identifier: &str, field: &str) -> Error {
+    Error::cldr_invalid_data(format!("{} has no {}", identifier, field))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::{ErrorKind, Locale};
+
+    /// Returns the `numbers.json` CLDR has for `locale`, as far as num-format uses it.
+    fn numbers_json(locale: &Locale, pattern: &str) -> String {
+        serde_json::json!({
+            "main": {
+                locale.name(): {
+                    "identity": { "language": locale.language() },
+                    "numbers": {
+                        "defaultNumberingSystem": "latn",
+                        "otherNumberingSystems": { "native": "latn" },
+                        "symbols-numberSystem-latn": {
+                            "decimal": locale.decimal(),
+                            "group": locale.separator(),
+                            "infinity": locale.infinity(),
+                            "minusSign": locale.minus_sign(),
+                            "nan": locale.nan(),
+                            "plusSign": locale.plus_sign(),
+                            "exponential": "E",
+                            "percentSign": "%"
+                        },
+                        "decimalFormats-numberSystem-latn": { "standard": pattern }
+                    }
+                }
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_cldr_json() {
+        let test_cases = [
+            (Locale::de, "#,##0.###"),
+            (Locale::hi, "#,##,##0.###"),
+            (Locale::en_US_POSIX, "0.######"),
+            (Locale::ar, "#,##0.###;#,##0.###-"),
+        ];
+        for (locale, pattern) in &test_cases {
+            let format = CustomFormat::from_cldr_json(&numbers_json(locale, pattern)).unwrap();
+            assert_eq!(
+                format,
+                CustomFormat::builder().format(locale).build().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cldr_json_errors() {
+        let invalid = |json: &str| match CustomFormat::from_cldr_json(json).unwrap_err().kind() {
+            ErrorKind::CldrInvalidData(message) => message.clone(),
+            kind => panic!("unexpected error kind: {:?}", kind),
+        };
+        assert!(!invalid("{").is_empty());
+        assert_eq!(invalid("{}"), "missing \"main\"");
+        assert_eq!(
+            invalid(r#"{"main": {}}"#),
+            "\"main\" does not hold exactly one locale"
+        );
+        assert_eq!(
+            invalid(r#"{"main": {"xx": {"numbers": {}}}}"#),
+            "xx has no numbers.defaultNumberingSystem"
+        );
+        let json = numbers_json(&Locale::en, "#,##0.###").replace("plusSign", "plus");
+        assert_eq!(
+            invalid(&json),
+            "en has no numbers.symbols-numberSystem-latn.plusSign"
+        );
+
+        let json = numbers_json(&Locale::en, "#,####0.###");
+        assert_eq!(
+            CustomFormat::from_cldr_json(&json).unwrap_err().kind(),
+            &ErrorKind::CldrUnsupportedGrouping("#,####0.###".into())
+        );
+
+        let json = numbers_json(&Locale::en, "#,##0.###").replace("NaN", &"N".repeat(65));
+        assert_eq!(
+            CustomFormat::from_cldr_json(&json).unwrap_err().kind(),
+            &ErrorKind::Capacity { len: 65, cap: 64 }
+        );
+    }
+
+    #[test]
+    fn test_cldr_dir() {
+        let dir = env::temp_dir().join(format!("num-format-cldr-{}", std::process::id()));
+        let locale_dir = dir.join("main").join("fr-CA");
+        fs::create_dir_all(&locale_dir).unwrap();
+        let json = numbers_json(&Locale::fr_CA, "#,##0.###");
+        fs::write(locale_dir.join("numbers.json"), json).unwrap();
+
+        let format = CustomFormat::from_cldr_dir(&dir, "fr-CA").unwrap();
+        assert_eq!(format.separator(), Locale::fr_CA.separator());
+        match CustomFormat::from_cldr_dir(&dir, "xx").unwrap_err().kind() {
+            ErrorKind::CldrIo { path, .. } => assert!(path.ends_with("numbers.json")),
+            kind => panic!("unexpected error kind: {:?}", kind),
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -46,6 +46,7 @@ impl CustomFormatBuilder {
     /// - The "separator" is longer than 8 bytes
     ///
     /// [`CustomFormat`]: struct.CustomFormat.html
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     pub fn build(self) -> Result<CustomFormat, Error> {
         Ok(CustomFormat {
             dec: self.dec?,
@@ -46,6 +46,40 @@ impl Error {
         }
     }
 
+    #[cfg(feature = "with-cldr-json")]
+    pub(crate) fn cldr_invalid_data<S>(message: S) -> Error
+    where
+        S: Into<String>,
+    {
+        Error {
+            kind: ErrorKind::CldrInvalidData(message.into()),
+        }
+    }
+
+    #[cfg(feature = "with-cldr-json")]
+    pub(crate) fn cldr_io<S, T>(path: S, message: T) -> Error
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Error {
+            kind: ErrorKind::CldrIo {
+                path: path.into(),
+                message: message.into(),
+            },
+        }
+    }
+
+    #[cfg(feature = "with-cldr-json")]
+    pub(crate) fn cldr_unsupported_grouping<S>(pattern: S) -> Error
+    where
+        S: Into<String>,
+    {
+        Error {
+            kind: ErrorKind::CldrUnsupportedGrouping(pattern.into()),
+        }
+    }
+
     #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
     pub(crate) fn interior_nul_byte<S>(locale_name: S) -> Error
     where
@@ -86,6 +120,30 @@ impl Error {
         };
     }
 
+    #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+    pub(crate) fn system_definition_io<S, T>(path: S, message: T) -> Error
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Error {
+            kind: ErrorKind::SystemDefinitionIo {
+                path: path.into(),
+                message: message.into(),
+            },
+        }
+    }
+
+    #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+    pub(crate) fn system_invalid_definition<S>(message: S) -> Error
+    where
+        S: Into<String>,
+    {
+        Error {
+            kind: ErrorKind::SystemInvalidDefinition(message.into()),
+        }
+    }
+
     #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
     pub(crate) fn system_invalid_return<S, T>(function_name: S, message: T) -> Error
     where
@@ -100,6 +158,16 @@ impl Error {
         }
     }
 
+    #[cfg(all(feature = "with-system-locale", unix))]
+    pub(crate) fn system_no_locale_names<S>(problems: S) -> Error
+    where
+        S: Into<String>,
+    {
+        Error {
+            kind: ErrorKind::SystemNoLocaleNames(problems.into()),
+        }
+    }
+
     #[cfg(all(feature = "with-system-locale", unix))]
     pub(crate) fn system_unsupported_encoding<S>(encoding_name: S) -> Error
     where
@@ -19,6 +19,23 @@ pub enum ErrorKind {
         cap: usize,
     },
 
+    #[cfg(feature = "with-cldr-json")]
+    /// CLDR data is malformed or lacks a field num-format needs.
+    CldrInvalidData(String),
+
+    #[cfg(feature = "with-cldr-json")]
+    /// Failed to read a CLDR data file.
+    CldrIo {
+        /// The path of the file.
+        path: String,
+        /// Details about the I/O error.
+        message: String,
+    },
+
+    #[cfg(feature = "with-cldr-json")]
+    /// CLDR data has a number pattern whose grouping is currently unsupported by num-format.
+    CldrUnsupportedGrouping(String),
+
     #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
     /// Locale name contains an interior nul byte, which is not allowed.
     InteriorNulByte(String),
@@ -47,6 +64,19 @@ pub enum ErrorKind {
     /// Failed to parse input into a number.
     ParseNumber(ArrayString<MAX_ERR_LEN>),
 
+    #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+    /// Failed to read a POSIX locale definition file.
+    SystemDefinitionIo {
+        /// The path of the file.
+        path: String,
+        /// Details about the I/O error.
+        message: String,
+    },
+
+    #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+    /// A POSIX locale definition file is malformed.
+    SystemInvalidDefinition(String),
+
     #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
     /// Call to C standard library or Windows API unexpectedly returned invalid data.
     SystemInvalidReturn {
@@ -56,6 +86,11 @@ pub enum ErrorKind {
         message: String,
     },
 
+    #[cfg(all(feature = "with-system-locale", unix))]
+    /// The names of the locales available on the operating system could not be read from any of
+    /// the places the C library keeps them, nor from the `locale` command.
+    SystemNoLocaleNames(String),
+
     #[cfg(all(feature = "with-system-locale", unix))]
     /// Attempted to use a system locale that relies on an encoding that is not currently supported
     /// by num-format.
@@ -77,6 +112,22 @@ impl fmt::Display for ErrorKind {
                 len, cap
             ),
 
+            #[cfg(feature = "with-cldr-json")]
+            CldrInvalidData(ref message) => write!(f, "Invalid CLDR data: {}", message),
+
+            #[cfg(feature = "with-cldr-json")]
+            CldrIo { path, message } => {
+                write!(f, "Failed to read CLDR data from {}: {}", path, message)
+            }
+
+            #[cfg(feature = "with-cldr-json")]
+            CldrUnsupportedGrouping(ref pattern) => write!(
+                f,
+                "CLDR data has a number pattern of {}, whose grouping is not currently \
+                 supported by num-format.",
+                pattern
+            ),
+
             #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
             InteriorNulByte(ref locale_name) => write!(
                 f,
@@ -90,9 +141,28 @@ impl fmt::Display for ErrorKind {
 
             ParseNumber(ref input) => write!(f, "Failed to parse {} into a number.", input),
 
+            #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+            SystemDefinitionIo { path, message } => write!(
+                f,
+                "Failed to read the locale definition {}: {}",
+                path, message
+            ),
+
+            #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+            SystemInvalidDefinition(ref message) => {
+                write!(f, "Invalid locale definition: {}", message)
+            }
+
             #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
             SystemInvalidReturn { message, .. } => write!(f, "{}", message),
 
+            #[cfg(all(feature = "with-system-locale", unix))]
+            SystemNoLocaleNames(ref problems) => write!(
+                f,
+                "Failed to read the names of the locales available on the operating system ({}).",
+                problems
+            ),
+
             #[cfg(all(feature = "with-system-locale", unix))]
             SystemUnsupportedEncoding(ref encoding_name) => write!(
                 f,
@@ -1,8 +1,17 @@
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
 use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
 use crate::Grouping;
 
 /// Trait that abstracts over [`CustomFormat`], [`Locale`], and `SystemLocale`.
 ///
+/// It is object safe, and implemented for references to formats as well as (with the `std`
+/// feature) boxed and reference-counted ones, so `&dyn Format`, `Box<dyn Format>` and
+/// `Arc<dyn Format>` can be passed wherever a format is expected. To choose among this crate's
+/// formats at runtime without a trait object, see [`AnyFormat`].
+///
+/// [`AnyFormat`]: enum.AnyFormat.html
 /// [`CustomFormat`]: struct.CustomFormat.html
 /// [`Locale`]: enum.Locale.html
 pub trait Format {
@@ -23,3 +32,47 @@ pub trait Format {
     /// Returns the string representation of a thousands separator.
     fn separator(&self) -> SeparatorStr<'_>;
 }
+
+macro_rules! impl_for_pointer {
+    ($type:ty) => {
+        impl<F> Format for $type
+        where
+            F: Format + ?Sized,
+        {
+            #[inline(always)]
+            fn decimal(&self) -> DecimalStr<'_> {
+                (**self).decimal()
+            }
+            #[inline(always)]
+            fn grouping(&self) -> Grouping {
+                (**self).grouping()
+            }
+            #[inline(always)]
+            fn infinity(&self) -> InfinityStr<'_> {
+                (**self).infinity()
+            }
+            #[inline(always)]
+            fn minus_sign(&self) -> MinusSignStr<'_> {
+                (**self).minus_sign()
+            }
+            #[inline(always)]
+            fn nan(&self) -> NanStr<'_> {
+                (**self).nan()
+            }
+            #[inline(always)]
+            fn plus_sign(&self) -> PlusSignStr<'_> {
+                (**self).plus_sign()
+            }
+            #[inline(always)]
+            fn separator(&self) -> SeparatorStr<'_> {
+                (**self).separator()
+            }
+        }
+    };
+}
+
+impl_for_pointer!(&F);
+#[cfg(feature = "std")]
+impl_for_pointer!(Box<F>);
+#[cfg(feature = "std")]
+impl_for_pointer!(Arc<F>);
@@ -16,7 +16,7 @@ use crate::to_formatted_str::ToFormattedStr;
 impl ToFormattedStr for u8 {
     #[doc(hidden)]
     #[inline(always)]
-    fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, _: &F) -> usize
+    fn read_to_buffer<F>(&self, buf: &mut Buffer, _: &F) -> usize
     where
         F: Format,
     {
@@ -29,7 +29,7 @@ macro_rules! impl_unsigned {
         impl ToFormattedStr for $type {
             #[doc(hidden)]
             #[inline(always)]
-            fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, format: &F) -> usize
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
             where
                 F: Format,
             {
@@ -60,7 +60,7 @@ macro_rules! impl_signed {
         impl ToFormattedStr for $type {
             #[doc(hidden)]
             #[inline(always)]
-            fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, format: &F) -> usize
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
             where
                 F: Format,
             {
@@ -103,7 +103,7 @@ impl Sealed for i128 {}
 impl ToFormattedStr for NonZeroU8 {
     #[doc(hidden)]
     #[inline(always)]
-    fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, _: &F) -> usize
+    fn read_to_buffer<F>(&self, buf: &mut Buffer, _: &F) -> usize
     where
         F: Format,
     {
@@ -116,7 +116,7 @@ macro_rules! impl_non_zero {
         impl ToFormattedStr for $type {
             #[doc(hidden)]
             #[inline(always)]
-            fn read_to_buffer<'a, F>(&self, buf: &'a mut Buffer, format: &F) -> usize
+            fn read_to_buffer<F>(&self, buf: &mut Buffer, format: &F) -> usize
             where
                 F: Format,
             {
@@ -6,8 +6,9 @@ use std::ops::Neg;
 
 use num_bigint::{BigInt, BigUint, Sign};
 
+use crate::plural_operands::LIMIT;
 use crate::sealed::Sealed;
-use crate::{Format, Grouping, ToFormattedString};
+use crate::{Format, Grouping, PluralOperands, ToFormattedString, ToPluralOperands};
 
 impl ToFormattedString for BigInt {
     #[inline(always)]
@@ -82,6 +83,22 @@ impl ToFormattedString for BigUint {
 impl Sealed for BigInt {}
 impl Sealed for BigUint {}
 
+impl ToPluralOperands for BigInt {
+    fn to_plural_operands(&self) -> PluralOperands {
+        self.magnitude().to_plural_operands()
+    }
+}
+
+impl ToPluralOperands for BigUint {
+    fn to_plural_operands(&self) -> PluralOperands {
+        // Numbers too large for a u128 are kept modulo LIMIT, plus LIMIT, as `from_integer` keeps
+        // the large ones that are not
+        let n = u128::try_from(self)
+            .unwrap_or_else(|_| LIMIT + u128::try_from(self % LIMIT).unwrap_or_default());
+        PluralOperands::from_integer(n)
+    }
+}
+
 #[inline(always)]
 fn io_algorithm<F, W>(s: String, mut w: W, format: &F) -> Result<usize, io::Error>
 where
@@ -0,0 +1,69 @@
+#![allow(trivial_numeric_casts)]
+
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+use crate::to_formatted_str::ToFormattedStr;
+
+/// Marker trait for the integer types (all those in the standard library, and their `NonZero`
+/// counterparts), which have plural categories and ordinal forms (see [`ordinal_category`]).
+///
+/// This trait is sealed; so you may not implement it on your own types.
+///
+/// [`ordinal_category`]: enum.Locale.html#method.ordinal_category
+pub trait Integer: ToFormattedStr {
+    #[doc(hidden)]
+    fn magnitude(&self) -> u128;
+}
+
+macro_rules! impl_unsigned {
+    ($($type:ty),*) => {
+        $(
+            impl Integer for $type {
+                #[doc(hidden)]
+                #[inline(always)]
+                fn magnitude(&self) -> u128 {
+                    *self as u128
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($type:ty),*) => {
+        $(
+            impl Integer for $type {
+                #[doc(hidden)]
+                #[inline(always)]
+                fn magnitude(&self) -> u128 {
+                    self.unsigned_abs() as u128
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_non_zero {
+    ($($type:ty),*) => {
+        $(
+            impl Integer for $type {
+                #[doc(hidden)]
+                #[inline(always)]
+                fn magnitude(&self) -> u128 {
+                    self.get() as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, usize, u64, u128);
+impl_signed!(i8, i16, i32, isize, i64, i128);
+impl_non_zero!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroUsize,
+    NonZeroU64,
+    NonZeroU128
+);
@@ -118,6 +118,10 @@ fn main() {
 }
 ```
 
+To follow the user's locale without the OS's locale data (e.g. in a container that has none
+installed), `Locale::from_env` resolves the `LC_ALL`, `LC_NUMERIC` or `LANG` environment variable
+(e.g. `"de_DE.UTF-8"`) to the closest [`Locale`], without calling the C library.
+
 ### `SystemLocale` *(available behind feature flag `with-system-locale`)*
 
 The `SystemLocale` type is another type that implements [`Format`]. It allows you to access your
@@ -152,6 +156,15 @@ fn main() {
 # fn main() {}
 ```
 
+On Unix-based systems, [`SystemMonetaryFormat`] similarly gives access to your OS's monetary
+format (the `LC_MONETARY` category), including its currency symbols, and writes amounts of money
+the way the C library's `strfmon` does (e.g. `"-1.234,50 €"` in `"de_DE.UTF-8"`).
+
+Programs that format many numbers in the system locale can use [`CachedSystemLocale`], a cheap
+handle to a process-wide copy of the default `SystemLocale` that is only queried again when you
+call its `refresh` method (e.g. after changing `LANG` or `LC_ALL` as your program reloads its
+configuration).
+
 ### `CustomFormat`
 
 [`CustomFormat`] is the third and final type that implements [`Format`]. You can use it to build
@@ -175,6 +188,44 @@ fn main() -> Result<(), Error> {
 }
 ```
 
+To choose among these at runtime (e.g. per request) without making your types generic, store an
+[`AnyFormat`], which holds any of them and dispatches without a trait object; or a `&dyn Format`,
+`Box<dyn Format>` or `Arc<dyn Format>`, which also implement [`Format`].
+
+# Ordinals
+
+With the `std` feature (on by default), [`ToOrdinalString`] writes integers as ordinals in the
+style of a [`Locale`], using CLDR's ordinal plural rules (which also tell you the [`PluralCategory`]
+of a number, see [`ordinal_category`]) and its rule-based number formats.
+
+```rust
+# use cfg_if::cfg_if; cfg_if! { if #[cfg(feature = "std")] {
+use num_format::{Locale, ToOrdinalString};
+
+fn main() {
+    assert_eq!(1001.to_ordinal_string(&Locale::en), "1,001st");
+    assert_eq!(1001.to_ordinal_string(&Locale::de), "1.001.");
+}
+# } else { fn main() {} } }
+```
+
+# Plural categories
+
+[`plural_category`] tells which of CLDR's cardinal [`PluralCategory`]s a number falls in, for
+choosing between the forms of a word that goes with it (e.g. "1 day" but "2 days"). Decimals are
+given as [`PluralOperands`], which keep the fraction digits the number is written with, since
+"1" and "1.0" differ in many languages.
+
+```rust
+use num_format::{Locale, PluralCategory, PluralOperands};
+
+fn main() {
+    assert_eq!(Locale::en.plural_category(&1), PluralCategory::One);
+    let operands = "1.0".parse::<PluralOperands>().unwrap();
+    assert_eq!(Locale::en.plural_category(&operands), PluralCategory::Other);
+}
+```
+
 # Requirements
 
 * Rust 1.56.0 or greater if compiled with `--no-default-features`
@@ -188,10 +239,27 @@ fn main() -> Result<(), Error> {
 | Available features   | What to put in your `Cargo.toml`                                      |
 | :------------------- | :-------------------------------------------------------------------- |
 | `no_std`             | `num-format = { version = "0.4.3", default-features = false }`          |
+| `with-cldr-json`     | `num-format = { version = "0.4.3", features = ["with-cldr-json"] }`     |
 | `with-num-bigint`    | `num-format = { version = "0.4.3", features = ["with-num-bigint"] }`    |
 | `with-serde`         | `num-format = { version = "0.4.3", features = ["with-serde"] }`         |
 | `with-system-locale` | `num-format = { version = "0.4.3", features = ["with-system-locale"] }` |
 
+# Choosing locales
+
+By default, every locale from CLDR can be looked up by name as a [`Locale`]. To save binary
+size, build with the `NUM_FORMAT_LOCALES` environment variable set to `en`, `eu` or `en,eu` to
+keep only the English locales or the locales of the official languages of the European Union
+(including English) available by name. This is a build option of the final binary rather than a
+feature, so no dependency can take locales away from another: every `Locale` variant exists,
+is returned by `Locale::iter` and has the same symbols, plural and ordinal rules and display
+names in every build, but the ones left out cannot be returned by `Locale::from_name` (nor by
+`Locale::negotiate`, `TaggedLocale::from_tag` and the other functions that resolve names).
+
+What this saves is the name lookup tables and the symbols of the left-out locales in numbering
+systems other than their default ones, about 40 KB of a release binary. The tables of symbols,
+plural rules, ordinal rules and display names are indexed by `Locale` variant, so they stay
+whole.
+
 # License
 
 **num-format** is licensed under either of:
@@ -201,8 +269,10 @@ fn main() -> Result<(), Error> {
 
 at your option.
 
+[`AnyFormat`]: https://docs.rs/num-format/0.4.3/num_format/enum.AnyFormat.html
 [bindgen]: https://crates.io/crates/bindgen
 [`Buffer`]: https://docs.rs/num-format/0.4.3/num_format/struct.Buffer.html
+[`CachedSystemLocale`]: https://docs.rs/num-format/0.4.3/num_format/struct.CachedSystemLocale.html
 [Common Locale Data Repository]: https://en.wikipedia.org/wiki/Common_Locale_Data_Repository
 [`CustomFormat`]: https://docs.rs/num-format/0.4.3/num_format/struct.CustomFormat.html
 [`File`]: https://doc.rust-lang.org/std/fs/struct.File.html
@@ -211,12 +281,18 @@ at your option.
 [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
 [`Locale`]: https://docs.rs/num-format/0.4.3/num_format/enum.Locale.html
 [`num_bigint::BigInt`]: https://docs.rs/num-bigint/0.2.2/num_bigint/struct.BigInt.html
+[`ordinal_category`]: https://docs.rs/num-format/0.4.3/num_format/enum.Locale.html#method.ordinal_category
 [picking a format]: #picking-a-format
+[`plural_category`]: https://docs.rs/num-format/0.4.3/num_format/enum.Locale.html#method.plural_category
+[`PluralCategory`]: https://docs.rs/num-format/0.4.3/num_format/enum.PluralCategory.html
+[`PluralOperands`]: https://docs.rs/num-format/0.4.3/num_format/struct.PluralOperands.html
 [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+[`SystemMonetaryFormat`]: https://docs.rs/num-format/0.4.3/num_format/struct.SystemMonetaryFormat.html
 [The Apache License, Version 2.0]: http://www.apache.org/licenses/LICENSE-2.0
 [The MIT license]: http://opensource.org/licenses/MIT
 [`ToFormattedString`]: https://docs.rs/num-format/0.4.3/num_format/trait.ToFormattedString.html
 [`to_formatted_string`]: https://docs.rs/num-format/0.4.3/num_format/trait.ToFormattedString.html#method.to_formatted_string
+[`ToOrdinalString`]: https://docs.rs/num-format/0.4.3/num_format/trait.ToOrdinalString.html
 [Unicode Consortium]: https://en.wikipedia.org/wiki/Unicode_Consortium
 [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
 [`WriteFormatted`]: https://docs.rs/num-format/0.4.3/num_format/trait.WriteFormatted.html
@@ -251,7 +327,12 @@ extern crate lazy_static;
 #[macro_use]
 extern crate serde;
 
+mod any_format;
 mod buffer;
+#[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+mod cached_system_locale;
+#[cfg(feature = "with-cldr-json")]
+mod cldr_json;
 mod constants;
 mod custom_format;
 mod custom_format_builder;
@@ -260,31 +341,57 @@ mod error_kind;
 mod format;
 mod grouping;
 mod impls;
+mod integer;
 mod locale;
+#[cfg(feature = "std")]
+mod locale_env;
+mod locale_queries;
+mod locale_tag;
+mod numbering_system;
+mod ordinal;
 pub mod parsing;
+mod plural_category;
+mod plural_operands;
 mod strings;
 #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
 mod system_locale;
+#[cfg(all(feature = "with-system-locale", unix))]
+mod system_monetary_format;
+mod tagged_locale;
 mod to_formatted_str;
 #[cfg(feature = "std")]
 mod to_formatted_string;
 #[cfg(feature = "std")]
+mod to_ordinal_string;
+#[cfg(feature = "std")]
 mod write_formatted;
 
+pub use self::any_format::AnyFormat;
 pub use self::buffer::Buffer;
+#[cfg(all(feature = "with-system-locale", any(unix, windows)))]
+pub use self::cached_system_locale::CachedSystemLocale;
 pub use self::custom_format::CustomFormat;
 pub use self::custom_format_builder::CustomFormatBuilder;
 pub use self::error::Error;
 pub use self::error_kind::ErrorKind;
 pub use self::format::Format;
 pub use self::grouping::Grouping;
+pub use self::integer::Integer;
 pub use self::locale::Locale;
+pub use self::numbering_system::NumberingSystem;
+pub use self::plural_category::PluralCategory;
+pub use self::plural_operands::{PluralOperands, ToPluralOperands};
 #[cfg(all(feature = "with-system-locale", any(unix, windows)))]
-pub use self::system_locale::SystemLocale;
+pub use self::system_locale::{FormatSymbol, SymbolDifference, SystemLocale};
+#[cfg(all(feature = "with-system-locale", unix))]
+pub use self::system_monetary_format::SystemMonetaryFormat;
+pub use self::tagged_locale::TaggedLocale;
 pub use self::to_formatted_str::ToFormattedStr;
 #[cfg(feature = "std")]
 pub use self::to_formatted_string::ToFormattedString;
 #[cfg(feature = "std")]
+pub use self::to_ordinal_string::ToOrdinalString;
+#[cfg(feature = "std")]
 pub use self::write_formatted::WriteFormatted;
 
 mod sealed {
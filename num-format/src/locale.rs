@@ -2,555 +2,12085 @@
 use crate::error::Error;
 use crate::format::Format;
 use crate::grouping::Grouping;
+use crate::integer::Integer;
+use crate::locale_tag;
+use crate::numbering_system::NumberingSystem;
+use crate::ordinal::{OrdinalPiece, OrdinalRule};
+use crate::plural_category::PluralCategory;
+use crate::plural_operands::{PluralOperands, ToPluralOperands};
 use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
 use core::str::FromStr;
-const AVAILABLE_NAMES: [&str; 542usize] = [
+const AVAILABLE_NAMES: &[&str] = &[
+    #[cfg(locales_other)]
     "af",
+    #[cfg(locales_other)]
     "af-NA",
+    #[cfg(locales_other)]
     "agq",
+    #[cfg(locales_other)]
     "ak",
+    #[cfg(locales_other)]
     "am",
+    #[cfg(locales_other)]
     "ar",
+    #[cfg(locales_other)]
     "ar-AE",
+    #[cfg(locales_other)]
     "ar-BH",
+    #[cfg(locales_other)]
     "ar-DJ",
+    #[cfg(locales_other)]
     "ar-DZ",
+    #[cfg(locales_other)]
     "ar-EG",
+    #[cfg(locales_other)]
     "ar-EH",
+    #[cfg(locales_other)]
     "ar-ER",
+    #[cfg(locales_other)]
     "ar-IL",
+    #[cfg(locales_other)]
     "ar-IQ",
+    #[cfg(locales_other)]
     "ar-JO",
+    #[cfg(locales_other)]
     "ar-KM",
+    #[cfg(locales_other)]
     "ar-KW",
+    #[cfg(locales_other)]
     "ar-LB",
+    #[cfg(locales_other)]
     "ar-LY",
+    #[cfg(locales_other)]
     "ar-MA",
+    #[cfg(locales_other)]
     "ar-MR",
+    #[cfg(locales_other)]
     "ar-OM",
+    #[cfg(locales_other)]
     "ar-PS",
+    #[cfg(locales_other)]
     "ar-QA",
+    #[cfg(locales_other)]
     "ar-SA",
+    #[cfg(locales_other)]
     "ar-SD",
+    #[cfg(locales_other)]
     "ar-SO",
+    #[cfg(locales_other)]
     "ar-SS",
+    #[cfg(locales_other)]
     "ar-SY",
+    #[cfg(locales_other)]
     "ar-TD",
+    #[cfg(locales_other)]
     "ar-TN",
+    #[cfg(locales_other)]
     "ar-YE",
+    #[cfg(locales_other)]
     "as",
+    #[cfg(locales_other)]
     "asa",
+    #[cfg(locales_other)]
     "ast",
+    #[cfg(locales_other)]
     "az",
+    #[cfg(locales_other)]
     "az-Cyrl",
+    #[cfg(locales_other)]
     "az-Latn",
+    #[cfg(locales_other)]
     "bas",
+    #[cfg(locales_other)]
     "be",
+    #[cfg(locales_other)]
     "bem",
+    #[cfg(locales_other)]
     "bez",
+    #[cfg(locales_eu)]
     "bg",
+    #[cfg(locales_other)]
     "bm",
+    #[cfg(locales_other)]
     "bn",
+    #[cfg(locales_other)]
     "bn-IN",
+    #[cfg(locales_other)]
     "bo",
+    #[cfg(locales_other)]
     "bo-IN",
+    #[cfg(locales_other)]
     "br",
+    #[cfg(locales_other)]
     "brx",
+    #[cfg(locales_other)]
     "bs",
+    #[cfg(locales_other)]
     "bs-Cyrl",
+    #[cfg(locales_other)]
     "bs-Latn",
+    #[cfg(locales_other)]
     "ca",
+    #[cfg(locales_other)]
     "ca-AD",
+    #[cfg(locales_other)]
     "ca-ES-VALENCIA",
+    #[cfg(locales_other)]
     "ca-FR",
+    #[cfg(locales_other)]
     "ca-IT",
+    #[cfg(locales_other)]
     "ccp",
+    #[cfg(locales_other)]
     "ccp-IN",
+    #[cfg(locales_other)]
     "ce",
+    #[cfg(locales_other)]
     "ceb",
+    #[cfg(locales_other)]
     "cgg",
+    #[cfg(locales_other)]
     "chr",
+    #[cfg(locales_other)]
     "ckb",
+    #[cfg(locales_other)]
     "ckb-IR",
+    #[cfg(locales_eu)]
     "cs",
+    #[cfg(locales_other)]
     "cu",
+    #[cfg(locales_other)]
     "cy",
+    #[cfg(locales_eu)]
     "da",
+    #[cfg(locales_eu)]
     "da-GL",
+    #[cfg(locales_other)]
     "dav",
+    #[cfg(locales_eu)]
     "de",
+    #[cfg(locales_eu)]
     "de-AT",
+    #[cfg(locales_eu)]
     "de-BE",
+    #[cfg(locales_eu)]
     "de-CH",
+    #[cfg(locales_eu)]
     "de-IT",
+    #[cfg(locales_eu)]
     "de-LI",
+    #[cfg(locales_eu)]
     "de-LU",
+    #[cfg(locales_other)]
     "dje",
+    #[cfg(locales_other)]
     "dsb",
+    #[cfg(locales_other)]
     "dua",
+    #[cfg(locales_other)]
     "dyo",
+    #[cfg(locales_other)]
     "dz",
+    #[cfg(locales_other)]
     "ebu",
+    #[cfg(locales_other)]
     "ee",
+    #[cfg(locales_other)]
     "ee-TG",
+    #[cfg(locales_eu)]
     "el",
+    #[cfg(locales_eu)]
     "el-CY",
+    #[cfg(locales_en)]
     "en",
+    #[cfg(locales_en)]
     "en-001",
+    #[cfg(locales_en)]
     "en-150",
+    #[cfg(locales_en)]
     "en-AE",
+    #[cfg(locales_en)]
     "en-AG",
+    #[cfg(locales_en)]
     "en-AI",
+    #[cfg(locales_en)]
     "en-AS",
+    #[cfg(locales_en)]
     "en-AT",
+    #[cfg(locales_en)]
     "en-AU",
+    #[cfg(locales_en)]
     "en-BB",
+    #[cfg(locales_en)]
     "en-BE",
+    #[cfg(locales_en)]
     "en-BI",
+    #[cfg(locales_en)]
     "en-BM",
+    #[cfg(locales_en)]
     "en-BS",
+    #[cfg(locales_en)]
     "en-BW",
+    #[cfg(locales_en)]
     "en-BZ",
+    #[cfg(locales_en)]
     "en-CA",
+    #[cfg(locales_en)]
     "en-CC",
+    #[cfg(locales_en)]
     "en-CH",
+    #[cfg(locales_en)]
     "en-CK",
+    #[cfg(locales_en)]
     "en-CM",
+    #[cfg(locales_en)]
     "en-CX",
+    #[cfg(locales_en)]
     "en-CY",
+    #[cfg(locales_en)]
     "en-DE",
+    #[cfg(locales_en)]
     "en-DG",
+    #[cfg(locales_en)]
     "en-DK",
+    #[cfg(locales_en)]
     "en-DM",
+    #[cfg(locales_en)]
     "en-ER",
+    #[cfg(locales_en)]
     "en-FI",
+    #[cfg(locales_en)]
     "en-FJ",
+    #[cfg(locales_en)]
     "en-FK",
+    #[cfg(locales_en)]
     "en-FM",
+    #[cfg(locales_en)]
     "en-GB",
+    #[cfg(locales_en)]
     "en-GD",
+    #[cfg(locales_en)]
     "en-GG",
+    #[cfg(locales_en)]
     "en-GH",
+    #[cfg(locales_en)]
     "en-GI",
+    #[cfg(locales_en)]
     "en-GM",
+    #[cfg(locales_en)]
     "en-GU",
+    #[cfg(locales_en)]
     "en-GY",
+    #[cfg(locales_en)]
     "en-HK",
+    #[cfg(locales_en)]
     "en-IE",
+    #[cfg(locales_en)]
     "en-IL",
+    #[cfg(locales_en)]
     "en-IM",
+    #[cfg(locales_en)]
     "en-IN",
+    #[cfg(locales_en)]
     "en-IO",
+    #[cfg(locales_en)]
     "en-JE",
+    #[cfg(locales_en)]
     "en-JM",
+    #[cfg(locales_en)]
     "en-KE",
+    #[cfg(locales_en)]
     "en-KI",
+    #[cfg(locales_en)]
     "en-KN",
+    #[cfg(locales_en)]
     "en-KY",
+    #[cfg(locales_en)]
     "en-LC",
+    #[cfg(locales_en)]
     "en-LR",
+    #[cfg(locales_en)]
     "en-LS",
+    #[cfg(locales_en)]
     "en-MG",
+    #[cfg(locales_en)]
     "en-MH",
+    #[cfg(locales_en)]
     "en-MO",
+    #[cfg(locales_en)]
     "en-MP",
+    #[cfg(locales_en)]
     "en-MS",
+    #[cfg(locales_en)]
     "en-MT",
+    #[cfg(locales_en)]
     "en-MU",
+    #[cfg(locales_en)]
     "en-MW",
+    #[cfg(locales_en)]
     "en-MY",
+    #[cfg(locales_en)]
     "en-NA",
+    #[cfg(locales_en)]
     "en-NF",
+    #[cfg(locales_en)]
     "en-NG",
+    #[cfg(locales_en)]
     "en-NL",
+    #[cfg(locales_en)]
     "en-NR",
+    #[cfg(locales_en)]
     "en-NU",
+    #[cfg(locales_en)]
     "en-NZ",
+    #[cfg(locales_en)]
     "en-PG",
+    #[cfg(locales_en)]
     "en-PH",
+    #[cfg(locales_en)]
     "en-PK",
+    #[cfg(locales_en)]
     "en-PN",
+    #[cfg(locales_en)]
     "en-PR",
+    #[cfg(locales_en)]
     "en-PW",
+    #[cfg(locales_en)]
     "en-RW",
+    #[cfg(locales_en)]
     "en-SB",
+    #[cfg(locales_en)]
     "en-SC",
+    #[cfg(locales_en)]
     "en-SD",
+    #[cfg(locales_en)]
     "en-SE",
+    #[cfg(locales_en)]
     "en-SG",
+    #[cfg(locales_en)]
     "en-SH",
+    #[cfg(locales_en)]
     "en-SI",
+    #[cfg(locales_en)]
     "en-SL",
+    #[cfg(locales_en)]
     "en-SS",
+    #[cfg(locales_en)]
     "en-SX",
+    #[cfg(locales_en)]
     "en-SZ",
+    #[cfg(locales_en)]
     "en-TC",
+    #[cfg(locales_en)]
     "en-TK",
+    #[cfg(locales_en)]
     "en-TO",
+    #[cfg(locales_en)]
     "en-TT",
+    #[cfg(locales_en)]
     "en-TV",
+    #[cfg(locales_en)]
     "en-TZ",
+    #[cfg(locales_en)]
     "en-UG",
+    #[cfg(locales_en)]
     "en-UM",
+    #[cfg(locales_en)]
     "en-US-POSIX",
+    #[cfg(locales_en)]
     "en-VC",
+    #[cfg(locales_en)]
     "en-VG",
+    #[cfg(locales_en)]
     "en-VI",
+    #[cfg(locales_en)]
     "en-VU",
+    #[cfg(locales_en)]
     "en-WS",
+    #[cfg(locales_en)]
     "en-ZA",
+    #[cfg(locales_en)]
     "en-ZM",
+    #[cfg(locales_en)]
     "en-ZW",
+    #[cfg(locales_other)]
     "eo",
+    #[cfg(locales_eu)]
     "es",
+    #[cfg(locales_eu)]
     "es-419",
+    #[cfg(locales_eu)]
     "es-AR",
+    #[cfg(locales_eu)]
     "es-BO",
+    #[cfg(locales_eu)]
     "es-BR",
+    #[cfg(locales_eu)]
     "es-BZ",
+    #[cfg(locales_eu)]
     "es-CL",
+    #[cfg(locales_eu)]
     "es-CO",
+    #[cfg(locales_eu)]
     "es-CR",
+    #[cfg(locales_eu)]
     "es-CU",
+    #[cfg(locales_eu)]
     "es-DO",
+    #[cfg(locales_eu)]
     "es-EA",
+    #[cfg(locales_eu)]
     "es-EC",
+    #[cfg(locales_eu)]
     "es-GQ",
+    #[cfg(locales_eu)]
     "es-GT",
+    #[cfg(locales_eu)]
     "es-HN",
+    #[cfg(locales_eu)]
     "es-IC",
+    #[cfg(locales_eu)]
     "es-MX",
+    #[cfg(locales_eu)]
     "es-NI",
+    #[cfg(locales_eu)]
     "es-PA",
+    #[cfg(locales_eu)]
     "es-PE",
+    #[cfg(locales_eu)]
     "es-PH",
+    #[cfg(locales_eu)]
     "es-PR",
+    #[cfg(locales_eu)]
     "es-PY",
+    #[cfg(locales_eu)]
     "es-SV",
+    #[cfg(locales_eu)]
     "es-US",
+    #[cfg(locales_eu)]
     "es-UY",
+    #[cfg(locales_eu)]
     "es-VE",
+    #[cfg(locales_eu)]
     "et",
+    #[cfg(locales_other)]
     "eu",
+    #[cfg(locales_other)]
     "ewo",
+    #[cfg(locales_other)]
     "fa",
+    #[cfg(locales_other)]
     "fa-AF",
+    #[cfg(locales_other)]
     "ff",
+    #[cfg(locales_other)]
     "ff-Latn",
+    #[cfg(locales_other)]
     "ff-Latn-BF",
+    #[cfg(locales_other)]
     "ff-Latn-CM",
+    #[cfg(locales_other)]
     "ff-Latn-GH",
+    #[cfg(locales_other)]
     "ff-Latn-GM",
+    #[cfg(locales_other)]
     "ff-Latn-GN",
+    #[cfg(locales_other)]
     "ff-Latn-GW",
+    #[cfg(locales_other)]
     "ff-Latn-LR",
+    #[cfg(locales_other)]
     "ff-Latn-MR",
+    #[cfg(locales_other)]
     "ff-Latn-NE",
+    #[cfg(locales_other)]
     "ff-Latn-NG",
+    #[cfg(locales_other)]
     "ff-Latn-SL",
+    #[cfg(locales_eu)]
     "fi",
+    #[cfg(locales_other)]
     "fil",
+    #[cfg(locales_other)]
     "fo",
+    #[cfg(locales_other)]
     "fo-DK",
+    #[cfg(locales_eu)]
     "fr",
+    #[cfg(locales_eu)]
     "fr-BE",
+    #[cfg(locales_eu)]
     "fr-BF",
+    #[cfg(locales_eu)]
     "fr-BI",
+    #[cfg(locales_eu)]
     "fr-BJ",
+    #[cfg(locales_eu)]
     "fr-BL",
+    #[cfg(locales_eu)]
     "fr-CA",
+    #[cfg(locales_eu)]
     "fr-CD",
+    #[cfg(locales_eu)]
     "fr-CF",
+    #[cfg(locales_eu)]
     "fr-CG",
+    #[cfg(locales_eu)]
     "fr-CH",
+    #[cfg(locales_eu)]
     "fr-CI",
+    #[cfg(locales_eu)]
     "fr-CM",
+    #[cfg(locales_eu)]
     "fr-DJ",
+    #[cfg(locales_eu)]
     "fr-DZ",
+    #[cfg(locales_eu)]
     "fr-GA",
+    #[cfg(locales_eu)]
     "fr-GF",
+    #[cfg(locales_eu)]
     "fr-GN",
+    #[cfg(locales_eu)]
     "fr-GP",
+    #[cfg(locales_eu)]
     "fr-GQ",
+    #[cfg(locales_eu)]
     "fr-HT",
+    #[cfg(locales_eu)]
     "fr-KM",
+    #[cfg(locales_eu)]
     "fr-LU",
+    #[cfg(locales_eu)]
     "fr-MA",
+    #[cfg(locales_eu)]
     "fr-MC",
+    #[cfg(locales_eu)]
     "fr-MF",
+    #[cfg(locales_eu)]
     "fr-MG",
+    #[cfg(locales_eu)]
     "fr-ML",
+    #[cfg(locales_eu)]
     "fr-MQ",
+    #[cfg(locales_eu)]
     "fr-MR",
+    #[cfg(locales_eu)]
     "fr-MU",
+    #[cfg(locales_eu)]
     "fr-NC",
+    #[cfg(locales_eu)]
     "fr-NE",
+    #[cfg(locales_eu)]
     "fr-PF",
+    #[cfg(locales_eu)]
     "fr-PM",
+    #[cfg(locales_eu)]
     "fr-RE",
+    #[cfg(locales_eu)]
     "fr-RW",
+    #[cfg(locales_eu)]
     "fr-SC",
+    #[cfg(locales_eu)]
     "fr-SN",
+    #[cfg(locales_eu)]
     "fr-SY",
+    #[cfg(locales_eu)]
     "fr-TD",
+    #[cfg(locales_eu)]
     "fr-TG",
+    #[cfg(locales_eu)]
     "fr-TN",
+    #[cfg(locales_eu)]
     "fr-VU",
+    #[cfg(locales_eu)]
     "fr-WF",
+    #[cfg(locales_eu)]
     "fr-YT",
+    #[cfg(locales_other)]
     "fur",
+    #[cfg(locales_other)]
     "fy",
+    #[cfg(locales_eu)]
     "ga",
+    #[cfg(locales_eu)]
     "ga-GB",
+    #[cfg(locales_other)]
     "gd",
+    #[cfg(locales_other)]
     "gl",
+    #[cfg(locales_other)]
     "gsw",
+    #[cfg(locales_other)]
     "gsw-FR",
+    #[cfg(locales_other)]
     "gsw-LI",
+    #[cfg(locales_other)]
     "gu",
+    #[cfg(locales_other)]
     "guz",
+    #[cfg(locales_other)]
     "gv",
+    #[cfg(locales_other)]
     "ha",
+    #[cfg(locales_other)]
     "ha-GH",
+    #[cfg(locales_other)]
     "ha-NE",
+    #[cfg(locales_other)]
     "haw",
+    #[cfg(locales_other)]
     "he",
+    #[cfg(locales_other)]
     "hi",
+    #[cfg(locales_eu)]
     "hr",
+    #[cfg(locales_eu)]
     "hr-BA",
+    #[cfg(locales_other)]
     "hsb",
+    #[cfg(locales_eu)]
     "hu",
+    #[cfg(locales_other)]
     "hy",
+    #[cfg(locales_other)]
     "ia",
+    #[cfg(locales_other)]
     "id",
+    #[cfg(locales_other)]
     "ig",
+    #[cfg(locales_other)]
     "ii",
+    #[cfg(locales_other)]
     "is",
+    #[cfg(locales_eu)]
     "it",
+    #[cfg(locales_eu)]
     "it-CH",
+    #[cfg(locales_eu)]
     "it-SM",
+    #[cfg(locales_eu)]
     "it-VA",
+    #[cfg(locales_other)]
     "ja",
+    #[cfg(locales_other)]
     "jgo",
+    #[cfg(locales_other)]
     "jmc",
+    #[cfg(locales_other)]
     "jv",
+    #[cfg(locales_other)]
     "ka",
+    #[cfg(locales_other)]
     "kab",
+    #[cfg(locales_other)]
     "kam",
+    #[cfg(locales_other)]
     "kde",
+    #[cfg(locales_other)]
     "kea",
+    #[cfg(locales_other)]
     "khq",
+    #[cfg(locales_other)]
     "ki",
+    #[cfg(locales_other)]
     "kk",
+    #[cfg(locales_other)]
     "kkj",
+    #[cfg(locales_other)]
     "kl",
+    #[cfg(locales_other)]
     "kln",
+    #[cfg(locales_other)]
     "km",
+    #[cfg(locales_other)]
     "kn",
+    #[cfg(locales_other)]
     "ko",
+    #[cfg(locales_other)]
     "ko-KP",
+    #[cfg(locales_other)]
     "kok",
+    #[cfg(locales_other)]
     "ks",
+    #[cfg(locales_other)]
     "ksb",
+    #[cfg(locales_other)]
     "ksf",
+    #[cfg(locales_other)]
     "ksh",
+    #[cfg(locales_other)]
     "ku",
+    #[cfg(locales_other)]
     "kw",
+    #[cfg(locales_other)]
     "ky",
+    #[cfg(locales_other)]
     "lag",
+    #[cfg(locales_other)]
     "lb",
+    #[cfg(locales_other)]
     "lg",
+    #[cfg(locales_other)]
     "lkt",
+    #[cfg(locales_other)]
     "ln",
+    #[cfg(locales_other)]
     "ln-AO",
+    #[cfg(locales_other)]
     "ln-CF",
+    #[cfg(locales_other)]
     "ln-CG",
+    #[cfg(locales_other)]
     "lo",
+    #[cfg(locales_other)]
     "lrc",
+    #[cfg(locales_other)]
     "lrc-IQ",
+    #[cfg(locales_eu)]
     "lt",
+    #[cfg(locales_other)]
     "lu",
+    #[cfg(locales_other)]
     "luo",
+    #[cfg(locales_other)]
     "luy",
+    #[cfg(locales_eu)]
     "lv",
+    #[cfg(locales_other)]
     "mas",
+    #[cfg(locales_other)]
     "mas-TZ",
+    #[cfg(locales_other)]
     "mer",
+    #[cfg(locales_other)]
     "mfe",
+    #[cfg(locales_other)]
     "mg",
+    #[cfg(locales_other)]
     "mgh",
+    #[cfg(locales_other)]
     "mgo",
+    #[cfg(locales_other)]
     "mi",
+    #[cfg(locales_other)]
     "mk",
+    #[cfg(locales_other)]
     "ml",
+    #[cfg(locales_other)]
     "mn",
+    #[cfg(locales_other)]
     "mr",
+    #[cfg(locales_other)]
     "ms",
+    #[cfg(locales_other)]
     "ms-BN",
+    #[cfg(locales_other)]
     "ms-SG",
+    #[cfg(locales_eu)]
     "mt",
+    #[cfg(locales_other)]
     "mua",
+    #[cfg(locales_other)]
     "my",
+    #[cfg(locales_other)]
     "mzn",
+    #[cfg(locales_other)]
     "naq",
+    #[cfg(locales_other)]
     "nb",
+    #[cfg(locales_other)]
     "nb-SJ",
+    #[cfg(locales_other)]
     "nd",
+    #[cfg(locales_other)]
     "nds",
+    #[cfg(locales_other)]
     "nds-NL",
+    #[cfg(locales_other)]
     "ne",
+    #[cfg(locales_other)]
     "ne-IN",
+    #[cfg(locales_eu)]
     "nl",
+    #[cfg(locales_eu)]
     "nl-AW",
+    #[cfg(locales_eu)]
     "nl-BE",
+    #[cfg(locales_eu)]
     "nl-BQ",
+    #[cfg(locales_eu)]
     "nl-CW",
+    #[cfg(locales_eu)]
     "nl-SR",
+    #[cfg(locales_eu)]
     "nl-SX",
+    #[cfg(locales_other)]
     "nmg",
+    #[cfg(locales_other)]
     "nn",
+    #[cfg(locales_other)]
     "nnh",
+    #[cfg(locales_other)]
     "nus",
+    #[cfg(locales_other)]
     "nyn",
+    #[cfg(locales_other)]
     "om",
+    #[cfg(locales_other)]
     "om-KE",
+    #[cfg(locales_other)]
     "or",
+    #[cfg(locales_other)]
     "os",
+    #[cfg(locales_other)]
     "os-RU",
+    #[cfg(locales_other)]
     "pa",
+    #[cfg(locales_other)]
     "pa-Arab",
+    #[cfg(locales_other)]
     "pa-Guru",
+    #[cfg(locales_eu)]
     "pl",
+    #[cfg(locales_other)]
     "prg",
+    #[cfg(locales_other)]
     "ps",
+    #[cfg(locales_other)]
     "ps-PK",
+    #[cfg(locales_eu)]
     "pt",
+    #[cfg(locales_eu)]
     "pt-AO",
+    #[cfg(locales_eu)]
     "pt-CH",
+    #[cfg(locales_eu)]
     "pt-CV",
+    #[cfg(locales_eu)]
     "pt-GQ",
+    #[cfg(locales_eu)]
     "pt-GW",
+    #[cfg(locales_eu)]
     "pt-LU",
+    #[cfg(locales_eu)]
     "pt-MO",
+    #[cfg(locales_eu)]
     "pt-MZ",
+    #[cfg(locales_eu)]
     "pt-PT",
+    #[cfg(locales_eu)]
     "pt-ST",
+    #[cfg(locales_eu)]
     "pt-TL",
+    #[cfg(locales_other)]
     "qu",
+    #[cfg(locales_other)]
     "qu-BO",
+    #[cfg(locales_other)]
     "qu-EC",
+    #[cfg(locales_other)]
     "rm",
+    #[cfg(locales_other)]
     "rn",
+    #[cfg(locales_eu)]
     "ro",
+    #[cfg(locales_eu)]
     "ro-MD",
+    #[cfg(locales_other)]
     "rof",
+    #[cfg(locales_other)]
     "root",
+    #[cfg(locales_other)]
     "ru",
+    #[cfg(locales_other)]
     "ru-BY",
+    #[cfg(locales_other)]
     "ru-KG",
+    #[cfg(locales_other)]
     "ru-KZ",
+    #[cfg(locales_other)]
     "ru-MD",
+    #[cfg(locales_other)]
     "ru-UA",
+    #[cfg(locales_other)]
     "rw",
+    #[cfg(locales_other)]
     "rwk",
+    #[cfg(locales_other)]
     "sah",
+    #[cfg(locales_other)]
     "saq",
+    #[cfg(locales_other)]
     "sbp",
+    #[cfg(locales_other)]
     "sd",
+    #[cfg(locales_other)]
     "se",
+    #[cfg(locales_other)]
     "se-FI",
+    #[cfg(locales_other)]
     "se-SE",
+    #[cfg(locales_other)]
     "seh",
+    #[cfg(locales_other)]
     "ses",
+    #[cfg(locales_other)]
     "sg",
+    #[cfg(locales_other)]
     "shi",
+    #[cfg(locales_other)]
     "shi-Latn",
+    #[cfg(locales_other)]
     "shi-Tfng",
+    #[cfg(locales_other)]
     "si",
+    #[cfg(locales_eu)]
     "sk",
+    #[cfg(locales_eu)]
     "sl",
+    #[cfg(locales_other)]
     "smn",
+    #[cfg(locales_other)]
     "sn",
+    #[cfg(locales_other)]
     "so",
+    #[cfg(locales_other)]
     "so-DJ",
+    #[cfg(locales_other)]
     "so-ET",
+    #[cfg(locales_other)]
     "so-KE",
+    #[cfg(locales_other)]
     "sq",
+    #[cfg(locales_other)]
     "sq-MK",
+    #[cfg(locales_other)]
     "sq-XK",
+    #[cfg(locales_other)]
     "sr",
+    #[cfg(locales_other)]
     "sr-Cyrl",
+    #[cfg(locales_other)]
     "sr-Cyrl-BA",
+    #[cfg(locales_other)]
     "sr-Cyrl-ME",
+    #[cfg(locales_other)]
     "sr-Cyrl-XK",
+    #[cfg(locales_other)]
     "sr-Latn",
+    #[cfg(locales_other)]
     "sr-Latn-BA",
+    #[cfg(locales_other)]
     "sr-Latn-ME",
+    #[cfg(locales_other)]
     "sr-Latn-XK",
+    #[cfg(locales_eu)]
     "sv",
+    #[cfg(locales_eu)]
     "sv-AX",
+    #[cfg(locales_eu)]
     "sv-FI",
+    #[cfg(locales_other)]
     "sw",
+    #[cfg(locales_other)]
     "sw-CD",
+    #[cfg(locales_other)]
     "sw-KE",
+    #[cfg(locales_other)]
     "sw-UG",
+    #[cfg(locales_other)]
     "ta",
+    #[cfg(locales_other)]
     "ta-LK",
+    #[cfg(locales_other)]
     "ta-MY",
+    #[cfg(locales_other)]
     "ta-SG",
+    #[cfg(locales_other)]
     "te",
+    #[cfg(locales_other)]
     "teo",
+    #[cfg(locales_other)]
     "teo-KE",
+    #[cfg(locales_other)]
     "tg",
+    #[cfg(locales_other)]
     "th",
+    #[cfg(locales_other)]
     "ti",
+    #[cfg(locales_other)]
     "ti-ER",
+    #[cfg(locales_other)]
     "tk",
+    #[cfg(locales_other)]
     "to",
+    #[cfg(locales_other)]
     "tr",
+    #[cfg(locales_other)]
     "tr-CY",
+    #[cfg(locales_other)]
     "tt",
+    #[cfg(locales_other)]
     "twq",
+    #[cfg(locales_other)]
     "tzm",
+    #[cfg(locales_other)]
     "ug",
+    #[cfg(locales_other)]
     "uk",
+    #[cfg(locales_other)]
     "ur",
+    #[cfg(locales_other)]
     "ur-IN",
+    #[cfg(locales_other)]
     "uz",
+    #[cfg(locales_other)]
     "uz-Arab",
+    #[cfg(locales_other)]
     "uz-Cyrl",
+    #[cfg(locales_other)]
     "uz-Latn",
+    #[cfg(locales_other)]
     "vai",
+    #[cfg(locales_other)]
     "vai-Latn",
+    #[cfg(locales_other)]
     "vai-Vaii",
+    #[cfg(locales_other)]
     "vi",
+    #[cfg(locales_other)]
     "vo",
+    #[cfg(locales_other)]
     "vun",
+    #[cfg(locales_other)]
     "wae",
+    #[cfg(locales_other)]
     "wo",
+    #[cfg(locales_other)]
     "xh",
+    #[cfg(locales_other)]
     "xog",
+    #[cfg(locales_other)]
     "yav",
+    #[cfg(locales_other)]
     "yi",
+    #[cfg(locales_other)]
     "yo",
+    #[cfg(locales_other)]
     "yo-BJ",
+    #[cfg(locales_other)]
     "yue",
+    #[cfg(locales_other)]
     "yue-Hans",
+    #[cfg(locales_other)]
     "yue-Hant",
+    #[cfg(locales_other)]
     "zgh",
+    #[cfg(locales_other)]
     "zh",
+    #[cfg(locales_other)]
     "zh-Hans",
+    #[cfg(locales_other)]
     "zh-Hans-HK",
+    #[cfg(locales_other)]
     "zh-Hans-MO",
+    #[cfg(locales_other)]
     "zh-Hans-SG",
+    #[cfg(locales_other)]
     "zh-Hant",
+    #[cfg(locales_other)]
     "zh-Hant-HK",
+    #[cfg(locales_other)]
     "zh-Hant-MO",
+    #[cfg(locales_other)]
     "zu",
 ];
+const ALL: &[Locale] = &[
+    Locale::af,
+    Locale::af_NA,
+    Locale::agq,
+    Locale::ak,
+    Locale::am,
+    Locale::ar,
+    Locale::ar_AE,
+    Locale::ar_BH,
+    Locale::ar_DJ,
+    Locale::ar_DZ,
+    Locale::ar_EG,
+    Locale::ar_EH,
+    Locale::ar_ER,
+    Locale::ar_IL,
+    Locale::ar_IQ,
+    Locale::ar_JO,
+    Locale::ar_KM,
+    Locale::ar_KW,
+    Locale::ar_LB,
+    Locale::ar_LY,
+    Locale::ar_MA,
+    Locale::ar_MR,
+    Locale::ar_OM,
+    Locale::ar_PS,
+    Locale::ar_QA,
+    Locale::ar_SA,
+    Locale::ar_SD,
+    Locale::ar_SO,
+    Locale::ar_SS,
+    Locale::ar_SY,
+    Locale::ar_TD,
+    Locale::ar_TN,
+    Locale::ar_YE,
+    Locale::as_,
+    Locale::asa,
+    Locale::ast,
+    Locale::az,
+    Locale::az_Cyrl,
+    Locale::az_Latn,
+    Locale::bas,
+    Locale::be,
+    Locale::bem,
+    Locale::bez,
+    Locale::bg,
+    Locale::bm,
+    Locale::bn,
+    Locale::bn_IN,
+    Locale::bo,
+    Locale::bo_IN,
+    Locale::br,
+    Locale::brx,
+    Locale::bs,
+    Locale::bs_Cyrl,
+    Locale::bs_Latn,
+    Locale::ca,
+    Locale::ca_AD,
+    Locale::ca_ES_VALENCIA,
+    Locale::ca_FR,
+    Locale::ca_IT,
+    Locale::ccp,
+    Locale::ccp_IN,
+    Locale::ce,
+    Locale::ceb,
+    Locale::cgg,
+    Locale::chr,
+    Locale::ckb,
+    Locale::ckb_IR,
+    Locale::cs,
+    Locale::cu,
+    Locale::cy,
+    Locale::da,
+    Locale::da_GL,
+    Locale::dav,
+    Locale::de,
+    Locale::de_AT,
+    Locale::de_BE,
+    Locale::de_CH,
+    Locale::de_IT,
+    Locale::de_LI,
+    Locale::de_LU,
+    Locale::dje,
+    Locale::dsb,
+    Locale::dua,
+    Locale::dyo,
+    Locale::dz,
+    Locale::ebu,
+    Locale::ee,
+    Locale::ee_TG,
+    Locale::el,
+    Locale::el_CY,
+    Locale::en,
+    Locale::en_001,
+    Locale::en_150,
+    Locale::en_AE,
+    Locale::en_AG,
+    Locale::en_AI,
+    Locale::en_AS,
+    Locale::en_AT,
+    Locale::en_AU,
+    Locale::en_BB,
+    Locale::en_BE,
+    Locale::en_BI,
+    Locale::en_BM,
+    Locale::en_BS,
+    Locale::en_BW,
+    Locale::en_BZ,
+    Locale::en_CA,
+    Locale::en_CC,
+    Locale::en_CH,
+    Locale::en_CK,
+    Locale::en_CM,
+    Locale::en_CX,
+    Locale::en_CY,
+    Locale::en_DE,
+    Locale::en_DG,
+    Locale::en_DK,
+    Locale::en_DM,
+    Locale::en_ER,
+    Locale::en_FI,
+    Locale::en_FJ,
+    Locale::en_FK,
+    Locale::en_FM,
+    Locale::en_GB,
+    Locale::en_GD,
+    Locale::en_GG,
+    Locale::en_GH,
+    Locale::en_GI,
+    Locale::en_GM,
+    Locale::en_GU,
+    Locale::en_GY,
+    Locale::en_HK,
+    Locale::en_IE,
+    Locale::en_IL,
+    Locale::en_IM,
+    Locale::en_IN,
+    Locale::en_IO,
+    Locale::en_JE,
+    Locale::en_JM,
+    Locale::en_KE,
+    Locale::en_KI,
+    Locale::en_KN,
+    Locale::en_KY,
+    Locale::en_LC,
+    Locale::en_LR,
+    Locale::en_LS,
+    Locale::en_MG,
+    Locale::en_MH,
+    Locale::en_MO,
+    Locale::en_MP,
+    Locale::en_MS,
+    Locale::en_MT,
+    Locale::en_MU,
+    Locale::en_MW,
+    Locale::en_MY,
+    Locale::en_NA,
+    Locale::en_NF,
+    Locale::en_NG,
+    Locale::en_NL,
+    Locale::en_NR,
+    Locale::en_NU,
+    Locale::en_NZ,
+    Locale::en_PG,
+    Locale::en_PH,
+    Locale::en_PK,
+    Locale::en_PN,
+    Locale::en_PR,
+    Locale::en_PW,
+    Locale::en_RW,
+    Locale::en_SB,
+    Locale::en_SC,
+    Locale::en_SD,
+    Locale::en_SE,
+    Locale::en_SG,
+    Locale::en_SH,
+    Locale::en_SI,
+    Locale::en_SL,
+    Locale::en_SS,
+    Locale::en_SX,
+    Locale::en_SZ,
+    Locale::en_TC,
+    Locale::en_TK,
+    Locale::en_TO,
+    Locale::en_TT,
+    Locale::en_TV,
+    Locale::en_TZ,
+    Locale::en_UG,
+    Locale::en_UM,
+    Locale::en_US_POSIX,
+    Locale::en_VC,
+    Locale::en_VG,
+    Locale::en_VI,
+    Locale::en_VU,
+    Locale::en_WS,
+    Locale::en_ZA,
+    Locale::en_ZM,
+    Locale::en_ZW,
+    Locale::eo,
+    Locale::es,
+    Locale::es_419,
+    Locale::es_AR,
+    Locale::es_BO,
+    Locale::es_BR,
+    Locale::es_BZ,
+    Locale::es_CL,
+    Locale::es_CO,
+    Locale::es_CR,
+    Locale::es_CU,
+    Locale::es_DO,
+    Locale::es_EA,
+    Locale::es_EC,
+    Locale::es_GQ,
+    Locale::es_GT,
+    Locale::es_HN,
+    Locale::es_IC,
+    Locale::es_MX,
+    Locale::es_NI,
+    Locale::es_PA,
+    Locale::es_PE,
+    Locale::es_PH,
+    Locale::es_PR,
+    Locale::es_PY,
+    Locale::es_SV,
+    Locale::es_US,
+    Locale::es_UY,
+    Locale::es_VE,
+    Locale::et,
+    Locale::eu,
+    Locale::ewo,
+    Locale::fa,
+    Locale::fa_AF,
+    Locale::ff,
+    Locale::ff_Latn,
+    Locale::ff_Latn_BF,
+    Locale::ff_Latn_CM,
+    Locale::ff_Latn_GH,
+    Locale::ff_Latn_GM,
+    Locale::ff_Latn_GN,
+    Locale::ff_Latn_GW,
+    Locale::ff_Latn_LR,
+    Locale::ff_Latn_MR,
+    Locale::ff_Latn_NE,
+    Locale::ff_Latn_NG,
+    Locale::ff_Latn_SL,
+    Locale::fi,
+    Locale::fil,
+    Locale::fo,
+    Locale::fo_DK,
+    Locale::fr,
+    Locale::fr_BE,
+    Locale::fr_BF,
+    Locale::fr_BI,
+    Locale::fr_BJ,
+    Locale::fr_BL,
+    Locale::fr_CA,
+    Locale::fr_CD,
+    Locale::fr_CF,
+    Locale::fr_CG,
+    Locale::fr_CH,
+    Locale::fr_CI,
+    Locale::fr_CM,
+    Locale::fr_DJ,
+    Locale::fr_DZ,
+    Locale::fr_GA,
+    Locale::fr_GF,
+    Locale::fr_GN,
+    Locale::fr_GP,
+    Locale::fr_GQ,
+    Locale::fr_HT,
+    Locale::fr_KM,
+    Locale::fr_LU,
+    Locale::fr_MA,
+    Locale::fr_MC,
+    Locale::fr_MF,
+    Locale::fr_MG,
+    Locale::fr_ML,
+    Locale::fr_MQ,
+    Locale::fr_MR,
+    Locale::fr_MU,
+    Locale::fr_NC,
+    Locale::fr_NE,
+    Locale::fr_PF,
+    Locale::fr_PM,
+    Locale::fr_RE,
+    Locale::fr_RW,
+    Locale::fr_SC,
+    Locale::fr_SN,
+    Locale::fr_SY,
+    Locale::fr_TD,
+    Locale::fr_TG,
+    Locale::fr_TN,
+    Locale::fr_VU,
+    Locale::fr_WF,
+    Locale::fr_YT,
+    Locale::fur,
+    Locale::fy,
+    Locale::ga,
+    Locale::ga_GB,
+    Locale::gd,
+    Locale::gl,
+    Locale::gsw,
+    Locale::gsw_FR,
+    Locale::gsw_LI,
+    Locale::gu,
+    Locale::guz,
+    Locale::gv,
+    Locale::ha,
+    Locale::ha_GH,
+    Locale::ha_NE,
+    Locale::haw,
+    Locale::he,
+    Locale::hi,
+    Locale::hr,
+    Locale::hr_BA,
+    Locale::hsb,
+    Locale::hu,
+    Locale::hy,
+    Locale::ia,
+    Locale::id,
+    Locale::ig,
+    Locale::ii,
+    Locale::is,
+    Locale::it,
+    Locale::it_CH,
+    Locale::it_SM,
+    Locale::it_VA,
+    Locale::ja,
+    Locale::jgo,
+    Locale::jmc,
+    Locale::jv,
+    Locale::ka,
+    Locale::kab,
+    Locale::kam,
+    Locale::kde,
+    Locale::kea,
+    Locale::khq,
+    Locale::ki,
+    Locale::kk,
+    Locale::kkj,
+    Locale::kl,
+    Locale::kln,
+    Locale::km,
+    Locale::kn,
+    Locale::ko,
+    Locale::ko_KP,
+    Locale::kok,
+    Locale::ks,
+    Locale::ksb,
+    Locale::ksf,
+    Locale::ksh,
+    Locale::ku,
+    Locale::kw,
+    Locale::ky,
+    Locale::lag,
+    Locale::lb,
+    Locale::lg,
+    Locale::lkt,
+    Locale::ln,
+    Locale::ln_AO,
+    Locale::ln_CF,
+    Locale::ln_CG,
+    Locale::lo,
+    Locale::lrc,
+    Locale::lrc_IQ,
+    Locale::lt,
+    Locale::lu,
+    Locale::luo,
+    Locale::luy,
+    Locale::lv,
+    Locale::mas,
+    Locale::mas_TZ,
+    Locale::mer,
+    Locale::mfe,
+    Locale::mg,
+    Locale::mgh,
+    Locale::mgo,
+    Locale::mi,
+    Locale::mk,
+    Locale::ml,
+    Locale::mn,
+    Locale::mr,
+    Locale::ms,
+    Locale::ms_BN,
+    Locale::ms_SG,
+    Locale::mt,
+    Locale::mua,
+    Locale::my,
+    Locale::mzn,
+    Locale::naq,
+    Locale::nb,
+    Locale::nb_SJ,
+    Locale::nd,
+    Locale::nds,
+    Locale::nds_NL,
+    Locale::ne,
+    Locale::ne_IN,
+    Locale::nl,
+    Locale::nl_AW,
+    Locale::nl_BE,
+    Locale::nl_BQ,
+    Locale::nl_CW,
+    Locale::nl_SR,
+    Locale::nl_SX,
+    Locale::nmg,
+    Locale::nn,
+    Locale::nnh,
+    Locale::nus,
+    Locale::nyn,
+    Locale::om,
+    Locale::om_KE,
+    Locale::or,
+    Locale::os,
+    Locale::os_RU,
+    Locale::pa,
+    Locale::pa_Arab,
+    Locale::pa_Guru,
+    Locale::pl,
+    Locale::prg,
+    Locale::ps,
+    Locale::ps_PK,
+    Locale::pt,
+    Locale::pt_AO,
+    Locale::pt_CH,
+    Locale::pt_CV,
+    Locale::pt_GQ,
+    Locale::pt_GW,
+    Locale::pt_LU,
+    Locale::pt_MO,
+    Locale::pt_MZ,
+    Locale::pt_PT,
+    Locale::pt_ST,
+    Locale::pt_TL,
+    Locale::qu,
+    Locale::qu_BO,
+    Locale::qu_EC,
+    Locale::rm,
+    Locale::rn,
+    Locale::ro,
+    Locale::ro_MD,
+    Locale::rof,
+    Locale::root,
+    Locale::ru,
+    Locale::ru_BY,
+    Locale::ru_KG,
+    Locale::ru_KZ,
+    Locale::ru_MD,
+    Locale::ru_UA,
+    Locale::rw,
+    Locale::rwk,
+    Locale::sah,
+    Locale::saq,
+    Locale::sbp,
+    Locale::sd,
+    Locale::se,
+    Locale::se_FI,
+    Locale::se_SE,
+    Locale::seh,
+    Locale::ses,
+    Locale::sg,
+    Locale::shi,
+    Locale::shi_Latn,
+    Locale::shi_Tfng,
+    Locale::si,
+    Locale::sk,
+    Locale::sl,
+    Locale::smn,
+    Locale::sn,
+    Locale::so,
+    Locale::so_DJ,
+    Locale::so_ET,
+    Locale::so_KE,
+    Locale::sq,
+    Locale::sq_MK,
+    Locale::sq_XK,
+    Locale::sr,
+    Locale::sr_Cyrl,
+    Locale::sr_Cyrl_BA,
+    Locale::sr_Cyrl_ME,
+    Locale::sr_Cyrl_XK,
+    Locale::sr_Latn,
+    Locale::sr_Latn_BA,
+    Locale::sr_Latn_ME,
+    Locale::sr_Latn_XK,
+    Locale::sv,
+    Locale::sv_AX,
+    Locale::sv_FI,
+    Locale::sw,
+    Locale::sw_CD,
+    Locale::sw_KE,
+    Locale::sw_UG,
+    Locale::ta,
+    Locale::ta_LK,
+    Locale::ta_MY,
+    Locale::ta_SG,
+    Locale::te,
+    Locale::teo,
+    Locale::teo_KE,
+    Locale::tg,
+    Locale::th,
+    Locale::ti,
+    Locale::ti_ER,
+    Locale::tk,
+    Locale::to,
+    Locale::tr,
+    Locale::tr_CY,
+    Locale::tt,
+    Locale::twq,
+    Locale::tzm,
+    Locale::ug,
+    Locale::uk,
+    Locale::ur,
+    Locale::ur_IN,
+    Locale::uz,
+    Locale::uz_Arab,
+    Locale::uz_Cyrl,
+    Locale::uz_Latn,
+    Locale::vai,
+    Locale::vai_Latn,
+    Locale::vai_Vaii,
+    Locale::vi,
+    Locale::vo,
+    Locale::vun,
+    Locale::wae,
+    Locale::wo,
+    Locale::xh,
+    Locale::xog,
+    Locale::yav,
+    Locale::yi,
+    Locale::yo,
+    Locale::yo_BJ,
+    Locale::yue,
+    Locale::yue_Hans,
+    Locale::yue_Hant,
+    Locale::zgh,
+    Locale::zh,
+    Locale::zh_Hans,
+    Locale::zh_Hans_HK,
+    Locale::zh_Hans_MO,
+    Locale::zh_Hans_SG,
+    Locale::zh_Hant,
+    Locale::zh_Hant_HK,
+    Locale::zh_Hant_MO,
+    Locale::zu,
+];
+const ENGLISH_NAMES: &[&str] = &[
+    "Afrikaans",
+    "Afrikaans (Namibia)",
+    "Aghem",
+    "Akan",
+    "Amharic",
+    "Arabic",
+    "Arabic (United Arab Emirates)",
+    "Arabic (Bahrain)",
+    "Arabic (Djibouti)",
+    "Arabic (Algeria)",
+    "Arabic (Egypt)",
+    "Arabic (Western Sahara)",
+    "Arabic (Eritrea)",
+    "Arabic (Israel)",
+    "Arabic (Iraq)",
+    "Arabic (Jordan)",
+    "Arabic (Comoros)",
+    "Arabic (Kuwait)",
+    "Arabic (Lebanon)",
+    "Arabic (Libya)",
+    "Arabic (Morocco)",
+    "Arabic (Mauritania)",
+    "Arabic (Oman)",
+    "Arabic (Palestinian Territories)",
+    "Arabic (Qatar)",
+    "Arabic (Saudi Arabia)",
+    "Arabic (Sudan)",
+    "Arabic (Somalia)",
+    "Arabic (South Sudan)",
+    "Arabic (Syria)",
+    "Arabic (Chad)",
+    "Arabic (Tunisia)",
+    "Arabic (Yemen)",
+    "Assamese",
+    "Asu",
+    "Asturian",
+    "Azerbaijani",
+    "Azerbaijani (Cyrillic)",
+    "Azerbaijani (Latin)",
+    "Basaa",
+    "Belarusian",
+    "Bemba",
+    "Bena",
+    "Bulgarian",
+    "Bambara",
+    "Bangla",
+    "Bangla (India)",
+    "Tibetan",
+    "Tibetan (India)",
+    "Breton",
+    "Bodo",
+    "Bosnian",
+    "Bosnian (Cyrillic)",
+    "Bosnian (Latin)",
+    "Catalan",
+    "Catalan (Andorra)",
+    "Catalan (Spain, Valencian)",
+    "Catalan (France)",
+    "Catalan (Italy)",
+    "Chakma",
+    "Chakma (India)",
+    "Chechen",
+    "Cebuano",
+    "Chiga",
+    "Cherokee",
+    "Central Kurdish",
+    "Central Kurdish (Iran)",
+    "Czech",
+    "Church Slavic",
+    "Welsh",
+    "Danish",
+    "Danish (Greenland)",
+    "Taita",
+    "German",
+    "Austrian German",
+    "German (Belgium)",
+    "Swiss High German",
+    "German (Italy)",
+    "German (Liechtenstein)",
+    "German (Luxembourg)",
+    "Zarma",
+    "Lower Sorbian",
+    "Duala",
+    "Jola-Fonyi",
+    "Dzongkha",
+    "Embu",
+    "Ewe",
+    "Ewe (Togo)",
+    "Greek",
+    "Greek (Cyprus)",
+    "English",
+    "English (world)",
+    "English (Europe)",
+    "English (United Arab Emirates)",
+    "English (Antigua & Barbuda)",
+    "English (Anguilla)",
+    "English (American Samoa)",
+    "English (Austria)",
+    "Australian English",
+    "English (Barbados)",
+    "English (Belgium)",
+    "English (Burundi)",
+    "English (Bermuda)",
+    "English (Bahamas)",
+    "English (Botswana)",
+    "English (Belize)",
+    "Canadian English",
+    "English (Cocos (Keeling) Islands)",
+    "English (Switzerland)",
+    "English (Cook Islands)",
+    "English (Cameroon)",
+    "English (Christmas Island)",
+    "English (Cyprus)",
+    "English (Germany)",
+    "English (Diego Garcia)",
+    "English (Denmark)",
+    "English (Dominica)",
+    "English (Eritrea)",
+    "English (Finland)",
+    "English (Fiji)",
+    "English (Falkland Islands)",
+    "English (Micronesia)",
+    "British English",
+    "English (Grenada)",
+    "English (Guernsey)",
+    "English (Ghana)",
+    "English (Gibraltar)",
+    "English (Gambia)",
+    "English (Guam)",
+    "English (Guyana)",
+    "English (Hong Kong SAR China)",
+    "English (Ireland)",
+    "English (Israel)",
+    "English (Isle of Man)",
+    "English (India)",
+    "English (British Indian Ocean Territory)",
+    "English (Jersey)",
+    "English (Jamaica)",
+    "English (Kenya)",
+    "English (Kiribati)",
+    "English (St. Kitts & Nevis)",
+    "English (Cayman Islands)",
+    "English (St. Lucia)",
+    "English (Liberia)",
+    "English (Lesotho)",
+    "English (Madagascar)",
+    "English (Marshall Islands)",
+    "English (Macao SAR China)",
+    "English (Northern Mariana Islands)",
+    "English (Montserrat)",
+    "English (Malta)",
+    "English (Mauritius)",
+    "English (Malawi)",
+    "English (Malaysia)",
+    "English (Namibia)",
+    "English (Norfolk Island)",
+    "English (Nigeria)",
+    "English (Netherlands)",
+    "English (Nauru)",
+    "English (Niue)",
+    "English (New Zealand)",
+    "English (Papua New Guinea)",
+    "English (Philippines)",
+    "English (Pakistan)",
+    "English (Pitcairn Islands)",
+    "English (Puerto Rico)",
+    "English (Palau)",
+    "English (Rwanda)",
+    "English (Solomon Islands)",
+    "English (Seychelles)",
+    "English (Sudan)",
+    "English (Sweden)",
+    "English (Singapore)",
+    "English (St. Helena)",
+    "English (Slovenia)",
+    "English (Sierra Leone)",
+    "English (South Sudan)",
+    "English (Sint Maarten)",
+    "English (Eswatini)",
+    "English (Turks & Caicos Islands)",
+    "English (Tokelau)",
+    "English (Tonga)",
+    "English (Trinidad & Tobago)",
+    "English (Tuvalu)",
+    "English (Tanzania)",
+    "English (Uganda)",
+    "English (U.S. Outlying Islands)",
+    "American English (Computer)",
+    "English (St. Vincent & Grenadines)",
+    "English (British Virgin Islands)",
+    "English (U.S. Virgin Islands)",
+    "English (Vanuatu)",
+    "English (Samoa)",
+    "English (South Africa)",
+    "English (Zambia)",
+    "English (Zimbabwe)",
+    "Esperanto",
+    "Spanish",
+    "Latin American Spanish",
+    "Spanish (Argentina)",
+    "Spanish (Bolivia)",
+    "Spanish (Brazil)",
+    "Spanish (Belize)",
+    "Spanish (Chile)",
+    "Spanish (Colombia)",
+    "Spanish (Costa Rica)",
+    "Spanish (Cuba)",
+    "Spanish (Dominican Republic)",
+    "Spanish (Ceuta & Melilla)",
+    "Spanish (Ecuador)",
+    "Spanish (Equatorial Guinea)",
+    "Spanish (Guatemala)",
+    "Spanish (Honduras)",
+    "Spanish (Canary Islands)",
+    "Mexican Spanish",
+    "Spanish (Nicaragua)",
+    "Spanish (Panama)",
+    "Spanish (Peru)",
+    "Spanish (Philippines)",
+    "Spanish (Puerto Rico)",
+    "Spanish (Paraguay)",
+    "Spanish (El Salvador)",
+    "Spanish (United States)",
+    "Spanish (Uruguay)",
+    "Spanish (Venezuela)",
+    "Estonian",
+    "Basque",
+    "Ewondo",
+    "Persian",
+    "Dari",
+    "Fula",
+    "Fula (Latin)",
+    "Fula (Latin, Burkina Faso)",
+    "Fula (Latin, Cameroon)",
+    "Fula (Latin, Ghana)",
+    "Fula (Latin, Gambia)",
+    "Fula (Latin, Guinea)",
+    "Fula (Latin, Guinea-Bissau)",
+    "Fula (Latin, Liberia)",
+    "Fula (Latin, Mauritania)",
+    "Fula (Latin, Niger)",
+    "Fula (Latin, Nigeria)",
+    "Fula (Latin, Sierra Leone)",
+    "Finnish",
+    "Filipino",
+    "Faroese",
+    "Faroese (Denmark)",
+    "French",
+    "French (Belgium)",
+    "French (Burkina Faso)",
+    "French (Burundi)",
+    "French (Benin)",
+    "French (St. Barthélemy)",
+    "Canadian French",
+    "French (Congo - Kinshasa)",
+    "French (Central African Republic)",
+    "French (Congo - Brazzaville)",
+    "Swiss French",
+    "French (Côte d’Ivoire)",
+    "French (Cameroon)",
+    "French (Djibouti)",
+    "French (Algeria)",
+    "French (Gabon)",
+    "French (French Guiana)",
+    "French (Guinea)",
+    "French (Guadeloupe)",
+    "French (Equatorial Guinea)",
+    "French (Haiti)",
+    "French (Comoros)",
+    "French (Luxembourg)",
+    "French (Morocco)",
+    "French (Monaco)",
+    "French (St. Martin)",
+    "French (Madagascar)",
+    "French (Mali)",
+    "French (Martinique)",
+    "French (Mauritania)",
+    "French (Mauritius)",
+    "French (New Caledonia)",
+    "French (Niger)",
+    "French (French Polynesia)",
+    "French (St. Pierre & Miquelon)",
+    "French (Réunion)",
+    "French (Rwanda)",
+    "French (Seychelles)",
+    "French (Senegal)",
+    "French (Syria)",
+    "French (Chad)",
+    "French (Togo)",
+    "French (Tunisia)",
+    "French (Vanuatu)",
+    "French (Wallis & Futuna)",
+    "French (Mayotte)",
+    "Friulian",
+    "Western Frisian",
+    "Irish",
+    "Irish (United Kingdom)",
+    "Scottish Gaelic",
+    "Galician",
+    "Swiss German",
+    "Swiss German (France)",
+    "Swiss German (Liechtenstein)",
+    "Gujarati",
+    "Gusii",
+    "Manx",
+    "Hausa",
+    "Hausa (Ghana)",
+    "Hausa (Niger)",
+    "Hawaiian",
+    "Hebrew",
+    "Hindi",
+    "Croatian",
+    "Croatian (Bosnia & Herzegovina)",
+    "Upper Sorbian",
+    "Hungarian",
+    "Armenian",
+    "Interlingua",
+    "Indonesian",
+    "Igbo",
+    "Sichuan Yi",
+    "Icelandic",
+    "Italian",
+    "Italian (Switzerland)",
+    "Italian (San Marino)",
+    "Italian (Vatican City)",
+    "Japanese",
+    "Ngomba",
+    "Machame",
+    "Javanese",
+    "Georgian",
+    "Kabyle",
+    "Kamba",
+    "Makonde",
+    "Kabuverdianu",
+    "Koyra Chiini",
+    "Kikuyu",
+    "Kazakh",
+    "Kako",
+    "Kalaallisut",
+    "Kalenjin",
+    "Khmer",
+    "Kannada",
+    "Korean",
+    "Korean (North Korea)",
+    "Konkani",
+    "Kashmiri",
+    "Shambala",
+    "Bafia",
+    "Colognian",
+    "Kurdish",
+    "Cornish",
+    "Kyrgyz",
+    "Langi",
+    "Luxembourgish",
+    "Ganda",
+    "Lakota",
+    "Lingala",
+    "Lingala (Angola)",
+    "Lingala (Central African Republic)",
+    "Lingala (Congo - Brazzaville)",
+    "Lao",
+    "Northern Luri",
+    "Northern Luri (Iraq)",
+    "Lithuanian",
+    "Luba-Katanga",
+    "Luo",
+    "Luyia",
+    "Latvian",
+    "Masai",
+    "Masai (Tanzania)",
+    "Meru",
+    "Morisyen",
+    "Malagasy",
+    "Makhuwa-Meetto",
+    "Metaʼ",
+    "Māori",
+    "Macedonian",
+    "Malayalam",
+    "Mongolian",
+    "Marathi",
+    "Malay",
+    "Malay (Brunei)",
+    "Malay (Singapore)",
+    "Maltese",
+    "Mundang",
+    "Burmese",
+    "Mazanderani",
+    "Nama",
+    "Norwegian Bokmål",
+    "Norwegian Bokmål (Svalbard & Jan Mayen)",
+    "North Ndebele",
+    "Low German",
+    "Low Saxon",
+    "Nepali",
+    "Nepali (India)",
+    "Dutch",
+    "Dutch (Aruba)",
+    "Flemish",
+    "Dutch (Caribbean Netherlands)",
+    "Dutch (Curaçao)",
+    "Dutch (Suriname)",
+    "Dutch (Sint Maarten)",
+    "Kwasio",
+    "Norwegian Nynorsk",
+    "Ngiemboon",
+    "Nuer",
+    "Nyankole",
+    "Oromo",
+    "Oromo (Kenya)",
+    "Odia",
+    "Ossetic",
+    "Ossetic (Russia)",
+    "Punjabi",
+    "Punjabi (Arabic)",
+    "Punjabi (Gurmukhi)",
+    "Polish",
+    "Prussian",
+    "Pashto",
+    "Pashto (Pakistan)",
+    "Portuguese",
+    "Portuguese (Angola)",
+    "Portuguese (Switzerland)",
+    "Portuguese (Cape Verde)",
+    "Portuguese (Equatorial Guinea)",
+    "Portuguese (Guinea-Bissau)",
+    "Portuguese (Luxembourg)",
+    "Portuguese (Macao SAR China)",
+    "Portuguese (Mozambique)",
+    "European Portuguese",
+    "Portuguese (São Tomé & Príncipe)",
+    "Portuguese (Timor-Leste)",
+    "Quechua",
+    "Quechua (Bolivia)",
+    "Quechua (Ecuador)",
+    "Romansh",
+    "Rundi",
+    "Romanian",
+    "Moldavian",
+    "Rombo",
+    "Root",
+    "Russian",
+    "Russian (Belarus)",
+    "Russian (Kyrgyzstan)",
+    "Russian (Kazakhstan)",
+    "Russian (Moldova)",
+    "Russian (Ukraine)",
+    "Kinyarwanda",
+    "Rwa",
+    "Yakut",
+    "Samburu",
+    "Sangu",
+    "Sindhi",
+    "Northern Sami",
+    "Northern Sami (Finland)",
+    "Northern Sami (Sweden)",
+    "Sena",
+    "Koyraboro Senni",
+    "Sango",
+    "Tachelhit",
+    "Tachelhit (Latin)",
+    "Tachelhit (Tifinagh)",
+    "Sinhala",
+    "Slovak",
+    "Slovenian",
+    "Inari Sami",
+    "Shona",
+    "Somali",
+    "Somali (Djibouti)",
+    "Somali (Ethiopia)",
+    "Somali (Kenya)",
+    "Albanian",
+    "Albanian (North Macedonia)",
+    "Albanian (Kosovo)",
+    "Serbian",
+    "Serbian (Cyrillic)",
+    "Serbian (Cyrillic, Bosnia & Herzegovina)",
+    "Montenegrin (Cyrillic)",
+    "Serbian (Cyrillic, Kosovo)",
+    "Serbian (Latin)",
+    "Serbian (Latin, Bosnia & Herzegovina)",
+    "Montenegrin (Latin)",
+    "Serbian (Latin, Kosovo)",
+    "Swedish",
+    "Swedish (Åland Islands)",
+    "Swedish (Finland)",
+    "Swahili",
+    "Congo Swahili",
+    "Swahili (Kenya)",
+    "Swahili (Uganda)",
+    "Tamil",
+    "Tamil (Sri Lanka)",
+    "Tamil (Malaysia)",
+    "Tamil (Singapore)",
+    "Telugu",
+    "Teso",
+    "Teso (Kenya)",
+    "Tajik",
+    "Thai",
+    "Tigrinya",
+    "Tigrinya (Eritrea)",
+    "Turkmen",
+    "Tongan",
+    "Turkish",
+    "Turkish (Cyprus)",
+    "Tatar",
+    "Tasawaq",
+    "Central Atlas Tamazight",
+    "Uyghur",
+    "Ukrainian",
+    "Urdu",
+    "Urdu (India)",
+    "Uzbek",
+    "Uzbek (Arabic)",
+    "Uzbek (Cyrillic)",
+    "Uzbek (Latin)",
+    "Vai",
+    "Vai (Latin)",
+    "Vai (Vai)",
+    "Vietnamese",
+    "Volapük",
+    "Vunjo",
+    "Walser",
+    "Wolof",
+    "Xhosa",
+    "Soga",
+    "Yangben",
+    "Yiddish",
+    "Yoruba",
+    "Yoruba (Benin)",
+    "Cantonese",
+    "Cantonese (Simplified)",
+    "Cantonese (Traditional)",
+    "Standard Moroccan Tamazight",
+    "Chinese",
+    "Simplified Chinese",
+    "Simplified Chinese (Hong Kong SAR China)",
+    "Simplified Chinese (Macao SAR China)",
+    "Simplified Chinese (Singapore)",
+    "Traditional Chinese",
+    "Traditional Chinese (Hong Kong SAR China)",
+    "Traditional Chinese (Macao SAR China)",
+    "Zulu",
+];
+#[doc = r" Deprecated language codes and their replacements, from CLDR's `aliases.json`."]
+pub(crate) const LANGUAGE_ALIASES: &[(&str, &str)] = &[
+    ("adp", "dz"),
+    ("afr", "af"),
+    ("aka", "ak"),
+    ("alb", "sq"),
+    ("als", "sq"),
+    ("amh", "am"),
+    ("ara", "ar"),
+    ("arb", "ar"),
+    ("arm", "hy"),
+    ("asm", "as"),
+    ("aze", "az"),
+    ("azj", "az"),
+    ("bam", "bm"),
+    ("baq", "eu"),
+    ("bel", "be"),
+    ("ben", "bn"),
+    ("bod", "bo"),
+    ("bos", "bs"),
+    ("bre", "br"),
+    ("bul", "bg"),
+    ("bur", "my"),
+    ("bxk", "luy"),
+    ("cat", "ca"),
+    ("ces", "cs"),
+    ("che", "ce"),
+    ("chi", "zh"),
+    ("chu", "cu"),
+    ("cmn", "zh"),
+    ("cor", "kw"),
+    ("cym", "cy"),
+    ("cze", "cs"),
+    ("dan", "da"),
+    ("deu", "de"),
+    ("drh", "mn"),
+    ("dut", "nl"),
+    ("dzo", "dz"),
+    ("ekk", "et"),
+    ("ell", "el"),
+    ("eng", "en"),
+    ("epo", "eo"),
+    ("est", "et"),
+    ("eus", "eu"),
+    ("ewe", "ee"),
+    ("fao", "fo"),
+    ("fas", "fa"),
+    ("fat", "ak"),
+    ("fin", "fi"),
+    ("fra", "fr"),
+    ("fre", "fr"),
+    ("fry", "fy"),
+    ("fuc", "ff"),
+    ("ful", "ff"),
+    ("gaz", "om"),
+    ("geo", "ka"),
+    ("ger", "de"),
+    ("gla", "gd"),
+    ("gle", "ga"),
+    ("glg", "gl"),
+    ("glv", "gv"),
+    ("gom", "kok"),
+    ("gre", "el"),
+    ("guj", "gu"),
+    ("hau", "ha"),
+    ("heb", "he"),
+    ("hin", "hi"),
+    ("hrv", "hr"),
+    ("hun", "hu"),
+    ("hye", "hy"),
+    ("ibo", "ig"),
+    ("ice", "is"),
+    ("iii", "ii"),
+    ("in", "id"),
+    ("ina", "ia"),
+    ("ind", "id"),
+    ("isl", "is"),
+    ("ita", "it"),
+    ("iw", "he"),
+    ("jav", "jv"),
+    ("jaw", "jv"),
+    ("ji", "yi"),
+    ("jpn", "ja"),
+    ("jw", "jv"),
+    ("kal", "kl"),
+    ("kan", "kn"),
+    ("kas", "ks"),
+    ("kat", "ka"),
+    ("kaz", "kk"),
+    ("khk", "mn"),
+    ("khm", "km"),
+    ("kik", "ki"),
+    ("kin", "rw"),
+    ("kir", "ky"),
+    ("kmr", "ku"),
+    ("kor", "ko"),
+    ("kur", "ku"),
+    ("lao", "lo"),
+    ("lav", "lv"),
+    ("lin", "ln"),
+    ("lit", "lt"),
+    ("ltz", "lb"),
+    ("lub", "lu"),
+    ("lug", "lg"),
+    ("lvs", "lv"),
+    ("mac", "mk"),
+    ("mal", "ml"),
+    ("mao", "mi"),
+    ("mar", "mr"),
+    ("may", "ms"),
+    ("mkd", "mk"),
+    ("mlg", "mg"),
+    ("mlt", "mt"),
+    ("mo", "ro"),
+    ("mol", "ro"),
+    ("mon", "mn"),
+    ("mri", "mi"),
+    ("msa", "ms"),
+    ("mya", "my"),
+    ("nde", "nd"),
+    ("nep", "ne"),
+    ("nld", "nl"),
+    ("nno", "nn"),
+    ("nob", "nb"),
+    ("npi", "ne"),
+    ("ori", "or"),
+    ("orm", "om"),
+    ("ory", "or"),
+    ("oss", "os"),
+    ("pan", "pa"),
+    ("pbu", "ps"),
+    ("per", "fa"),
+    ("pes", "fa"),
+    ("plt", "mg"),
+    ("pol", "pl"),
+    ("por", "pt"),
+    ("prp", "gu"),
+    ("pus", "ps"),
+    ("que", "qu"),
+    ("quz", "qu"),
+    ("roh", "rm"),
+    ("ron", "ro"),
+    ("rum", "ro"),
+    ("run", "rn"),
+    ("rus", "ru"),
+    ("sag", "sg"),
+    ("scc", "sr"),
+    ("scr", "hr"),
+    ("sin", "si"),
+    ("slk", "sk"),
+    ("slo", "sk"),
+    ("slv", "sl"),
+    ("sme", "se"),
+    ("sna", "sn"),
+    ("snd", "sd"),
+    ("som", "so"),
+    ("spa", "es"),
+    ("spy", "kln"),
+    ("sqi", "sq"),
+    ("srp", "sr"),
+    ("swa", "sw"),
+    ("swe", "sv"),
+    ("swh", "sw"),
+    ("tam", "ta"),
+    ("tat", "tt"),
+    ("tel", "te"),
+    ("tgk", "tg"),
+    ("tgl", "fil"),
+    ("tha", "th"),
+    ("tib", "bo"),
+    ("tir", "ti"),
+    ("tl", "fil"),
+    ("ton", "to"),
+    ("tuk", "tk"),
+    ("tur", "tr"),
+    ("twi", "ak"),
+    ("uig", "ug"),
+    ("ukr", "uk"),
+    ("urd", "ur"),
+    ("uzb", "uz"),
+    ("uzn", "uz"),
+    ("vie", "vi"),
+    ("vol", "vo"),
+    ("wel", "cy"),
+    ("wol", "wo"),
+    ("xho", "xh"),
+    ("ydd", "yi"),
+    ("yid", "yi"),
+    ("yor", "yo"),
+    ("zho", "zh"),
+    ("zsm", "ms"),
+    ("zul", "zu"),
+];
+#[doc = r" Scripts implied by a language and region when a tag names no script, from CLDR's"]
+#[doc = r" `likelySubtags.json`. Only those that differ from the language's own likely script"]
+#[doc = r" are listed."]
+pub(crate) const LIKELY_SCRIPTS: &[(&str, &str, &str)] = &[
+    ("az", "IQ", "Arab"),
+    ("az", "IR", "Arab"),
+    ("az", "RU", "Cyrl"),
+    ("ha", "CM", "Arab"),
+    ("ha", "SD", "Arab"),
+    ("kk", "AF", "Arab"),
+    ("kk", "CN", "Arab"),
+    ("kk", "IR", "Arab"),
+    ("kk", "MN", "Arab"),
+    ("ku", "AM", "Cyrl"),
+    ("ku", "AZ", "Cyrl"),
+    ("ku", "GE", "Cyrl"),
+    ("ku", "IQ", "Arab"),
+    ("ku", "IR", "Arab"),
+    ("ku", "LB", "Arab"),
+    ("ku", "TM", "Cyrl"),
+    ("ky", "CN", "Arab"),
+    ("ky", "TR", "Latn"),
+    ("mn", "CN", "Mong"),
+    ("ms", "CC", "Arab"),
+    ("pa", "PK", "Arab"),
+    ("sd", "IN", "Deva"),
+    ("sr", "ME", "Latn"),
+    ("sr", "RO", "Latn"),
+    ("sr", "TR", "Latn"),
+    ("tg", "PK", "Arab"),
+    ("ug", "KZ", "Cyrl"),
+    ("ug", "MN", "Cyrl"),
+    ("uz", "AF", "Arab"),
+    ("uz", "CN", "Cyrl"),
+    ("yue", "CN", "Hans"),
+    ("zh", "AU", "Hant"),
+    ("zh", "BN", "Hant"),
+    ("zh", "GB", "Hant"),
+    ("zh", "GF", "Hant"),
+    ("zh", "HK", "Hant"),
+    ("zh", "ID", "Hant"),
+    ("zh", "MO", "Hant"),
+    ("zh", "PA", "Hant"),
+    ("zh", "PF", "Hant"),
+    ("zh", "PH", "Hant"),
+    ("zh", "SR", "Hant"),
+    ("zh", "TH", "Hant"),
+    ("zh", "TW", "Hant"),
+    ("zh", "US", "Hant"),
+    ("zh", "VN", "Hant"),
+];
+const NAMES: &[&str] = &[
+    "af",
+    "af-NA",
+    "agq",
+    "ak",
+    "am",
+    "ar",
+    "ar-AE",
+    "ar-BH",
+    "ar-DJ",
+    "ar-DZ",
+    "ar-EG",
+    "ar-EH",
+    "ar-ER",
+    "ar-IL",
+    "ar-IQ",
+    "ar-JO",
+    "ar-KM",
+    "ar-KW",
+    "ar-LB",
+    "ar-LY",
+    "ar-MA",
+    "ar-MR",
+    "ar-OM",
+    "ar-PS",
+    "ar-QA",
+    "ar-SA",
+    "ar-SD",
+    "ar-SO",
+    "ar-SS",
+    "ar-SY",
+    "ar-TD",
+    "ar-TN",
+    "ar-YE",
+    "as",
+    "asa",
+    "ast",
+    "az",
+    "az-Cyrl",
+    "az-Latn",
+    "bas",
+    "be",
+    "bem",
+    "bez",
+    "bg",
+    "bm",
+    "bn",
+    "bn-IN",
+    "bo",
+    "bo-IN",
+    "br",
+    "brx",
+    "bs",
+    "bs-Cyrl",
+    "bs-Latn",
+    "ca",
+    "ca-AD",
+    "ca-ES-VALENCIA",
+    "ca-FR",
+    "ca-IT",
+    "ccp",
+    "ccp-IN",
+    "ce",
+    "ceb",
+    "cgg",
+    "chr",
+    "ckb",
+    "ckb-IR",
+    "cs",
+    "cu",
+    "cy",
+    "da",
+    "da-GL",
+    "dav",
+    "de",
+    "de-AT",
+    "de-BE",
+    "de-CH",
+    "de-IT",
+    "de-LI",
+    "de-LU",
+    "dje",
+    "dsb",
+    "dua",
+    "dyo",
+    "dz",
+    "ebu",
+    "ee",
+    "ee-TG",
+    "el",
+    "el-CY",
+    "en",
+    "en-001",
+    "en-150",
+    "en-AE",
+    "en-AG",
+    "en-AI",
+    "en-AS",
+    "en-AT",
+    "en-AU",
+    "en-BB",
+    "en-BE",
+    "en-BI",
+    "en-BM",
+    "en-BS",
+    "en-BW",
+    "en-BZ",
+    "en-CA",
+    "en-CC",
+    "en-CH",
+    "en-CK",
+    "en-CM",
+    "en-CX",
+    "en-CY",
+    "en-DE",
+    "en-DG",
+    "en-DK",
+    "en-DM",
+    "en-ER",
+    "en-FI",
+    "en-FJ",
+    "en-FK",
+    "en-FM",
+    "en-GB",
+    "en-GD",
+    "en-GG",
+    "en-GH",
+    "en-GI",
+    "en-GM",
+    "en-GU",
+    "en-GY",
+    "en-HK",
+    "en-IE",
+    "en-IL",
+    "en-IM",
+    "en-IN",
+    "en-IO",
+    "en-JE",
+    "en-JM",
+    "en-KE",
+    "en-KI",
+    "en-KN",
+    "en-KY",
+    "en-LC",
+    "en-LR",
+    "en-LS",
+    "en-MG",
+    "en-MH",
+    "en-MO",
+    "en-MP",
+    "en-MS",
+    "en-MT",
+    "en-MU",
+    "en-MW",
+    "en-MY",
+    "en-NA",
+    "en-NF",
+    "en-NG",
+    "en-NL",
+    "en-NR",
+    "en-NU",
+    "en-NZ",
+    "en-PG",
+    "en-PH",
+    "en-PK",
+    "en-PN",
+    "en-PR",
+    "en-PW",
+    "en-RW",
+    "en-SB",
+    "en-SC",
+    "en-SD",
+    "en-SE",
+    "en-SG",
+    "en-SH",
+    "en-SI",
+    "en-SL",
+    "en-SS",
+    "en-SX",
+    "en-SZ",
+    "en-TC",
+    "en-TK",
+    "en-TO",
+    "en-TT",
+    "en-TV",
+    "en-TZ",
+    "en-UG",
+    "en-UM",
+    "en-US-POSIX",
+    "en-VC",
+    "en-VG",
+    "en-VI",
+    "en-VU",
+    "en-WS",
+    "en-ZA",
+    "en-ZM",
+    "en-ZW",
+    "eo",
+    "es",
+    "es-419",
+    "es-AR",
+    "es-BO",
+    "es-BR",
+    "es-BZ",
+    "es-CL",
+    "es-CO",
+    "es-CR",
+    "es-CU",
+    "es-DO",
+    "es-EA",
+    "es-EC",
+    "es-GQ",
+    "es-GT",
+    "es-HN",
+    "es-IC",
+    "es-MX",
+    "es-NI",
+    "es-PA",
+    "es-PE",
+    "es-PH",
+    "es-PR",
+    "es-PY",
+    "es-SV",
+    "es-US",
+    "es-UY",
+    "es-VE",
+    "et",
+    "eu",
+    "ewo",
+    "fa",
+    "fa-AF",
+    "ff",
+    "ff-Latn",
+    "ff-Latn-BF",
+    "ff-Latn-CM",
+    "ff-Latn-GH",
+    "ff-Latn-GM",
+    "ff-Latn-GN",
+    "ff-Latn-GW",
+    "ff-Latn-LR",
+    "ff-Latn-MR",
+    "ff-Latn-NE",
+    "ff-Latn-NG",
+    "ff-Latn-SL",
+    "fi",
+    "fil",
+    "fo",
+    "fo-DK",
+    "fr",
+    "fr-BE",
+    "fr-BF",
+    "fr-BI",
+    "fr-BJ",
+    "fr-BL",
+    "fr-CA",
+    "fr-CD",
+    "fr-CF",
+    "fr-CG",
+    "fr-CH",
+    "fr-CI",
+    "fr-CM",
+    "fr-DJ",
+    "fr-DZ",
+    "fr-GA",
+    "fr-GF",
+    "fr-GN",
+    "fr-GP",
+    "fr-GQ",
+    "fr-HT",
+    "fr-KM",
+    "fr-LU",
+    "fr-MA",
+    "fr-MC",
+    "fr-MF",
+    "fr-MG",
+    "fr-ML",
+    "fr-MQ",
+    "fr-MR",
+    "fr-MU",
+    "fr-NC",
+    "fr-NE",
+    "fr-PF",
+    "fr-PM",
+    "fr-RE",
+    "fr-RW",
+    "fr-SC",
+    "fr-SN",
+    "fr-SY",
+    "fr-TD",
+    "fr-TG",
+    "fr-TN",
+    "fr-VU",
+    "fr-WF",
+    "fr-YT",
+    "fur",
+    "fy",
+    "ga",
+    "ga-GB",
+    "gd",
+    "gl",
+    "gsw",
+    "gsw-FR",
+    "gsw-LI",
+    "gu",
+    "guz",
+    "gv",
+    "ha",
+    "ha-GH",
+    "ha-NE",
+    "haw",
+    "he",
+    "hi",
+    "hr",
+    "hr-BA",
+    "hsb",
+    "hu",
+    "hy",
+    "ia",
+    "id",
+    "ig",
+    "ii",
+    "is",
+    "it",
+    "it-CH",
+    "it-SM",
+    "it-VA",
+    "ja",
+    "jgo",
+    "jmc",
+    "jv",
+    "ka",
+    "kab",
+    "kam",
+    "kde",
+    "kea",
+    "khq",
+    "ki",
+    "kk",
+    "kkj",
+    "kl",
+    "kln",
+    "km",
+    "kn",
+    "ko",
+    "ko-KP",
+    "kok",
+    "ks",
+    "ksb",
+    "ksf",
+    "ksh",
+    "ku",
+    "kw",
+    "ky",
+    "lag",
+    "lb",
+    "lg",
+    "lkt",
+    "ln",
+    "ln-AO",
+    "ln-CF",
+    "ln-CG",
+    "lo",
+    "lrc",
+    "lrc-IQ",
+    "lt",
+    "lu",
+    "luo",
+    "luy",
+    "lv",
+    "mas",
+    "mas-TZ",
+    "mer",
+    "mfe",
+    "mg",
+    "mgh",
+    "mgo",
+    "mi",
+    "mk",
+    "ml",
+    "mn",
+    "mr",
+    "ms",
+    "ms-BN",
+    "ms-SG",
+    "mt",
+    "mua",
+    "my",
+    "mzn",
+    "naq",
+    "nb",
+    "nb-SJ",
+    "nd",
+    "nds",
+    "nds-NL",
+    "ne",
+    "ne-IN",
+    "nl",
+    "nl-AW",
+    "nl-BE",
+    "nl-BQ",
+    "nl-CW",
+    "nl-SR",
+    "nl-SX",
+    "nmg",
+    "nn",
+    "nnh",
+    "nus",
+    "nyn",
+    "om",
+    "om-KE",
+    "or",
+    "os",
+    "os-RU",
+    "pa",
+    "pa-Arab",
+    "pa-Guru",
+    "pl",
+    "prg",
+    "ps",
+    "ps-PK",
+    "pt",
+    "pt-AO",
+    "pt-CH",
+    "pt-CV",
+    "pt-GQ",
+    "pt-GW",
+    "pt-LU",
+    "pt-MO",
+    "pt-MZ",
+    "pt-PT",
+    "pt-ST",
+    "pt-TL",
+    "qu",
+    "qu-BO",
+    "qu-EC",
+    "rm",
+    "rn",
+    "ro",
+    "ro-MD",
+    "rof",
+    "root",
+    "ru",
+    "ru-BY",
+    "ru-KG",
+    "ru-KZ",
+    "ru-MD",
+    "ru-UA",
+    "rw",
+    "rwk",
+    "sah",
+    "saq",
+    "sbp",
+    "sd",
+    "se",
+    "se-FI",
+    "se-SE",
+    "seh",
+    "ses",
+    "sg",
+    "shi",
+    "shi-Latn",
+    "shi-Tfng",
+    "si",
+    "sk",
+    "sl",
+    "smn",
+    "sn",
+    "so",
+    "so-DJ",
+    "so-ET",
+    "so-KE",
+    "sq",
+    "sq-MK",
+    "sq-XK",
+    "sr",
+    "sr-Cyrl",
+    "sr-Cyrl-BA",
+    "sr-Cyrl-ME",
+    "sr-Cyrl-XK",
+    "sr-Latn",
+    "sr-Latn-BA",
+    "sr-Latn-ME",
+    "sr-Latn-XK",
+    "sv",
+    "sv-AX",
+    "sv-FI",
+    "sw",
+    "sw-CD",
+    "sw-KE",
+    "sw-UG",
+    "ta",
+    "ta-LK",
+    "ta-MY",
+    "ta-SG",
+    "te",
+    "teo",
+    "teo-KE",
+    "tg",
+    "th",
+    "ti",
+    "ti-ER",
+    "tk",
+    "to",
+    "tr",
+    "tr-CY",
+    "tt",
+    "twq",
+    "tzm",
+    "ug",
+    "uk",
+    "ur",
+    "ur-IN",
+    "uz",
+    "uz-Arab",
+    "uz-Cyrl",
+    "uz-Latn",
+    "vai",
+    "vai-Latn",
+    "vai-Vaii",
+    "vi",
+    "vo",
+    "vun",
+    "wae",
+    "wo",
+    "xh",
+    "xog",
+    "yav",
+    "yi",
+    "yo",
+    "yo-BJ",
+    "yue",
+    "yue-Hans",
+    "yue-Hant",
+    "zgh",
+    "zh",
+    "zh-Hans",
+    "zh-Hans-HK",
+    "zh-Hans-MO",
+    "zh-Hans-SG",
+    "zh-Hant",
+    "zh-Hant-HK",
+    "zh-Hant-MO",
+    "zu",
+];
+const NATIVE_NAMES: &[&str] = &[
+    "Afrikaans",
+    "Afrikaans (Namibië)",
+    "Aghem",
+    "Akan",
+    "አማርኛ",
+    "العربية",
+    "العربية (الإمارات العربية المتحدة)",
+    "العربية (البحرين)",
+    "العربية (جيبوتي)",
+    "العربية (الجزائر)",
+    "العربية (مصر)",
+    "العربية (الصحراء الغربية)",
+    "العربية (إريتريا)",
+    "العربية (إسرائيل)",
+    "العربية (العراق)",
+    "العربية (الأردن)",
+    "العربية (جزر القمر)",
+    "العربية (الكويت)",
+    "العربية (لبنان)",
+    "العربية (ليبيا)",
+    "العربية (المغرب)",
+    "العربية (موريتانيا)",
+    "العربية (ع\u{64f}مان)",
+    "العربية (الأراضي الفلسطينية)",
+    "العربية (قطر)",
+    "العربية (المملكة العربية السعودية)",
+    "العربية (السودان)",
+    "العربية (الصومال)",
+    "العربية (جنوب السودان)",
+    "العربية (سوريا)",
+    "العربية (تشاد)",
+    "العربية (تونس)",
+    "العربية (اليمن)",
+    "অসমীয\u{9bc}\u{9be}",
+    "Kipare",
+    "asturianu",
+    "azərbaycan",
+    "азәрбајҹан (Кирил)",
+    "azərbaycan (latın)",
+    "Ɓàsàa",
+    "беларуская",
+    "Ichibemba",
+    "Hibena",
+    "български",
+    "bamanakan",
+    "ব\u{9be}ংল\u{9be}",
+    "ব\u{9be}ংল\u{9be} (ভ\u{9be}রত)",
+    "བ\u{f7c}ད་ས\u{f90}ད་",
+    "བ\u{f7c}ད་ས\u{f90}ད་ (ར\u{f92}\u{fb1}་གར་)",
+    "brezhoneg",
+    "बर’",
+    "bosanski",
+    "босански (ћирилица)",
+    "bosanski (latinica)",
+    "català",
+    "català (Andorra)",
+    "català (Espanya, valencià)",
+    "català (França)",
+    "català (Itàlia)",
+    "𑄌𑄋\u{11134}𑄟\u{11133}𑄦",
+    "𑄌𑄋\u{11134}𑄟\u{11133}𑄦 (𑄞𑄢\u{11127}𑄖\u{11134})",
+    "нохчийн",
+    "Cebuano",
+    "Rukiga",
+    "ᏣᎳᎩ",
+    "کوردیی ناوەندی",
+    "کوردیی ناوەندی (ئێران)",
+    "čeština",
+    "Church Slavic",
+    "Cymraeg",
+    "dansk",
+    "dansk (Grønland)",
+    "Kitaita",
+    "Deutsch",
+    "Österreichisches Deutsch",
+    "Deutsch (Belgien)",
+    "Schweizer Hochdeutsch",
+    "Deutsch (Italien)",
+    "Deutsch (Liechtenstein)",
+    "Deutsch (Luxemburg)",
+    "Zarmaciine",
+    "dolnoserbšćina",
+    "duálá",
+    "joola",
+    "ར\u{fab}\u{f7c}ང་ཁ",
+    "Kĩembu",
+    "eʋegbe",
+    "eʋegbe (Togo nutome)",
+    "Ελληνικά",
+    "Ελληνικά (Κύπρος)",
+    "English",
+    "English (world)",
+    "English (Europe)",
+    "English (United Arab Emirates)",
+    "English (Antigua & Barbuda)",
+    "English (Anguilla)",
+    "English (American Samoa)",
+    "English (Austria)",
+    "Australian English",
+    "English (Barbados)",
+    "English (Belgium)",
+    "English (Burundi)",
+    "English (Bermuda)",
+    "English (Bahamas)",
+    "English (Botswana)",
+    "English (Belize)",
+    "Canadian English",
+    "English (Cocos (Keeling) Islands)",
+    "English (Switzerland)",
+    "English (Cook Islands)",
+    "English (Cameroon)",
+    "English (Christmas Island)",
+    "English (Cyprus)",
+    "English (Germany)",
+    "English (Diego Garcia)",
+    "English (Denmark)",
+    "English (Dominica)",
+    "English (Eritrea)",
+    "English (Finland)",
+    "English (Fiji)",
+    "English (Falkland Islands)",
+    "English (Micronesia)",
+    "British English",
+    "English (Grenada)",
+    "English (Guernsey)",
+    "English (Ghana)",
+    "English (Gibraltar)",
+    "English (Gambia)",
+    "English (Guam)",
+    "English (Guyana)",
+    "English (Hong Kong SAR China)",
+    "English (Ireland)",
+    "English (Israel)",
+    "English (Isle of Man)",
+    "English (India)",
+    "English (British Indian Ocean Territory)",
+    "English (Jersey)",
+    "English (Jamaica)",
+    "English (Kenya)",
+    "English (Kiribati)",
+    "English (St Kitts & Nevis)",
+    "English (Cayman Islands)",
+    "English (St Lucia)",
+    "English (Liberia)",
+    "English (Lesotho)",
+    "English (Madagascar)",
+    "English (Marshall Islands)",
+    "English (Macao SAR China)",
+    "English (Northern Mariana Islands)",
+    "English (Montserrat)",
+    "English (Malta)",
+    "English (Mauritius)",
+    "English (Malawi)",
+    "English (Malaysia)",
+    "English (Namibia)",
+    "English (Norfolk Island)",
+    "English (Nigeria)",
+    "English (Netherlands)",
+    "English (Nauru)",
+    "English (Niue)",
+    "English (New Zealand)",
+    "English (Papua New Guinea)",
+    "English (Philippines)",
+    "English (Pakistan)",
+    "English (Pitcairn Islands)",
+    "English (Puerto Rico)",
+    "English (Palau)",
+    "English (Rwanda)",
+    "English (Solomon Islands)",
+    "English (Seychelles)",
+    "English (Sudan)",
+    "English (Sweden)",
+    "English (Singapore)",
+    "English (St Helena)",
+    "English (Slovenia)",
+    "English (Sierra Leone)",
+    "English (South Sudan)",
+    "English (Sint Maarten)",
+    "English (Eswatini)",
+    "English (Turks & Caicos Islands)",
+    "English (Tokelau)",
+    "English (Tonga)",
+    "English (Trinidad & Tobago)",
+    "English (Tuvalu)",
+    "English (Tanzania)",
+    "English (Uganda)",
+    "English (U.S. Outlying Islands)",
+    "American English (Computer)",
+    "English (St Vincent & the Grenadines)",
+    "English (British Virgin Islands)",
+    "English (U.S. Virgin Islands)",
+    "English (Vanuatu)",
+    "English (Samoa)",
+    "English (South Africa)",
+    "English (Zambia)",
+    "English (Zimbabwe)",
+    "Esperanto",
+    "español",
+    "español latinoamericano",
+    "español (Argentina)",
+    "español (Bolivia)",
+    "español (Brasil)",
+    "español (Belice)",
+    "español (Chile)",
+    "español (Colombia)",
+    "español (Costa Rica)",
+    "español (Cuba)",
+    "español (República Dominicana)",
+    "español (Ceuta y Melilla)",
+    "español (Ecuador)",
+    "español (Guinea Ecuatorial)",
+    "español (Guatemala)",
+    "español (Honduras)",
+    "español (Canarias)",
+    "español de México",
+    "español (Nicaragua)",
+    "español (Panamá)",
+    "español (Perú)",
+    "español (Filipinas)",
+    "español (Puerto Rico)",
+    "español (Paraguay)",
+    "español (El Salvador)",
+    "español (Estados Unidos)",
+    "español (Uruguay)",
+    "español (Venezuela)",
+    "eesti",
+    "euskara",
+    "ewondo",
+    "فارسی",
+    "دری",
+    "Pulaar",
+    "Pulaar (Latn)",
+    "Pulaar (Latn, Burkibaa Faaso)",
+    "Pulaar (Latn, Kameruun)",
+    "Pulaar (Latn, Ganaa)",
+    "Pulaar (Latn, Gammbi)",
+    "Pulaar (Latn, Gine)",
+    "Pulaar (Latn, Gine-Bisaawo)",
+    "Pulaar (Latn, Liberiyaa)",
+    "Pulaar (Latn, Muritani)",
+    "Pulaar (Latn, Nijeer)",
+    "Pulaar (Latn, Nijeriyaa)",
+    "Pulaar (Latn, Seraa liyon)",
+    "suomi",
+    "Filipino",
+    "føroyskt",
+    "føroyskt (Danmark)",
+    "français",
+    "français (Belgique)",
+    "français (Burkina Faso)",
+    "français (Burundi)",
+    "français (Bénin)",
+    "français (Saint-Barthélemy)",
+    "français canadien",
+    "français (Congo-Kinshasa)",
+    "français (République centrafricaine)",
+    "français (Congo-Brazzaville)",
+    "français suisse",
+    "français (Côte d’Ivoire)",
+    "français (Cameroun)",
+    "français (Djibouti)",
+    "français (Algérie)",
+    "français (Gabon)",
+    "français (Guyane française)",
+    "français (Guinée)",
+    "français (Guadeloupe)",
+    "français (Guinée équatoriale)",
+    "français (Haïti)",
+    "français (Comores)",
+    "français (Luxembourg)",
+    "français (Maroc)",
+    "français (Monaco)",
+    "français (Saint-Martin)",
+    "français (Madagascar)",
+    "français (Mali)",
+    "français (Martinique)",
+    "français (Mauritanie)",
+    "français (Maurice)",
+    "français (Nouvelle-Calédonie)",
+    "français (Niger)",
+    "français (Polynésie française)",
+    "français (Saint-Pierre-et-Miquelon)",
+    "français (La Réunion)",
+    "français (Rwanda)",
+    "français (Seychelles)",
+    "français (Sénégal)",
+    "français (Syrie)",
+    "français (Tchad)",
+    "français (Togo)",
+    "français (Tunisie)",
+    "français (Vanuatu)",
+    "français (Wallis-et-Futuna)",
+    "français (Mayotte)",
+    "furlan",
+    "Frysk",
+    "Gaeilge",
+    "Gaeilge (an Ríocht Aontaithe)",
+    "Gàidhlig",
+    "galego",
+    "Schwiizertüütsch",
+    "Schwiizertüütsch (Frankriich)",
+    "Schwiizertüütsch (Liächteschtäi)",
+    "ગ\u{ac1}જરાતી",
+    "Ekegusii",
+    "Gaelg",
+    "Hausa",
+    "Hausa (Gana)",
+    "Hausa (Nijar)",
+    "ʻŌlelo Hawaiʻi",
+    "עברית",
+    "हिन\u{94d}दी",
+    "hrvatski",
+    "hrvatski (Bosna i Hercegovina)",
+    "hornjoserbšćina",
+    "magyar",
+    "հայերեն",
+    "interlingua",
+    "Indonesia",
+    "Igbo",
+    "ꆈꌠꉙ",
+    "íslenska",
+    "italiano",
+    "italiano (Svizzera)",
+    "italiano (San Marino)",
+    "italiano (Città del Vaticano)",
+    "日本語",
+    "Ndaꞌa",
+    "Kimachame",
+    "Jawa",
+    "ქართული",
+    "Taqbaylit",
+    "Kikamba",
+    "Chimakonde",
+    "kabuverdianu",
+    "Koyra ciini",
+    "Gikuyu",
+    "қазақ тілі",
+    "kakɔ",
+    "kalaallisut",
+    "Kalenjin",
+    "ខ\u{17d2}មែរ",
+    "ಕನ\u{ccd}ನಡ",
+    "한국어",
+    "한국어(조선민주주의인민공화국)",
+    "को\u{902}कणी",
+    "کٲش\u{64f}ر",
+    "Kishambaa",
+    "rikpa",
+    "Kölsch",
+    "kurdî (kurmancî)",
+    "kernewek",
+    "кыргызча",
+    "Kɨlaangi",
+    "Lëtzebuergesch",
+    "Luganda",
+    "Lakȟólʼiyapi",
+    "lingála",
+    "lingála (Angóla)",
+    "lingála (Repibiki ya Afríka ya Káti)",
+    "lingála (Kongo)",
+    "ລາວ",
+    "لۊری شومالی",
+    "لۊری شومالی (IQ)",
+    "lietuvių",
+    "Tshiluba",
+    "Dholuo",
+    "Luluhia",
+    "latviešu",
+    "Maa",
+    "Maa (Tansania)",
+    "Kĩmĩrũ",
+    "kreol morisien",
+    "Malagasy",
+    "Makua",
+    "metaʼ",
+    "Māori",
+    "македонски",
+    "മലയ\u{d3e}ളം",
+    "монгол",
+    "मराठी",
+    "Melayu",
+    "Melayu (Brunei)",
+    "Melayu (Singapura)",
+    "Malti",
+    "MUNDAŊ",
+    "မြန\u{103a}မာ",
+    "مازرونی",
+    "Khoekhoegowab",
+    "norsk bokmål",
+    "norsk bokmål (Svalbard og Jan Mayen)",
+    "isiNdebele",
+    "Neddersass’sch",
+    "Neddersass’sch (Nedderlannen)",
+    "न\u{947}पाली",
+    "न\u{947}पाली (भारत)",
+    "Nederlands",
+    "Nederlands (Aruba)",
+    "Vlaams",
+    "Nederlands (Caribisch Nederland)",
+    "Nederlands (Curaçao)",
+    "Nederlands (Suriname)",
+    "Nederlands (Sint-Maarten)",
+    "Kwasio",
+    "norsk nynorsk",
+    "Shwóŋò ngiembɔɔn",
+    "Thok Nath",
+    "Runyankore",
+    "Oromoo",
+    "Oromoo (Keeniyaa)",
+    "ଓଡ\u{b3c}\u{b3f}ଆ",
+    "ирон",
+    "ирон (Уӕрӕсе)",
+    "ਪ\u{a70}ਜਾਬੀ",
+    "پنجابی (عربی)",
+    "ਪ\u{a70}ਜਾਬੀ (ਗ\u{a41}ਰਮ\u{a41}ਖੀ)",
+    "polski",
+    "prūsiskan",
+    "پښتو",
+    "پښتو (پاکستان)",
+    "português",
+    "português (Angola)",
+    "português (Suíça)",
+    "português (Cabo Verde)",
+    "português (Guiné Equatorial)",
+    "português (Guiné-Bissau)",
+    "português (Luxemburgo)",
+    "português (Macau, RAE da China)",
+    "português (Moçambique)",
+    "português europeu",
+    "português (São Tomé e Príncipe)",
+    "português (Timor-Leste)",
+    "Runasimi",
+    "Runasimi (Bolivia)",
+    "Runasimi (Ecuador)",
+    "rumantsch",
+    "Ikirundi",
+    "română",
+    "română (Republica Moldova)",
+    "Kihorombo",
+    "Root",
+    "русский",
+    "русский (Беларусь)",
+    "русский (Киргизия)",
+    "русский (Казахстан)",
+    "русский (Молдова)",
+    "русский (Украина)",
+    "Ikinyarwanda",
+    "Kiruwa",
+    "саха тыла",
+    "Kisampur",
+    "Ishisangu",
+    "سنڌي",
+    "davvisámegiella",
+    "davvisámegiella (Suopma)",
+    "davvisámegiella (Ruoŧŧa)",
+    "sena",
+    "Koyraboro senni",
+    "Sängö",
+    "ⵜⴰⵛⵍⵃⵉⵜ",
+    "Tashelḥiyt (Latn)",
+    "ⵜⴰⵛⵍⵃⵉⵜ (Tfng)",
+    "ස\u{dd2}ංහල",
+    "slovenčina",
+    "slovenščina",
+    "anarâškielâ",
+    "chiShona",
+    "Soomaali",
+    "Soomaali (Jabuuti)",
+    "Soomaali (Itoobiya)",
+    "Soomaali (Kenya)",
+    "shqip",
+    "shqip (Maqedonia e Veriut)",
+    "shqip (Kosovë)",
+    "српски",
+    "српски (ћирилица)",
+    "српски (ћирилица, Босна и Херцеговина)",
+    "српски (ћирилица, Црна Гора)",
+    "српски (ћирилица, Косово)",
+    "srpski (latinica)",
+    "srpski (latinica, Bosna i Hercegovina)",
+    "srpski (latinica, Crna Gora)",
+    "srpski (latinica, Kosovo)",
+    "svenska",
+    "svenska (Åland)",
+    "svenska (Finland)",
+    "Kiswahili",
+    "Kiswahili (Kongo - Kinshasa)",
+    "Kiswahili (Kenya)",
+    "Kiswahili (Uganda)",
+    "தமிழ\u{bcd}",
+    "தமிழ\u{bcd} (இலங\u{bcd}கை)",
+    "தமிழ\u{bcd} (மலேசிய\u{bbe})",
+    "தமிழ\u{bcd} (சிங\u{bcd}கப\u{bcd}பூர\u{bcd})",
+    "త\u{c46}లుగు",
+    "Kiteso",
+    "Kiteso (Kenia)",
+    "тоҷикӣ",
+    "ไทย",
+    "ትግርኛ",
+    "ትግርኛ (ኤርትራ)",
+    "türkmen dili",
+    "lea fakatonga",
+    "Türkçe",
+    "Türkçe (Kıbrıs)",
+    "татар",
+    "Tasawaq senni",
+    "Tamaziɣt n laṭlaṣ",
+    "ئۇيغۇرچە",
+    "українська",
+    "اردو",
+    "اردو (بھارت)",
+    "o‘zbek",
+    "اوزبیک (عربی)",
+    "ўзбекча (Кирил)",
+    "o‘zbek (lotin)",
+    "ꕙꔤ",
+    "Vai (Latn)",
+    "ꕙꔤ (Vaii)",
+    "Tiếng Việt",
+    "Volapük",
+    "Kyivunjo",
+    "Walser",
+    "Wolof",
+    "IsiXhosa",
+    "Olusoga",
+    "nuasue",
+    "יי\u{5b4}דיש",
+    "Èdè Yorùbá",
+    "Èdè Yorùbá (Bɛ\u{300}nɛ\u{300})",
+    "粵語",
+    "粤语 (简体)",
+    "粵語 (繁體)",
+    "ⵜⴰⵎⴰⵣⵉⵖⵜ",
+    "中文",
+    "简体中文",
+    "简体中文（中国香港特别行政区）",
+    "简体中文（中国澳门特别行政区）",
+    "简体中文（新加坡）",
+    "繁體中文",
+    "繁體中文（中國香港特別行政區）",
+    "繁體中文（中國澳門特別行政區）",
+    "isiZulu",
+];
+#[doc = r" The symbols of locales in the other numbering systems of [`NumberingSystem`], where"]
+#[doc = r" they differ from those of the locale's default numbering system, as indices into"]
+#[doc = r" `SYMBOLS`. Sorted by locale, then numbering system."]
+const NUMBERING_SYSTEM_SYMBOLS: &[(Locale, NumberingSystem, u8)] = &[
+    #[cfg(locales_other)]
+    (Locale::af, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::af, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::af_NA, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::af_NA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::agq, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::agq, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ak, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ak, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::am, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::am, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_AE, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_BH, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DJ, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_DZ, NumberingSystem::Arab, 8),
+    #[cfg(locales_other)]
+    (Locale::ar_DZ, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EG, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_EH, NumberingSystem::Arab, 8),
+    #[cfg(locales_other)]
+    (Locale::ar_EH, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_ER, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IL, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_IQ, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_JO, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KM, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_KW, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Beng, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Deva, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Fullwide, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Gujr, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Guru, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Khmr, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Knda, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Laoo, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Latn, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Mlym, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Mymr, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Orya, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Tamldec, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Telu, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Thai, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LB, NumberingSystem::Tibt, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_LY, NumberingSystem::Arab, 8),
+    #[cfg(locales_other)]
+    (Locale::ar_LY, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_MA, NumberingSystem::Arab, 8),
+    #[cfg(locales_other)]
+    (Locale::ar_MA, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Beng, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Deva, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Fullwide, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Gujr, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Guru, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Khmr, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Knda, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Laoo, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Latn, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Mlym, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Mymr, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Orya, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Tamldec, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Telu, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Thai, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_MR, NumberingSystem::Tibt, 7),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_OM, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_PS, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_QA, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SA, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SD, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SO, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SS, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_SY, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TD, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_TN, NumberingSystem::Arab, 8),
+    #[cfg(locales_other)]
+    (Locale::ar_TN, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Arabext, 5),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Beng, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Deva, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Fullwide, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Gujr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Guru, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Khmr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Knda, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Laoo, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Latn, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Mlym, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Mymr, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Orya, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Tamldec, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Telu, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Thai, 6),
+    #[cfg(locales_other)]
+    (Locale::ar_YE, NumberingSystem::Tibt, 6),
+    #[cfg(locales_other)]
+    (Locale::as_, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::as_, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::asa, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::asa, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ast, NumberingSystem::Arab, 13),
+    #[cfg(locales_other)]
+    (Locale::ast, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::az, NumberingSystem::Arab, 15),
+    #[cfg(locales_other)]
+    (Locale::az, NumberingSystem::Arabext, 16),
+    #[cfg(locales_other)]
+    (Locale::az_Cyrl, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::az_Cyrl, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::az_Latn, NumberingSystem::Arab, 15),
+    #[cfg(locales_other)]
+    (Locale::az_Latn, NumberingSystem::Arabext, 16),
+    #[cfg(locales_other)]
+    (Locale::bas, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::bas, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::be, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::be, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::bem, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::bem, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::bez, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::bez, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::bg, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::bg, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::bm, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::bm, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::bn, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::bn, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::bn_IN, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::bn_IN, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::bo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::bo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::bo, NumberingSystem::Tibt, 17),
+    #[cfg(locales_other)]
+    (Locale::bo_IN, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::bo_IN, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::bo_IN, NumberingSystem::Tibt, 17),
+    #[cfg(locales_other)]
+    (Locale::br, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::br, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::brx, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::brx, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::bs, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::bs, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::bs_Cyrl, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::bs_Cyrl, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::bs_Latn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::bs_Latn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ca, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ca, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ca_AD, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ca_AD, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ca_ES_VALENCIA, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ca_ES_VALENCIA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ca_FR, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ca_FR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ca_IT, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ca_IT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ccp, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::ccp, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::ccp_IN, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::ccp_IN, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::ce, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ce, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ceb, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ceb, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::cgg, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::cgg, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::chr, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::chr, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Beng, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Deva, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Fullwide, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Gujr, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Guru, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Khmr, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Knda, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Laoo, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Latn, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Mlym, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Mymr, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Orya, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Tamldec, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Telu, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Thai, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb, NumberingSystem::Tibt, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Beng, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Deva, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Fullwide, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Gujr, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Guru, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Khmr, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Knda, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Laoo, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Latn, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Mlym, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Mymr, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Orya, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Tamldec, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Telu, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Thai, 19),
+    #[cfg(locales_other)]
+    (Locale::ckb_IR, NumberingSystem::Tibt, 19),
+    #[cfg(locales_eu)]
+    (Locale::cs, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::cs, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::cu, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::cu, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::cy, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::cy, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::da, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::da, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::da_GL, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::da_GL, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::dav, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::dav, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::de, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::de, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::de_AT, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::de_AT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::de_BE, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::de_BE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::de_CH, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::de_CH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::de_IT, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::de_IT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::de_LI, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::de_LI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::de_LU, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::de_LU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::dje, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::dje, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::dsb, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::dsb, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::dua, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::dua, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::dyo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::dyo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Beng, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Deva, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Fullwide, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Gujr, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Guru, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Khmr, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Knda, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Laoo, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Latn, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Mlym, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Mymr, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Orya, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Tamldec, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Telu, 9),
+    #[cfg(locales_other)]
+    (Locale::dz, NumberingSystem::Thai, 9),
+    #[cfg(locales_other)]
+    (Locale::ebu, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ebu, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ee, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ee, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ee_TG, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ee_TG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::el, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::el, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::el_CY, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::el_CY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_001, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_001, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_150, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_150, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_AE, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_AE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_AG, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_AG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_AI, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_AI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_AS, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_AS, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_AT, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_AT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_AU, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_AU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_BB, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_BB, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_BE, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_BE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_BI, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_BI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_BM, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_BM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_BS, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_BS, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_BW, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_BW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_BZ, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_BZ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_CA, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_CA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_CC, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_CC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_CH, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_CH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_CK, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_CK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_CM, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_CM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_CX, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_CX, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_CY, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_CY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_DE, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_DE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_DG, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_DG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_DK, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_DK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_DM, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_DM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_ER, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_ER, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_FI, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_FI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_FJ, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_FJ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_FK, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_FK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_FM, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_FM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_GB, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_GB, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_GD, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_GD, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_GG, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_GG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_GH, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_GH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_GI, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_GI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_GM, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_GM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_GU, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_GU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_GY, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_GY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_HK, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_HK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_IE, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_IE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_IL, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_IL, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_IM, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_IM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_IN, NumberingSystem::Arab, 10),
+    #[cfg(locales_en)]
+    (Locale::en_IN, NumberingSystem::Arabext, 11),
+    #[cfg(locales_en)]
+    (Locale::en_IO, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_IO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_JE, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_JE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_JM, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_JM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_KE, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_KE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_KI, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_KI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_KN, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_KN, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_KY, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_KY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_LC, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_LC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_LR, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_LR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_LS, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_LS, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_MG, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_MG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_MH, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_MH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_MO, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_MO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_MP, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_MP, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_MS, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_MS, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_MT, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_MT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_MU, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_MU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_MW, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_MW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_MY, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_MY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_NA, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_NA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_NF, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_NF, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_NG, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_NG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_NL, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_NL, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_NR, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_NR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_NU, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_NU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_NZ, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_NZ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_PG, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_PG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_PH, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_PH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_PK, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_PK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_PN, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_PN, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_PR, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_PR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_PW, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_PW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_RW, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_RW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SB, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SB, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SC, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SD, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SD, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SE, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SG, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SH, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SI, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SL, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SL, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SS, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SS, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SX, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SX, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_SZ, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_SZ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_TC, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_TC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_TK, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_TK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_TO, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_TO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_TT, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_TT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_TV, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_TV, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_TZ, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_TZ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_UG, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_UG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_UM, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_UM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_US_POSIX, NumberingSystem::Arab, 25),
+    #[cfg(locales_en)]
+    (Locale::en_US_POSIX, NumberingSystem::Arabext, 26),
+    #[cfg(locales_en)]
+    (Locale::en_VC, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_VC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_VG, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_VG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_VI, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_VI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_VU, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_VU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_WS, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_WS, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_ZA, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_ZA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_ZM, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_ZM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_en)]
+    (Locale::en_ZW, NumberingSystem::Arab, 1),
+    #[cfg(locales_en)]
+    (Locale::en_ZW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::eo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::eo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_419, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_419, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_AR, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_AR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_BO, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_BO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_BR, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_BR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_BZ, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_BZ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_CL, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_CL, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_CO, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_CO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_CR, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_CR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_CU, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_CU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_DO, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_DO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_EA, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_EA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_EC, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_EC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_GQ, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_GQ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_GT, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_GT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_HN, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_HN, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_IC, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_IC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_MX, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_MX, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_NI, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_NI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_PA, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_PA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_PE, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_PE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_PH, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_PH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_PR, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_PR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_PY, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_PY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_SV, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_SV, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_US, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_US, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_UY, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_UY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::es_VE, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::es_VE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::et, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::et, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::eu, NumberingSystem::Arab, 29),
+    #[cfg(locales_other)]
+    (Locale::eu, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ewo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ewo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Arab, 31),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Beng, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Deva, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Fullwide, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Gujr, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Guru, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Khmr, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Knda, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Laoo, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Latn, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Mlym, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Mymr, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Orya, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Tamldec, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Telu, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Thai, 32),
+    #[cfg(locales_other)]
+    (Locale::fa, NumberingSystem::Tibt, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Arab, 31),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Beng, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Deva, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Fullwide, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Gujr, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Guru, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Khmr, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Knda, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Laoo, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Latn, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Mlym, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Mymr, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Orya, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Tamldec, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Telu, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Thai, 32),
+    #[cfg(locales_other)]
+    (Locale::fa_AF, NumberingSystem::Tibt, 32),
+    #[cfg(locales_other)]
+    (Locale::ff, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_BF, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_BF, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_CM, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_CM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_GH, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_GH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_GM, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_GM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_GN, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_GN, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_GW, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_GW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_LR, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_LR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_MR, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_MR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_NE, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_NE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_NG, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_NG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_SL, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ff_Latn_SL, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fi, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::fi, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::fil, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::fil, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::fo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::fo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::fo_DK, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::fo_DK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_BE, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_BE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_BF, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_BF, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_BI, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_BI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_BJ, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_BJ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_BL, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_BL, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_CA, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_CA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_CD, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_CD, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_CF, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_CF, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_CG, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_CG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_CH, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_CH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_CI, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_CI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_CM, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_CM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_DJ, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_DJ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_DZ, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_DZ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_GA, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_GA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_GF, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_GF, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_GN, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_GN, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_GP, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_GP, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_GQ, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_GQ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_HT, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_HT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_KM, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_KM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_LU, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_LU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_MA, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_MA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_MC, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_MC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_MF, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_MF, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_MG, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_MG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_ML, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_ML, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_MQ, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_MQ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_MR, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_MR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_MU, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_MU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_NC, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_NC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_NE, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_NE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_PF, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_PF, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_PM, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_PM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_RE, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_RE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_RW, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_RW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_SC, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_SC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_SN, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_SN, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_SY, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_SY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_TD, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_TD, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_TG, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_TG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_TN, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_TN, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_VU, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_VU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_WF, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_WF, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::fr_YT, NumberingSystem::Arab, 35),
+    #[cfg(locales_eu)]
+    (Locale::fr_YT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::fur, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::fur, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::fy, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::fy, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::ga, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::ga, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::ga_GB, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::ga_GB, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::gd, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::gd, NumberingSystem::Arabext, 36),
+    #[cfg(locales_other)]
+    (Locale::gl, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::gl, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::gsw, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::gsw, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::gsw_FR, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::gsw_FR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::gsw_LI, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::gsw_LI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::gu, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::gu, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::guz, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::guz, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::gv, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::gv, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ha, NumberingSystem::Arabext, 38),
+    #[cfg(locales_other)]
+    (Locale::ha_GH, NumberingSystem::Arabext, 38),
+    #[cfg(locales_other)]
+    (Locale::ha_NE, NumberingSystem::Arabext, 38),
+    #[cfg(locales_other)]
+    (Locale::haw, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::haw, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::he, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::he, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::hi, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::hi, NumberingSystem::Arabext, 11),
+    #[cfg(locales_eu)]
+    (Locale::hr, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::hr, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::hr_BA, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::hr_BA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::hsb, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::hsb, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::hu, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::hu, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::hy, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::hy, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ia, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ia, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::id, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::id, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ig, NumberingSystem::Arab, 13),
+    #[cfg(locales_other)]
+    (Locale::ig, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ii, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ii, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::is, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::is, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::it, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::it, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::it_CH, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::it_CH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::it_SM, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::it_SM, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::it_VA, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::it_VA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ja, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ja, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::jgo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::jgo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::jmc, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::jmc, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::jv, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::jv, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ka, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ka, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kab, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kab, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kam, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kam, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kde, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kde, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kea, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kea, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::khq, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::khq, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ki, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ki, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kk, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kk, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kkj, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kkj, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kl, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kl, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kln, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kln, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Beng, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Deva, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Fullwide, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Gujr, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Guru, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Knda, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Laoo, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Latn, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Mlym, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Mymr, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Orya, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Tamldec, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Thai, 3),
+    #[cfg(locales_other)]
+    (Locale::km, NumberingSystem::Tibt, 3),
+    #[cfg(locales_other)]
+    (Locale::kn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ko, NumberingSystem::Arab, 13),
+    #[cfg(locales_other)]
+    (Locale::ko, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ko_KP, NumberingSystem::Arab, 13),
+    #[cfg(locales_other)]
+    (Locale::ko_KP, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kok, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::kok, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::kok, NumberingSystem::Deva, 43),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Beng, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Deva, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Fullwide, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Gujr, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Guru, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Khmr, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Knda, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Laoo, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Latn, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Mlym, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Mymr, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Orya, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Tamldec, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Telu, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Thai, 44),
+    #[cfg(locales_other)]
+    (Locale::ks, NumberingSystem::Tibt, 44),
+    #[cfg(locales_other)]
+    (Locale::ksb, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ksb, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ksf, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ksf, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ksh, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ksh, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ku, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ku, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::kw, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::kw, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ky, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ky, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::lag, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::lag, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::lb, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::lb, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::lg, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::lg, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::lkt, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::lkt, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ln, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ln, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ln_AO, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ln_AO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ln_CF, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ln_CF, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ln_CG, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ln_CG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::lo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::lo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Beng, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Deva, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Fullwide, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Gujr, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Guru, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Khmr, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Knda, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Laoo, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Latn, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Mlym, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Mymr, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Orya, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Tamldec, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Thai, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc, NumberingSystem::Tibt, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Beng, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Deva, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Fullwide, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Gujr, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Guru, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Khmr, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Knda, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Laoo, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Latn, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Mlym, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Mymr, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Orya, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Tamldec, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Thai, 3),
+    #[cfg(locales_other)]
+    (Locale::lrc_IQ, NumberingSystem::Tibt, 3),
+    #[cfg(locales_eu)]
+    (Locale::lt, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::lt, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::lu, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::lu, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::luo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::luo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::luy, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::luy, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::lv, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::lv, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mas, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mas, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mas_TZ, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mas_TZ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mer, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mer, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mfe, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mfe, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mg, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mg, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mgh, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mgh, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mgo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mgo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mi, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mi, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mk, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mk, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ml, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::ml, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::mn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mr, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::mr, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::ms, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ms, NumberingSystem::Arabext, 48),
+    #[cfg(locales_other)]
+    (Locale::ms_BN, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ms_BN, NumberingSystem::Arabext, 48),
+    #[cfg(locales_other)]
+    (Locale::ms_SG, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ms_SG, NumberingSystem::Arabext, 48),
+    #[cfg(locales_eu)]
+    (Locale::mt, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::mt, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mua, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mua, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::my, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::my, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Beng, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Deva, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Fullwide, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Gujr, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Guru, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Khmr, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Knda, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Laoo, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Latn, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Mlym, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Mymr, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Orya, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Tamldec, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Thai, 3),
+    #[cfg(locales_other)]
+    (Locale::mzn, NumberingSystem::Tibt, 3),
+    #[cfg(locales_other)]
+    (Locale::naq, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::naq, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::nb, NumberingSystem::Arab, 50),
+    #[cfg(locales_other)]
+    (Locale::nb_SJ, NumberingSystem::Arab, 50),
+    #[cfg(locales_other)]
+    (Locale::nd, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::nd, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::nds, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::nds, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::nds_NL, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::nds_NL, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ne, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::ne, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::ne_IN, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::ne_IN, NumberingSystem::Arabext, 11),
+    #[cfg(locales_eu)]
+    (Locale::nl, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::nl, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::nl_AW, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::nl_AW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::nl_BE, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::nl_BE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::nl_BQ, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::nl_BQ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::nl_CW, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::nl_CW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::nl_SR, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::nl_SR, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::nl_SX, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::nl_SX, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::nmg, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::nmg, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::nn, NumberingSystem::Arab, 50),
+    #[cfg(locales_other)]
+    (Locale::nnh, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::nnh, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::nus, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::nus, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::nyn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::nyn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::om, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::om, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::om_KE, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::om_KE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::or, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::or, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::os, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::os, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::os_RU, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::os_RU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::pa, NumberingSystem::Arabext, 52),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Beng, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Deva, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Fullwide, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Gujr, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Guru, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Khmr, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Knda, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Laoo, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Latn, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Mlym, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Mymr, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Orya, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Tamldec, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Telu, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Thai, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Arab, NumberingSystem::Tibt, 39),
+    #[cfg(locales_other)]
+    (Locale::pa_Guru, NumberingSystem::Arabext, 52),
+    #[cfg(locales_eu)]
+    (Locale::pl, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pl, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::prg, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::prg, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Beng, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Deva, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Fullwide, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Gujr, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Guru, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Khmr, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Knda, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Laoo, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Latn, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Mlym, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Mymr, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Orya, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Tamldec, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Telu, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Thai, 53),
+    #[cfg(locales_other)]
+    (Locale::ps, NumberingSystem::Tibt, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Beng, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Deva, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Fullwide, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Gujr, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Guru, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Khmr, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Knda, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Laoo, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Latn, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Mlym, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Mymr, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Orya, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Tamldec, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Telu, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Thai, 53),
+    #[cfg(locales_other)]
+    (Locale::ps_PK, NumberingSystem::Tibt, 53),
+    #[cfg(locales_eu)]
+    (Locale::pt, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_AO, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_AO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_CH, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_CH, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_CV, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_CV, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_GQ, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_GQ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_GW, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_GW, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_LU, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_LU, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_MO, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_MO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_MZ, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_MZ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_PT, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_PT, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_ST, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_ST, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::pt_TL, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::pt_TL, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::qu, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::qu, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::qu_BO, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::qu_BO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::qu_EC, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::qu_EC, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::rm, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::rm, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::rn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::rn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::ro, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::ro, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::ro_MD, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::ro_MD, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::rof, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::rof, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::root, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::root, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ru, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ru, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ru_BY, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ru_BY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ru_KG, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ru_KG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ru_KZ, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ru_KZ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ru_MD, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ru_MD, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ru_UA, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ru_UA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::rw, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::rw, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::rwk, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::rwk, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sah, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sah, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::saq, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::saq, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sbp, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sbp, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Arabext, 56),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Beng, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Deva, 44),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Fullwide, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Gujr, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Guru, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Khmr, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Knda, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Laoo, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Latn, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Mlym, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Mymr, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Orya, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Tamldec, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Thai, 3),
+    #[cfg(locales_other)]
+    (Locale::sd, NumberingSystem::Tibt, 3),
+    #[cfg(locales_other)]
+    (Locale::se, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::se, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::se_FI, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::se_FI, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::se_SE, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::se_SE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::seh, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::seh, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ses, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ses, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sg, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sg, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::shi, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::shi, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::shi_Latn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::shi_Latn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::shi_Tfng, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::shi_Tfng, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::si, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::si, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::sk, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::sk, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::sl, NumberingSystem::Arab, 1),
+    #[cfg(locales_eu)]
+    (Locale::sl, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::smn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::smn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::so, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::so, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::so, NumberingSystem::Knda, 3),
+    #[cfg(locales_other)]
+    (Locale::so, NumberingSystem::Mymr, 3),
+    #[cfg(locales_other)]
+    (Locale::so, NumberingSystem::Tamldec, 3),
+    #[cfg(locales_other)]
+    (Locale::so, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::so, NumberingSystem::Thai, 3),
+    #[cfg(locales_other)]
+    (Locale::so, NumberingSystem::Tibt, 3),
+    #[cfg(locales_other)]
+    (Locale::so_DJ, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::so_DJ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::so_DJ, NumberingSystem::Knda, 3),
+    #[cfg(locales_other)]
+    (Locale::so_DJ, NumberingSystem::Mymr, 3),
+    #[cfg(locales_other)]
+    (Locale::so_DJ, NumberingSystem::Tamldec, 3),
+    #[cfg(locales_other)]
+    (Locale::so_DJ, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::so_DJ, NumberingSystem::Thai, 3),
+    #[cfg(locales_other)]
+    (Locale::so_DJ, NumberingSystem::Tibt, 3),
+    #[cfg(locales_other)]
+    (Locale::so_ET, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::so_ET, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::so_ET, NumberingSystem::Knda, 3),
+    #[cfg(locales_other)]
+    (Locale::so_ET, NumberingSystem::Mymr, 3),
+    #[cfg(locales_other)]
+    (Locale::so_ET, NumberingSystem::Tamldec, 3),
+    #[cfg(locales_other)]
+    (Locale::so_ET, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::so_ET, NumberingSystem::Thai, 3),
+    #[cfg(locales_other)]
+    (Locale::so_ET, NumberingSystem::Tibt, 3),
+    #[cfg(locales_other)]
+    (Locale::so_KE, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::so_KE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::so_KE, NumberingSystem::Knda, 3),
+    #[cfg(locales_other)]
+    (Locale::so_KE, NumberingSystem::Mymr, 3),
+    #[cfg(locales_other)]
+    (Locale::so_KE, NumberingSystem::Tamldec, 3),
+    #[cfg(locales_other)]
+    (Locale::so_KE, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::so_KE, NumberingSystem::Thai, 3),
+    #[cfg(locales_other)]
+    (Locale::so_KE, NumberingSystem::Tibt, 3),
+    #[cfg(locales_other)]
+    (Locale::sq, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sq, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sq_MK, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sq_MK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sq_XK, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sq_XK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sr, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sr, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sr_Cyrl, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sr_Cyrl, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sr_Cyrl_BA, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sr_Cyrl_BA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sr_Cyrl_ME, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sr_Cyrl_ME, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sr_Cyrl_XK, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sr_Cyrl_XK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sr_Latn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sr_Latn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sr_Latn_BA, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sr_Latn_BA, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sr_Latn_ME, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sr_Latn_ME, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sr_Latn_XK, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sr_Latn_XK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_eu)]
+    (Locale::sv, NumberingSystem::Arab, 50),
+    #[cfg(locales_eu)]
+    (Locale::sv_AX, NumberingSystem::Arab, 50),
+    #[cfg(locales_eu)]
+    (Locale::sv_FI, NumberingSystem::Arab, 50),
+    #[cfg(locales_other)]
+    (Locale::sw, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sw, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sw_CD, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sw_CD, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sw_KE, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sw_KE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::sw_UG, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::sw_UG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ta, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::ta, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::ta_LK, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::ta_LK, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::ta_MY, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ta_MY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ta_SG, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ta_SG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::te, NumberingSystem::Arab, 10),
+    #[cfg(locales_other)]
+    (Locale::te, NumberingSystem::Arabext, 11),
+    #[cfg(locales_other)]
+    (Locale::te, NumberingSystem::Telu, 3),
+    #[cfg(locales_other)]
+    (Locale::teo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::teo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::teo_KE, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::teo_KE, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::tg, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::tg, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::th, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::th, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ti, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ti, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ti_ER, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ti_ER, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::tk, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::tk, NumberingSystem::Arabext, 60),
+    #[cfg(locales_other)]
+    (Locale::to, NumberingSystem::Arab, 13),
+    #[cfg(locales_other)]
+    (Locale::to, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::tr, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::tr, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::tr_CY, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::tr_CY, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::tt, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::tt, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::twq, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::twq, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::tzm, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::tzm, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ug, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::ug, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::uk, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::uk, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ur, NumberingSystem::Arab, 62),
+    #[cfg(locales_other)]
+    (Locale::ur, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Arab, 62),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Beng, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Deva, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Fullwide, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Gujr, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Guru, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Khmr, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Knda, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Laoo, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Latn, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Mlym, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Mymr, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Orya, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Tamldec, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Telu, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Thai, 39),
+    #[cfg(locales_other)]
+    (Locale::ur_IN, NumberingSystem::Tibt, 39),
+    #[cfg(locales_other)]
+    (Locale::uz, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::uz, NumberingSystem::Arabext, 64),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Beng, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Deva, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Fullwide, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Gujr, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Guru, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Khmr, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Knda, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Laoo, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Latn, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Mlym, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Mymr, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Orya, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Tamldec, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Telu, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Thai, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Arab, NumberingSystem::Tibt, 53),
+    #[cfg(locales_other)]
+    (Locale::uz_Cyrl, NumberingSystem::Arab, 66),
+    #[cfg(locales_other)]
+    (Locale::uz_Cyrl, NumberingSystem::Arabext, 66),
+    #[cfg(locales_other)]
+    (Locale::uz_Latn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::uz_Latn, NumberingSystem::Arabext, 64),
+    #[cfg(locales_other)]
+    (Locale::vai, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::vai, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::vai_Latn, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::vai_Latn, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::vai_Vaii, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::vai_Vaii, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::vi, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::vi, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::vo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::vo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::vun, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::vun, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::wae, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::wae, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::wo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::wo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::xh, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::xh, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::xog, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::xog, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::yav, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::yav, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::yi, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::yi, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::yo, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::yo, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::yo_BJ, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::yo_BJ, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::yue, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::yue, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::yue_Hans, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::yue_Hans, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::yue_Hant, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::yue_Hant, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::zgh, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::zgh, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::zh, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::zh, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::zh_Hans, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::zh_Hans, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::zh_Hans_HK, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::zh_Hans_HK, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::zh_Hans_MO, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::zh_Hans_MO, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::zh_Hans_SG, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::zh_Hans_SG, NumberingSystem::Arabext, 2),
+    #[cfg(locales_other)]
+    (Locale::zh_Hant, NumberingSystem::Arab, 70),
+    #[cfg(locales_other)]
+    (Locale::zh_Hant, NumberingSystem::Arabext, 71),
+    #[cfg(locales_other)]
+    (Locale::zh_Hant_HK, NumberingSystem::Arab, 70),
+    #[cfg(locales_other)]
+    (Locale::zh_Hant_HK, NumberingSystem::Arabext, 71),
+    #[cfg(locales_other)]
+    (Locale::zh_Hant_MO, NumberingSystem::Arab, 70),
+    #[cfg(locales_other)]
+    (Locale::zh_Hant_MO, NumberingSystem::Arabext, 71),
+    #[cfg(locales_other)]
+    (Locale::zu, NumberingSystem::Arab, 1),
+    #[cfg(locales_other)]
+    (Locale::zu, NumberingSystem::Arabext, 2),
+];
+const ORDINAL_INDICES: &[u8] = &[
+    0, 0, 2, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 2, 2, 2, 5, 2, 5, 2, 2, 2, 2, 7, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 11, 11, 11, 11, 11, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 13, 13, 14, 14, 15, 15,
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+    15, 15, 15, 15, 15, 15, 15, 15, 2, 16, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 16, 18, 16, 18,
+    18, 16, 18, 18, 18, 18, 16, 18, 18, 18, 18, 18, 18, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 20, 2, 2, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21,
+    21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21, 21,
+    21, 21, 21, 21, 21, 2, 2, 23, 23, 2, 2, 2, 2, 2, 24, 2, 2, 2, 2, 2, 2, 26, 27, 2, 2, 2, 2, 2,
+    2, 29, 2, 2, 2, 30, 30, 30, 30, 33, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 34, 2, 2, 2, 35, 2, 36, 36,
+    2, 2, 2, 2, 2, 2, 2, 37, 2, 2, 2, 2, 2, 2, 2, 2, 39, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 40, 40, 40, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 41, 41, 41, 41, 41, 41, 41, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+    2, 2, 2, 2, 2, 44, 44, 2, 2, 45, 45, 45, 45, 45, 45, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 46, 46, 46, 2, 2, 2, 2, 48,
+    48, 48, 48, 2, 2, 2, 2, 49, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 50, 2, 2, 2, 2, 2, 2, 2, 2, 2, 52, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 53, 54, 53, 2, 55, 55, 55, 55, 55, 56, 56, 56, 2,
+];
+const ORDINAL_PLURAL_RULES: &[u8] = &[
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 2, 2, 2, 0, 3, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+    6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 0, 0, 7, 7, 8, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 10, 7, 0, 0, 0,
+    0, 0, 11, 11, 11, 11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0,
+    0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0,
+    14, 7, 7, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 7, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19,
+    19, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 20, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21,
+    0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+const ORDINAL_RULE_SETS: &[&[OrdinalRule]] = &[
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::RuleSet(1)],
+    }],
+    &[
+        OrdinalRule {
+            base: 0,
+            pieces: &[OrdinalPiece::Text("ste")],
+        },
+        OrdinalRule {
+            base: 1,
+            pieces: &[OrdinalPiece::Text("ste")],
+        },
+        OrdinalRule {
+            base: 2,
+            pieces: &[OrdinalPiece::Text("de")],
+        },
+        OrdinalRule {
+            base: 20,
+            pieces: &[OrdinalPiece::Text("ste")],
+        },
+        OrdinalRule {
+            base: 100,
+            pieces: &[OrdinalPiece::Remainder {
+                rule_set: 1,
+                divisor: 100,
+            }],
+        },
+    ],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text(".")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text("ኛ")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::RuleSet(6)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("'inci")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(8)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Number,
+            OrdinalPiece::Text("-"),
+            OrdinalPiece::RuleSet(9),
+        ],
+    }],
+    &[
+        OrdinalRule {
+            base: 0,
+            pieces: &[OrdinalPiece::Text("и")],
+        },
+        OrdinalRule {
+            base: 1,
+            pieces: &[OrdinalPiece::Text("ви")],
+        },
+        OrdinalRule {
+            base: 2,
+            pieces: &[OrdinalPiece::Text("ри")],
+        },
+        OrdinalRule {
+            base: 3,
+            pieces: &[OrdinalPiece::Text("ти")],
+        },
+        OrdinalRule {
+            base: 5,
+            pieces: &[OrdinalPiece::Text("и")],
+        },
+        OrdinalRule {
+            base: 20,
+            pieces: &[OrdinalPiece::Remainder {
+                rule_set: 9,
+                divisor: 10,
+            }],
+        },
+        OrdinalRule {
+            base: 100,
+            pieces: &[OrdinalPiece::Remainder {
+                rule_set: 10,
+                divisor: 100,
+            }],
+        },
+        OrdinalRule {
+            base: 1000,
+            pieces: &[OrdinalPiece::Remainder {
+                rule_set: 9,
+                divisor: 1000,
+            }],
+        },
+    ],
+    &[
+        OrdinalRule {
+            base: 0,
+            pieces: &[OrdinalPiece::Text("тен")],
+        },
+        OrdinalRule {
+            base: 1,
+            pieces: &[OrdinalPiece::Remainder {
+                rule_set: 9,
+                divisor: 1,
+            }],
+        },
+        OrdinalRule {
+            base: 100,
+            pieces: &[OrdinalPiece::Remainder {
+                rule_set: 10,
+                divisor: 100,
+            }],
+        },
+    ],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(12)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Number,
+            OrdinalPiece::Plural(["è", "r", "n", "t", "è", "è"]),
+        ],
+    }],
+    &[
+        OrdinalRule {
+            base: 0,
+            pieces: &[OrdinalPiece::Number, OrdinalPiece::Text(" lia")],
+        },
+        OrdinalRule {
+            base: 1,
+            pieces: &[OrdinalPiece::Number, OrdinalPiece::Text(" tɔ")],
+        },
+        OrdinalRule {
+            base: 2,
+            pieces: &[OrdinalPiece::Number, OrdinalPiece::Text(" lia")],
+        },
+    ],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Number,
+            OrdinalPiece::Plural(["th", "st", "nd", "rd", "th", "th"]),
+        ],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(17)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text(".º")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(19)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text("º.")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("ika"), OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(22)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Number,
+            OrdinalPiece::Plural(["e", "er", "e", "e", "e", "e"]),
+        ],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text("ú")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(25)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Number,
+            OrdinalPiece::Plural(["મા", "લા", "જા", "થા", "\u{acd}ઠા", "મા"]),
+        ],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(28)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Number,
+            OrdinalPiece::Plural(["वा\u{901}", "ला", "रा", "था", "ठा", "वा\u{901}"]),
+        ],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("ke-"), OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(31)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::RuleSet(32)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("º")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("第"), OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Number,
+            OrdinalPiece::Text("-"),
+            OrdinalPiece::Plural(["ші", "ші", "ші", "ші", "шы", "ші"]),
+        ],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("ទ\u{17b8}"), OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text("번째")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::RuleSet(38)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("'инчи")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Text("ท\u{e35}\u{e48}\u{200b}"),
+            OrdinalPiece::Number,
+        ],
+    }],
+    &[
+        OrdinalRule {
+            base: 0,
+            pieces: &[OrdinalPiece::Text("ke-"), OrdinalPiece::Number],
+        },
+        OrdinalRule {
+            base: 1,
+            pieces: &[OrdinalPiece::Text("No. 1")],
+        },
+        OrdinalRule {
+            base: 2,
+            pieces: &[OrdinalPiece::Text("ke-"), OrdinalPiece::Number],
+        },
+    ],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text("e")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(43)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text("º")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text("a")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(47)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Number,
+            OrdinalPiece::Plural([":e", ":a", ":e", ":e", ":e", ":e"]),
+        ],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Number, OrdinalPiece::Text(".")],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Text("ท\u{e35}\u{e48}\u{a0}"),
+            OrdinalPiece::Number,
+        ],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::RuleSet(51)],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[
+            OrdinalPiece::Number,
+            OrdinalPiece::Text("-"),
+            OrdinalPiece::Plural(["ий", "ий", "ий", "ій", "ий", "ий"]),
+        ],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("thứ "), OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("第"), OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("第"), OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("第"), OrdinalPiece::Number],
+    }],
+    &[OrdinalRule {
+        base: 0,
+        pieces: &[OrdinalPiece::Text("第"), OrdinalPiece::Number],
+    }],
+];
+#[doc = r" Parents that differ from what truncating a tag would give, from CLDR's"]
+#[doc = r#" `parentLocales.json`. A parent of `"root"` means there is nothing to fall back to"#]
+#[doc = r" (e.g. `az-Arab` must not fall back to `az`, which is written in Latin script)."]
+pub(crate) const PARENT_LOCALES: &[(&str, &str)] = &[
+    ("az-Arab", "root"),
+    ("az-Cyrl", "root"),
+    ("bm-Nkoo", "root"),
+    ("bs-Cyrl", "root"),
+    ("en-150", "en-001"),
+    ("en-AG", "en-001"),
+    ("en-AI", "en-001"),
+    ("en-AT", "en-150"),
+    ("en-AU", "en-001"),
+    ("en-BB", "en-001"),
+    ("en-BE", "en-150"),
+    ("en-BM", "en-001"),
+    ("en-BS", "en-001"),
+    ("en-BW", "en-001"),
+    ("en-BZ", "en-001"),
+    ("en-CA", "en-001"),
+    ("en-CC", "en-001"),
+    ("en-CH", "en-150"),
+    ("en-CK", "en-001"),
+    ("en-CM", "en-001"),
+    ("en-CX", "en-001"),
+    ("en-CY", "en-001"),
+    ("en-DE", "en-150"),
+    ("en-DG", "en-001"),
+    ("en-DK", "en-150"),
+    ("en-DM", "en-001"),
+    ("en-Dsrt", "root"),
+    ("en-ER", "en-001"),
+    ("en-FI", "en-150"),
+    ("en-FJ", "en-001"),
+    ("en-FK", "en-001"),
+    ("en-FM", "en-001"),
+    ("en-GB", "en-001"),
+    ("en-GD", "en-001"),
+    ("en-GG", "en-001"),
+    ("en-GH", "en-001"),
+    ("en-GI", "en-001"),
+    ("en-GM", "en-001"),
+    ("en-GY", "en-001"),
+    ("en-HK", "en-001"),
+    ("en-IE", "en-001"),
+    ("en-IL", "en-001"),
+    ("en-IM", "en-001"),
+    ("en-IN", "en-001"),
+    ("en-IO", "en-001"),
+    ("en-JE", "en-001"),
+    ("en-JM", "en-001"),
+    ("en-KE", "en-001"),
+    ("en-KI", "en-001"),
+    ("en-KN", "en-001"),
+    ("en-KY", "en-001"),
+    ("en-LC", "en-001"),
+    ("en-LR", "en-001"),
+    ("en-LS", "en-001"),
+    ("en-MG", "en-001"),
+    ("en-MO", "en-001"),
+    ("en-MS", "en-001"),
+    ("en-MT", "en-001"),
+    ("en-MU", "en-001"),
+    ("en-MW", "en-001"),
+    ("en-MY", "en-001"),
+    ("en-NA", "en-001"),
+    ("en-NF", "en-001"),
+    ("en-NG", "en-001"),
+    ("en-NL", "en-150"),
+    ("en-NR", "en-001"),
+    ("en-NU", "en-001"),
+    ("en-NZ", "en-001"),
+    ("en-PG", "en-001"),
+    ("en-PH", "en-001"),
+    ("en-PK", "en-001"),
+    ("en-PN", "en-001"),
+    ("en-PW", "en-001"),
+    ("en-RW", "en-001"),
+    ("en-SB", "en-001"),
+    ("en-SC", "en-001"),
+    ("en-SD", "en-001"),
+    ("en-SE", "en-150"),
+    ("en-SG", "en-001"),
+    ("en-SH", "en-001"),
+    ("en-SI", "en-150"),
+    ("en-SL", "en-001"),
+    ("en-SS", "en-001"),
+    ("en-SX", "en-001"),
+    ("en-SZ", "en-001"),
+    ("en-Shaw", "root"),
+    ("en-TC", "en-001"),
+    ("en-TK", "en-001"),
+    ("en-TO", "en-001"),
+    ("en-TT", "en-001"),
+    ("en-TV", "en-001"),
+    ("en-TZ", "en-001"),
+    ("en-UG", "en-001"),
+    ("en-VC", "en-001"),
+    ("en-VG", "en-001"),
+    ("en-VU", "en-001"),
+    ("en-WS", "en-001"),
+    ("en-ZA", "en-001"),
+    ("en-ZM", "en-001"),
+    ("en-ZW", "en-001"),
+    ("es-AR", "es-419"),
+    ("es-BO", "es-419"),
+    ("es-BR", "es-419"),
+    ("es-BZ", "es-419"),
+    ("es-CL", "es-419"),
+    ("es-CO", "es-419"),
+    ("es-CR", "es-419"),
+    ("es-CU", "es-419"),
+    ("es-DO", "es-419"),
+    ("es-EC", "es-419"),
+    ("es-GT", "es-419"),
+    ("es-HN", "es-419"),
+    ("es-MX", "es-419"),
+    ("es-NI", "es-419"),
+    ("es-PA", "es-419"),
+    ("es-PE", "es-419"),
+    ("es-PR", "es-419"),
+    ("es-PY", "es-419"),
+    ("es-SV", "es-419"),
+    ("es-US", "es-419"),
+    ("es-UY", "es-419"),
+    ("es-VE", "es-419"),
+    ("ff-Adlm", "root"),
+    ("ff-Arab", "root"),
+    ("ha-Arab", "root"),
+    ("hi-Latn", "root"),
+    ("iu-Latn", "root"),
+    ("kk-Arab", "root"),
+    ("ks-Deva", "root"),
+    ("ku-Arab", "root"),
+    ("ky-Arab", "root"),
+    ("ky-Latn", "root"),
+    ("ml-Arab", "root"),
+    ("mn-Mong", "root"),
+    ("mni-Mtei", "root"),
+    ("ms-Arab", "root"),
+    ("pa-Arab", "root"),
+    ("pt-AO", "pt-PT"),
+    ("pt-CH", "pt-PT"),
+    ("pt-CV", "pt-PT"),
+    ("pt-FR", "pt-PT"),
+    ("pt-GQ", "pt-PT"),
+    ("pt-GW", "pt-PT"),
+    ("pt-LU", "pt-PT"),
+    ("pt-MO", "pt-PT"),
+    ("pt-MZ", "pt-PT"),
+    ("pt-ST", "pt-PT"),
+    ("pt-TL", "pt-PT"),
+    ("sat-Deva", "root"),
+    ("sd-Deva", "root"),
+    ("sd-Khoj", "root"),
+    ("sd-Sind", "root"),
+    ("shi-Latn", "root"),
+    ("so-Arab", "root"),
+    ("sr-Latn", "root"),
+    ("sw-Arab", "root"),
+    ("tg-Arab", "root"),
+    ("ug-Cyrl", "root"),
+    ("uz-Arab", "root"),
+    ("uz-Cyrl", "root"),
+    ("vai-Latn", "root"),
+    ("wo-Arab", "root"),
+    ("yo-Arab", "root"),
+    ("yue-Hans", "root"),
+    ("zh-Hant", "root"),
+    ("zh-Hant-MO", "zh-Hant-HK"),
+];
+const PLURAL_RULES: &[u8] = &[
+    0, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 3, 0, 5, 0, 0, 0, 1, 6, 0, 0, 0, 1, 3, 3, 1, 1, 7, 0, 8, 8, 8, 9, 9, 9, 9, 9, 1, 1, 0, 10,
+    0, 0, 0, 0, 11, 1, 12, 13, 13, 1, 5, 5, 5, 5, 5, 5, 5, 1, 14, 1, 1, 1, 1, 0, 0, 0, 0, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+    5, 5, 5, 5, 5, 5, 5, 0, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 5, 0, 1, 3, 3, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+    16, 16, 16, 5, 10, 0, 0, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+    17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+    17, 17, 17, 17, 17, 0, 5, 18, 18, 19, 5, 0, 0, 0, 3, 1, 20, 0, 0, 0, 0, 21, 3, 8, 8, 14, 0, 16,
+    5, 1, 1, 1, 22, 9, 9, 9, 9, 1, 0, 0, 1, 0, 16, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 3, 1, 1, 3, 0, 0,
+    1, 23, 0, 24, 0, 25, 0, 0, 1, 2, 2, 2, 2, 1, 1, 1, 26, 1, 1, 1, 27, 0, 0, 1, 1, 2, 1, 0, 1, 28,
+    0, 0, 0, 1, 1, 1, 29, 1, 1, 1, 30, 0, 0, 0, 1, 1, 0, 0, 5, 5, 5, 5, 5, 5, 5, 1, 0, 0, 1, 0, 0,
+    0, 0, 0, 0, 2, 2, 2, 31, 27, 0, 0, 17, 17, 17, 17, 17, 17, 17, 17, 17, 9, 17, 17, 1, 1, 1, 0,
+    1, 32, 32, 0, 1, 33, 33, 33, 33, 33, 33, 1, 0, 1, 0, 1, 0, 30, 30, 30, 0, 1, 1, 34, 34, 34, 35,
+    11, 36, 30, 0, 0, 0, 0, 0, 0, 0, 0, 8, 8, 8, 8, 8, 8, 8, 8, 8, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0,
+    0, 0, 0, 1, 1, 2, 2, 0, 1, 0, 0, 1, 1, 37, 0, 33, 5, 5, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0,
+    0, 1, 5, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 3,
+];
+const SUBTAGS: &[Subtags] = &[
+    Subtags {
+        language: "af",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "af",
+        script: None,
+        region: Some("NA"),
+        variant: None,
+    },
+    Subtags {
+        language: "agq",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ak",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "am",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("AE"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("BH"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("DJ"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("DZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("EG"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("EH"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("ER"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("IL"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("IQ"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("JO"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("KM"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("KW"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("LB"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("LY"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("MA"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("MR"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("OM"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("PS"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("QA"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("SA"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("SD"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("SO"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("SS"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("SY"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("TD"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("TN"),
+        variant: None,
+    },
+    Subtags {
+        language: "ar",
+        script: None,
+        region: Some("YE"),
+        variant: None,
+    },
+    Subtags {
+        language: "as",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "asa",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ast",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "az",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "az",
+        script: Some("Cyrl"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "az",
+        script: Some("Latn"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bas",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "be",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bem",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bez",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bg",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bm",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bn",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bn",
+        script: None,
+        region: Some("IN"),
+        variant: None,
+    },
+    Subtags {
+        language: "bo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bo",
+        script: None,
+        region: Some("IN"),
+        variant: None,
+    },
+    Subtags {
+        language: "br",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "brx",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bs",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bs",
+        script: Some("Cyrl"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "bs",
+        script: Some("Latn"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ca",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ca",
+        script: None,
+        region: Some("AD"),
+        variant: None,
+    },
+    Subtags {
+        language: "ca",
+        script: None,
+        region: Some("ES"),
+        variant: Some("VALENCIA"),
+    },
+    Subtags {
+        language: "ca",
+        script: None,
+        region: Some("FR"),
+        variant: None,
+    },
+    Subtags {
+        language: "ca",
+        script: None,
+        region: Some("IT"),
+        variant: None,
+    },
+    Subtags {
+        language: "ccp",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ccp",
+        script: None,
+        region: Some("IN"),
+        variant: None,
+    },
+    Subtags {
+        language: "ce",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ceb",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "cgg",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "chr",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ckb",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ckb",
+        script: None,
+        region: Some("IR"),
+        variant: None,
+    },
+    Subtags {
+        language: "cs",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "cu",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "cy",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "da",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "da",
+        script: None,
+        region: Some("GL"),
+        variant: None,
+    },
+    Subtags {
+        language: "dav",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "de",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "de",
+        script: None,
+        region: Some("AT"),
+        variant: None,
+    },
+    Subtags {
+        language: "de",
+        script: None,
+        region: Some("BE"),
+        variant: None,
+    },
+    Subtags {
+        language: "de",
+        script: None,
+        region: Some("CH"),
+        variant: None,
+    },
+    Subtags {
+        language: "de",
+        script: None,
+        region: Some("IT"),
+        variant: None,
+    },
+    Subtags {
+        language: "de",
+        script: None,
+        region: Some("LI"),
+        variant: None,
+    },
+    Subtags {
+        language: "de",
+        script: None,
+        region: Some("LU"),
+        variant: None,
+    },
+    Subtags {
+        language: "dje",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "dsb",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "dua",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "dyo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "dz",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ebu",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ee",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ee",
+        script: None,
+        region: Some("TG"),
+        variant: None,
+    },
+    Subtags {
+        language: "el",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "el",
+        script: None,
+        region: Some("CY"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("001"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("150"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("AE"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("AG"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("AI"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("AS"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("AT"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("AU"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("BB"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("BE"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("BI"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("BM"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("BS"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("BW"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("BZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("CA"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("CC"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("CH"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("CK"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("CM"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("CX"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("CY"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("DE"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("DG"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("DK"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("DM"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("ER"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("FI"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("FJ"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("FK"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("FM"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("GB"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("GD"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("GG"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("GH"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("GI"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("GM"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("GU"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("GY"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("HK"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("IE"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("IL"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("IM"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("IN"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("IO"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("JE"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("JM"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("KE"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("KI"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("KN"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("KY"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("LC"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("LR"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("LS"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("MG"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("MH"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("MO"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("MP"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("MS"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("MT"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("MU"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("MW"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("MY"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("NA"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("NF"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("NG"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("NL"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("NR"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("NU"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("NZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("PG"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("PH"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("PK"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("PN"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("PR"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("PW"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("RW"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SB"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SC"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SD"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SE"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SG"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SH"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SI"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SL"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SS"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SX"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("SZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("TC"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("TK"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("TO"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("TT"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("TV"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("TZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("UG"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("UM"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("US"),
+        variant: Some("POSIX"),
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("VC"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("VG"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("VI"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("VU"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("WS"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("ZA"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("ZM"),
+        variant: None,
+    },
+    Subtags {
+        language: "en",
+        script: None,
+        region: Some("ZW"),
+        variant: None,
+    },
+    Subtags {
+        language: "eo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("419"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("AR"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("BO"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("BR"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("BZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("CL"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("CO"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("CR"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("CU"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("DO"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("EA"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("EC"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("GQ"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("GT"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("HN"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("IC"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("MX"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("NI"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("PA"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("PE"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("PH"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("PR"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("PY"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("SV"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("US"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("UY"),
+        variant: None,
+    },
+    Subtags {
+        language: "es",
+        script: None,
+        region: Some("VE"),
+        variant: None,
+    },
+    Subtags {
+        language: "et",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "eu",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ewo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "fa",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "fa",
+        script: None,
+        region: Some("AF"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("BF"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("CM"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("GH"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("GM"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("GN"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("GW"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("LR"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("MR"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("NE"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("NG"),
+        variant: None,
+    },
+    Subtags {
+        language: "ff",
+        script: Some("Latn"),
+        region: Some("SL"),
+        variant: None,
+    },
+    Subtags {
+        language: "fi",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "fil",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "fo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "fo",
+        script: None,
+        region: Some("DK"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("BE"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("BF"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("BI"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("BJ"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("BL"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("CA"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("CD"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("CF"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("CG"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("CH"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("CI"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("CM"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("DJ"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("DZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("GA"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("GF"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("GN"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("GP"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("GQ"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("HT"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("KM"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("LU"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("MA"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("MC"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("MF"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("MG"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("ML"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("MQ"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("MR"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("MU"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("NC"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("NE"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("PF"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("PM"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("RE"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("RW"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("SC"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("SN"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("SY"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("TD"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("TG"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("TN"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("VU"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("WF"),
+        variant: None,
+    },
+    Subtags {
+        language: "fr",
+        script: None,
+        region: Some("YT"),
+        variant: None,
+    },
+    Subtags {
+        language: "fur",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "fy",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ga",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ga",
+        script: None,
+        region: Some("GB"),
+        variant: None,
+    },
+    Subtags {
+        language: "gd",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "gl",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "gsw",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "gsw",
+        script: None,
+        region: Some("FR"),
+        variant: None,
+    },
+    Subtags {
+        language: "gsw",
+        script: None,
+        region: Some("LI"),
+        variant: None,
+    },
+    Subtags {
+        language: "gu",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "guz",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "gv",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ha",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ha",
+        script: None,
+        region: Some("GH"),
+        variant: None,
+    },
+    Subtags {
+        language: "ha",
+        script: None,
+        region: Some("NE"),
+        variant: None,
+    },
+    Subtags {
+        language: "haw",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "he",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "hi",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "hr",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "hr",
+        script: None,
+        region: Some("BA"),
+        variant: None,
+    },
+    Subtags {
+        language: "hsb",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "hu",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "hy",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ia",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "id",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ig",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ii",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "is",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "it",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "it",
+        script: None,
+        region: Some("CH"),
+        variant: None,
+    },
+    Subtags {
+        language: "it",
+        script: None,
+        region: Some("SM"),
+        variant: None,
+    },
+    Subtags {
+        language: "it",
+        script: None,
+        region: Some("VA"),
+        variant: None,
+    },
+    Subtags {
+        language: "ja",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "jgo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "jmc",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "jv",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ka",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kab",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kam",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kde",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kea",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "khq",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ki",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kk",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kkj",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kl",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kln",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "km",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kn",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ko",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ko",
+        script: None,
+        region: Some("KP"),
+        variant: None,
+    },
+    Subtags {
+        language: "kok",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ks",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ksb",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ksf",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ksh",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ku",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "kw",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ky",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "lag",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "lb",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "lg",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "lkt",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ln",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ln",
+        script: None,
+        region: Some("AO"),
+        variant: None,
+    },
+    Subtags {
+        language: "ln",
+        script: None,
+        region: Some("CF"),
+        variant: None,
+    },
+    Subtags {
+        language: "ln",
+        script: None,
+        region: Some("CG"),
+        variant: None,
+    },
+    Subtags {
+        language: "lo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "lrc",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "lrc",
+        script: None,
+        region: Some("IQ"),
+        variant: None,
+    },
+    Subtags {
+        language: "lt",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "lu",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "luo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "luy",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "lv",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mas",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mas",
+        script: None,
+        region: Some("TZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "mer",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mfe",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mg",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mgh",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mgo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mi",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mk",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ml",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mn",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mr",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ms",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ms",
+        script: None,
+        region: Some("BN"),
+        variant: None,
+    },
+    Subtags {
+        language: "ms",
+        script: None,
+        region: Some("SG"),
+        variant: None,
+    },
+    Subtags {
+        language: "mt",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mua",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "my",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "mzn",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "naq",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "nb",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "nb",
+        script: None,
+        region: Some("SJ"),
+        variant: None,
+    },
+    Subtags {
+        language: "nd",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "nds",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "nds",
+        script: None,
+        region: Some("NL"),
+        variant: None,
+    },
+    Subtags {
+        language: "ne",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ne",
+        script: None,
+        region: Some("IN"),
+        variant: None,
+    },
+    Subtags {
+        language: "nl",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "nl",
+        script: None,
+        region: Some("AW"),
+        variant: None,
+    },
+    Subtags {
+        language: "nl",
+        script: None,
+        region: Some("BE"),
+        variant: None,
+    },
+    Subtags {
+        language: "nl",
+        script: None,
+        region: Some("BQ"),
+        variant: None,
+    },
+    Subtags {
+        language: "nl",
+        script: None,
+        region: Some("CW"),
+        variant: None,
+    },
+    Subtags {
+        language: "nl",
+        script: None,
+        region: Some("SR"),
+        variant: None,
+    },
+    Subtags {
+        language: "nl",
+        script: None,
+        region: Some("SX"),
+        variant: None,
+    },
+    Subtags {
+        language: "nmg",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "nn",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "nnh",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "nus",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "nyn",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "om",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "om",
+        script: None,
+        region: Some("KE"),
+        variant: None,
+    },
+    Subtags {
+        language: "or",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "os",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "os",
+        script: None,
+        region: Some("RU"),
+        variant: None,
+    },
+    Subtags {
+        language: "pa",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "pa",
+        script: Some("Arab"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "pa",
+        script: Some("Guru"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "pl",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "prg",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ps",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ps",
+        script: None,
+        region: Some("PK"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("AO"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("CH"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("CV"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("GQ"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("GW"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("LU"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("MO"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("MZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("PT"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("ST"),
+        variant: None,
+    },
+    Subtags {
+        language: "pt",
+        script: None,
+        region: Some("TL"),
+        variant: None,
+    },
+    Subtags {
+        language: "qu",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "qu",
+        script: None,
+        region: Some("BO"),
+        variant: None,
+    },
+    Subtags {
+        language: "qu",
+        script: None,
+        region: Some("EC"),
+        variant: None,
+    },
+    Subtags {
+        language: "rm",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "rn",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ro",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ro",
+        script: None,
+        region: Some("MD"),
+        variant: None,
+    },
+    Subtags {
+        language: "rof",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "root",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ru",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ru",
+        script: None,
+        region: Some("BY"),
+        variant: None,
+    },
+    Subtags {
+        language: "ru",
+        script: None,
+        region: Some("KG"),
+        variant: None,
+    },
+    Subtags {
+        language: "ru",
+        script: None,
+        region: Some("KZ"),
+        variant: None,
+    },
+    Subtags {
+        language: "ru",
+        script: None,
+        region: Some("MD"),
+        variant: None,
+    },
+    Subtags {
+        language: "ru",
+        script: None,
+        region: Some("UA"),
+        variant: None,
+    },
+    Subtags {
+        language: "rw",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "rwk",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sah",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "saq",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sbp",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sd",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "se",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "se",
+        script: None,
+        region: Some("FI"),
+        variant: None,
+    },
+    Subtags {
+        language: "se",
+        script: None,
+        region: Some("SE"),
+        variant: None,
+    },
+    Subtags {
+        language: "seh",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ses",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sg",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "shi",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "shi",
+        script: Some("Latn"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "shi",
+        script: Some("Tfng"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "si",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sk",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sl",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "smn",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sn",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "so",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "so",
+        script: None,
+        region: Some("DJ"),
+        variant: None,
+    },
+    Subtags {
+        language: "so",
+        script: None,
+        region: Some("ET"),
+        variant: None,
+    },
+    Subtags {
+        language: "so",
+        script: None,
+        region: Some("KE"),
+        variant: None,
+    },
+    Subtags {
+        language: "sq",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sq",
+        script: None,
+        region: Some("MK"),
+        variant: None,
+    },
+    Subtags {
+        language: "sq",
+        script: None,
+        region: Some("XK"),
+        variant: None,
+    },
+    Subtags {
+        language: "sr",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sr",
+        script: Some("Cyrl"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sr",
+        script: Some("Cyrl"),
+        region: Some("BA"),
+        variant: None,
+    },
+    Subtags {
+        language: "sr",
+        script: Some("Cyrl"),
+        region: Some("ME"),
+        variant: None,
+    },
+    Subtags {
+        language: "sr",
+        script: Some("Cyrl"),
+        region: Some("XK"),
+        variant: None,
+    },
+    Subtags {
+        language: "sr",
+        script: Some("Latn"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sr",
+        script: Some("Latn"),
+        region: Some("BA"),
+        variant: None,
+    },
+    Subtags {
+        language: "sr",
+        script: Some("Latn"),
+        region: Some("ME"),
+        variant: None,
+    },
+    Subtags {
+        language: "sr",
+        script: Some("Latn"),
+        region: Some("XK"),
+        variant: None,
+    },
+    Subtags {
+        language: "sv",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sv",
+        script: None,
+        region: Some("AX"),
+        variant: None,
+    },
+    Subtags {
+        language: "sv",
+        script: None,
+        region: Some("FI"),
+        variant: None,
+    },
+    Subtags {
+        language: "sw",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "sw",
+        script: None,
+        region: Some("CD"),
+        variant: None,
+    },
+    Subtags {
+        language: "sw",
+        script: None,
+        region: Some("KE"),
+        variant: None,
+    },
+    Subtags {
+        language: "sw",
+        script: None,
+        region: Some("UG"),
+        variant: None,
+    },
+    Subtags {
+        language: "ta",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ta",
+        script: None,
+        region: Some("LK"),
+        variant: None,
+    },
+    Subtags {
+        language: "ta",
+        script: None,
+        region: Some("MY"),
+        variant: None,
+    },
+    Subtags {
+        language: "ta",
+        script: None,
+        region: Some("SG"),
+        variant: None,
+    },
+    Subtags {
+        language: "te",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "teo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "teo",
+        script: None,
+        region: Some("KE"),
+        variant: None,
+    },
+    Subtags {
+        language: "tg",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "th",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ti",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ti",
+        script: None,
+        region: Some("ER"),
+        variant: None,
+    },
+    Subtags {
+        language: "tk",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "to",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "tr",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "tr",
+        script: None,
+        region: Some("CY"),
+        variant: None,
+    },
+    Subtags {
+        language: "tt",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "twq",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "tzm",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ug",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "uk",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ur",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "ur",
+        script: None,
+        region: Some("IN"),
+        variant: None,
+    },
+    Subtags {
+        language: "uz",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "uz",
+        script: Some("Arab"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "uz",
+        script: Some("Cyrl"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "uz",
+        script: Some("Latn"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "vai",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "vai",
+        script: Some("Latn"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "vai",
+        script: Some("Vaii"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "vi",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "vo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "vun",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "wae",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "wo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "xh",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "xog",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "yav",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "yi",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "yo",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "yo",
+        script: None,
+        region: Some("BJ"),
+        variant: None,
+    },
+    Subtags {
+        language: "yue",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "yue",
+        script: Some("Hans"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "yue",
+        script: Some("Hant"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "zgh",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "zh",
+        script: None,
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "zh",
+        script: Some("Hans"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "zh",
+        script: Some("Hans"),
+        region: Some("HK"),
+        variant: None,
+    },
+    Subtags {
+        language: "zh",
+        script: Some("Hans"),
+        region: Some("MO"),
+        variant: None,
+    },
+    Subtags {
+        language: "zh",
+        script: Some("Hans"),
+        region: Some("SG"),
+        variant: None,
+    },
+    Subtags {
+        language: "zh",
+        script: Some("Hant"),
+        region: None,
+        variant: None,
+    },
+    Subtags {
+        language: "zh",
+        script: Some("Hant"),
+        region: Some("HK"),
+        variant: None,
+    },
+    Subtags {
+        language: "zh",
+        script: Some("Hant"),
+        region: Some("MO"),
+        variant: None,
+    },
+    Subtags {
+        language: "zu",
+        script: None,
+        region: None,
+        variant: None,
+    },
+];
+const SYMBOL_INDICES: &[u8] = &[
+    0, 0, 0, 3, 3, 4, 4, 4, 4, 7, 4, 6, 4, 4, 4, 4, 4, 4, 4, 7, 7, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 7,
+    4, 9, 3, 12, 14, 14, 14, 0, 0, 3, 3, 0, 3, 9, 9, 3, 3, 0, 9, 14, 14, 14, 14, 14, 14, 14, 14, 9,
+    9, 18, 3, 3, 3, 13, 13, 0, 3, 3, 14, 14, 3, 14, 0, 14, 20, 14, 20, 14, 21, 14, 0, 0, 22, 3, 23,
+    23, 14, 14, 3, 3, 3, 3, 3, 3, 3, 14, 3, 3, 14, 3, 3, 3, 3, 3, 3, 3, 20, 3, 3, 3, 3, 14, 3, 14,
+    3, 3, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 9, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 3, 14, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 0, 3, 3, 14, 3, 3, 3, 3,
+    3, 3, 3, 3, 3, 3, 3, 3, 24, 3, 3, 3, 3, 3, 0, 3, 3, 0, 14, 3, 14, 14, 3, 3, 14, 14, 0, 3, 3,
+    14, 14, 14, 3, 3, 14, 3, 3, 3, 3, 14, 3, 14, 3, 3, 14, 14, 27, 28, 0, 30, 30, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 33, 3, 28, 28, 34, 34, 34, 34, 34, 34, 0, 34, 34, 34, 34, 34, 34, 34, 34,
+    34, 34, 34, 34, 34, 34, 34, 14, 14, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+    34, 34, 34, 34, 34, 34, 34, 14, 14, 3, 3, 3, 14, 37, 37, 37, 9, 3, 3, 3, 3, 3, 3, 39, 9, 14,
+    14, 14, 0, 40, 14, 14, 3, 3, 14, 14, 20, 14, 14, 3, 14, 3, 14, 41, 0, 3, 3, 0, 21, 3, 42, 14,
+    14, 3, 14, 3, 3, 3, 3, 11, 3, 0, 27, 14, 3, 45, 3, 14, 3, 3, 14, 14, 14, 14, 46, 2, 2, 27, 14,
+    3, 3, 47, 3, 3, 3, 21, 3, 14, 3, 3, 14, 9, 3, 9, 3, 14, 3, 3, 14, 49, 2, 3, 27, 27, 3, 3, 3, 9,
+    9, 14, 14, 14, 14, 14, 14, 14, 0, 27, 14, 3, 3, 3, 3, 9, 51, 51, 9, 2, 9, 0, 3, 2, 2, 14, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 14, 3, 37, 14, 14, 14, 3, 3, 54, 54, 54, 54, 54, 54, 14, 3, 55,
+    3, 3, 1, 27, 27, 27, 14, 21, 14, 0, 0, 0, 3, 0, 28, 57, 3, 58, 58, 58, 58, 0, 0, 0, 14, 14, 14,
+    14, 14, 14, 14, 14, 14, 27, 27, 27, 3, 14, 3, 3, 9, 9, 3, 3, 9, 3, 3, 0, 3, 3, 3, 59, 61, 14,
+    14, 0, 21, 0, 3, 0, 39, 2, 63, 2, 65, 63, 3, 3, 3, 14, 3, 3, 67, 14, 21, 3, 0, 3, 3, 3, 68, 69,
+    68, 0, 3, 3, 3, 3, 3, 68, 68, 68, 3,
+];
+const SYMBOLS: &[Symbols] = &[
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{61c}-",
+        nan: "NaN",
+        plus_sign: "\u{61c}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}-\u{200e}",
+        nan: "NaN",
+        plus_sign: "\u{200e}+\u{200e}",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{61c}-",
+        nan: "ليس\u{a0}رقم",
+        plus_sign: "\u{61c}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}-\u{200e}",
+        nan: "ليس\u{a0}رقم\u{64b}ا",
+        plus_sign: "\u{200e}+\u{200e}",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}-",
+        nan: "ليس\u{a0}رقم\u{64b}ا",
+        plus_sign: "\u{200e}+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}-",
+        nan: "ليس\u{a0}رقم\u{64b}ا",
+        plus_sign: "\u{200e}+",
+        separator: ".",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{61c}-",
+        nan: "ليس\u{a0}رقم\u{64b}ا",
+        plus_sign: "\u{61c}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Indian,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Indian,
+        infinity: "∞",
+        minus_sign: "\u{61c}-",
+        nan: "NaN",
+        plus_sign: "\u{61c}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Indian,
+        infinity: "∞",
+        minus_sign: "\u{200e}-\u{200e}",
+        nan: "NaN",
+        plus_sign: "\u{200e}+\u{200e}",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "ND",
+        plus_sign: "+",
+        separator: ".",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200f}-",
+        nan: "NaN",
+        plus_sign: "\u{200f}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: ".",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Posix,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Posix,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "ཨང་མ\u{f7a}ན་",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "Терхьаш\u{a0}дац",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "\u{200e}+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "’",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Indian,
+        infinity: "ག\u{fb2}ངས་མ\u{f7a}ད",
+        minus_sign: "-",
+        nan: "ཨང་མད",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "mnn",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Posix,
+        infinity: "INF",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Posix,
+        infinity: "∞",
+        minus_sign: "\u{61c}-",
+        nan: "NaN",
+        plus_sign: "\u{61c}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Posix,
+        infinity: "∞",
+        minus_sign: "\u{200e}-\u{200e}",
+        nan: "NaN",
+        plus_sign: "\u{200e}+\u{200e}",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "−",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "−",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: ".",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}−",
+        nan: "ناعدد",
+        plus_sign: "\u{200e}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{61c}-",
+        nan: "ناعدد",
+        plus_sign: "\u{61c}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}−",
+        nan: "ناعدد",
+        plus_sign: "\u{200e}+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "−",
+        nan: "epäluku",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "\u{202f}",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{61c}-",
+        nan: "NaN",
+        plus_sign: "\u{200f}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "\u{200e}+\u{200e}",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "−",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "’",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}-",
+        nan: "NaN",
+        plus_sign: "\u{200e}+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "ՈչԹ",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "არ\u{a0}არის\u{a0}რიცხვი",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "сан\u{a0}емес",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Indian,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "0/0",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "،",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "сан\u{a0}эмес",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "ບ\u{ecd}\u{ec8}\u{200b}ແມ\u{ec8}ນ\u{200b}ໂຕ\u{200b}ເລກ",
+        plus_sign: "+",
+        separator: ".",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NS",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}-\u{200e}",
+        nan: "NaN",
+        plus_sign: "\u{200e}+\u{200e}",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "ဂဏန\u{103a}းမဟ\u{102f}တ\u{103a}သော",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{61c}−",
+        nan: "NaN",
+        plus_sign: "\u{61c}+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "НН",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Indian,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}−",
+        nan: "NaN",
+        plus_sign: "\u{200e}+",
+        separator: ".",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "не\u{a0}число",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "чыыһыла\u{a0}буотах",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}-\u{200e}",
+        nan: "NaN",
+        plus_sign: "\u{200e}+\u{200e}",
+        separator: "،",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "epiloho",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "MaL",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "san\u{a0}däl",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}-\u{200e}",
+        nan: "San\u{a0}däl",
+        plus_sign: "\u{200e}+\u{200e}",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "TF",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: "،",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{61c}-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "son\u{a0}emas",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "son\u{a0}emas",
+        plus_sign: "+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "ҳақиқий\u{a0}сон\u{a0}эмас",
+        plus_sign: "+",
+        separator: "\u{a0}",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "ҳақиқий\u{a0}сон\u{a0}эмас",
+        plus_sign: "+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: ",",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "NaN",
+        plus_sign: "+",
+        separator: "’",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "非數值",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: ".",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "非数值",
+        plus_sign: "+",
+        separator: ",",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "-",
+        nan: "非數值",
+        plus_sign: "\u{61c}+",
+        separator: "٬",
+    },
+    Symbols {
+        decimal: "٫",
+        grouping: Grouping::Standard,
+        infinity: "∞",
+        minus_sign: "\u{200e}-\u{200e}",
+        nan: "非數值",
+        plus_sign: "+\u{200e}",
+        separator: "٬",
+    },
+];
+#[doc = r" Subtags of a locale's identifier."]
+struct Subtags {
+    language: &'static str,
+    script: Option<&'static str>,
+    region: Option<&'static str>,
+    variant: Option<&'static str>,
+}
+#[doc = r" Symbols of one or more locales that format numbers identically."]
+pub(crate) struct Symbols {
+    pub(crate) decimal: &'static str,
+    pub(crate) grouping: Grouping,
+    pub(crate) infinity: &'static str,
+    pub(crate) minus_sign: &'static str,
+    pub(crate) nan: &'static str,
+    pub(crate) plus_sign: &'static str,
+    pub(crate) separator: &'static str,
+}
+#[doc = r" Returns the category of `n` under the ordinal plural rules with the given index."]
+#[allow(clippy::manual_is_multiple_of)]
+fn ordinal_category(rules: u8, n: u128) -> PluralCategory {
+    match rules {
+        1 => {
+            if matches!(n, 1 | 5 | 7..=10) {
+                PluralCategory::One
+            } else if matches!(n, 2..=3) {
+                PluralCategory::Two
+            } else if n == 4 {
+                PluralCategory::Few
+            } else if n == 6 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        2 => {
+            if matches ! (n % 10 , 1 ..= 2 | 5 | 7 ..= 8) || matches!(n % 100, 20 | 50 | 70 | 80) {
+                PluralCategory::One
+            } else if matches!(n % 10, 3..=4)
+                || matches!(
+                    n % 1000,
+                    100 | 200 | 300 | 400 | 500 | 600 | 700 | 800 | 900
+                )
+            {
+                PluralCategory::Few
+            } else if n == 0 || n % 10 == 6 || matches!(n % 100, 40 | 60 | 90) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        3 => {
+            if matches!(n % 10, 2..=3) && !matches!(n % 100, 12..=13) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        4 => {
+            if matches!(n, 1 | 3) {
+                PluralCategory::One
+            } else if n == 2 {
+                PluralCategory::Two
+            } else if n == 4 {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        5 => {
+            if matches!(n, 0 | 7..=9) {
+                PluralCategory::Zero
+            } else if n == 1 {
+                PluralCategory::One
+            } else if n == 2 {
+                PluralCategory::Two
+            } else if matches!(n, 3..=4) {
+                PluralCategory::Few
+            } else if matches!(n, 5..=6) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        6 => {
+            if n % 10 == 1 && n % 100 != 11 {
+                PluralCategory::One
+            } else if n % 10 == 2 && n % 100 != 12 {
+                PluralCategory::Two
+            } else if n % 10 == 3 && n % 100 != 13 {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        7 => {
+            if n == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        8 => {
+            if matches!(n, 1 | 11) {
+                PluralCategory::One
+            } else if matches!(n, 2 | 12) {
+                PluralCategory::Two
+            } else if matches!(n, 3 | 13) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        9 => {
+            if n == 1 {
+                PluralCategory::One
+            } else if matches!(n, 2..=3) {
+                PluralCategory::Two
+            } else if n == 4 {
+                PluralCategory::Few
+            } else if n == 6 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        10 => {
+            if matches!(n, 1 | 5) {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        11 => {
+            if matches!(n, 8 | 11 | 80 | 800) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        12 => {
+            if n == 1 {
+                PluralCategory::One
+            } else if n == 0 || matches!(n % 100, 2..=20 | 40 | 60 | 80) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        13 => {
+            if n % 10 == 6 || n % 10 == 9 || n % 10 == 0 && n != 0 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        14 => {
+            if n == 1 {
+                PluralCategory::One
+            } else if matches!(n, 2..=3) {
+                PluralCategory::Two
+            } else if n == 4 {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        15 => {
+            if matches!(n, 1..=4)
+                || matches ! (n % 100 , 1 ..= 4 | 21 ..= 24 | 41 ..= 44 | 61 ..= 64 | 81 ..= 84)
+            {
+                PluralCategory::One
+            } else if n == 5 || n % 100 == 5 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        16 => {
+            if n % 10 == 1 && n % 100 != 11 {
+                PluralCategory::One
+            } else if n % 10 == 2 && n % 100 != 12 {
+                PluralCategory::Two
+            } else if matches!(n % 10, 7..=8) && !matches!(n % 100, 17..=18) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        17 => {
+            if matches!(n, 1..=4) {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        18 => {
+            if matches!(n, 1 | 5 | 7..=9) {
+                PluralCategory::One
+            } else if matches!(n, 2..=3) {
+                PluralCategory::Two
+            } else if n == 4 {
+                PluralCategory::Few
+            } else if n == 6 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        19 => {
+            if n == 1 {
+                PluralCategory::One
+            } else if n % 10 == 4 && n % 100 != 14 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        20 => {
+            if matches!(n % 10, 1..=2) && !matches!(n % 100, 11..=12) {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        21 => {
+            if matches!(n % 10, 6 | 9) || n == 10 {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        22 => {
+            if n % 10 == 3 && n % 100 != 13 {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        _ => PluralCategory::Other,
+    }
+}
+#[doc = r" Returns the category of a number under the cardinal plural rules with the given index."]
+#[allow(clippy::manual_is_multiple_of, clippy::nonminimal_bool)]
+fn plural_category(rules: u8, o: &PluralOperands) -> PluralCategory {
+    match rules {
+        0 => {
+            if o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        2 => {
+            if o.t == 0 && matches!(o.i, 0..=1) {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        3 => {
+            if o.i == 0 || o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        4 => {
+            if o.t == 0 && o.i == 0 {
+                PluralCategory::Zero
+            } else if o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else if o.t == 0 && o.i == 2 {
+                PluralCategory::Two
+            } else if o.t == 0 && matches!(o.i % 100, 3..=10) {
+                PluralCategory::Few
+            } else if o.t == 0 && matches!(o.i % 100, 11..=99) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        5 => {
+            if o.i == 1 && o.v == 0 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        6 => {
+            if o.t == 0 && o.i % 10 == 1 && (o.t != 0 || o.i % 100 != 11) {
+                PluralCategory::One
+            } else if o.t == 0
+                && matches!(o.i % 10, 2..=4)
+                && (o.t != 0 || !matches!(o.i % 100, 12..=14))
+            {
+                PluralCategory::Few
+            } else if o.t == 0 && o.i % 10 == 0
+                || o.t == 0 && matches!(o.i % 10, 5..=9)
+                || o.t == 0 && matches!(o.i % 100, 11..=14)
+            {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        7 => {
+            if o.t == 0 && o.i % 10 == 1 && (o.t != 0 || !matches!(o.i % 100, 11 | 71 | 91)) {
+                PluralCategory::One
+            } else if o.t == 0 && o.i % 10 == 2 && (o.t != 0 || !matches!(o.i % 100, 12 | 72 | 92))
+            {
+                PluralCategory::Two
+            } else if o.t == 0
+                && matches!(o.i % 10, 3..=4 | 9)
+                && (o.t != 0 || !matches ! (o . i % 100 , 10 ..= 19 | 70 ..= 79 | 90 ..= 99))
+            {
+                PluralCategory::Few
+            } else if (o.t != 0 || o.i != 0) && o.t == 0 && o.i % 1000000 == 0 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        8 => {
+            if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.f % 10 == 1 && o.f % 100 != 11 {
+                PluralCategory::One
+            } else if o.v == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14)
+                || matches!(o.f % 10, 2..=4) && !matches!(o.f % 100, 12..=14)
+            {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        9 => {
+            if o.i == 1 && o.v == 0 {
+                PluralCategory::One
+            } else if o.i != 0 && o.i % 1000000 == 0 && o.v == 0 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        10 => {
+            if o.v == 0 && matches!(o.i, 1..=3)
+                || o.v == 0 && !matches!(o.i % 10, 4 | 6 | 9)
+                || o.v != 0 && !matches!(o.f % 10, 4 | 6 | 9)
+            {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        11 => {
+            if o.i == 1 && o.v == 0 {
+                PluralCategory::One
+            } else if matches!(o.i, 2..=4) && o.v == 0 {
+                PluralCategory::Few
+            } else if o.v != 0 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        12 => {
+            if o.t == 0 && o.i == 0 {
+                PluralCategory::Zero
+            } else if o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else if o.t == 0 && o.i == 2 {
+                PluralCategory::Two
+            } else if o.t == 0 && o.i == 3 {
+                PluralCategory::Few
+            } else if o.t == 0 && o.i == 6 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        13 => {
+            if o.t == 0 && o.i == 1 || o.t != 0 && matches!(o.i, 0..=1) {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        14 => {
+            if o.v == 0 && o.i % 100 == 1 || o.f % 100 == 1 {
+                PluralCategory::One
+            } else if o.v == 0 && o.i % 100 == 2 || o.f % 100 == 2 {
+                PluralCategory::Two
+            } else if o.v == 0 && matches!(o.i % 100, 3..=4) || matches!(o.f % 100, 3..=4) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        15 => {
+            if o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else if o.i != 0 && o.i % 1000000 == 0 && o.v == 0 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        16 => {
+            if matches!(o.i, 0..=1) {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        17 => {
+            if matches!(o.i, 0..=1) {
+                PluralCategory::One
+            } else if o.i != 0 && o.i % 1000000 == 0 && o.v == 0 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        18 => {
+            if o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else if o.t == 0 && o.i == 2 {
+                PluralCategory::Two
+            } else if o.t == 0 && matches!(o.i, 3..=6) {
+                PluralCategory::Few
+            } else if o.t == 0 && matches!(o.i, 7..=10) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        19 => {
+            if o.t == 0 && matches!(o.i, 1 | 11) {
+                PluralCategory::One
+            } else if o.t == 0 && matches!(o.i, 2 | 12) {
+                PluralCategory::Two
+            } else if o.t == 0 && matches ! (o . i , 3 ..= 10 | 13 ..= 19) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        20 => {
+            if o.v == 0 && o.i % 10 == 1 {
+                PluralCategory::One
+            } else if o.v == 0 && o.i % 10 == 2 {
+                PluralCategory::Two
+            } else if o.v == 0 && matches!(o.i % 100, 0 | 20 | 40 | 60 | 80) {
+                PluralCategory::Few
+            } else if o.v != 0 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        21 => {
+            if o.i == 1 && o.v == 0 || o.i == 0 && o.v != 0 {
+                PluralCategory::One
+            } else if o.i == 2 && o.v == 0 {
+                PluralCategory::Two
+            } else {
+                PluralCategory::Other
+            }
+        }
+        22 => {
+            if o.t == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.t % 10 == 1 && o.t % 100 != 11 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        23 => {
+            if o.t == 0 && o.i == 0 {
+                PluralCategory::Zero
+            } else if o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        24 => {
+            if o.t == 0 && o.i == 0 {
+                PluralCategory::Zero
+            } else if o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else if o.t == 0 && matches!(o.i % 100, 2 | 22 | 42 | 62 | 82)
+                || o.t == 0
+                    && o.i % 1000 == 0
+                    && o.t == 0
+                    && matches!(o.i % 100000, 1000..=20000 | 40000 | 60000 | 80000)
+                || (o.t != 0 || o.i != 0) && o.t == 0 && o.i % 1000000 == 100000
+            {
+                PluralCategory::Two
+            } else if o.t == 0 && matches!(o.i % 100, 3 | 23 | 43 | 63 | 83) {
+                PluralCategory::Few
+            } else if (o.t != 0 || o.i != 1)
+                && o.t == 0
+                && matches!(o.i % 100, 1 | 21 | 41 | 61 | 81)
+            {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        25 => {
+            if o.t == 0 && o.i == 0 {
+                PluralCategory::Zero
+            } else if matches!(o.i, 0..=1) && (o.t != 0 || o.i != 0) {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        26 => {
+            if o.t == 0 && o.i % 10 == 1 && (o.t != 0 || !matches!(o.i % 100, 11..=19)) {
+                PluralCategory::One
+            } else if o.t == 0
+                && matches!(o.i % 10, 2..=9)
+                && (o.t != 0 || !matches!(o.i % 100, 11..=19))
+            {
+                PluralCategory::Few
+            } else if o.f != 0 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        27 => {
+            if o.t == 0 && o.i % 10 == 0
+                || o.t == 0 && matches!(o.i % 100, 11..=19)
+                || o.v == 2 && matches!(o.f % 100, 11..=19)
+            {
+                PluralCategory::Zero
+            } else if o.t == 0 && o.i % 10 == 1 && (o.t != 0 || o.i % 100 != 11)
+                || o.v == 2 && o.f % 10 == 1 && o.f % 100 != 11
+                || o.v != 2 && o.f % 10 == 1
+            {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        28 => {
+            if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 || o.f % 10 == 1 && o.f % 100 != 11 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        29 => {
+            if o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else if o.t == 0 && o.i == 2 {
+                PluralCategory::Two
+            } else if o.t == 0 && o.i == 0 || o.t == 0 && matches!(o.i % 100, 3..=10) {
+                PluralCategory::Few
+            } else if o.t == 0 && matches!(o.i % 100, 11..=19) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        30 => {
+            if o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else if o.t == 0 && o.i == 2 {
+                PluralCategory::Two
+            } else {
+                PluralCategory::Other
+            }
+        }
+        31 => {
+            if o.i == 1 && o.v == 0 {
+                PluralCategory::One
+            } else if o.v == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14) {
+                PluralCategory::Few
+            } else if o.v == 0 && o.i != 1 && matches!(o.i % 10, 0..=1)
+                || o.v == 0 && matches!(o.i % 10, 5..=9)
+                || o.v == 0 && matches!(o.i % 100, 12..=14)
+            {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        32 => {
+            if o.i == 1 && o.v == 0 {
+                PluralCategory::One
+            } else if o.v != 0
+                || o.t == 0 && o.i == 0
+                || (o.t != 0 || o.i != 1) && o.t == 0 && matches!(o.i % 100, 1..=19)
+            {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        33 => {
+            if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 {
+                PluralCategory::One
+            } else if o.v == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14) {
+                PluralCategory::Few
+            } else if o.v == 0 && o.i % 10 == 0
+                || o.v == 0 && matches!(o.i % 10, 5..=9)
+                || o.v == 0 && matches!(o.i % 100, 11..=14)
+            {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        34 => {
+            if o.i == 0 || o.t == 0 && o.i == 1 {
+                PluralCategory::One
+            } else if o.t == 0 && matches!(o.i, 2..=10) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        35 => {
+            if o.t == 0 && matches!(o.i, 0..=1) || o.i == 0 && o.f == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        36 => {
+            if o.v == 0 && o.i % 100 == 1 {
+                PluralCategory::One
+            } else if o.v == 0 && o.i % 100 == 2 {
+                PluralCategory::Two
+            } else if o.v == 0 && matches!(o.i % 100, 3..=4) || o.v != 0 {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        37 => {
+            if o.t == 0 && matches!(o.i, 0..=1) || o.t == 0 && matches!(o.i, 11..=99) {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        _ => PluralCategory::Other,
+    }
+}
+#[doc = r" Returns the ordinal rule set with the given index."]
+pub(crate) fn ordinal_rule_set(index: u8) -> &'static [OrdinalRule] {
+    ORDINAL_RULE_SETS[usize::from(index)]
+}
 #[doc = r"<b><u>A key type</u></b>. Represents formats from the [Unicode Consortium]'s"]
 #[doc = r"[Common Locale Data Repository (CLDR)]. Implements [`Format`]."]
 #[doc = r""]
+#[doc = r"All of CLDR's locales can be looked up by name unless num-format is built with the"]
+#[doc = r"`NUM_FORMAT_LOCALES` environment variable set to `en` or `eu` (or both), in which case"]
+#[doc = r"only the English locales or the locales of the official languages of the European Union"]
+#[doc = r"(including English) can be. Every variant exists, is returned by [`iter`] and has the"]
+#[doc = r"same symbols, plural rules, ordinal rules and display names in every build; one that is"]
+#[doc = r"left out is only missing from [`available_names`] and cannot be returned by"]
+#[doc = r"[`from_name`] (or [`negotiate`], which resolves names the same way)."]
+#[doc = r""]
 #[doc = r"# Example"]
 #[doc = r"```"]
 #[doc = r"use num_format::{Buffer, Locale};"]
@@ -567,7 +12097,11 @@ const AVAILABLE_NAMES: [&str; 542usize] = [
 #[doc = r"}"]
 #[doc = r"```"]
 #[doc = r""]
+#[doc = r" [`available_names`]: enum.Locale.html#method.available_names"]
 #[doc = r" [`Format`]: trait.Format.html"]
+#[doc = r" [`from_name`]: enum.Locale.html#method.from_name"]
+#[doc = r" [`iter`]: enum.Locale.html#method.iter"]
+#[doc = r" [`negotiate`]: enum.Locale.html#method.negotiate"]
 #[doc = r" [Common Locale Data Repository (CLDR)]: https://en.wikipedia.org/wiki/Common_Locale_Data_Repository"]
 #[doc = r" [Unicode Consortium]: https://en.wikipedia.org/wiki/Unicode_Consortium"]
 #[allow(non_camel_case_types, missing_docs)]
@@ -1121,6 +12655,14 @@ impl Locale {
     #[doc = r" Constructs a [`Locale`] from its name. For a list of available names, see"]
     #[doc = r" [`available_names`]."]
     #[doc = r""]
+    #[doc = r" Besides the available names themselves, accepts BCP 47 language tags (e.g. `en-US`,"]
+    #[doc = r" `zh-Hant-TW` or `pt-br`) and POSIX locale names (e.g. `en_US.UTF-8` or"]
+    #[doc = r" `sr_RS@latin`), matched case-insensitively. A tag without an exact match falls back"]
+    #[doc = r" to its closest available parent, as CLDR does (e.g. `en-US` to `en`, `zh-TW` to"]
+    #[doc = r" `zh-Hant` and `de-DE-u-nu-latn` to `de`). To pick the best match from a list of"]
+    #[doc = r" tags in order of preference, see [`negotiate`]. Unicode extension keywords (e.g."]
+    #[doc = r" `-u-nu-arab`) are ignored here; to honour them, see [`TaggedLocale`]."]
+    #[doc = r""]
     #[doc = r" # Errors"]
     #[doc = r""]
     #[doc = r" Returns an error if the name provided cannot be"]
@@ -1128,5321 +12670,1963 @@ impl Locale {
     #[doc = r""]
     #[doc = r" [`available_names`]: enum.Locale.html#method.available_names"]
     #[doc = r" [`Locale`]: enum.Locale.html"]
+    #[doc = r" [`negotiate`]: enum.Locale.html#method.negotiate"]
+    #[doc = r" [`TaggedLocale`]: struct.TaggedLocale.html"]
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     pub fn from_name<S>(name: S) -> Result<Locale, Error>
     where
         S: AsRef<str>,
     {
         let name = name.as_ref();
-        name.parse::<Locale>()
-    }
-    #[doc = r" Returns a static slice of all available names."]
-    pub fn available_names() -> &'static [&'static str] {
-        &AVAILABLE_NAMES
-    }
-    #[doc = r" Returns the locale's decimal representation."]
-    pub fn decimal(&self) -> &'static str {
-        use self::Locale::*;
-        match self {
-            af => ",",
-            af_NA => ",",
-            agq => ",",
-            ak => ".",
-            am => ".",
-            ar => "٫",
-            ar_AE => "٫",
-            ar_BH => "٫",
-            ar_DJ => "٫",
-            ar_DZ => ",",
-            ar_EG => "٫",
-            ar_EH => ".",
-            ar_ER => "٫",
-            ar_IL => "٫",
-            ar_IQ => "٫",
-            ar_JO => "٫",
-            ar_KM => "٫",
-            ar_KW => "٫",
-            ar_LB => "٫",
-            ar_LY => ",",
-            ar_MA => ",",
-            ar_MR => "٫",
-            ar_OM => "٫",
-            ar_PS => "٫",
-            ar_QA => "٫",
-            ar_SA => "٫",
-            ar_SD => "٫",
-            ar_SO => "٫",
-            ar_SS => "٫",
-            ar_SY => "٫",
-            ar_TD => "٫",
-            ar_TN => ",",
-            ar_YE => "٫",
-            as_ => ".",
-            asa => ".",
-            ast => ",",
-            az => ",",
-            az_Cyrl => ",",
-            az_Latn => ",",
-            bas => ",",
-            be => ",",
-            bem => ".",
-            bez => ".",
-            bg => ",",
-            bm => ".",
-            bn => ".",
-            bn_IN => ".",
-            bo => ".",
-            bo_IN => ".",
-            br => ",",
-            brx => ".",
-            bs => ",",
-            bs_Cyrl => ",",
-            bs_Latn => ",",
-            ca => ",",
-            ca_AD => ",",
-            ca_ES_VALENCIA => ",",
-            ca_FR => ",",
-            ca_IT => ",",
-            ccp => ".",
-            ccp_IN => ".",
-            ce => ".",
-            ceb => ".",
-            cgg => ".",
-            chr => ".",
-            ckb => "٫",
-            ckb_IR => "٫",
-            cs => ",",
-            cu => ".",
-            cy => ".",
-            da => ",",
-            da_GL => ",",
-            dav => ".",
-            de => ",",
-            de_AT => ",",
-            de_BE => ",",
-            de_CH => ".",
-            de_IT => ",",
-            de_LI => ".",
-            de_LU => ",",
-            dje => ".",
-            dsb => ",",
-            dua => ",",
-            dyo => ",",
-            dz => ".",
-            ebu => ".",
-            ee => ".",
-            ee_TG => ".",
-            el => ",",
-            el_CY => ",",
-            en => ".",
-            en_001 => ".",
-            en_150 => ".",
-            en_AE => ".",
-            en_AG => ".",
-            en_AI => ".",
-            en_AS => ".",
-            en_AT => ",",
-            en_AU => ".",
-            en_BB => ".",
-            en_BE => ",",
-            en_BI => ".",
-            en_BM => ".",
-            en_BS => ".",
-            en_BW => ".",
-            en_BZ => ".",
-            en_CA => ".",
-            en_CC => ".",
-            en_CH => ".",
-            en_CK => ".",
-            en_CM => ".",
-            en_CX => ".",
-            en_CY => ".",
-            en_DE => ",",
-            en_DG => ".",
-            en_DK => ",",
-            en_DM => ".",
-            en_ER => ".",
-            en_FI => ",",
-            en_FJ => ".",
-            en_FK => ".",
-            en_FM => ".",
-            en_GB => ".",
-            en_GD => ".",
-            en_GG => ".",
-            en_GH => ".",
-            en_GI => ".",
-            en_GM => ".",
-            en_GU => ".",
-            en_GY => ".",
-            en_HK => ".",
-            en_IE => ".",
-            en_IL => ".",
-            en_IM => ".",
-            en_IN => ".",
-            en_IO => ".",
-            en_JE => ".",
-            en_JM => ".",
-            en_KE => ".",
-            en_KI => ".",
-            en_KN => ".",
-            en_KY => ".",
-            en_LC => ".",
-            en_LR => ".",
-            en_LS => ".",
-            en_MG => ".",
-            en_MH => ".",
-            en_MO => ".",
-            en_MP => ".",
-            en_MS => ".",
-            en_MT => ".",
-            en_MU => ".",
-            en_MW => ".",
-            en_MY => ".",
-            en_NA => ".",
-            en_NF => ".",
-            en_NG => ".",
-            en_NL => ",",
-            en_NR => ".",
-            en_NU => ".",
-            en_NZ => ".",
-            en_PG => ".",
-            en_PH => ".",
-            en_PK => ".",
-            en_PN => ".",
-            en_PR => ".",
-            en_PW => ".",
-            en_RW => ".",
-            en_SB => ".",
-            en_SC => ".",
-            en_SD => ".",
-            en_SE => ",",
-            en_SG => ".",
-            en_SH => ".",
-            en_SI => ",",
-            en_SL => ".",
-            en_SS => ".",
-            en_SX => ".",
-            en_SZ => ".",
-            en_TC => ".",
-            en_TK => ".",
-            en_TO => ".",
-            en_TT => ".",
-            en_TV => ".",
-            en_TZ => ".",
-            en_UG => ".",
-            en_UM => ".",
-            en_US_POSIX => ".",
-            en_VC => ".",
-            en_VG => ".",
-            en_VI => ".",
-            en_VU => ".",
-            en_WS => ".",
-            en_ZA => ",",
-            en_ZM => ".",
-            en_ZW => ".",
-            eo => ",",
-            es => ",",
-            es_419 => ".",
-            es_AR => ",",
-            es_BO => ",",
-            es_BR => ".",
-            es_BZ => ".",
-            es_CL => ",",
-            es_CO => ",",
-            es_CR => ",",
-            es_CU => ".",
-            es_DO => ".",
-            es_EA => ",",
-            es_EC => ",",
-            es_GQ => ",",
-            es_GT => ".",
-            es_HN => ".",
-            es_IC => ",",
-            es_MX => ".",
-            es_NI => ".",
-            es_PA => ".",
-            es_PE => ".",
-            es_PH => ",",
-            es_PR => ".",
-            es_PY => ",",
-            es_SV => ".",
-            es_US => ".",
-            es_UY => ",",
-            es_VE => ",",
-            et => ",",
-            eu => ",",
-            ewo => ",",
-            fa => "٫",
-            fa_AF => "٫",
-            ff => ",",
-            ff_Latn => ",",
-            ff_Latn_BF => ",",
-            ff_Latn_CM => ",",
-            ff_Latn_GH => ",",
-            ff_Latn_GM => ",",
-            ff_Latn_GN => ",",
-            ff_Latn_GW => ",",
-            ff_Latn_LR => ",",
-            ff_Latn_MR => ",",
-            ff_Latn_NE => ",",
-            ff_Latn_NG => ",",
-            ff_Latn_SL => ",",
-            fi => ",",
-            fil => ".",
-            fo => ",",
-            fo_DK => ",",
-            fr => ",",
-            fr_BE => ",",
-            fr_BF => ",",
-            fr_BI => ",",
-            fr_BJ => ",",
-            fr_BL => ",",
-            fr_CA => ",",
-            fr_CD => ",",
-            fr_CF => ",",
-            fr_CG => ",",
-            fr_CH => ",",
-            fr_CI => ",",
-            fr_CM => ",",
-            fr_DJ => ",",
-            fr_DZ => ",",
-            fr_GA => ",",
-            fr_GF => ",",
-            fr_GN => ",",
-            fr_GP => ",",
-            fr_GQ => ",",
-            fr_HT => ",",
-            fr_KM => ",",
-            fr_LU => ",",
-            fr_MA => ",",
-            fr_MC => ",",
-            fr_MF => ",",
-            fr_MG => ",",
-            fr_ML => ",",
-            fr_MQ => ",",
-            fr_MR => ",",
-            fr_MU => ",",
-            fr_NC => ",",
-            fr_NE => ",",
-            fr_PF => ",",
-            fr_PM => ",",
-            fr_RE => ",",
-            fr_RW => ",",
-            fr_SC => ",",
-            fr_SN => ",",
-            fr_SY => ",",
-            fr_TD => ",",
-            fr_TG => ",",
-            fr_TN => ",",
-            fr_VU => ",",
-            fr_WF => ",",
-            fr_YT => ",",
-            fur => ",",
-            fy => ",",
-            ga => ".",
-            ga_GB => ".",
-            gd => ".",
-            gl => ",",
-            gsw => ".",
-            gsw_FR => ".",
-            gsw_LI => ".",
-            gu => ".",
-            guz => ".",
-            gv => ".",
-            ha => ".",
-            ha_GH => ".",
-            ha_NE => ".",
-            haw => ".",
-            he => ".",
-            hi => ".",
-            hr => ",",
-            hr_BA => ",",
-            hsb => ",",
-            hu => ",",
-            hy => ",",
-            ia => ",",
-            id => ",",
-            ig => ".",
-            ii => ".",
-            is => ",",
-            it => ",",
-            it_CH => ".",
-            it_SM => ",",
-            it_VA => ",",
-            ja => ".",
-            jgo => ",",
-            jmc => ".",
-            jv => ",",
-            ka => ",",
-            kab => ",",
-            kam => ".",
-            kde => ".",
-            kea => ",",
-            khq => ".",
-            ki => ".",
-            kk => ",",
-            kkj => ",",
-            kl => ",",
-            kln => ".",
-            km => ",",
-            kn => ".",
-            ko => ".",
-            ko_KP => ".",
-            kok => ".",
-            ks => "٫",
-            ksb => ".",
-            ksf => ",",
-            ksh => ",",
-            ku => ",",
-            kw => ".",
-            ky => ",",
-            lag => ".",
-            lb => ",",
-            lg => ".",
-            lkt => ".",
-            ln => ",",
-            ln_AO => ",",
-            ln_CF => ",",
-            ln_CG => ",",
-            lo => ",",
-            lrc => "٫",
-            lrc_IQ => "٫",
-            lt => ",",
-            lu => ",",
-            luo => ".",
-            luy => ".",
-            lv => ",",
-            mas => ".",
-            mas_TZ => ".",
-            mer => ".",
-            mfe => ".",
-            mg => ".",
-            mgh => ",",
-            mgo => ".",
-            mi => ".",
-            mk => ",",
-            ml => ".",
-            mn => ".",
-            mr => ".",
-            ms => ".",
-            ms_BN => ",",
-            ms_SG => ".",
-            mt => ".",
-            mua => ",",
-            my => ".",
-            mzn => "٫",
-            naq => ".",
-            nb => ",",
-            nb_SJ => ",",
-            nd => ".",
-            nds => ".",
-            nds_NL => ".",
-            ne => ".",
-            ne_IN => ".",
-            nl => ",",
-            nl_AW => ",",
-            nl_BE => ",",
-            nl_BQ => ",",
-            nl_CW => ",",
-            nl_SR => ",",
-            nl_SX => ",",
-            nmg => ",",
-            nn => ",",
-            nnh => ",",
-            nus => ".",
-            nyn => ".",
-            om => ".",
-            om_KE => ".",
-            or => ".",
-            os => ",",
-            os_RU => ",",
-            pa => ".",
-            pa_Arab => "٫",
-            pa_Guru => ".",
-            pl => ",",
-            prg => ".",
-            ps => "٫",
-            ps_PK => "٫",
-            pt => ",",
-            pt_AO => ",",
-            pt_CH => ",",
-            pt_CV => ",",
-            pt_GQ => ",",
-            pt_GW => ",",
-            pt_LU => ",",
-            pt_MO => ",",
-            pt_MZ => ",",
-            pt_PT => ",",
-            pt_ST => ",",
-            pt_TL => ",",
-            qu => ".",
-            qu_BO => ",",
-            qu_EC => ".",
-            rm => ".",
-            rn => ",",
-            ro => ",",
-            ro_MD => ",",
-            rof => ".",
-            root => ".",
-            ru => ",",
-            ru_BY => ",",
-            ru_KG => ",",
-            ru_KZ => ",",
-            ru_MD => ",",
-            ru_UA => ",",
-            rw => ",",
-            rwk => ".",
-            sah => ",",
-            saq => ".",
-            sbp => ".",
-            sd => "٫",
-            se => ",",
-            se_FI => ",",
-            se_SE => ",",
-            seh => ",",
-            ses => ".",
-            sg => ",",
-            shi => ",",
-            shi_Latn => ",",
-            shi_Tfng => ",",
-            si => ".",
-            sk => ",",
-            sl => ",",
-            smn => ",",
-            sn => ".",
-            so => ".",
-            so_DJ => ".",
-            so_ET => ".",
-            so_KE => ".",
-            sq => ",",
-            sq_MK => ",",
-            sq_XK => ",",
-            sr => ",",
-            sr_Cyrl => ",",
-            sr_Cyrl_BA => ",",
-            sr_Cyrl_ME => ",",
-            sr_Cyrl_XK => ",",
-            sr_Latn => ",",
-            sr_Latn_BA => ",",
-            sr_Latn_ME => ",",
-            sr_Latn_XK => ",",
-            sv => ",",
-            sv_AX => ",",
-            sv_FI => ",",
-            sw => ".",
-            sw_CD => ",",
-            sw_KE => ".",
-            sw_UG => ".",
-            ta => ".",
-            ta_LK => ".",
-            ta_MY => ".",
-            ta_SG => ".",
-            te => ".",
-            teo => ".",
-            teo_KE => ".",
-            tg => ",",
-            th => ".",
-            ti => ".",
-            ti_ER => ".",
-            tk => ",",
-            to => ".",
-            tr => ",",
-            tr_CY => ",",
-            tt => ",",
-            twq => ".",
-            tzm => ",",
-            ug => ".",
-            uk => ",",
-            ur => ".",
-            ur_IN => "٫",
-            uz => ",",
-            uz_Arab => "٫",
-            uz_Cyrl => ",",
-            uz_Latn => ",",
-            vai => ".",
-            vai_Latn => ".",
-            vai_Vaii => ".",
-            vi => ",",
-            vo => ".",
-            vun => ".",
-            wae => ",",
-            wo => ",",
-            xh => ".",
-            xog => ".",
-            yav => ",",
-            yi => ".",
-            yo => ".",
-            yo_BJ => ".",
-            yue => ".",
-            yue_Hans => ".",
-            yue_Hant => ".",
-            zgh => ",",
-            zh => ".",
-            zh_Hans => ".",
-            zh_Hans_HK => ".",
-            zh_Hans_MO => ".",
-            zh_Hans_SG => ".",
-            zh_Hant => ".",
-            zh_Hant_HK => ".",
-            zh_Hant_MO => ".",
-            zu => ".",
-        }
-    }
-    #[doc = r" Returns the locale's [`Grouping`]."]
-    #[doc = r""]
-    #[doc = r" [`Grouping`]: enum.Grouping.html"]
-    pub fn grouping(&self) -> Grouping {
-        use self::Grouping::*;
-        use self::Locale::*;
-        match self {
-            af => Standard,
-            af_NA => Standard,
-            agq => Standard,
-            ak => Standard,
-            am => Standard,
-            ar => Standard,
-            ar_AE => Standard,
-            ar_BH => Standard,
-            ar_DJ => Standard,
-            ar_DZ => Standard,
-            ar_EG => Standard,
-            ar_EH => Standard,
-            ar_ER => Standard,
-            ar_IL => Standard,
-            ar_IQ => Standard,
-            ar_JO => Standard,
-            ar_KM => Standard,
-            ar_KW => Standard,
-            ar_LB => Standard,
-            ar_LY => Standard,
-            ar_MA => Standard,
-            ar_MR => Standard,
-            ar_OM => Standard,
-            ar_PS => Standard,
-            ar_QA => Standard,
-            ar_SA => Standard,
-            ar_SD => Standard,
-            ar_SO => Standard,
-            ar_SS => Standard,
-            ar_SY => Standard,
-            ar_TD => Standard,
-            ar_TN => Standard,
-            ar_YE => Standard,
-            as_ => Indian,
-            asa => Standard,
-            ast => Standard,
-            az => Standard,
-            az_Cyrl => Standard,
-            az_Latn => Standard,
-            bas => Standard,
-            be => Standard,
-            bem => Standard,
-            bez => Standard,
-            bg => Standard,
-            bm => Standard,
-            bn => Indian,
-            bn_IN => Indian,
-            bo => Standard,
-            bo_IN => Standard,
-            br => Standard,
-            brx => Indian,
-            bs => Standard,
-            bs_Cyrl => Standard,
-            bs_Latn => Standard,
-            ca => Standard,
-            ca_AD => Standard,
-            ca_ES_VALENCIA => Standard,
-            ca_FR => Standard,
-            ca_IT => Standard,
-            ccp => Indian,
-            ccp_IN => Indian,
-            ce => Standard,
-            ceb => Standard,
-            cgg => Standard,
-            chr => Standard,
-            ckb => Standard,
-            ckb_IR => Standard,
-            cs => Standard,
-            cu => Standard,
-            cy => Standard,
-            da => Standard,
-            da_GL => Standard,
-            dav => Standard,
-            de => Standard,
-            de_AT => Standard,
-            de_BE => Standard,
-            de_CH => Standard,
-            de_IT => Standard,
-            de_LI => Standard,
-            de_LU => Standard,
-            dje => Standard,
-            dsb => Standard,
-            dua => Standard,
-            dyo => Standard,
-            dz => Indian,
-            ebu => Standard,
-            ee => Standard,
-            ee_TG => Standard,
-            el => Standard,
-            el_CY => Standard,
-            en => Standard,
-            en_001 => Standard,
-            en_150 => Standard,
-            en_AE => Standard,
-            en_AG => Standard,
-            en_AI => Standard,
-            en_AS => Standard,
-            en_AT => Standard,
-            en_AU => Standard,
-            en_BB => Standard,
-            en_BE => Standard,
-            en_BI => Standard,
-            en_BM => Standard,
-            en_BS => Standard,
-            en_BW => Standard,
-            en_BZ => Standard,
-            en_CA => Standard,
-            en_CC => Standard,
-            en_CH => Standard,
-            en_CK => Standard,
-            en_CM => Standard,
-            en_CX => Standard,
-            en_CY => Standard,
-            en_DE => Standard,
-            en_DG => Standard,
-            en_DK => Standard,
-            en_DM => Standard,
-            en_ER => Standard,
-            en_FI => Standard,
-            en_FJ => Standard,
-            en_FK => Standard,
-            en_FM => Standard,
-            en_GB => Standard,
-            en_GD => Standard,
-            en_GG => Standard,
-            en_GH => Standard,
-            en_GI => Standard,
-            en_GM => Standard,
-            en_GU => Standard,
-            en_GY => Standard,
-            en_HK => Standard,
-            en_IE => Standard,
-            en_IL => Standard,
-            en_IM => Standard,
-            en_IN => Indian,
-            en_IO => Standard,
-            en_JE => Standard,
-            en_JM => Standard,
-            en_KE => Standard,
-            en_KI => Standard,
-            en_KN => Standard,
-            en_KY => Standard,
-            en_LC => Standard,
-            en_LR => Standard,
-            en_LS => Standard,
-            en_MG => Standard,
-            en_MH => Standard,
-            en_MO => Standard,
-            en_MP => Standard,
-            en_MS => Standard,
-            en_MT => Standard,
-            en_MU => Standard,
-            en_MW => Standard,
-            en_MY => Standard,
-            en_NA => Standard,
-            en_NF => Standard,
-            en_NG => Standard,
-            en_NL => Standard,
-            en_NR => Standard,
-            en_NU => Standard,
-            en_NZ => Standard,
-            en_PG => Standard,
-            en_PH => Standard,
-            en_PK => Standard,
-            en_PN => Standard,
-            en_PR => Standard,
-            en_PW => Standard,
-            en_RW => Standard,
-            en_SB => Standard,
-            en_SC => Standard,
-            en_SD => Standard,
-            en_SE => Standard,
-            en_SG => Standard,
-            en_SH => Standard,
-            en_SI => Standard,
-            en_SL => Standard,
-            en_SS => Standard,
-            en_SX => Standard,
-            en_SZ => Standard,
-            en_TC => Standard,
-            en_TK => Standard,
-            en_TO => Standard,
-            en_TT => Standard,
-            en_TV => Standard,
-            en_TZ => Standard,
-            en_UG => Standard,
-            en_UM => Standard,
-            en_US_POSIX => Posix,
-            en_VC => Standard,
-            en_VG => Standard,
-            en_VI => Standard,
-            en_VU => Standard,
-            en_WS => Standard,
-            en_ZA => Standard,
-            en_ZM => Standard,
-            en_ZW => Standard,
-            eo => Standard,
-            es => Standard,
-            es_419 => Standard,
-            es_AR => Standard,
-            es_BO => Standard,
-            es_BR => Standard,
-            es_BZ => Standard,
-            es_CL => Standard,
-            es_CO => Standard,
-            es_CR => Standard,
-            es_CU => Standard,
-            es_DO => Standard,
-            es_EA => Standard,
-            es_EC => Standard,
-            es_GQ => Standard,
-            es_GT => Standard,
-            es_HN => Standard,
-            es_IC => Standard,
-            es_MX => Standard,
-            es_NI => Standard,
-            es_PA => Standard,
-            es_PE => Standard,
-            es_PH => Standard,
-            es_PR => Standard,
-            es_PY => Standard,
-            es_SV => Standard,
-            es_US => Standard,
-            es_UY => Standard,
-            es_VE => Standard,
-            et => Standard,
-            eu => Standard,
-            ewo => Standard,
-            fa => Standard,
-            fa_AF => Standard,
-            ff => Standard,
-            ff_Latn => Standard,
-            ff_Latn_BF => Standard,
-            ff_Latn_CM => Standard,
-            ff_Latn_GH => Standard,
-            ff_Latn_GM => Standard,
-            ff_Latn_GN => Standard,
-            ff_Latn_GW => Standard,
-            ff_Latn_LR => Standard,
-            ff_Latn_MR => Standard,
-            ff_Latn_NE => Standard,
-            ff_Latn_NG => Standard,
-            ff_Latn_SL => Standard,
-            fi => Standard,
-            fil => Standard,
-            fo => Standard,
-            fo_DK => Standard,
-            fr => Standard,
-            fr_BE => Standard,
-            fr_BF => Standard,
-            fr_BI => Standard,
-            fr_BJ => Standard,
-            fr_BL => Standard,
-            fr_CA => Standard,
-            fr_CD => Standard,
-            fr_CF => Standard,
-            fr_CG => Standard,
-            fr_CH => Standard,
-            fr_CI => Standard,
-            fr_CM => Standard,
-            fr_DJ => Standard,
-            fr_DZ => Standard,
-            fr_GA => Standard,
-            fr_GF => Standard,
-            fr_GN => Standard,
-            fr_GP => Standard,
-            fr_GQ => Standard,
-            fr_HT => Standard,
-            fr_KM => Standard,
-            fr_LU => Standard,
-            fr_MA => Standard,
-            fr_MC => Standard,
-            fr_MF => Standard,
-            fr_MG => Standard,
-            fr_ML => Standard,
-            fr_MQ => Standard,
-            fr_MR => Standard,
-            fr_MU => Standard,
-            fr_NC => Standard,
-            fr_NE => Standard,
-            fr_PF => Standard,
-            fr_PM => Standard,
-            fr_RE => Standard,
-            fr_RW => Standard,
-            fr_SC => Standard,
-            fr_SN => Standard,
-            fr_SY => Standard,
-            fr_TD => Standard,
-            fr_TG => Standard,
-            fr_TN => Standard,
-            fr_VU => Standard,
-            fr_WF => Standard,
-            fr_YT => Standard,
-            fur => Standard,
-            fy => Standard,
-            ga => Standard,
-            ga_GB => Standard,
-            gd => Standard,
-            gl => Standard,
-            gsw => Standard,
-            gsw_FR => Standard,
-            gsw_LI => Standard,
-            gu => Indian,
-            guz => Standard,
-            gv => Standard,
-            ha => Standard,
-            ha_GH => Standard,
-            ha_NE => Standard,
-            haw => Standard,
-            he => Standard,
-            hi => Indian,
-            hr => Standard,
-            hr_BA => Standard,
-            hsb => Standard,
-            hu => Standard,
-            hy => Standard,
-            ia => Standard,
-            id => Standard,
-            ig => Standard,
-            ii => Standard,
-            is => Standard,
-            it => Standard,
-            it_CH => Standard,
-            it_SM => Standard,
-            it_VA => Standard,
-            ja => Standard,
-            jgo => Standard,
-            jmc => Standard,
-            jv => Standard,
-            ka => Standard,
-            kab => Standard,
-            kam => Standard,
-            kde => Standard,
-            kea => Standard,
-            khq => Standard,
-            ki => Standard,
-            kk => Standard,
-            kkj => Standard,
-            kl => Standard,
-            kln => Standard,
-            km => Standard,
-            kn => Standard,
-            ko => Standard,
-            ko_KP => Standard,
-            kok => Standard,
-            ks => Indian,
-            ksb => Standard,
-            ksf => Standard,
-            ksh => Standard,
-            ku => Standard,
-            kw => Standard,
-            ky => Standard,
-            lag => Standard,
-            lb => Standard,
-            lg => Standard,
-            lkt => Standard,
-            ln => Standard,
-            ln_AO => Standard,
-            ln_CF => Standard,
-            ln_CG => Standard,
-            lo => Standard,
-            lrc => Standard,
-            lrc_IQ => Standard,
-            lt => Standard,
-            lu => Standard,
-            luo => Standard,
-            luy => Standard,
-            lv => Standard,
-            mas => Standard,
-            mas_TZ => Standard,
-            mer => Standard,
-            mfe => Standard,
-            mg => Standard,
-            mgh => Standard,
-            mgo => Standard,
-            mi => Standard,
-            mk => Standard,
-            ml => Indian,
-            mn => Standard,
-            mr => Indian,
-            ms => Standard,
-            ms_BN => Standard,
-            ms_SG => Standard,
-            mt => Standard,
-            mua => Standard,
-            my => Standard,
-            mzn => Standard,
-            naq => Standard,
-            nb => Standard,
-            nb_SJ => Standard,
-            nd => Standard,
-            nds => Standard,
-            nds_NL => Standard,
-            ne => Indian,
-            ne_IN => Indian,
-            nl => Standard,
-            nl_AW => Standard,
-            nl_BE => Standard,
-            nl_BQ => Standard,
-            nl_CW => Standard,
-            nl_SR => Standard,
-            nl_SX => Standard,
-            nmg => Standard,
-            nn => Standard,
-            nnh => Standard,
-            nus => Standard,
-            nyn => Standard,
-            om => Standard,
-            om_KE => Standard,
-            or => Indian,
-            os => Standard,
-            os_RU => Standard,
-            pa => Indian,
-            pa_Arab => Standard,
-            pa_Guru => Indian,
-            pl => Standard,
-            prg => Standard,
-            ps => Standard,
-            ps_PK => Standard,
-            pt => Standard,
-            pt_AO => Standard,
-            pt_CH => Standard,
-            pt_CV => Standard,
-            pt_GQ => Standard,
-            pt_GW => Standard,
-            pt_LU => Standard,
-            pt_MO => Standard,
-            pt_MZ => Standard,
-            pt_PT => Standard,
-            pt_ST => Standard,
-            pt_TL => Standard,
-            qu => Standard,
-            qu_BO => Standard,
-            qu_EC => Standard,
-            rm => Standard,
-            rn => Standard,
-            ro => Standard,
-            ro_MD => Standard,
-            rof => Standard,
-            root => Standard,
-            ru => Standard,
-            ru_BY => Standard,
-            ru_KG => Standard,
-            ru_KZ => Standard,
-            ru_MD => Standard,
-            ru_UA => Standard,
-            rw => Standard,
-            rwk => Standard,
-            sah => Standard,
-            saq => Standard,
-            sbp => Standard,
-            sd => Standard,
-            se => Standard,
-            se_FI => Standard,
-            se_SE => Standard,
-            seh => Standard,
-            ses => Standard,
-            sg => Standard,
-            shi => Standard,
-            shi_Latn => Standard,
-            shi_Tfng => Standard,
-            si => Standard,
-            sk => Standard,
-            sl => Standard,
-            smn => Standard,
-            sn => Standard,
-            so => Standard,
-            so_DJ => Standard,
-            so_ET => Standard,
-            so_KE => Standard,
-            sq => Standard,
-            sq_MK => Standard,
-            sq_XK => Standard,
-            sr => Standard,
-            sr_Cyrl => Standard,
-            sr_Cyrl_BA => Standard,
-            sr_Cyrl_ME => Standard,
-            sr_Cyrl_XK => Standard,
-            sr_Latn => Standard,
-            sr_Latn_BA => Standard,
-            sr_Latn_ME => Standard,
-            sr_Latn_XK => Standard,
-            sv => Standard,
-            sv_AX => Standard,
-            sv_FI => Standard,
-            sw => Standard,
-            sw_CD => Standard,
-            sw_KE => Standard,
-            sw_UG => Standard,
-            ta => Indian,
-            ta_LK => Indian,
-            ta_MY => Standard,
-            ta_SG => Standard,
-            te => Indian,
-            teo => Standard,
-            teo_KE => Standard,
-            tg => Standard,
-            th => Standard,
-            ti => Standard,
-            ti_ER => Standard,
-            tk => Standard,
-            to => Standard,
-            tr => Standard,
-            tr_CY => Standard,
-            tt => Standard,
-            twq => Standard,
-            tzm => Standard,
-            ug => Standard,
-            uk => Standard,
-            ur => Standard,
-            ur_IN => Standard,
-            uz => Standard,
-            uz_Arab => Standard,
-            uz_Cyrl => Standard,
-            uz_Latn => Standard,
-            vai => Standard,
-            vai_Latn => Standard,
-            vai_Vaii => Standard,
-            vi => Standard,
-            vo => Standard,
-            vun => Standard,
-            wae => Standard,
-            wo => Standard,
-            xh => Standard,
-            xog => Standard,
-            yav => Standard,
-            yi => Standard,
-            yo => Standard,
-            yo_BJ => Standard,
-            yue => Standard,
-            yue_Hans => Standard,
-            yue_Hant => Standard,
-            zgh => Standard,
-            zh => Standard,
-            zh_Hans => Standard,
-            zh_Hans_HK => Standard,
-            zh_Hans_MO => Standard,
-            zh_Hans_SG => Standard,
-            zh_Hant => Standard,
-            zh_Hant_HK => Standard,
-            zh_Hant_MO => Standard,
-            zu => Standard,
-        }
-    }
-    #[doc = r" Returns the locale's infinity representation."]
-    pub fn infinity(&self) -> &'static str {
-        use self::Locale::*;
-        match self {
-            af => "∞",
-            af_NA => "∞",
-            agq => "∞",
-            ak => "∞",
-            am => "∞",
-            ar => "∞",
-            ar_AE => "∞",
-            ar_BH => "∞",
-            ar_DJ => "∞",
-            ar_DZ => "∞",
-            ar_EG => "∞",
-            ar_EH => "∞",
-            ar_ER => "∞",
-            ar_IL => "∞",
-            ar_IQ => "∞",
-            ar_JO => "∞",
-            ar_KM => "∞",
-            ar_KW => "∞",
-            ar_LB => "∞",
-            ar_LY => "∞",
-            ar_MA => "∞",
-            ar_MR => "∞",
-            ar_OM => "∞",
-            ar_PS => "∞",
-            ar_QA => "∞",
-            ar_SA => "∞",
-            ar_SD => "∞",
-            ar_SO => "∞",
-            ar_SS => "∞",
-            ar_SY => "∞",
-            ar_TD => "∞",
-            ar_TN => "∞",
-            ar_YE => "∞",
-            as_ => "∞",
-            asa => "∞",
-            ast => "∞",
-            az => "∞",
-            az_Cyrl => "∞",
-            az_Latn => "∞",
-            bas => "∞",
-            be => "∞",
-            bem => "∞",
-            bez => "∞",
-            bg => "∞",
-            bm => "∞",
-            bn => "∞",
-            bn_IN => "∞",
-            bo => "∞",
-            bo_IN => "∞",
-            br => "∞",
-            brx => "∞",
-            bs => "∞",
-            bs_Cyrl => "∞",
-            bs_Latn => "∞",
-            ca => "∞",
-            ca_AD => "∞",
-            ca_ES_VALENCIA => "∞",
-            ca_FR => "∞",
-            ca_IT => "∞",
-            ccp => "∞",
-            ccp_IN => "∞",
-            ce => "∞",
-            ceb => "∞",
-            cgg => "∞",
-            chr => "∞",
-            ckb => "∞",
-            ckb_IR => "∞",
-            cs => "∞",
-            cu => "∞",
-            cy => "∞",
-            da => "∞",
-            da_GL => "∞",
-            dav => "∞",
-            de => "∞",
-            de_AT => "∞",
-            de_BE => "∞",
-            de_CH => "∞",
-            de_IT => "∞",
-            de_LI => "∞",
-            de_LU => "∞",
-            dje => "∞",
-            dsb => "∞",
-            dua => "∞",
-            dyo => "∞",
-            dz => "ག\u{fb2}ངས་མ\u{f7a}ད",
-            ebu => "∞",
-            ee => "∞",
-            ee_TG => "∞",
-            el => "∞",
-            el_CY => "∞",
-            en => "∞",
-            en_001 => "∞",
-            en_150 => "∞",
-            en_AE => "∞",
-            en_AG => "∞",
-            en_AI => "∞",
-            en_AS => "∞",
-            en_AT => "∞",
-            en_AU => "∞",
-            en_BB => "∞",
-            en_BE => "∞",
-            en_BI => "∞",
-            en_BM => "∞",
-            en_BS => "∞",
-            en_BW => "∞",
-            en_BZ => "∞",
-            en_CA => "∞",
-            en_CC => "∞",
-            en_CH => "∞",
-            en_CK => "∞",
-            en_CM => "∞",
-            en_CX => "∞",
-            en_CY => "∞",
-            en_DE => "∞",
-            en_DG => "∞",
-            en_DK => "∞",
-            en_DM => "∞",
-            en_ER => "∞",
-            en_FI => "∞",
-            en_FJ => "∞",
-            en_FK => "∞",
-            en_FM => "∞",
-            en_GB => "∞",
-            en_GD => "∞",
-            en_GG => "∞",
-            en_GH => "∞",
-            en_GI => "∞",
-            en_GM => "∞",
-            en_GU => "∞",
-            en_GY => "∞",
-            en_HK => "∞",
-            en_IE => "∞",
-            en_IL => "∞",
-            en_IM => "∞",
-            en_IN => "∞",
-            en_IO => "∞",
-            en_JE => "∞",
-            en_JM => "∞",
-            en_KE => "∞",
-            en_KI => "∞",
-            en_KN => "∞",
-            en_KY => "∞",
-            en_LC => "∞",
-            en_LR => "∞",
-            en_LS => "∞",
-            en_MG => "∞",
-            en_MH => "∞",
-            en_MO => "∞",
-            en_MP => "∞",
-            en_MS => "∞",
-            en_MT => "∞",
-            en_MU => "∞",
-            en_MW => "∞",
-            en_MY => "∞",
-            en_NA => "∞",
-            en_NF => "∞",
-            en_NG => "∞",
-            en_NL => "∞",
-            en_NR => "∞",
-            en_NU => "∞",
-            en_NZ => "∞",
-            en_PG => "∞",
-            en_PH => "∞",
-            en_PK => "∞",
-            en_PN => "∞",
-            en_PR => "∞",
-            en_PW => "∞",
-            en_RW => "∞",
-            en_SB => "∞",
-            en_SC => "∞",
-            en_SD => "∞",
-            en_SE => "∞",
-            en_SG => "∞",
-            en_SH => "∞",
-            en_SI => "∞",
-            en_SL => "∞",
-            en_SS => "∞",
-            en_SX => "∞",
-            en_SZ => "∞",
-            en_TC => "∞",
-            en_TK => "∞",
-            en_TO => "∞",
-            en_TT => "∞",
-            en_TV => "∞",
-            en_TZ => "∞",
-            en_UG => "∞",
-            en_UM => "∞",
-            en_US_POSIX => "INF",
-            en_VC => "∞",
-            en_VG => "∞",
-            en_VI => "∞",
-            en_VU => "∞",
-            en_WS => "∞",
-            en_ZA => "∞",
-            en_ZM => "∞",
-            en_ZW => "∞",
-            eo => "∞",
-            es => "∞",
-            es_419 => "∞",
-            es_AR => "∞",
-            es_BO => "∞",
-            es_BR => "∞",
-            es_BZ => "∞",
-            es_CL => "∞",
-            es_CO => "∞",
-            es_CR => "∞",
-            es_CU => "∞",
-            es_DO => "∞",
-            es_EA => "∞",
-            es_EC => "∞",
-            es_GQ => "∞",
-            es_GT => "∞",
-            es_HN => "∞",
-            es_IC => "∞",
-            es_MX => "∞",
-            es_NI => "∞",
-            es_PA => "∞",
-            es_PE => "∞",
-            es_PH => "∞",
-            es_PR => "∞",
-            es_PY => "∞",
-            es_SV => "∞",
-            es_US => "∞",
-            es_UY => "∞",
-            es_VE => "∞",
-            et => "∞",
-            eu => "∞",
-            ewo => "∞",
-            fa => "∞",
-            fa_AF => "∞",
-            ff => "∞",
-            ff_Latn => "∞",
-            ff_Latn_BF => "∞",
-            ff_Latn_CM => "∞",
-            ff_Latn_GH => "∞",
-            ff_Latn_GM => "∞",
-            ff_Latn_GN => "∞",
-            ff_Latn_GW => "∞",
-            ff_Latn_LR => "∞",
-            ff_Latn_MR => "∞",
-            ff_Latn_NE => "∞",
-            ff_Latn_NG => "∞",
-            ff_Latn_SL => "∞",
-            fi => "∞",
-            fil => "∞",
-            fo => "∞",
-            fo_DK => "∞",
-            fr => "∞",
-            fr_BE => "∞",
-            fr_BF => "∞",
-            fr_BI => "∞",
-            fr_BJ => "∞",
-            fr_BL => "∞",
-            fr_CA => "∞",
-            fr_CD => "∞",
-            fr_CF => "∞",
-            fr_CG => "∞",
-            fr_CH => "∞",
-            fr_CI => "∞",
-            fr_CM => "∞",
-            fr_DJ => "∞",
-            fr_DZ => "∞",
-            fr_GA => "∞",
-            fr_GF => "∞",
-            fr_GN => "∞",
-            fr_GP => "∞",
-            fr_GQ => "∞",
-            fr_HT => "∞",
-            fr_KM => "∞",
-            fr_LU => "∞",
-            fr_MA => "∞",
-            fr_MC => "∞",
-            fr_MF => "∞",
-            fr_MG => "∞",
-            fr_ML => "∞",
-            fr_MQ => "∞",
-            fr_MR => "∞",
-            fr_MU => "∞",
-            fr_NC => "∞",
-            fr_NE => "∞",
-            fr_PF => "∞",
-            fr_PM => "∞",
-            fr_RE => "∞",
-            fr_RW => "∞",
-            fr_SC => "∞",
-            fr_SN => "∞",
-            fr_SY => "∞",
-            fr_TD => "∞",
-            fr_TG => "∞",
-            fr_TN => "∞",
-            fr_VU => "∞",
-            fr_WF => "∞",
-            fr_YT => "∞",
-            fur => "∞",
-            fy => "∞",
-            ga => "∞",
-            ga_GB => "∞",
-            gd => "∞",
-            gl => "∞",
-            gsw => "∞",
-            gsw_FR => "∞",
-            gsw_LI => "∞",
-            gu => "∞",
-            guz => "∞",
-            gv => "∞",
-            ha => "∞",
-            ha_GH => "∞",
-            ha_NE => "∞",
-            haw => "∞",
-            he => "∞",
-            hi => "∞",
-            hr => "∞",
-            hr_BA => "∞",
-            hsb => "∞",
-            hu => "∞",
-            hy => "∞",
-            ia => "∞",
-            id => "∞",
-            ig => "∞",
-            ii => "∞",
-            is => "∞",
-            it => "∞",
-            it_CH => "∞",
-            it_SM => "∞",
-            it_VA => "∞",
-            ja => "∞",
-            jgo => "∞",
-            jmc => "∞",
-            jv => "∞",
-            ka => "∞",
-            kab => "∞",
-            kam => "∞",
-            kde => "∞",
-            kea => "∞",
-            khq => "∞",
-            ki => "∞",
-            kk => "∞",
-            kkj => "∞",
-            kl => "∞",
-            kln => "∞",
-            km => "∞",
-            kn => "∞",
-            ko => "∞",
-            ko_KP => "∞",
-            kok => "∞",
-            ks => "∞",
-            ksb => "∞",
-            ksf => "∞",
-            ksh => "∞",
-            ku => "∞",
-            kw => "∞",
-            ky => "∞",
-            lag => "∞",
-            lb => "∞",
-            lg => "∞",
-            lkt => "∞",
-            ln => "∞",
-            ln_AO => "∞",
-            ln_CF => "∞",
-            ln_CG => "∞",
-            lo => "∞",
-            lrc => "∞",
-            lrc_IQ => "∞",
-            lt => "∞",
-            lu => "∞",
-            luo => "∞",
-            luy => "∞",
-            lv => "∞",
-            mas => "∞",
-            mas_TZ => "∞",
-            mer => "∞",
-            mfe => "∞",
-            mg => "∞",
-            mgh => "∞",
-            mgo => "∞",
-            mi => "∞",
-            mk => "∞",
-            ml => "∞",
-            mn => "∞",
-            mr => "∞",
-            ms => "∞",
-            ms_BN => "∞",
-            ms_SG => "∞",
-            mt => "∞",
-            mua => "∞",
-            my => "∞",
-            mzn => "∞",
-            naq => "∞",
-            nb => "∞",
-            nb_SJ => "∞",
-            nd => "∞",
-            nds => "∞",
-            nds_NL => "∞",
-            ne => "∞",
-            ne_IN => "∞",
-            nl => "∞",
-            nl_AW => "∞",
-            nl_BE => "∞",
-            nl_BQ => "∞",
-            nl_CW => "∞",
-            nl_SR => "∞",
-            nl_SX => "∞",
-            nmg => "∞",
-            nn => "∞",
-            nnh => "∞",
-            nus => "∞",
-            nyn => "∞",
-            om => "∞",
-            om_KE => "∞",
-            or => "∞",
-            os => "∞",
-            os_RU => "∞",
-            pa => "∞",
-            pa_Arab => "∞",
-            pa_Guru => "∞",
-            pl => "∞",
-            prg => "∞",
-            ps => "∞",
-            ps_PK => "∞",
-            pt => "∞",
-            pt_AO => "∞",
-            pt_CH => "∞",
-            pt_CV => "∞",
-            pt_GQ => "∞",
-            pt_GW => "∞",
-            pt_LU => "∞",
-            pt_MO => "∞",
-            pt_MZ => "∞",
-            pt_PT => "∞",
-            pt_ST => "∞",
-            pt_TL => "∞",
-            qu => "∞",
-            qu_BO => "∞",
-            qu_EC => "∞",
-            rm => "∞",
-            rn => "∞",
-            ro => "∞",
-            ro_MD => "∞",
-            rof => "∞",
-            root => "∞",
-            ru => "∞",
-            ru_BY => "∞",
-            ru_KG => "∞",
-            ru_KZ => "∞",
-            ru_MD => "∞",
-            ru_UA => "∞",
-            rw => "∞",
-            rwk => "∞",
-            sah => "∞",
-            saq => "∞",
-            sbp => "∞",
-            sd => "∞",
-            se => "∞",
-            se_FI => "∞",
-            se_SE => "∞",
-            seh => "∞",
-            ses => "∞",
-            sg => "∞",
-            shi => "∞",
-            shi_Latn => "∞",
-            shi_Tfng => "∞",
-            si => "∞",
-            sk => "∞",
-            sl => "∞",
-            smn => "∞",
-            sn => "∞",
-            so => "∞",
-            so_DJ => "∞",
-            so_ET => "∞",
-            so_KE => "∞",
-            sq => "∞",
-            sq_MK => "∞",
-            sq_XK => "∞",
-            sr => "∞",
-            sr_Cyrl => "∞",
-            sr_Cyrl_BA => "∞",
-            sr_Cyrl_ME => "∞",
-            sr_Cyrl_XK => "∞",
-            sr_Latn => "∞",
-            sr_Latn_BA => "∞",
-            sr_Latn_ME => "∞",
-            sr_Latn_XK => "∞",
-            sv => "∞",
-            sv_AX => "∞",
-            sv_FI => "∞",
-            sw => "∞",
-            sw_CD => "∞",
-            sw_KE => "∞",
-            sw_UG => "∞",
-            ta => "∞",
-            ta_LK => "∞",
-            ta_MY => "∞",
-            ta_SG => "∞",
-            te => "∞",
-            teo => "∞",
-            teo_KE => "∞",
-            tg => "∞",
-            th => "∞",
-            ti => "∞",
-            ti_ER => "∞",
-            tk => "∞",
-            to => "∞",
-            tr => "∞",
-            tr_CY => "∞",
-            tt => "∞",
-            twq => "∞",
-            tzm => "∞",
-            ug => "∞",
-            uk => "∞",
-            ur => "∞",
-            ur_IN => "∞",
-            uz => "∞",
-            uz_Arab => "∞",
-            uz_Cyrl => "∞",
-            uz_Latn => "∞",
-            vai => "∞",
-            vai_Latn => "∞",
-            vai_Vaii => "∞",
-            vi => "∞",
-            vo => "∞",
-            vun => "∞",
-            wae => "∞",
-            wo => "∞",
-            xh => "∞",
-            xog => "∞",
-            yav => "∞",
-            yi => "∞",
-            yo => "∞",
-            yo_BJ => "∞",
-            yue => "∞",
-            yue_Hans => "∞",
-            yue_Hant => "∞",
-            zgh => "∞",
-            zh => "∞",
-            zh_Hans => "∞",
-            zh_Hans_HK => "∞",
-            zh_Hans_MO => "∞",
-            zh_Hans_SG => "∞",
-            zh_Hant => "∞",
-            zh_Hant_HK => "∞",
-            zh_Hant_MO => "∞",
-            zu => "∞",
-        }
-    }
-    #[doc = r" Returns the locale's minus sign representation."]
-    pub fn minus_sign(&self) -> &'static str {
-        use self::Locale::*;
-        match self {
-            af => "-",
-            af_NA => "-",
-            agq => "-",
-            ak => "-",
-            am => "-",
-            ar => "\u{61c}-",
-            ar_AE => "\u{61c}-",
-            ar_BH => "\u{61c}-",
-            ar_DJ => "\u{61c}-",
-            ar_DZ => "\u{200e}-",
-            ar_EG => "\u{61c}-",
-            ar_EH => "\u{200e}-",
-            ar_ER => "\u{61c}-",
-            ar_IL => "\u{61c}-",
-            ar_IQ => "\u{61c}-",
-            ar_JO => "\u{61c}-",
-            ar_KM => "\u{61c}-",
-            ar_KW => "\u{61c}-",
-            ar_LB => "\u{61c}-",
-            ar_LY => "\u{200e}-",
-            ar_MA => "\u{200e}-",
-            ar_MR => "\u{61c}-",
-            ar_OM => "\u{61c}-",
-            ar_PS => "\u{61c}-",
-            ar_QA => "\u{61c}-",
-            ar_SA => "\u{61c}-",
-            ar_SD => "\u{61c}-",
-            ar_SO => "\u{61c}-",
-            ar_SS => "\u{61c}-",
-            ar_SY => "\u{61c}-",
-            ar_TD => "\u{61c}-",
-            ar_TN => "\u{200e}-",
-            ar_YE => "\u{61c}-",
-            as_ => "-",
-            asa => "-",
-            ast => "-",
-            az => "-",
-            az_Cyrl => "-",
-            az_Latn => "-",
-            bas => "-",
-            be => "-",
-            bem => "-",
-            bez => "-",
-            bg => "-",
-            bm => "-",
-            bn => "-",
-            bn_IN => "-",
-            bo => "-",
-            bo_IN => "-",
-            br => "-",
-            brx => "-",
-            bs => "-",
-            bs_Cyrl => "-",
-            bs_Latn => "-",
-            ca => "-",
-            ca_AD => "-",
-            ca_ES_VALENCIA => "-",
-            ca_FR => "-",
-            ca_IT => "-",
-            ccp => "-",
-            ccp_IN => "-",
-            ce => "-",
-            ceb => "-",
-            cgg => "-",
-            chr => "-",
-            ckb => "\u{200f}-",
-            ckb_IR => "\u{200f}-",
-            cs => "-",
-            cu => "-",
-            cy => "-",
-            da => "-",
-            da_GL => "-",
-            dav => "-",
-            de => "-",
-            de_AT => "-",
-            de_BE => "-",
-            de_CH => "-",
-            de_IT => "-",
-            de_LI => "-",
-            de_LU => "-",
-            dje => "-",
-            dsb => "-",
-            dua => "-",
-            dyo => "-",
-            dz => "-",
-            ebu => "-",
-            ee => "-",
-            ee_TG => "-",
-            el => "-",
-            el_CY => "-",
-            en => "-",
-            en_001 => "-",
-            en_150 => "-",
-            en_AE => "-",
-            en_AG => "-",
-            en_AI => "-",
-            en_AS => "-",
-            en_AT => "-",
-            en_AU => "-",
-            en_BB => "-",
-            en_BE => "-",
-            en_BI => "-",
-            en_BM => "-",
-            en_BS => "-",
-            en_BW => "-",
-            en_BZ => "-",
-            en_CA => "-",
-            en_CC => "-",
-            en_CH => "-",
-            en_CK => "-",
-            en_CM => "-",
-            en_CX => "-",
-            en_CY => "-",
-            en_DE => "-",
-            en_DG => "-",
-            en_DK => "-",
-            en_DM => "-",
-            en_ER => "-",
-            en_FI => "-",
-            en_FJ => "-",
-            en_FK => "-",
-            en_FM => "-",
-            en_GB => "-",
-            en_GD => "-",
-            en_GG => "-",
-            en_GH => "-",
-            en_GI => "-",
-            en_GM => "-",
-            en_GU => "-",
-            en_GY => "-",
-            en_HK => "-",
-            en_IE => "-",
-            en_IL => "-",
-            en_IM => "-",
-            en_IN => "-",
-            en_IO => "-",
-            en_JE => "-",
-            en_JM => "-",
-            en_KE => "-",
-            en_KI => "-",
-            en_KN => "-",
-            en_KY => "-",
-            en_LC => "-",
-            en_LR => "-",
-            en_LS => "-",
-            en_MG => "-",
-            en_MH => "-",
-            en_MO => "-",
-            en_MP => "-",
-            en_MS => "-",
-            en_MT => "-",
-            en_MU => "-",
-            en_MW => "-",
-            en_MY => "-",
-            en_NA => "-",
-            en_NF => "-",
-            en_NG => "-",
-            en_NL => "-",
-            en_NR => "-",
-            en_NU => "-",
-            en_NZ => "-",
-            en_PG => "-",
-            en_PH => "-",
-            en_PK => "-",
-            en_PN => "-",
-            en_PR => "-",
-            en_PW => "-",
-            en_RW => "-",
-            en_SB => "-",
-            en_SC => "-",
-            en_SD => "-",
-            en_SE => "-",
-            en_SG => "-",
-            en_SH => "-",
-            en_SI => "-",
-            en_SL => "-",
-            en_SS => "-",
-            en_SX => "-",
-            en_SZ => "-",
-            en_TC => "-",
-            en_TK => "-",
-            en_TO => "-",
-            en_TT => "-",
-            en_TV => "-",
-            en_TZ => "-",
-            en_UG => "-",
-            en_UM => "-",
-            en_US_POSIX => "-",
-            en_VC => "-",
-            en_VG => "-",
-            en_VI => "-",
-            en_VU => "-",
-            en_WS => "-",
-            en_ZA => "-",
-            en_ZM => "-",
-            en_ZW => "-",
-            eo => "-",
-            es => "-",
-            es_419 => "-",
-            es_AR => "-",
-            es_BO => "-",
-            es_BR => "-",
-            es_BZ => "-",
-            es_CL => "-",
-            es_CO => "-",
-            es_CR => "-",
-            es_CU => "-",
-            es_DO => "-",
-            es_EA => "-",
-            es_EC => "-",
-            es_GQ => "-",
-            es_GT => "-",
-            es_HN => "-",
-            es_IC => "-",
-            es_MX => "-",
-            es_NI => "-",
-            es_PA => "-",
-            es_PE => "-",
-            es_PH => "-",
-            es_PR => "-",
-            es_PY => "-",
-            es_SV => "-",
-            es_US => "-",
-            es_UY => "-",
-            es_VE => "-",
-            et => "−",
-            eu => "−",
-            ewo => "-",
-            fa => "\u{200e}−",
-            fa_AF => "\u{200e}−",
-            ff => "-",
-            ff_Latn => "-",
-            ff_Latn_BF => "-",
-            ff_Latn_CM => "-",
-            ff_Latn_GH => "-",
-            ff_Latn_GM => "-",
-            ff_Latn_GN => "-",
-            ff_Latn_GW => "-",
-            ff_Latn_LR => "-",
-            ff_Latn_MR => "-",
-            ff_Latn_NE => "-",
-            ff_Latn_NG => "-",
-            ff_Latn_SL => "-",
-            fi => "−",
-            fil => "-",
-            fo => "−",
-            fo_DK => "−",
-            fr => "-",
-            fr_BE => "-",
-            fr_BF => "-",
-            fr_BI => "-",
-            fr_BJ => "-",
-            fr_BL => "-",
-            fr_CA => "-",
-            fr_CD => "-",
-            fr_CF => "-",
-            fr_CG => "-",
-            fr_CH => "-",
-            fr_CI => "-",
-            fr_CM => "-",
-            fr_DJ => "-",
-            fr_DZ => "-",
-            fr_GA => "-",
-            fr_GF => "-",
-            fr_GN => "-",
-            fr_GP => "-",
-            fr_GQ => "-",
-            fr_HT => "-",
-            fr_KM => "-",
-            fr_LU => "-",
-            fr_MA => "-",
-            fr_MC => "-",
-            fr_MF => "-",
-            fr_MG => "-",
-            fr_ML => "-",
-            fr_MQ => "-",
-            fr_MR => "-",
-            fr_MU => "-",
-            fr_NC => "-",
-            fr_NE => "-",
-            fr_PF => "-",
-            fr_PM => "-",
-            fr_RE => "-",
-            fr_RW => "-",
-            fr_SC => "-",
-            fr_SN => "-",
-            fr_SY => "-",
-            fr_TD => "-",
-            fr_TG => "-",
-            fr_TN => "-",
-            fr_VU => "-",
-            fr_WF => "-",
-            fr_YT => "-",
-            fur => "-",
-            fy => "-",
-            ga => "-",
-            ga_GB => "-",
-            gd => "-",
-            gl => "-",
-            gsw => "−",
-            gsw_FR => "−",
-            gsw_LI => "−",
-            gu => "-",
-            guz => "-",
-            gv => "-",
-            ha => "-",
-            ha_GH => "-",
-            ha_NE => "-",
-            haw => "-",
-            he => "\u{200e}-",
-            hi => "-",
-            hr => "-",
-            hr_BA => "-",
-            hsb => "-",
-            hu => "-",
-            hy => "-",
-            ia => "-",
-            id => "-",
-            ig => "-",
-            ii => "-",
-            is => "-",
-            it => "-",
-            it_CH => "-",
-            it_SM => "-",
-            it_VA => "-",
-            ja => "-",
-            jgo => "-",
-            jmc => "-",
-            jv => "-",
-            ka => "-",
-            kab => "-",
-            kam => "-",
-            kde => "-",
-            kea => "-",
-            khq => "-",
-            ki => "-",
-            kk => "-",
-            kkj => "-",
-            kl => "-",
-            kln => "-",
-            km => "-",
-            kn => "-",
-            ko => "-",
-            ko_KP => "-",
-            kok => "-",
-            ks => "\u{200e}-\u{200e}",
-            ksb => "-",
-            ksf => "-",
-            ksh => "−",
-            ku => "-",
-            kw => "-",
-            ky => "-",
-            lag => "-",
-            lb => "-",
-            lg => "-",
-            lkt => "-",
-            ln => "-",
-            ln_AO => "-",
-            ln_CF => "-",
-            ln_CG => "-",
-            lo => "-",
-            lrc => "\u{200e}-\u{200e}",
-            lrc_IQ => "\u{200e}-\u{200e}",
-            lt => "−",
-            lu => "-",
-            luo => "-",
-            luy => "-",
-            lv => "-",
-            mas => "-",
-            mas_TZ => "-",
-            mer => "-",
-            mfe => "-",
-            mg => "-",
-            mgh => "-",
-            mgo => "-",
-            mi => "-",
-            mk => "-",
-            ml => "-",
-            mn => "-",
-            mr => "-",
-            ms => "-",
-            ms_BN => "-",
-            ms_SG => "-",
-            mt => "-",
-            mua => "-",
-            my => "-",
-            mzn => "\u{200e}-\u{200e}",
-            naq => "-",
-            nb => "−",
-            nb_SJ => "−",
-            nd => "-",
-            nds => "-",
-            nds_NL => "-",
-            ne => "-",
-            ne_IN => "-",
-            nl => "-",
-            nl_AW => "-",
-            nl_BE => "-",
-            nl_BQ => "-",
-            nl_CW => "-",
-            nl_SR => "-",
-            nl_SX => "-",
-            nmg => "-",
-            nn => "−",
-            nnh => "-",
-            nus => "-",
-            nyn => "-",
-            om => "-",
-            om_KE => "-",
-            or => "-",
-            os => "-",
-            os_RU => "-",
-            pa => "-",
-            pa_Arab => "\u{200e}-\u{200e}",
-            pa_Guru => "-",
-            pl => "-",
-            prg => "-",
-            ps => "\u{200e}-\u{200e}",
-            ps_PK => "\u{200e}-\u{200e}",
-            pt => "-",
-            pt_AO => "-",
-            pt_CH => "-",
-            pt_CV => "-",
-            pt_GQ => "-",
-            pt_GW => "-",
-            pt_LU => "-",
-            pt_MO => "-",
-            pt_MZ => "-",
-            pt_PT => "-",
-            pt_ST => "-",
-            pt_TL => "-",
-            qu => "-",
-            qu_BO => "-",
-            qu_EC => "-",
-            rm => "−",
-            rn => "-",
-            ro => "-",
-            ro_MD => "-",
-            rof => "-",
-            root => "-",
-            ru => "-",
-            ru_BY => "-",
-            ru_KG => "-",
-            ru_KZ => "-",
-            ru_MD => "-",
-            ru_UA => "-",
-            rw => "-",
-            rwk => "-",
-            sah => "-",
-            saq => "-",
-            sbp => "-",
-            sd => "\u{61c}-",
-            se => "−",
-            se_FI => "−",
-            se_SE => "−",
-            seh => "-",
-            ses => "-",
-            sg => "-",
-            shi => "-",
-            shi_Latn => "-",
-            shi_Tfng => "-",
-            si => "-",
-            sk => "-",
-            sl => "−",
-            smn => "-",
-            sn => "-",
-            so => "-",
-            so_DJ => "-",
-            so_ET => "-",
-            so_KE => "-",
-            sq => "-",
-            sq_MK => "-",
-            sq_XK => "-",
-            sr => "-",
-            sr_Cyrl => "-",
-            sr_Cyrl_BA => "-",
-            sr_Cyrl_ME => "-",
-            sr_Cyrl_XK => "-",
-            sr_Latn => "-",
-            sr_Latn_BA => "-",
-            sr_Latn_ME => "-",
-            sr_Latn_XK => "-",
-            sv => "−",
-            sv_AX => "−",
-            sv_FI => "−",
-            sw => "-",
-            sw_CD => "-",
-            sw_KE => "-",
-            sw_UG => "-",
-            ta => "-",
-            ta_LK => "-",
-            ta_MY => "-",
-            ta_SG => "-",
-            te => "-",
-            teo => "-",
-            teo_KE => "-",
-            tg => "-",
-            th => "-",
-            ti => "-",
-            ti_ER => "-",
-            tk => "-",
-            to => "-",
-            tr => "-",
-            tr_CY => "-",
-            tt => "-",
-            twq => "-",
-            tzm => "-",
-            ug => "-",
-            uk => "-",
-            ur => "\u{200e}-",
-            ur_IN => "\u{200e}-\u{200e}",
-            uz => "-",
-            uz_Arab => "\u{200e}-\u{200e}",
-            uz_Cyrl => "-",
-            uz_Latn => "-",
-            vai => "-",
-            vai_Latn => "-",
-            vai_Vaii => "-",
-            vi => "-",
-            vo => "-",
-            vun => "-",
-            wae => "-",
-            wo => "-",
-            xh => "-",
-            xog => "-",
-            yav => "-",
-            yi => "-",
-            yo => "-",
-            yo_BJ => "-",
-            yue => "-",
-            yue_Hans => "-",
-            yue_Hant => "-",
-            zgh => "-",
-            zh => "-",
-            zh_Hans => "-",
-            zh_Hans_HK => "-",
-            zh_Hans_MO => "-",
-            zh_Hans_SG => "-",
-            zh_Hant => "-",
-            zh_Hant_HK => "-",
-            zh_Hant_MO => "-",
-            zu => "-",
-        }
-    }
-    #[doc = r" Returns the locale's name."]
-    pub fn name(&self) -> &'static str {
-        use self::Locale::*;
-        match self {
-            af => "af",
-            af_NA => "af-NA",
-            agq => "agq",
-            ak => "ak",
-            am => "am",
-            ar => "ar",
-            ar_AE => "ar-AE",
-            ar_BH => "ar-BH",
-            ar_DJ => "ar-DJ",
-            ar_DZ => "ar-DZ",
-            ar_EG => "ar-EG",
-            ar_EH => "ar-EH",
-            ar_ER => "ar-ER",
-            ar_IL => "ar-IL",
-            ar_IQ => "ar-IQ",
-            ar_JO => "ar-JO",
-            ar_KM => "ar-KM",
-            ar_KW => "ar-KW",
-            ar_LB => "ar-LB",
-            ar_LY => "ar-LY",
-            ar_MA => "ar-MA",
-            ar_MR => "ar-MR",
-            ar_OM => "ar-OM",
-            ar_PS => "ar-PS",
-            ar_QA => "ar-QA",
-            ar_SA => "ar-SA",
-            ar_SD => "ar-SD",
-            ar_SO => "ar-SO",
-            ar_SS => "ar-SS",
-            ar_SY => "ar-SY",
-            ar_TD => "ar-TD",
-            ar_TN => "ar-TN",
-            ar_YE => "ar-YE",
-            as_ => "as",
-            asa => "asa",
-            ast => "ast",
-            az => "az",
-            az_Cyrl => "az-Cyrl",
-            az_Latn => "az-Latn",
-            bas => "bas",
-            be => "be",
-            bem => "bem",
-            bez => "bez",
-            bg => "bg",
-            bm => "bm",
-            bn => "bn",
-            bn_IN => "bn-IN",
-            bo => "bo",
-            bo_IN => "bo-IN",
-            br => "br",
-            brx => "brx",
-            bs => "bs",
-            bs_Cyrl => "bs-Cyrl",
-            bs_Latn => "bs-Latn",
-            ca => "ca",
-            ca_AD => "ca-AD",
-            ca_ES_VALENCIA => "ca-ES-VALENCIA",
-            ca_FR => "ca-FR",
-            ca_IT => "ca-IT",
-            ccp => "ccp",
-            ccp_IN => "ccp-IN",
-            ce => "ce",
-            ceb => "ceb",
-            cgg => "cgg",
-            chr => "chr",
-            ckb => "ckb",
-            ckb_IR => "ckb-IR",
-            cs => "cs",
-            cu => "cu",
-            cy => "cy",
-            da => "da",
-            da_GL => "da-GL",
-            dav => "dav",
-            de => "de",
-            de_AT => "de-AT",
-            de_BE => "de-BE",
-            de_CH => "de-CH",
-            de_IT => "de-IT",
-            de_LI => "de-LI",
-            de_LU => "de-LU",
-            dje => "dje",
-            dsb => "dsb",
-            dua => "dua",
-            dyo => "dyo",
-            dz => "dz",
-            ebu => "ebu",
-            ee => "ee",
-            ee_TG => "ee-TG",
-            el => "el",
-            el_CY => "el-CY",
-            en => "en",
-            en_001 => "en-001",
-            en_150 => "en-150",
-            en_AE => "en-AE",
-            en_AG => "en-AG",
-            en_AI => "en-AI",
-            en_AS => "en-AS",
-            en_AT => "en-AT",
-            en_AU => "en-AU",
-            en_BB => "en-BB",
-            en_BE => "en-BE",
-            en_BI => "en-BI",
-            en_BM => "en-BM",
-            en_BS => "en-BS",
-            en_BW => "en-BW",
-            en_BZ => "en-BZ",
-            en_CA => "en-CA",
-            en_CC => "en-CC",
-            en_CH => "en-CH",
-            en_CK => "en-CK",
-            en_CM => "en-CM",
-            en_CX => "en-CX",
-            en_CY => "en-CY",
-            en_DE => "en-DE",
-            en_DG => "en-DG",
-            en_DK => "en-DK",
-            en_DM => "en-DM",
-            en_ER => "en-ER",
-            en_FI => "en-FI",
-            en_FJ => "en-FJ",
-            en_FK => "en-FK",
-            en_FM => "en-FM",
-            en_GB => "en-GB",
-            en_GD => "en-GD",
-            en_GG => "en-GG",
-            en_GH => "en-GH",
-            en_GI => "en-GI",
-            en_GM => "en-GM",
-            en_GU => "en-GU",
-            en_GY => "en-GY",
-            en_HK => "en-HK",
-            en_IE => "en-IE",
-            en_IL => "en-IL",
-            en_IM => "en-IM",
-            en_IN => "en-IN",
-            en_IO => "en-IO",
-            en_JE => "en-JE",
-            en_JM => "en-JM",
-            en_KE => "en-KE",
-            en_KI => "en-KI",
-            en_KN => "en-KN",
-            en_KY => "en-KY",
-            en_LC => "en-LC",
-            en_LR => "en-LR",
-            en_LS => "en-LS",
-            en_MG => "en-MG",
-            en_MH => "en-MH",
-            en_MO => "en-MO",
-            en_MP => "en-MP",
-            en_MS => "en-MS",
-            en_MT => "en-MT",
-            en_MU => "en-MU",
-            en_MW => "en-MW",
-            en_MY => "en-MY",
-            en_NA => "en-NA",
-            en_NF => "en-NF",
-            en_NG => "en-NG",
-            en_NL => "en-NL",
-            en_NR => "en-NR",
-            en_NU => "en-NU",
-            en_NZ => "en-NZ",
-            en_PG => "en-PG",
-            en_PH => "en-PH",
-            en_PK => "en-PK",
-            en_PN => "en-PN",
-            en_PR => "en-PR",
-            en_PW => "en-PW",
-            en_RW => "en-RW",
-            en_SB => "en-SB",
-            en_SC => "en-SC",
-            en_SD => "en-SD",
-            en_SE => "en-SE",
-            en_SG => "en-SG",
-            en_SH => "en-SH",
-            en_SI => "en-SI",
-            en_SL => "en-SL",
-            en_SS => "en-SS",
-            en_SX => "en-SX",
-            en_SZ => "en-SZ",
-            en_TC => "en-TC",
-            en_TK => "en-TK",
-            en_TO => "en-TO",
-            en_TT => "en-TT",
-            en_TV => "en-TV",
-            en_TZ => "en-TZ",
-            en_UG => "en-UG",
-            en_UM => "en-UM",
-            en_US_POSIX => "en-US-POSIX",
-            en_VC => "en-VC",
-            en_VG => "en-VG",
-            en_VI => "en-VI",
-            en_VU => "en-VU",
-            en_WS => "en-WS",
-            en_ZA => "en-ZA",
-            en_ZM => "en-ZM",
-            en_ZW => "en-ZW",
-            eo => "eo",
-            es => "es",
-            es_419 => "es-419",
-            es_AR => "es-AR",
-            es_BO => "es-BO",
-            es_BR => "es-BR",
-            es_BZ => "es-BZ",
-            es_CL => "es-CL",
-            es_CO => "es-CO",
-            es_CR => "es-CR",
-            es_CU => "es-CU",
-            es_DO => "es-DO",
-            es_EA => "es-EA",
-            es_EC => "es-EC",
-            es_GQ => "es-GQ",
-            es_GT => "es-GT",
-            es_HN => "es-HN",
-            es_IC => "es-IC",
-            es_MX => "es-MX",
-            es_NI => "es-NI",
-            es_PA => "es-PA",
-            es_PE => "es-PE",
-            es_PH => "es-PH",
-            es_PR => "es-PR",
-            es_PY => "es-PY",
-            es_SV => "es-SV",
-            es_US => "es-US",
-            es_UY => "es-UY",
-            es_VE => "es-VE",
-            et => "et",
-            eu => "eu",
-            ewo => "ewo",
-            fa => "fa",
-            fa_AF => "fa-AF",
-            ff => "ff",
-            ff_Latn => "ff-Latn",
-            ff_Latn_BF => "ff-Latn-BF",
-            ff_Latn_CM => "ff-Latn-CM",
-            ff_Latn_GH => "ff-Latn-GH",
-            ff_Latn_GM => "ff-Latn-GM",
-            ff_Latn_GN => "ff-Latn-GN",
-            ff_Latn_GW => "ff-Latn-GW",
-            ff_Latn_LR => "ff-Latn-LR",
-            ff_Latn_MR => "ff-Latn-MR",
-            ff_Latn_NE => "ff-Latn-NE",
-            ff_Latn_NG => "ff-Latn-NG",
-            ff_Latn_SL => "ff-Latn-SL",
-            fi => "fi",
-            fil => "fil",
-            fo => "fo",
-            fo_DK => "fo-DK",
-            fr => "fr",
-            fr_BE => "fr-BE",
-            fr_BF => "fr-BF",
-            fr_BI => "fr-BI",
-            fr_BJ => "fr-BJ",
-            fr_BL => "fr-BL",
-            fr_CA => "fr-CA",
-            fr_CD => "fr-CD",
-            fr_CF => "fr-CF",
-            fr_CG => "fr-CG",
-            fr_CH => "fr-CH",
-            fr_CI => "fr-CI",
-            fr_CM => "fr-CM",
-            fr_DJ => "fr-DJ",
-            fr_DZ => "fr-DZ",
-            fr_GA => "fr-GA",
-            fr_GF => "fr-GF",
-            fr_GN => "fr-GN",
-            fr_GP => "fr-GP",
-            fr_GQ => "fr-GQ",
-            fr_HT => "fr-HT",
-            fr_KM => "fr-KM",
-            fr_LU => "fr-LU",
-            fr_MA => "fr-MA",
-            fr_MC => "fr-MC",
-            fr_MF => "fr-MF",
-            fr_MG => "fr-MG",
-            fr_ML => "fr-ML",
-            fr_MQ => "fr-MQ",
-            fr_MR => "fr-MR",
-            fr_MU => "fr-MU",
-            fr_NC => "fr-NC",
-            fr_NE => "fr-NE",
-            fr_PF => "fr-PF",
-            fr_PM => "fr-PM",
-            fr_RE => "fr-RE",
-            fr_RW => "fr-RW",
-            fr_SC => "fr-SC",
-            fr_SN => "fr-SN",
-            fr_SY => "fr-SY",
-            fr_TD => "fr-TD",
-            fr_TG => "fr-TG",
-            fr_TN => "fr-TN",
-            fr_VU => "fr-VU",
-            fr_WF => "fr-WF",
-            fr_YT => "fr-YT",
-            fur => "fur",
-            fy => "fy",
-            ga => "ga",
-            ga_GB => "ga-GB",
-            gd => "gd",
-            gl => "gl",
-            gsw => "gsw",
-            gsw_FR => "gsw-FR",
-            gsw_LI => "gsw-LI",
-            gu => "gu",
-            guz => "guz",
-            gv => "gv",
-            ha => "ha",
-            ha_GH => "ha-GH",
-            ha_NE => "ha-NE",
-            haw => "haw",
-            he => "he",
-            hi => "hi",
-            hr => "hr",
-            hr_BA => "hr-BA",
-            hsb => "hsb",
-            hu => "hu",
-            hy => "hy",
-            ia => "ia",
-            id => "id",
-            ig => "ig",
-            ii => "ii",
-            is => "is",
-            it => "it",
-            it_CH => "it-CH",
-            it_SM => "it-SM",
-            it_VA => "it-VA",
-            ja => "ja",
-            jgo => "jgo",
-            jmc => "jmc",
-            jv => "jv",
-            ka => "ka",
-            kab => "kab",
-            kam => "kam",
-            kde => "kde",
-            kea => "kea",
-            khq => "khq",
-            ki => "ki",
-            kk => "kk",
-            kkj => "kkj",
-            kl => "kl",
-            kln => "kln",
-            km => "km",
-            kn => "kn",
-            ko => "ko",
-            ko_KP => "ko-KP",
-            kok => "kok",
-            ks => "ks",
-            ksb => "ksb",
-            ksf => "ksf",
-            ksh => "ksh",
-            ku => "ku",
-            kw => "kw",
-            ky => "ky",
-            lag => "lag",
-            lb => "lb",
-            lg => "lg",
-            lkt => "lkt",
-            ln => "ln",
-            ln_AO => "ln-AO",
-            ln_CF => "ln-CF",
-            ln_CG => "ln-CG",
-            lo => "lo",
-            lrc => "lrc",
-            lrc_IQ => "lrc-IQ",
-            lt => "lt",
-            lu => "lu",
-            luo => "luo",
-            luy => "luy",
-            lv => "lv",
-            mas => "mas",
-            mas_TZ => "mas-TZ",
-            mer => "mer",
-            mfe => "mfe",
-            mg => "mg",
-            mgh => "mgh",
-            mgo => "mgo",
-            mi => "mi",
-            mk => "mk",
-            ml => "ml",
-            mn => "mn",
-            mr => "mr",
-            ms => "ms",
-            ms_BN => "ms-BN",
-            ms_SG => "ms-SG",
-            mt => "mt",
-            mua => "mua",
-            my => "my",
-            mzn => "mzn",
-            naq => "naq",
-            nb => "nb",
-            nb_SJ => "nb-SJ",
-            nd => "nd",
-            nds => "nds",
-            nds_NL => "nds-NL",
-            ne => "ne",
-            ne_IN => "ne-IN",
-            nl => "nl",
-            nl_AW => "nl-AW",
-            nl_BE => "nl-BE",
-            nl_BQ => "nl-BQ",
-            nl_CW => "nl-CW",
-            nl_SR => "nl-SR",
-            nl_SX => "nl-SX",
-            nmg => "nmg",
-            nn => "nn",
-            nnh => "nnh",
-            nus => "nus",
-            nyn => "nyn",
-            om => "om",
-            om_KE => "om-KE",
-            or => "or",
-            os => "os",
-            os_RU => "os-RU",
-            pa => "pa",
-            pa_Arab => "pa-Arab",
-            pa_Guru => "pa-Guru",
-            pl => "pl",
-            prg => "prg",
-            ps => "ps",
-            ps_PK => "ps-PK",
-            pt => "pt",
-            pt_AO => "pt-AO",
-            pt_CH => "pt-CH",
-            pt_CV => "pt-CV",
-            pt_GQ => "pt-GQ",
-            pt_GW => "pt-GW",
-            pt_LU => "pt-LU",
-            pt_MO => "pt-MO",
-            pt_MZ => "pt-MZ",
-            pt_PT => "pt-PT",
-            pt_ST => "pt-ST",
-            pt_TL => "pt-TL",
-            qu => "qu",
-            qu_BO => "qu-BO",
-            qu_EC => "qu-EC",
-            rm => "rm",
-            rn => "rn",
-            ro => "ro",
-            ro_MD => "ro-MD",
-            rof => "rof",
-            root => "root",
-            ru => "ru",
-            ru_BY => "ru-BY",
-            ru_KG => "ru-KG",
-            ru_KZ => "ru-KZ",
-            ru_MD => "ru-MD",
-            ru_UA => "ru-UA",
-            rw => "rw",
-            rwk => "rwk",
-            sah => "sah",
-            saq => "saq",
-            sbp => "sbp",
-            sd => "sd",
-            se => "se",
-            se_FI => "se-FI",
-            se_SE => "se-SE",
-            seh => "seh",
-            ses => "ses",
-            sg => "sg",
-            shi => "shi",
-            shi_Latn => "shi-Latn",
-            shi_Tfng => "shi-Tfng",
-            si => "si",
-            sk => "sk",
-            sl => "sl",
-            smn => "smn",
-            sn => "sn",
-            so => "so",
-            so_DJ => "so-DJ",
-            so_ET => "so-ET",
-            so_KE => "so-KE",
-            sq => "sq",
-            sq_MK => "sq-MK",
-            sq_XK => "sq-XK",
-            sr => "sr",
-            sr_Cyrl => "sr-Cyrl",
-            sr_Cyrl_BA => "sr-Cyrl-BA",
-            sr_Cyrl_ME => "sr-Cyrl-ME",
-            sr_Cyrl_XK => "sr-Cyrl-XK",
-            sr_Latn => "sr-Latn",
-            sr_Latn_BA => "sr-Latn-BA",
-            sr_Latn_ME => "sr-Latn-ME",
-            sr_Latn_XK => "sr-Latn-XK",
-            sv => "sv",
-            sv_AX => "sv-AX",
-            sv_FI => "sv-FI",
-            sw => "sw",
-            sw_CD => "sw-CD",
-            sw_KE => "sw-KE",
-            sw_UG => "sw-UG",
-            ta => "ta",
-            ta_LK => "ta-LK",
-            ta_MY => "ta-MY",
-            ta_SG => "ta-SG",
-            te => "te",
-            teo => "teo",
-            teo_KE => "teo-KE",
-            tg => "tg",
-            th => "th",
-            ti => "ti",
-            ti_ER => "ti-ER",
-            tk => "tk",
-            to => "to",
-            tr => "tr",
-            tr_CY => "tr-CY",
-            tt => "tt",
-            twq => "twq",
-            tzm => "tzm",
-            ug => "ug",
-            uk => "uk",
-            ur => "ur",
-            ur_IN => "ur-IN",
-            uz => "uz",
-            uz_Arab => "uz-Arab",
-            uz_Cyrl => "uz-Cyrl",
-            uz_Latn => "uz-Latn",
-            vai => "vai",
-            vai_Latn => "vai-Latn",
-            vai_Vaii => "vai-Vaii",
-            vi => "vi",
-            vo => "vo",
-            vun => "vun",
-            wae => "wae",
-            wo => "wo",
-            xh => "xh",
-            xog => "xog",
-            yav => "yav",
-            yi => "yi",
-            yo => "yo",
-            yo_BJ => "yo-BJ",
-            yue => "yue",
-            yue_Hans => "yue-Hans",
-            yue_Hant => "yue-Hant",
-            zgh => "zgh",
-            zh => "zh",
-            zh_Hans => "zh-Hans",
-            zh_Hans_HK => "zh-Hans-HK",
-            zh_Hans_MO => "zh-Hans-MO",
-            zh_Hans_SG => "zh-Hans-SG",
-            zh_Hant => "zh-Hant",
-            zh_Hant_HK => "zh-Hant-HK",
-            zh_Hant_MO => "zh-Hant-MO",
-            zu => "zu",
-        }
-    }
-    #[doc = r" Returns the locale's NaN representation."]
-    pub fn nan(&self) -> &'static str {
-        use self::Locale::*;
-        match self {
-            af => "NaN",
-            af_NA => "NaN",
-            agq => "NaN",
-            ak => "NaN",
-            am => "NaN",
-            ar => "ليس\u{a0}رقم",
-            ar_AE => "ليس\u{a0}رقم",
-            ar_BH => "ليس\u{a0}رقم",
-            ar_DJ => "ليس\u{a0}رقم",
-            ar_DZ => "ليس\u{a0}رقم\u{64b}ا",
-            ar_EG => "ليس\u{a0}رقم",
-            ar_EH => "ليس\u{a0}رقم\u{64b}ا",
-            ar_ER => "ليس\u{a0}رقم",
-            ar_IL => "ليس\u{a0}رقم",
-            ar_IQ => "ليس\u{a0}رقم",
-            ar_JO => "ليس\u{a0}رقم",
-            ar_KM => "ليس\u{a0}رقم",
-            ar_KW => "ليس\u{a0}رقم",
-            ar_LB => "ليس\u{a0}رقم",
-            ar_LY => "ليس\u{a0}رقم\u{64b}ا",
-            ar_MA => "ليس\u{a0}رقم\u{64b}ا",
-            ar_MR => "ليس\u{a0}رقم",
-            ar_OM => "ليس\u{a0}رقم",
-            ar_PS => "ليس\u{a0}رقم",
-            ar_QA => "ليس\u{a0}رقم",
-            ar_SA => "ليس\u{a0}رقم",
-            ar_SD => "ليس\u{a0}رقم",
-            ar_SO => "ليس\u{a0}رقم",
-            ar_SS => "ليس\u{a0}رقم",
-            ar_SY => "ليس\u{a0}رقم",
-            ar_TD => "ليس\u{a0}رقم",
-            ar_TN => "ليس\u{a0}رقم\u{64b}ا",
-            ar_YE => "ليس\u{a0}رقم",
-            as_ => "NaN",
-            asa => "NaN",
-            ast => "ND",
-            az => "NaN",
-            az_Cyrl => "NaN",
-            az_Latn => "NaN",
-            bas => "NaN",
-            be => "NaN",
-            bem => "NaN",
-            bez => "NaN",
-            bg => "NaN",
-            bm => "NaN",
-            bn => "NaN",
-            bn_IN => "NaN",
-            bo => "NaN",
-            bo_IN => "NaN",
-            br => "NaN",
-            brx => "NaN",
-            bs => "NaN",
-            bs_Cyrl => "NaN",
-            bs_Latn => "NaN",
-            ca => "NaN",
-            ca_AD => "NaN",
-            ca_ES_VALENCIA => "NaN",
-            ca_FR => "NaN",
-            ca_IT => "NaN",
-            ccp => "NaN",
-            ccp_IN => "NaN",
-            ce => "Терхьаш\u{a0}дац",
-            ceb => "NaN",
-            cgg => "NaN",
-            chr => "NaN",
-            ckb => "NaN",
-            ckb_IR => "NaN",
-            cs => "NaN",
-            cu => "NaN",
-            cy => "NaN",
-            da => "NaN",
-            da_GL => "NaN",
-            dav => "NaN",
-            de => "NaN",
-            de_AT => "NaN",
-            de_BE => "NaN",
-            de_CH => "NaN",
-            de_IT => "NaN",
-            de_LI => "NaN",
-            de_LU => "NaN",
-            dje => "NaN",
-            dsb => "NaN",
-            dua => "NaN",
-            dyo => "NaN",
-            dz => "ཨང་མད",
-            ebu => "NaN",
-            ee => "mnn",
-            ee_TG => "mnn",
-            el => "NaN",
-            el_CY => "NaN",
-            en => "NaN",
-            en_001 => "NaN",
-            en_150 => "NaN",
-            en_AE => "NaN",
-            en_AG => "NaN",
-            en_AI => "NaN",
-            en_AS => "NaN",
-            en_AT => "NaN",
-            en_AU => "NaN",
-            en_BB => "NaN",
-            en_BE => "NaN",
-            en_BI => "NaN",
-            en_BM => "NaN",
-            en_BS => "NaN",
-            en_BW => "NaN",
-            en_BZ => "NaN",
-            en_CA => "NaN",
-            en_CC => "NaN",
-            en_CH => "NaN",
-            en_CK => "NaN",
-            en_CM => "NaN",
-            en_CX => "NaN",
-            en_CY => "NaN",
-            en_DE => "NaN",
-            en_DG => "NaN",
-            en_DK => "NaN",
-            en_DM => "NaN",
-            en_ER => "NaN",
-            en_FI => "NaN",
-            en_FJ => "NaN",
-            en_FK => "NaN",
-            en_FM => "NaN",
-            en_GB => "NaN",
-            en_GD => "NaN",
-            en_GG => "NaN",
-            en_GH => "NaN",
-            en_GI => "NaN",
-            en_GM => "NaN",
-            en_GU => "NaN",
-            en_GY => "NaN",
-            en_HK => "NaN",
-            en_IE => "NaN",
-            en_IL => "NaN",
-            en_IM => "NaN",
-            en_IN => "NaN",
-            en_IO => "NaN",
-            en_JE => "NaN",
-            en_JM => "NaN",
-            en_KE => "NaN",
-            en_KI => "NaN",
-            en_KN => "NaN",
-            en_KY => "NaN",
-            en_LC => "NaN",
-            en_LR => "NaN",
-            en_LS => "NaN",
-            en_MG => "NaN",
-            en_MH => "NaN",
-            en_MO => "NaN",
-            en_MP => "NaN",
-            en_MS => "NaN",
-            en_MT => "NaN",
-            en_MU => "NaN",
-            en_MW => "NaN",
-            en_MY => "NaN",
-            en_NA => "NaN",
-            en_NF => "NaN",
-            en_NG => "NaN",
-            en_NL => "NaN",
-            en_NR => "NaN",
-            en_NU => "NaN",
-            en_NZ => "NaN",
-            en_PG => "NaN",
-            en_PH => "NaN",
-            en_PK => "NaN",
-            en_PN => "NaN",
-            en_PR => "NaN",
-            en_PW => "NaN",
-            en_RW => "NaN",
-            en_SB => "NaN",
-            en_SC => "NaN",
-            en_SD => "NaN",
-            en_SE => "NaN",
-            en_SG => "NaN",
-            en_SH => "NaN",
-            en_SI => "NaN",
-            en_SL => "NaN",
-            en_SS => "NaN",
-            en_SX => "NaN",
-            en_SZ => "NaN",
-            en_TC => "NaN",
-            en_TK => "NaN",
-            en_TO => "NaN",
-            en_TT => "NaN",
-            en_TV => "NaN",
-            en_TZ => "NaN",
-            en_UG => "NaN",
-            en_UM => "NaN",
-            en_US_POSIX => "NaN",
-            en_VC => "NaN",
-            en_VG => "NaN",
-            en_VI => "NaN",
-            en_VU => "NaN",
-            en_WS => "NaN",
-            en_ZA => "NaN",
-            en_ZM => "NaN",
-            en_ZW => "NaN",
-            eo => "NaN",
-            es => "NaN",
-            es_419 => "NaN",
-            es_AR => "NaN",
-            es_BO => "NaN",
-            es_BR => "NaN",
-            es_BZ => "NaN",
-            es_CL => "NaN",
-            es_CO => "NaN",
-            es_CR => "NaN",
-            es_CU => "NaN",
-            es_DO => "NaN",
-            es_EA => "NaN",
-            es_EC => "NaN",
-            es_GQ => "NaN",
-            es_GT => "NaN",
-            es_HN => "NaN",
-            es_IC => "NaN",
-            es_MX => "NaN",
-            es_NI => "NaN",
-            es_PA => "NaN",
-            es_PE => "NaN",
-            es_PH => "NaN",
-            es_PR => "NaN",
-            es_PY => "NaN",
-            es_SV => "NaN",
-            es_US => "NaN",
-            es_UY => "NaN",
-            es_VE => "NaN",
-            et => "NaN",
-            eu => "NaN",
-            ewo => "NaN",
-            fa => "ناعدد",
-            fa_AF => "ناعدد",
-            ff => "NaN",
-            ff_Latn => "NaN",
-            ff_Latn_BF => "NaN",
-            ff_Latn_CM => "NaN",
-            ff_Latn_GH => "NaN",
-            ff_Latn_GM => "NaN",
-            ff_Latn_GN => "NaN",
-            ff_Latn_GW => "NaN",
-            ff_Latn_LR => "NaN",
-            ff_Latn_MR => "NaN",
-            ff_Latn_NE => "NaN",
-            ff_Latn_NG => "NaN",
-            ff_Latn_SL => "NaN",
-            fi => "epäluku",
-            fil => "NaN",
-            fo => "NaN",
-            fo_DK => "NaN",
-            fr => "NaN",
-            fr_BE => "NaN",
-            fr_BF => "NaN",
-            fr_BI => "NaN",
-            fr_BJ => "NaN",
-            fr_BL => "NaN",
-            fr_CA => "NaN",
-            fr_CD => "NaN",
-            fr_CF => "NaN",
-            fr_CG => "NaN",
-            fr_CH => "NaN",
-            fr_CI => "NaN",
-            fr_CM => "NaN",
-            fr_DJ => "NaN",
-            fr_DZ => "NaN",
-            fr_GA => "NaN",
-            fr_GF => "NaN",
-            fr_GN => "NaN",
-            fr_GP => "NaN",
-            fr_GQ => "NaN",
-            fr_HT => "NaN",
-            fr_KM => "NaN",
-            fr_LU => "NaN",
-            fr_MA => "NaN",
-            fr_MC => "NaN",
-            fr_MF => "NaN",
-            fr_MG => "NaN",
-            fr_ML => "NaN",
-            fr_MQ => "NaN",
-            fr_MR => "NaN",
-            fr_MU => "NaN",
-            fr_NC => "NaN",
-            fr_NE => "NaN",
-            fr_PF => "NaN",
-            fr_PM => "NaN",
-            fr_RE => "NaN",
-            fr_RW => "NaN",
-            fr_SC => "NaN",
-            fr_SN => "NaN",
-            fr_SY => "NaN",
-            fr_TD => "NaN",
-            fr_TG => "NaN",
-            fr_TN => "NaN",
-            fr_VU => "NaN",
-            fr_WF => "NaN",
-            fr_YT => "NaN",
-            fur => "NaN",
-            fy => "NaN",
-            ga => "NaN",
-            ga_GB => "NaN",
-            gd => "NaN",
-            gl => "NaN",
-            gsw => "NaN",
-            gsw_FR => "NaN",
-            gsw_LI => "NaN",
-            gu => "NaN",
-            guz => "NaN",
-            gv => "NaN",
-            ha => "NaN",
-            ha_GH => "NaN",
-            ha_NE => "NaN",
-            haw => "NaN",
-            he => "NaN",
-            hi => "NaN",
-            hr => "NaN",
-            hr_BA => "NaN",
-            hsb => "NaN",
-            hu => "NaN",
-            hy => "ՈչԹ",
-            ia => "NaN",
-            id => "NaN",
-            ig => "NaN",
-            ii => "NaN",
-            is => "NaN",
-            it => "NaN",
-            it_CH => "NaN",
-            it_SM => "NaN",
-            it_VA => "NaN",
-            ja => "NaN",
-            jgo => "NaN",
-            jmc => "NaN",
-            jv => "NaN",
-            ka => "არ\u{a0}არის\u{a0}რიცხვი",
-            kab => "NaN",
-            kam => "NaN",
-            kde => "NaN",
-            kea => "NaN",
-            khq => "NaN",
-            ki => "NaN",
-            kk => "сан\u{a0}емес",
-            kkj => "NaN",
-            kl => "NaN",
-            kln => "NaN",
-            km => "NaN",
-            kn => "NaN",
-            ko => "NaN",
-            ko_KP => "NaN",
-            kok => "NaN",
-            ks => "NaN",
-            ksb => "NaN",
-            ksf => "NaN",
-            ksh => "NaN",
-            ku => "NaN",
-            kw => "NaN",
-            ky => "сан\u{a0}эмес",
-            lag => "NaN",
-            lb => "NaN",
-            lg => "NaN",
-            lkt => "NaN",
-            ln => "NaN",
-            ln_AO => "NaN",
-            ln_CF => "NaN",
-            ln_CG => "NaN",
-            lo => "ບ\u{ecd}\u{ec8}\u{200b}ແມ\u{ec8}ນ\u{200b}ໂຕ\u{200b}ເລກ",
-            lrc => "NaN",
-            lrc_IQ => "NaN",
-            lt => "NaN",
-            lu => "NaN",
-            luo => "NaN",
-            luy => "NaN",
-            lv => "NS",
-            mas => "NaN",
-            mas_TZ => "NaN",
-            mer => "NaN",
-            mfe => "NaN",
-            mg => "NaN",
-            mgh => "NaN",
-            mgo => "NaN",
-            mi => "NaN",
-            mk => "NaN",
-            ml => "NaN",
-            mn => "NaN",
-            mr => "NaN",
-            ms => "NaN",
-            ms_BN => "NaN",
-            ms_SG => "NaN",
-            mt => "NaN",
-            mua => "NaN",
-            my => "ဂဏန\u{103a}းမဟ\u{102f}တ\u{103a}သော",
-            mzn => "NaN",
-            naq => "NaN",
-            nb => "NaN",
-            nb_SJ => "NaN",
-            nd => "NaN",
-            nds => "NaN",
-            nds_NL => "NaN",
-            ne => "NaN",
-            ne_IN => "NaN",
-            nl => "NaN",
-            nl_AW => "NaN",
-            nl_BE => "NaN",
-            nl_BQ => "NaN",
-            nl_CW => "NaN",
-            nl_SR => "NaN",
-            nl_SX => "NaN",
-            nmg => "NaN",
-            nn => "NaN",
-            nnh => "NaN",
-            nus => "NaN",
-            nyn => "NaN",
-            om => "NaN",
-            om_KE => "NaN",
-            or => "NaN",
-            os => "НН",
-            os_RU => "НН",
-            pa => "NaN",
-            pa_Arab => "NaN",
-            pa_Guru => "NaN",
-            pl => "NaN",
-            prg => "NaN",
-            ps => "NaN",
-            ps_PK => "NaN",
-            pt => "NaN",
-            pt_AO => "NaN",
-            pt_CH => "NaN",
-            pt_CV => "NaN",
-            pt_GQ => "NaN",
-            pt_GW => "NaN",
-            pt_LU => "NaN",
-            pt_MO => "NaN",
-            pt_MZ => "NaN",
-            pt_PT => "NaN",
-            pt_ST => "NaN",
-            pt_TL => "NaN",
-            qu => "NaN",
-            qu_BO => "NaN",
-            qu_EC => "NaN",
-            rm => "NaN",
-            rn => "NaN",
-            ro => "NaN",
-            ro_MD => "NaN",
-            rof => "NaN",
-            root => "NaN",
-            ru => "не\u{a0}число",
-            ru_BY => "не\u{a0}число",
-            ru_KG => "не\u{a0}число",
-            ru_KZ => "не\u{a0}число",
-            ru_MD => "не\u{a0}число",
-            ru_UA => "не\u{a0}число",
-            rw => "NaN",
-            rwk => "NaN",
-            sah => "чыыһыла\u{a0}буотах",
-            saq => "NaN",
-            sbp => "NaN",
-            sd => "NaN",
-            se => "NaN",
-            se_FI => "NaN",
-            se_SE => "NaN",
-            seh => "NaN",
-            ses => "NaN",
-            sg => "NaN",
-            shi => "NaN",
-            shi_Latn => "NaN",
-            shi_Tfng => "NaN",
-            si => "NaN",
-            sk => "NaN",
-            sl => "NaN",
-            smn => "epiloho",
-            sn => "NaN",
-            so => "MaL",
-            so_DJ => "MaL",
-            so_ET => "MaL",
-            so_KE => "MaL",
-            sq => "NaN",
-            sq_MK => "NaN",
-            sq_XK => "NaN",
-            sr => "NaN",
-            sr_Cyrl => "NaN",
-            sr_Cyrl_BA => "NaN",
-            sr_Cyrl_ME => "NaN",
-            sr_Cyrl_XK => "NaN",
-            sr_Latn => "NaN",
-            sr_Latn_BA => "NaN",
-            sr_Latn_ME => "NaN",
-            sr_Latn_XK => "NaN",
-            sv => "NaN",
-            sv_AX => "NaN",
-            sv_FI => "NaN",
-            sw => "NaN",
-            sw_CD => "NaN",
-            sw_KE => "NaN",
-            sw_UG => "NaN",
-            ta => "NaN",
-            ta_LK => "NaN",
-            ta_MY => "NaN",
-            ta_SG => "NaN",
-            te => "NaN",
-            teo => "NaN",
-            teo_KE => "NaN",
-            tg => "NaN",
-            th => "NaN",
-            ti => "NaN",
-            ti_ER => "NaN",
-            tk => "san\u{a0}däl",
-            to => "TF",
-            tr => "NaN",
-            tr_CY => "NaN",
-            tt => "NaN",
-            twq => "NaN",
-            tzm => "NaN",
-            ug => "NaN",
-            uk => "NaN",
-            ur => "NaN",
-            ur_IN => "NaN",
-            uz => "son\u{a0}emas",
-            uz_Arab => "NaN",
-            uz_Cyrl => "ҳақиқий\u{a0}сон\u{a0}эмас",
-            uz_Latn => "son\u{a0}emas",
-            vai => "NaN",
-            vai_Latn => "NaN",
-            vai_Vaii => "NaN",
-            vi => "NaN",
-            vo => "NaN",
-            vun => "NaN",
-            wae => "NaN",
-            wo => "NaN",
-            xh => "NaN",
-            xog => "NaN",
-            yav => "NaN",
-            yi => "NaN",
-            yo => "NaN",
-            yo_BJ => "NaN",
-            yue => "非數值",
-            yue_Hans => "非数值",
-            yue_Hant => "非數值",
-            zgh => "NaN",
-            zh => "NaN",
-            zh_Hans => "NaN",
-            zh_Hans_HK => "NaN",
-            zh_Hans_MO => "NaN",
-            zh_Hans_SG => "NaN",
-            zh_Hant => "非數值",
-            zh_Hant_HK => "非數值",
-            zh_Hant_MO => "非數值",
-            zu => "NaN",
-        }
-    }
-    #[doc = r" Returns the locale's plus sign representation."]
-    pub fn plus_sign(&self) -> &'static str {
-        use self::Locale::*;
-        match self {
-            af => "+",
-            af_NA => "+",
-            agq => "+",
-            ak => "+",
-            am => "+",
-            ar => "\u{61c}+",
-            ar_AE => "\u{61c}+",
-            ar_BH => "\u{61c}+",
-            ar_DJ => "\u{61c}+",
-            ar_DZ => "\u{200e}+",
-            ar_EG => "\u{61c}+",
-            ar_EH => "\u{200e}+",
-            ar_ER => "\u{61c}+",
-            ar_IL => "\u{61c}+",
-            ar_IQ => "\u{61c}+",
-            ar_JO => "\u{61c}+",
-            ar_KM => "\u{61c}+",
-            ar_KW => "\u{61c}+",
-            ar_LB => "\u{61c}+",
-            ar_LY => "\u{200e}+",
-            ar_MA => "\u{200e}+",
-            ar_MR => "\u{61c}+",
-            ar_OM => "\u{61c}+",
-            ar_PS => "\u{61c}+",
-            ar_QA => "\u{61c}+",
-            ar_SA => "\u{61c}+",
-            ar_SD => "\u{61c}+",
-            ar_SO => "\u{61c}+",
-            ar_SS => "\u{61c}+",
-            ar_SY => "\u{61c}+",
-            ar_TD => "\u{61c}+",
-            ar_TN => "\u{200e}+",
-            ar_YE => "\u{61c}+",
-            as_ => "+",
-            asa => "+",
-            ast => "+",
-            az => "+",
-            az_Cyrl => "+",
-            az_Latn => "+",
-            bas => "+",
-            be => "+",
-            bem => "+",
-            bez => "+",
-            bg => "+",
-            bm => "+",
-            bn => "+",
-            bn_IN => "+",
-            bo => "+",
-            bo_IN => "+",
-            br => "+",
-            brx => "+",
-            bs => "+",
-            bs_Cyrl => "+",
-            bs_Latn => "+",
-            ca => "+",
-            ca_AD => "+",
-            ca_ES_VALENCIA => "+",
-            ca_FR => "+",
-            ca_IT => "+",
-            ccp => "+",
-            ccp_IN => "+",
-            ce => "+",
-            ceb => "+",
-            cgg => "+",
-            chr => "+",
-            ckb => "\u{200f}+",
-            ckb_IR => "\u{200f}+",
-            cs => "+",
-            cu => "+",
-            cy => "+",
-            da => "+",
-            da_GL => "+",
-            dav => "+",
-            de => "+",
-            de_AT => "+",
-            de_BE => "+",
-            de_CH => "+",
-            de_IT => "+",
-            de_LI => "+",
-            de_LU => "+",
-            dje => "+",
-            dsb => "+",
-            dua => "+",
-            dyo => "+",
-            dz => "+",
-            ebu => "+",
-            ee => "+",
-            ee_TG => "+",
-            el => "+",
-            el_CY => "+",
-            en => "+",
-            en_001 => "+",
-            en_150 => "+",
-            en_AE => "+",
-            en_AG => "+",
-            en_AI => "+",
-            en_AS => "+",
-            en_AT => "+",
-            en_AU => "+",
-            en_BB => "+",
-            en_BE => "+",
-            en_BI => "+",
-            en_BM => "+",
-            en_BS => "+",
-            en_BW => "+",
-            en_BZ => "+",
-            en_CA => "+",
-            en_CC => "+",
-            en_CH => "+",
-            en_CK => "+",
-            en_CM => "+",
-            en_CX => "+",
-            en_CY => "+",
-            en_DE => "+",
-            en_DG => "+",
-            en_DK => "+",
-            en_DM => "+",
-            en_ER => "+",
-            en_FI => "+",
-            en_FJ => "+",
-            en_FK => "+",
-            en_FM => "+",
-            en_GB => "+",
-            en_GD => "+",
-            en_GG => "+",
-            en_GH => "+",
-            en_GI => "+",
-            en_GM => "+",
-            en_GU => "+",
-            en_GY => "+",
-            en_HK => "+",
-            en_IE => "+",
-            en_IL => "+",
-            en_IM => "+",
-            en_IN => "+",
-            en_IO => "+",
-            en_JE => "+",
-            en_JM => "+",
-            en_KE => "+",
-            en_KI => "+",
-            en_KN => "+",
-            en_KY => "+",
-            en_LC => "+",
-            en_LR => "+",
-            en_LS => "+",
-            en_MG => "+",
-            en_MH => "+",
-            en_MO => "+",
-            en_MP => "+",
-            en_MS => "+",
-            en_MT => "+",
-            en_MU => "+",
-            en_MW => "+",
-            en_MY => "+",
-            en_NA => "+",
-            en_NF => "+",
-            en_NG => "+",
-            en_NL => "+",
-            en_NR => "+",
-            en_NU => "+",
-            en_NZ => "+",
-            en_PG => "+",
-            en_PH => "+",
-            en_PK => "+",
-            en_PN => "+",
-            en_PR => "+",
-            en_PW => "+",
-            en_RW => "+",
-            en_SB => "+",
-            en_SC => "+",
-            en_SD => "+",
-            en_SE => "+",
-            en_SG => "+",
-            en_SH => "+",
-            en_SI => "+",
-            en_SL => "+",
-            en_SS => "+",
-            en_SX => "+",
-            en_SZ => "+",
-            en_TC => "+",
-            en_TK => "+",
-            en_TO => "+",
-            en_TT => "+",
-            en_TV => "+",
-            en_TZ => "+",
-            en_UG => "+",
-            en_UM => "+",
-            en_US_POSIX => "+",
-            en_VC => "+",
-            en_VG => "+",
-            en_VI => "+",
-            en_VU => "+",
-            en_WS => "+",
-            en_ZA => "+",
-            en_ZM => "+",
-            en_ZW => "+",
-            eo => "+",
-            es => "+",
-            es_419 => "+",
-            es_AR => "+",
-            es_BO => "+",
-            es_BR => "+",
-            es_BZ => "+",
-            es_CL => "+",
-            es_CO => "+",
-            es_CR => "+",
-            es_CU => "+",
-            es_DO => "+",
-            es_EA => "+",
-            es_EC => "+",
-            es_GQ => "+",
-            es_GT => "+",
-            es_HN => "+",
-            es_IC => "+",
-            es_MX => "+",
-            es_NI => "+",
-            es_PA => "+",
-            es_PE => "+",
-            es_PH => "+",
-            es_PR => "+",
-            es_PY => "+",
-            es_SV => "+",
-            es_US => "+",
-            es_UY => "+",
-            es_VE => "+",
-            et => "+",
-            eu => "+",
-            ewo => "+",
-            fa => "\u{200e}+",
-            fa_AF => "\u{200e}+",
-            ff => "+",
-            ff_Latn => "+",
-            ff_Latn_BF => "+",
-            ff_Latn_CM => "+",
-            ff_Latn_GH => "+",
-            ff_Latn_GM => "+",
-            ff_Latn_GN => "+",
-            ff_Latn_GW => "+",
-            ff_Latn_LR => "+",
-            ff_Latn_MR => "+",
-            ff_Latn_NE => "+",
-            ff_Latn_NG => "+",
-            ff_Latn_SL => "+",
-            fi => "+",
-            fil => "+",
-            fo => "+",
-            fo_DK => "+",
-            fr => "+",
-            fr_BE => "+",
-            fr_BF => "+",
-            fr_BI => "+",
-            fr_BJ => "+",
-            fr_BL => "+",
-            fr_CA => "+",
-            fr_CD => "+",
-            fr_CF => "+",
-            fr_CG => "+",
-            fr_CH => "+",
-            fr_CI => "+",
-            fr_CM => "+",
-            fr_DJ => "+",
-            fr_DZ => "+",
-            fr_GA => "+",
-            fr_GF => "+",
-            fr_GN => "+",
-            fr_GP => "+",
-            fr_GQ => "+",
-            fr_HT => "+",
-            fr_KM => "+",
-            fr_LU => "+",
-            fr_MA => "+",
-            fr_MC => "+",
-            fr_MF => "+",
-            fr_MG => "+",
-            fr_ML => "+",
-            fr_MQ => "+",
-            fr_MR => "+",
-            fr_MU => "+",
-            fr_NC => "+",
-            fr_NE => "+",
-            fr_PF => "+",
-            fr_PM => "+",
-            fr_RE => "+",
-            fr_RW => "+",
-            fr_SC => "+",
-            fr_SN => "+",
-            fr_SY => "+",
-            fr_TD => "+",
-            fr_TG => "+",
-            fr_TN => "+",
-            fr_VU => "+",
-            fr_WF => "+",
-            fr_YT => "+",
-            fur => "+",
-            fy => "+",
-            ga => "+",
-            ga_GB => "+",
-            gd => "+",
-            gl => "+",
-            gsw => "+",
-            gsw_FR => "+",
-            gsw_LI => "+",
-            gu => "+",
-            guz => "+",
-            gv => "+",
-            ha => "+",
-            ha_GH => "+",
-            ha_NE => "+",
-            haw => "+",
-            he => "\u{200e}+",
-            hi => "+",
-            hr => "+",
-            hr_BA => "+",
-            hsb => "+",
-            hu => "+",
-            hy => "+",
-            ia => "+",
-            id => "+",
-            ig => "+",
-            ii => "+",
-            is => "+",
-            it => "+",
-            it_CH => "+",
-            it_SM => "+",
-            it_VA => "+",
-            ja => "+",
-            jgo => "+",
-            jmc => "+",
-            jv => "+",
-            ka => "+",
-            kab => "+",
-            kam => "+",
-            kde => "+",
-            kea => "+",
-            khq => "+",
-            ki => "+",
-            kk => "+",
-            kkj => "+",
-            kl => "+",
-            kln => "+",
-            km => "+",
-            kn => "+",
-            ko => "+",
-            ko_KP => "+",
-            kok => "+",
-            ks => "\u{200e}+\u{200e}",
-            ksb => "+",
-            ksf => "+",
-            ksh => "+",
-            ku => "+",
-            kw => "+",
-            ky => "+",
-            lag => "+",
-            lb => "+",
-            lg => "+",
-            lkt => "+",
-            ln => "+",
-            ln_AO => "+",
-            ln_CF => "+",
-            ln_CG => "+",
-            lo => "+",
-            lrc => "\u{200e}+\u{200e}",
-            lrc_IQ => "\u{200e}+\u{200e}",
-            lt => "+",
-            lu => "+",
-            luo => "+",
-            luy => "+",
-            lv => "+",
-            mas => "+",
-            mas_TZ => "+",
-            mer => "+",
-            mfe => "+",
-            mg => "+",
-            mgh => "+",
-            mgo => "+",
-            mi => "+",
-            mk => "+",
-            ml => "+",
-            mn => "+",
-            mr => "+",
-            ms => "+",
-            ms_BN => "+",
-            ms_SG => "+",
-            mt => "+",
-            mua => "+",
-            my => "+",
-            mzn => "\u{200e}+\u{200e}",
-            naq => "+",
-            nb => "+",
-            nb_SJ => "+",
-            nd => "+",
-            nds => "+",
-            nds_NL => "+",
-            ne => "+",
-            ne_IN => "+",
-            nl => "+",
-            nl_AW => "+",
-            nl_BE => "+",
-            nl_BQ => "+",
-            nl_CW => "+",
-            nl_SR => "+",
-            nl_SX => "+",
-            nmg => "+",
-            nn => "+",
-            nnh => "+",
-            nus => "+",
-            nyn => "+",
-            om => "+",
-            om_KE => "+",
-            or => "+",
-            os => "+",
-            os_RU => "+",
-            pa => "+",
-            pa_Arab => "\u{200e}+\u{200e}",
-            pa_Guru => "+",
-            pl => "+",
-            prg => "+",
-            ps => "\u{200e}+\u{200e}",
-            ps_PK => "\u{200e}+\u{200e}",
-            pt => "+",
-            pt_AO => "+",
-            pt_CH => "+",
-            pt_CV => "+",
-            pt_GQ => "+",
-            pt_GW => "+",
-            pt_LU => "+",
-            pt_MO => "+",
-            pt_MZ => "+",
-            pt_PT => "+",
-            pt_ST => "+",
-            pt_TL => "+",
-            qu => "+",
-            qu_BO => "+",
-            qu_EC => "+",
-            rm => "+",
-            rn => "+",
-            ro => "+",
-            ro_MD => "+",
-            rof => "+",
-            root => "+",
-            ru => "+",
-            ru_BY => "+",
-            ru_KG => "+",
-            ru_KZ => "+",
-            ru_MD => "+",
-            ru_UA => "+",
-            rw => "+",
-            rwk => "+",
-            sah => "+",
-            saq => "+",
-            sbp => "+",
-            sd => "\u{61c}+",
-            se => "+",
-            se_FI => "+",
-            se_SE => "+",
-            seh => "+",
-            ses => "+",
-            sg => "+",
-            shi => "+",
-            shi_Latn => "+",
-            shi_Tfng => "+",
-            si => "+",
-            sk => "+",
-            sl => "+",
-            smn => "+",
-            sn => "+",
-            so => "+",
-            so_DJ => "+",
-            so_ET => "+",
-            so_KE => "+",
-            sq => "+",
-            sq_MK => "+",
-            sq_XK => "+",
-            sr => "+",
-            sr_Cyrl => "+",
-            sr_Cyrl_BA => "+",
-            sr_Cyrl_ME => "+",
-            sr_Cyrl_XK => "+",
-            sr_Latn => "+",
-            sr_Latn_BA => "+",
-            sr_Latn_ME => "+",
-            sr_Latn_XK => "+",
-            sv => "+",
-            sv_AX => "+",
-            sv_FI => "+",
-            sw => "+",
-            sw_CD => "+",
-            sw_KE => "+",
-            sw_UG => "+",
-            ta => "+",
-            ta_LK => "+",
-            ta_MY => "+",
-            ta_SG => "+",
-            te => "+",
-            teo => "+",
-            teo_KE => "+",
-            tg => "+",
-            th => "+",
-            ti => "+",
-            ti_ER => "+",
-            tk => "+",
-            to => "+",
-            tr => "+",
-            tr_CY => "+",
-            tt => "+",
-            twq => "+",
-            tzm => "+",
-            ug => "+",
-            uk => "+",
-            ur => "\u{200e}+",
-            ur_IN => "\u{200e}+\u{200e}",
-            uz => "+",
-            uz_Arab => "\u{200e}+\u{200e}",
-            uz_Cyrl => "+",
-            uz_Latn => "+",
-            vai => "+",
-            vai_Latn => "+",
-            vai_Vaii => "+",
-            vi => "+",
-            vo => "+",
-            vun => "+",
-            wae => "+",
-            wo => "+",
-            xh => "+",
-            xog => "+",
-            yav => "+",
-            yi => "+",
-            yo => "+",
-            yo_BJ => "+",
-            yue => "+",
-            yue_Hans => "+",
-            yue_Hant => "+",
-            zgh => "+",
-            zh => "+",
-            zh_Hans => "+",
-            zh_Hans_HK => "+",
-            zh_Hans_MO => "+",
-            zh_Hans_SG => "+",
-            zh_Hant => "+",
-            zh_Hant_HK => "+",
-            zh_Hant_MO => "+",
-            zu => "+",
-        }
-    }
-    #[doc = r" Returns the locale's separator representation, if any."]
-    pub fn separator(&self) -> &'static str {
-        use self::Locale::*;
-        match self {
-            af => "\u{a0}",
-            af_NA => "\u{a0}",
-            agq => "\u{a0}",
-            ak => ",",
-            am => ",",
-            ar => "٬",
-            ar_AE => "٬",
-            ar_BH => "٬",
-            ar_DJ => "٬",
-            ar_DZ => ".",
-            ar_EG => "٬",
-            ar_EH => ",",
-            ar_ER => "٬",
-            ar_IL => "٬",
-            ar_IQ => "٬",
-            ar_JO => "٬",
-            ar_KM => "٬",
-            ar_KW => "٬",
-            ar_LB => "٬",
-            ar_LY => ".",
-            ar_MA => ".",
-            ar_MR => "٬",
-            ar_OM => "٬",
-            ar_PS => "٬",
-            ar_QA => "٬",
-            ar_SA => "٬",
-            ar_SD => "٬",
-            ar_SO => "٬",
-            ar_SS => "٬",
-            ar_SY => "٬",
-            ar_TD => "٬",
-            ar_TN => ".",
-            ar_YE => "٬",
-            as_ => ",",
-            asa => ",",
-            ast => ".",
-            az => ".",
-            az_Cyrl => ".",
-            az_Latn => ".",
-            bas => "\u{a0}",
-            be => "\u{a0}",
-            bem => ",",
-            bez => ",",
-            bg => "\u{a0}",
-            bm => ",",
-            bn => ",",
-            bn_IN => ",",
-            bo => ",",
-            bo_IN => ",",
-            br => "\u{a0}",
-            brx => ",",
-            bs => ".",
-            bs_Cyrl => ".",
-            bs_Latn => ".",
-            ca => ".",
-            ca_AD => ".",
-            ca_ES_VALENCIA => ".",
-            ca_FR => ".",
-            ca_IT => ".",
-            ccp => ",",
-            ccp_IN => ",",
-            ce => ",",
-            ceb => ",",
-            cgg => ",",
-            chr => ",",
-            ckb => "٬",
-            ckb_IR => "٬",
-            cs => "\u{a0}",
-            cu => ",",
-            cy => ",",
-            da => ".",
-            da_GL => ".",
-            dav => ",",
-            de => ".",
-            de_AT => "\u{a0}",
-            de_BE => ".",
-            de_CH => "’",
-            de_IT => ".",
-            de_LI => "’",
-            de_LU => ".",
-            dje => "\u{a0}",
-            dsb => ".",
-            dua => "\u{a0}",
-            dyo => "\u{a0}",
-            dz => ",",
-            ebu => ",",
-            ee => ",",
-            ee_TG => ",",
-            el => ".",
-            el_CY => ".",
-            en => ",",
-            en_001 => ",",
-            en_150 => ",",
-            en_AE => ",",
-            en_AG => ",",
-            en_AI => ",",
-            en_AS => ",",
-            en_AT => ".",
-            en_AU => ",",
-            en_BB => ",",
-            en_BE => ".",
-            en_BI => ",",
-            en_BM => ",",
-            en_BS => ",",
-            en_BW => ",",
-            en_BZ => ",",
-            en_CA => ",",
-            en_CC => ",",
-            en_CH => "’",
-            en_CK => ",",
-            en_CM => ",",
-            en_CX => ",",
-            en_CY => ",",
-            en_DE => ".",
-            en_DG => ",",
-            en_DK => ".",
-            en_DM => ",",
-            en_ER => ",",
-            en_FI => "\u{a0}",
-            en_FJ => ",",
-            en_FK => ",",
-            en_FM => ",",
-            en_GB => ",",
-            en_GD => ",",
-            en_GG => ",",
-            en_GH => ",",
-            en_GI => ",",
-            en_GM => ",",
-            en_GU => ",",
-            en_GY => ",",
-            en_HK => ",",
-            en_IE => ",",
-            en_IL => ",",
-            en_IM => ",",
-            en_IN => ",",
-            en_IO => ",",
-            en_JE => ",",
-            en_JM => ",",
-            en_KE => ",",
-            en_KI => ",",
-            en_KN => ",",
-            en_KY => ",",
-            en_LC => ",",
-            en_LR => ",",
-            en_LS => ",",
-            en_MG => ",",
-            en_MH => ",",
-            en_MO => ",",
-            en_MP => ",",
-            en_MS => ",",
-            en_MT => ",",
-            en_MU => ",",
-            en_MW => ",",
-            en_MY => ",",
-            en_NA => ",",
-            en_NF => ",",
-            en_NG => ",",
-            en_NL => ".",
-            en_NR => ",",
-            en_NU => ",",
-            en_NZ => ",",
-            en_PG => ",",
-            en_PH => ",",
-            en_PK => ",",
-            en_PN => ",",
-            en_PR => ",",
-            en_PW => ",",
-            en_RW => ",",
-            en_SB => ",",
-            en_SC => ",",
-            en_SD => ",",
-            en_SE => "\u{a0}",
-            en_SG => ",",
-            en_SH => ",",
-            en_SI => ".",
-            en_SL => ",",
-            en_SS => ",",
-            en_SX => ",",
-            en_SZ => ",",
-            en_TC => ",",
-            en_TK => ",",
-            en_TO => ",",
-            en_TT => ",",
-            en_TV => ",",
-            en_TZ => ",",
-            en_UG => ",",
-            en_UM => ",",
-            en_US_POSIX => ",",
-            en_VC => ",",
-            en_VG => ",",
-            en_VI => ",",
-            en_VU => ",",
-            en_WS => ",",
-            en_ZA => "\u{a0}",
-            en_ZM => ",",
-            en_ZW => ",",
-            eo => "\u{a0}",
-            es => ".",
-            es_419 => ",",
-            es_AR => ".",
-            es_BO => ".",
-            es_BR => ",",
-            es_BZ => ",",
-            es_CL => ".",
-            es_CO => ".",
-            es_CR => "\u{a0}",
-            es_CU => ",",
-            es_DO => ",",
-            es_EA => ".",
-            es_EC => ".",
-            es_GQ => ".",
-            es_GT => ",",
-            es_HN => ",",
-            es_IC => ".",
-            es_MX => ",",
-            es_NI => ",",
-            es_PA => ",",
-            es_PE => ",",
-            es_PH => ".",
-            es_PR => ",",
-            es_PY => ".",
-            es_SV => ",",
-            es_US => ",",
-            es_UY => ".",
-            es_VE => ".",
-            et => "\u{a0}",
-            eu => ".",
-            ewo => "\u{a0}",
-            fa => "٬",
-            fa_AF => "٬",
-            ff => "\u{a0}",
-            ff_Latn => "\u{a0}",
-            ff_Latn_BF => "\u{a0}",
-            ff_Latn_CM => "\u{a0}",
-            ff_Latn_GH => "\u{a0}",
-            ff_Latn_GM => "\u{a0}",
-            ff_Latn_GN => "\u{a0}",
-            ff_Latn_GW => "\u{a0}",
-            ff_Latn_LR => "\u{a0}",
-            ff_Latn_MR => "\u{a0}",
-            ff_Latn_NE => "\u{a0}",
-            ff_Latn_NG => "\u{a0}",
-            ff_Latn_SL => "\u{a0}",
-            fi => "\u{a0}",
-            fil => ",",
-            fo => ".",
-            fo_DK => ".",
-            fr => "\u{202f}",
-            fr_BE => "\u{202f}",
-            fr_BF => "\u{202f}",
-            fr_BI => "\u{202f}",
-            fr_BJ => "\u{202f}",
-            fr_BL => "\u{202f}",
-            fr_CA => "\u{a0}",
-            fr_CD => "\u{202f}",
-            fr_CF => "\u{202f}",
-            fr_CG => "\u{202f}",
-            fr_CH => "\u{202f}",
-            fr_CI => "\u{202f}",
-            fr_CM => "\u{202f}",
-            fr_DJ => "\u{202f}",
-            fr_DZ => "\u{202f}",
-            fr_GA => "\u{202f}",
-            fr_GF => "\u{202f}",
-            fr_GN => "\u{202f}",
-            fr_GP => "\u{202f}",
-            fr_GQ => "\u{202f}",
-            fr_HT => "\u{202f}",
-            fr_KM => "\u{202f}",
-            fr_LU => ".",
-            fr_MA => ".",
-            fr_MC => "\u{202f}",
-            fr_MF => "\u{202f}",
-            fr_MG => "\u{202f}",
-            fr_ML => "\u{202f}",
-            fr_MQ => "\u{202f}",
-            fr_MR => "\u{202f}",
-            fr_MU => "\u{202f}",
-            fr_NC => "\u{202f}",
-            fr_NE => "\u{202f}",
-            fr_PF => "\u{202f}",
-            fr_PM => "\u{202f}",
-            fr_RE => "\u{202f}",
-            fr_RW => "\u{202f}",
-            fr_SC => "\u{202f}",
-            fr_SN => "\u{202f}",
-            fr_SY => "\u{202f}",
-            fr_TD => "\u{202f}",
-            fr_TG => "\u{202f}",
-            fr_TN => "\u{202f}",
-            fr_VU => "\u{202f}",
-            fr_WF => "\u{202f}",
-            fr_YT => "\u{202f}",
-            fur => ".",
-            fy => ".",
-            ga => ",",
-            ga_GB => ",",
-            gd => ",",
-            gl => ".",
-            gsw => "’",
-            gsw_FR => "’",
-            gsw_LI => "’",
-            gu => ",",
-            guz => ",",
-            gv => ",",
-            ha => ",",
-            ha_GH => ",",
-            ha_NE => ",",
-            haw => ",",
-            he => ",",
-            hi => ",",
-            hr => ".",
-            hr_BA => ".",
-            hsb => ".",
-            hu => "\u{a0}",
-            hy => "\u{a0}",
-            ia => ".",
-            id => ".",
-            ig => ",",
-            ii => ",",
-            is => ".",
-            it => ".",
-            it_CH => "’",
-            it_SM => ".",
-            it_VA => ".",
-            ja => ",",
-            jgo => ".",
-            jmc => ",",
-            jv => ".",
-            ka => "\u{a0}",
-            kab => "\u{a0}",
-            kam => ",",
-            kde => ",",
-            kea => "\u{a0}",
-            khq => "\u{a0}",
-            ki => ",",
-            kk => "\u{a0}",
-            kkj => ".",
-            kl => ".",
-            kln => ",",
-            km => ".",
-            kn => ",",
-            ko => ",",
-            ko_KP => ",",
-            kok => ",",
-            ks => "٬",
-            ksb => ",",
-            ksf => "\u{a0}",
-            ksh => "\u{a0}",
-            ku => ".",
-            kw => ",",
-            ky => "\u{a0}",
-            lag => ",",
-            lb => ".",
-            lg => ",",
-            lkt => ",",
-            ln => ".",
-            ln_AO => ".",
-            ln_CF => ".",
-            ln_CG => ".",
-            lo => ".",
-            lrc => "٬",
-            lrc_IQ => "٬",
-            lt => "\u{a0}",
-            lu => ".",
-            luo => ",",
-            luy => ",",
-            lv => "\u{a0}",
-            mas => ",",
-            mas_TZ => ",",
-            mer => ",",
-            mfe => "\u{a0}",
-            mg => ",",
-            mgh => ".",
-            mgo => ",",
-            mi => ",",
-            mk => ".",
-            ml => ",",
-            mn => ",",
-            mr => ",",
-            ms => ",",
-            ms_BN => ".",
-            ms_SG => ",",
-            mt => ",",
-            mua => ".",
-            my => ",",
-            mzn => "٬",
-            naq => ",",
-            nb => "\u{a0}",
-            nb_SJ => "\u{a0}",
-            nd => ",",
-            nds => ",",
-            nds_NL => ",",
-            ne => ",",
-            ne_IN => ",",
-            nl => ".",
-            nl_AW => ".",
-            nl_BE => ".",
-            nl_BQ => ".",
-            nl_CW => ".",
-            nl_SR => ".",
-            nl_SX => ".",
-            nmg => "\u{a0}",
-            nn => "\u{a0}",
-            nnh => ".",
-            nus => ",",
-            nyn => ",",
-            om => ",",
-            om_KE => ",",
-            or => ",",
-            os => "\u{a0}",
-            os_RU => "\u{a0}",
-            pa => ",",
-            pa_Arab => "٬",
-            pa_Guru => ",",
-            pl => "\u{a0}",
-            prg => ",",
-            ps => "٬",
-            ps_PK => "٬",
-            pt => ".",
-            pt_AO => "\u{a0}",
-            pt_CH => "\u{a0}",
-            pt_CV => "\u{a0}",
-            pt_GQ => "\u{a0}",
-            pt_GW => "\u{a0}",
-            pt_LU => "\u{a0}",
-            pt_MO => "\u{a0}",
-            pt_MZ => "\u{a0}",
-            pt_PT => "\u{a0}",
-            pt_ST => "\u{a0}",
-            pt_TL => "\u{a0}",
-            qu => ",",
-            qu_BO => ".",
-            qu_EC => ",",
-            rm => "’",
-            rn => ".",
-            ro => ".",
-            ro_MD => ".",
-            rof => ",",
-            root => ",",
-            ru => "\u{a0}",
-            ru_BY => "\u{a0}",
-            ru_KG => "\u{a0}",
-            ru_KZ => "\u{a0}",
-            ru_MD => "\u{a0}",
-            ru_UA => "\u{a0}",
-            rw => ".",
-            rwk => ",",
-            sah => "\u{a0}",
-            saq => ",",
-            sbp => ",",
-            sd => "٬",
-            se => "\u{a0}",
-            se_FI => "\u{a0}",
-            se_SE => "\u{a0}",
-            seh => ".",
-            ses => "\u{a0}",
-            sg => ".",
-            shi => "\u{a0}",
-            shi_Latn => "\u{a0}",
-            shi_Tfng => "\u{a0}",
-            si => ",",
-            sk => "\u{a0}",
-            sl => ".",
-            smn => "\u{a0}",
-            sn => ",",
-            so => ",",
-            so_DJ => ",",
-            so_ET => ",",
-            so_KE => ",",
-            sq => "\u{a0}",
-            sq_MK => "\u{a0}",
-            sq_XK => "\u{a0}",
-            sr => ".",
-            sr_Cyrl => ".",
-            sr_Cyrl_BA => ".",
-            sr_Cyrl_ME => ".",
-            sr_Cyrl_XK => ".",
-            sr_Latn => ".",
-            sr_Latn_BA => ".",
-            sr_Latn_ME => ".",
-            sr_Latn_XK => ".",
-            sv => "\u{a0}",
-            sv_AX => "\u{a0}",
-            sv_FI => "\u{a0}",
-            sw => ",",
-            sw_CD => ".",
-            sw_KE => ",",
-            sw_UG => ",",
-            ta => ",",
-            ta_LK => ",",
-            ta_MY => ",",
-            ta_SG => ",",
-            te => ",",
-            teo => ",",
-            teo_KE => ",",
-            tg => "\u{a0}",
-            th => ",",
-            ti => ",",
-            ti_ER => ",",
-            tk => "\u{a0}",
-            to => ",",
-            tr => ".",
-            tr_CY => ".",
-            tt => "\u{a0}",
-            twq => "\u{a0}",
-            tzm => "\u{a0}",
-            ug => ",",
-            uk => "\u{a0}",
-            ur => ",",
-            ur_IN => "٬",
-            uz => "\u{a0}",
-            uz_Arab => "٬",
-            uz_Cyrl => "\u{a0}",
-            uz_Latn => "\u{a0}",
-            vai => ",",
-            vai_Latn => ",",
-            vai_Vaii => ",",
-            vi => ".",
-            vo => ",",
-            vun => ",",
-            wae => "’",
-            wo => ".",
-            xh => "\u{a0}",
-            xog => ",",
-            yav => "\u{a0}",
-            yi => ",",
-            yo => ",",
-            yo_BJ => ",",
-            yue => ",",
-            yue_Hans => ",",
-            yue_Hant => ",",
-            zgh => "\u{a0}",
-            zh => ",",
-            zh_Hans => ",",
-            zh_Hans_HK => ",",
-            zh_Hans_MO => ",",
-            zh_Hans_SG => ",",
-            zh_Hant => ",",
-            zh_Hant_HK => ",",
-            zh_Hant_MO => ",",
-            zu => ",",
-        }
-    }
-}
-impl Format for Locale {
-    #[inline(always)]
-    fn decimal(&self) -> DecimalStr<'_> {
-        DecimalStr::new(self.decimal()).unwrap()
-    }
-    #[inline(always)]
-    fn grouping(&self) -> Grouping {
-        self.grouping()
-    }
-    #[inline(always)]
-    fn infinity(&self) -> InfinityStr<'_> {
-        InfinityStr::new(self.infinity()).unwrap()
-    }
-    #[inline(always)]
-    fn minus_sign(&self) -> MinusSignStr<'_> {
-        MinusSignStr::new(self.minus_sign()).unwrap()
-    }
-    #[inline(always)]
-    fn nan(&self) -> NanStr<'_> {
-        NanStr::new(self.nan()).unwrap()
-    }
-    #[inline(always)]
-    fn plus_sign(&self) -> PlusSignStr<'_> {
-        PlusSignStr::new(self.plus_sign()).unwrap()
-    }
-    #[inline(always)]
-    fn separator(&self) -> SeparatorStr<'_> {
-        SeparatorStr::new(self.separator()).unwrap()
+        Locale::from_exact_name(name)
+            .or_else(|| locale_tag::resolve(name))
+            .ok_or_else(|| Error::parse_locale(name))
     }
-}
-impl FromStr for Locale {
-    type Err = Error;
-    #[doc = r" Same as [`from_name`]."]
-    #[doc = r""]
-    #[doc = r" [`from_name`]: enum.Locale.html#method.from_name"]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    pub(crate) fn from_exact_name(s: &str) -> Option<Locale> {
         use self::Locale::*;
         let locale = match s {
+            #[cfg(locales_other)]
             "af" => af,
+            #[cfg(locales_other)]
             "af-NA" => af_NA,
+            #[cfg(locales_other)]
             "af_NA" => af_NA,
+            #[cfg(locales_other)]
             "agq" => agq,
+            #[cfg(locales_other)]
             "ak" => ak,
+            #[cfg(locales_other)]
             "am" => am,
+            #[cfg(locales_other)]
             "ar" => ar,
+            #[cfg(locales_other)]
             "ar-AE" => ar_AE,
+            #[cfg(locales_other)]
             "ar_AE" => ar_AE,
+            #[cfg(locales_other)]
             "ar-BH" => ar_BH,
+            #[cfg(locales_other)]
             "ar_BH" => ar_BH,
+            #[cfg(locales_other)]
             "ar-DJ" => ar_DJ,
+            #[cfg(locales_other)]
             "ar_DJ" => ar_DJ,
+            #[cfg(locales_other)]
             "ar-DZ" => ar_DZ,
+            #[cfg(locales_other)]
             "ar_DZ" => ar_DZ,
+            #[cfg(locales_other)]
             "ar-EG" => ar_EG,
+            #[cfg(locales_other)]
             "ar_EG" => ar_EG,
+            #[cfg(locales_other)]
             "ar-EH" => ar_EH,
+            #[cfg(locales_other)]
             "ar_EH" => ar_EH,
+            #[cfg(locales_other)]
             "ar-ER" => ar_ER,
+            #[cfg(locales_other)]
             "ar_ER" => ar_ER,
+            #[cfg(locales_other)]
             "ar-IL" => ar_IL,
+            #[cfg(locales_other)]
             "ar_IL" => ar_IL,
+            #[cfg(locales_other)]
             "ar-IQ" => ar_IQ,
+            #[cfg(locales_other)]
             "ar_IQ" => ar_IQ,
+            #[cfg(locales_other)]
             "ar-JO" => ar_JO,
+            #[cfg(locales_other)]
             "ar_JO" => ar_JO,
+            #[cfg(locales_other)]
             "ar-KM" => ar_KM,
+            #[cfg(locales_other)]
             "ar_KM" => ar_KM,
+            #[cfg(locales_other)]
             "ar-KW" => ar_KW,
+            #[cfg(locales_other)]
             "ar_KW" => ar_KW,
+            #[cfg(locales_other)]
             "ar-LB" => ar_LB,
+            #[cfg(locales_other)]
             "ar_LB" => ar_LB,
+            #[cfg(locales_other)]
             "ar-LY" => ar_LY,
+            #[cfg(locales_other)]
             "ar_LY" => ar_LY,
+            #[cfg(locales_other)]
             "ar-MA" => ar_MA,
+            #[cfg(locales_other)]
             "ar_MA" => ar_MA,
+            #[cfg(locales_other)]
             "ar-MR" => ar_MR,
+            #[cfg(locales_other)]
             "ar_MR" => ar_MR,
+            #[cfg(locales_other)]
             "ar-OM" => ar_OM,
+            #[cfg(locales_other)]
             "ar_OM" => ar_OM,
+            #[cfg(locales_other)]
             "ar-PS" => ar_PS,
+            #[cfg(locales_other)]
             "ar_PS" => ar_PS,
+            #[cfg(locales_other)]
             "ar-QA" => ar_QA,
+            #[cfg(locales_other)]
             "ar_QA" => ar_QA,
+            #[cfg(locales_other)]
             "ar-SA" => ar_SA,
+            #[cfg(locales_other)]
             "ar_SA" => ar_SA,
+            #[cfg(locales_other)]
             "ar-SD" => ar_SD,
+            #[cfg(locales_other)]
             "ar_SD" => ar_SD,
+            #[cfg(locales_other)]
             "ar-SO" => ar_SO,
+            #[cfg(locales_other)]
             "ar_SO" => ar_SO,
+            #[cfg(locales_other)]
             "ar-SS" => ar_SS,
+            #[cfg(locales_other)]
             "ar_SS" => ar_SS,
+            #[cfg(locales_other)]
             "ar-SY" => ar_SY,
+            #[cfg(locales_other)]
             "ar_SY" => ar_SY,
+            #[cfg(locales_other)]
             "ar-TD" => ar_TD,
+            #[cfg(locales_other)]
             "ar_TD" => ar_TD,
+            #[cfg(locales_other)]
             "ar-TN" => ar_TN,
+            #[cfg(locales_other)]
             "ar_TN" => ar_TN,
+            #[cfg(locales_other)]
             "ar-YE" => ar_YE,
+            #[cfg(locales_other)]
             "ar_YE" => ar_YE,
+            #[cfg(locales_other)]
             "as" => as_,
+            #[cfg(locales_other)]
             "asa" => asa,
+            #[cfg(locales_other)]
             "ast" => ast,
+            #[cfg(locales_other)]
             "az" => az,
+            #[cfg(locales_other)]
             "az-Cyrl" => az_Cyrl,
+            #[cfg(locales_other)]
             "az_Cyrl" => az_Cyrl,
+            #[cfg(locales_other)]
             "az-Latn" => az_Latn,
+            #[cfg(locales_other)]
             "az_Latn" => az_Latn,
+            #[cfg(locales_other)]
             "bas" => bas,
+            #[cfg(locales_other)]
             "be" => be,
+            #[cfg(locales_other)]
             "bem" => bem,
+            #[cfg(locales_other)]
             "bez" => bez,
+            #[cfg(locales_eu)]
             "bg" => bg,
+            #[cfg(locales_other)]
             "bm" => bm,
+            #[cfg(locales_other)]
             "bn" => bn,
+            #[cfg(locales_other)]
             "bn-IN" => bn_IN,
+            #[cfg(locales_other)]
             "bn_IN" => bn_IN,
+            #[cfg(locales_other)]
             "bo" => bo,
+            #[cfg(locales_other)]
             "bo-IN" => bo_IN,
+            #[cfg(locales_other)]
             "bo_IN" => bo_IN,
+            #[cfg(locales_other)]
             "br" => br,
+            #[cfg(locales_other)]
             "brx" => brx,
+            #[cfg(locales_other)]
             "bs" => bs,
+            #[cfg(locales_other)]
             "bs-Cyrl" => bs_Cyrl,
+            #[cfg(locales_other)]
             "bs_Cyrl" => bs_Cyrl,
+            #[cfg(locales_other)]
             "bs-Latn" => bs_Latn,
+            #[cfg(locales_other)]
             "bs_Latn" => bs_Latn,
+            #[cfg(locales_other)]
             "ca" => ca,
+            #[cfg(locales_other)]
             "ca-AD" => ca_AD,
+            #[cfg(locales_other)]
             "ca_AD" => ca_AD,
+            #[cfg(locales_other)]
             "ca-ES-VALENCIA" => ca_ES_VALENCIA,
+            #[cfg(locales_other)]
             "ca_ES_VALENCIA" => ca_ES_VALENCIA,
+            #[cfg(locales_other)]
             "ca-FR" => ca_FR,
+            #[cfg(locales_other)]
             "ca_FR" => ca_FR,
+            #[cfg(locales_other)]
             "ca-IT" => ca_IT,
+            #[cfg(locales_other)]
             "ca_IT" => ca_IT,
+            #[cfg(locales_other)]
             "ccp" => ccp,
+            #[cfg(locales_other)]
             "ccp-IN" => ccp_IN,
+            #[cfg(locales_other)]
             "ccp_IN" => ccp_IN,
+            #[cfg(locales_other)]
             "ce" => ce,
+            #[cfg(locales_other)]
             "ceb" => ceb,
+            #[cfg(locales_other)]
             "cgg" => cgg,
+            #[cfg(locales_other)]
             "chr" => chr,
+            #[cfg(locales_other)]
             "ckb" => ckb,
+            #[cfg(locales_other)]
             "ckb-IR" => ckb_IR,
+            #[cfg(locales_other)]
             "ckb_IR" => ckb_IR,
+            #[cfg(locales_eu)]
             "cs" => cs,
+            #[cfg(locales_other)]
             "cu" => cu,
+            #[cfg(locales_other)]
             "cy" => cy,
+            #[cfg(locales_eu)]
             "da" => da,
+            #[cfg(locales_eu)]
             "da-GL" => da_GL,
+            #[cfg(locales_eu)]
             "da_GL" => da_GL,
+            #[cfg(locales_other)]
             "dav" => dav,
+            #[cfg(locales_eu)]
             "de" => de,
+            #[cfg(locales_eu)]
             "de-AT" => de_AT,
+            #[cfg(locales_eu)]
             "de_AT" => de_AT,
+            #[cfg(locales_eu)]
             "de-BE" => de_BE,
+            #[cfg(locales_eu)]
             "de_BE" => de_BE,
+            #[cfg(locales_eu)]
             "de-CH" => de_CH,
+            #[cfg(locales_eu)]
             "de_CH" => de_CH,
+            #[cfg(locales_eu)]
             "de-IT" => de_IT,
+            #[cfg(locales_eu)]
             "de_IT" => de_IT,
+            #[cfg(locales_eu)]
             "de-LI" => de_LI,
+            #[cfg(locales_eu)]
             "de_LI" => de_LI,
+            #[cfg(locales_eu)]
             "de-LU" => de_LU,
+            #[cfg(locales_eu)]
             "de_LU" => de_LU,
+            #[cfg(locales_other)]
             "dje" => dje,
+            #[cfg(locales_other)]
             "dsb" => dsb,
+            #[cfg(locales_other)]
             "dua" => dua,
+            #[cfg(locales_other)]
             "dyo" => dyo,
+            #[cfg(locales_other)]
             "dz" => dz,
+            #[cfg(locales_other)]
             "ebu" => ebu,
+            #[cfg(locales_other)]
             "ee" => ee,
+            #[cfg(locales_other)]
             "ee-TG" => ee_TG,
+            #[cfg(locales_other)]
             "ee_TG" => ee_TG,
+            #[cfg(locales_eu)]
             "el" => el,
+            #[cfg(locales_eu)]
             "el-CY" => el_CY,
+            #[cfg(locales_eu)]
             "el_CY" => el_CY,
+            #[cfg(locales_en)]
             "en" => en,
+            #[cfg(locales_en)]
             "en-001" => en_001,
+            #[cfg(locales_en)]
             "en_001" => en_001,
+            #[cfg(locales_en)]
             "en-150" => en_150,
+            #[cfg(locales_en)]
             "en_150" => en_150,
+            #[cfg(locales_en)]
             "en-AE" => en_AE,
+            #[cfg(locales_en)]
             "en_AE" => en_AE,
+            #[cfg(locales_en)]
             "en-AG" => en_AG,
+            #[cfg(locales_en)]
             "en_AG" => en_AG,
+            #[cfg(locales_en)]
             "en-AI" => en_AI,
+            #[cfg(locales_en)]
             "en_AI" => en_AI,
+            #[cfg(locales_en)]
             "en-AS" => en_AS,
+            #[cfg(locales_en)]
             "en_AS" => en_AS,
+            #[cfg(locales_en)]
             "en-AT" => en_AT,
+            #[cfg(locales_en)]
             "en_AT" => en_AT,
+            #[cfg(locales_en)]
             "en-AU" => en_AU,
+            #[cfg(locales_en)]
             "en_AU" => en_AU,
+            #[cfg(locales_en)]
             "en-BB" => en_BB,
+            #[cfg(locales_en)]
             "en_BB" => en_BB,
+            #[cfg(locales_en)]
             "en-BE" => en_BE,
+            #[cfg(locales_en)]
             "en_BE" => en_BE,
+            #[cfg(locales_en)]
             "en-BI" => en_BI,
+            #[cfg(locales_en)]
             "en_BI" => en_BI,
+            #[cfg(locales_en)]
             "en-BM" => en_BM,
+            #[cfg(locales_en)]
             "en_BM" => en_BM,
+            #[cfg(locales_en)]
             "en-BS" => en_BS,
+            #[cfg(locales_en)]
             "en_BS" => en_BS,
+            #[cfg(locales_en)]
             "en-BW" => en_BW,
+            #[cfg(locales_en)]
             "en_BW" => en_BW,
+            #[cfg(locales_en)]
             "en-BZ" => en_BZ,
+            #[cfg(locales_en)]
             "en_BZ" => en_BZ,
+            #[cfg(locales_en)]
             "en-CA" => en_CA,
+            #[cfg(locales_en)]
             "en_CA" => en_CA,
+            #[cfg(locales_en)]
             "en-CC" => en_CC,
+            #[cfg(locales_en)]
             "en_CC" => en_CC,
+            #[cfg(locales_en)]
             "en-CH" => en_CH,
+            #[cfg(locales_en)]
             "en_CH" => en_CH,
+            #[cfg(locales_en)]
             "en-CK" => en_CK,
+            #[cfg(locales_en)]
             "en_CK" => en_CK,
+            #[cfg(locales_en)]
             "en-CM" => en_CM,
+            #[cfg(locales_en)]
             "en_CM" => en_CM,
+            #[cfg(locales_en)]
             "en-CX" => en_CX,
+            #[cfg(locales_en)]
             "en_CX" => en_CX,
+            #[cfg(locales_en)]
             "en-CY" => en_CY,
+            #[cfg(locales_en)]
             "en_CY" => en_CY,
+            #[cfg(locales_en)]
             "en-DE" => en_DE,
+            #[cfg(locales_en)]
             "en_DE" => en_DE,
+            #[cfg(locales_en)]
             "en-DG" => en_DG,
+            #[cfg(locales_en)]
             "en_DG" => en_DG,
+            #[cfg(locales_en)]
             "en-DK" => en_DK,
+            #[cfg(locales_en)]
             "en_DK" => en_DK,
+            #[cfg(locales_en)]
             "en-DM" => en_DM,
+            #[cfg(locales_en)]
             "en_DM" => en_DM,
+            #[cfg(locales_en)]
             "en-ER" => en_ER,
+            #[cfg(locales_en)]
             "en_ER" => en_ER,
+            #[cfg(locales_en)]
             "en-FI" => en_FI,
+            #[cfg(locales_en)]
             "en_FI" => en_FI,
+            #[cfg(locales_en)]
             "en-FJ" => en_FJ,
+            #[cfg(locales_en)]
             "en_FJ" => en_FJ,
+            #[cfg(locales_en)]
             "en-FK" => en_FK,
+            #[cfg(locales_en)]
             "en_FK" => en_FK,
+            #[cfg(locales_en)]
             "en-FM" => en_FM,
+            #[cfg(locales_en)]
             "en_FM" => en_FM,
+            #[cfg(locales_en)]
             "en-GB" => en_GB,
+            #[cfg(locales_en)]
             "en_GB" => en_GB,
+            #[cfg(locales_en)]
             "en-GD" => en_GD,
+            #[cfg(locales_en)]
             "en_GD" => en_GD,
+            #[cfg(locales_en)]
             "en-GG" => en_GG,
+            #[cfg(locales_en)]
             "en_GG" => en_GG,
+            #[cfg(locales_en)]
             "en-GH" => en_GH,
+            #[cfg(locales_en)]
             "en_GH" => en_GH,
+            #[cfg(locales_en)]
             "en-GI" => en_GI,
+            #[cfg(locales_en)]
             "en_GI" => en_GI,
+            #[cfg(locales_en)]
             "en-GM" => en_GM,
+            #[cfg(locales_en)]
             "en_GM" => en_GM,
+            #[cfg(locales_en)]
             "en-GU" => en_GU,
+            #[cfg(locales_en)]
             "en_GU" => en_GU,
+            #[cfg(locales_en)]
             "en-GY" => en_GY,
+            #[cfg(locales_en)]
             "en_GY" => en_GY,
+            #[cfg(locales_en)]
             "en-HK" => en_HK,
+            #[cfg(locales_en)]
             "en_HK" => en_HK,
+            #[cfg(locales_en)]
             "en-IE" => en_IE,
+            #[cfg(locales_en)]
             "en_IE" => en_IE,
+            #[cfg(locales_en)]
             "en-IL" => en_IL,
+            #[cfg(locales_en)]
             "en_IL" => en_IL,
+            #[cfg(locales_en)]
             "en-IM" => en_IM,
+            #[cfg(locales_en)]
             "en_IM" => en_IM,
+            #[cfg(locales_en)]
             "en-IN" => en_IN,
+            #[cfg(locales_en)]
             "en_IN" => en_IN,
+            #[cfg(locales_en)]
             "en-IO" => en_IO,
+            #[cfg(locales_en)]
             "en_IO" => en_IO,
+            #[cfg(locales_en)]
             "en-JE" => en_JE,
+            #[cfg(locales_en)]
             "en_JE" => en_JE,
+            #[cfg(locales_en)]
             "en-JM" => en_JM,
+            #[cfg(locales_en)]
             "en_JM" => en_JM,
+            #[cfg(locales_en)]
             "en-KE" => en_KE,
+            #[cfg(locales_en)]
             "en_KE" => en_KE,
+            #[cfg(locales_en)]
             "en-KI" => en_KI,
+            #[cfg(locales_en)]
             "en_KI" => en_KI,
+            #[cfg(locales_en)]
             "en-KN" => en_KN,
+            #[cfg(locales_en)]
             "en_KN" => en_KN,
+            #[cfg(locales_en)]
             "en-KY" => en_KY,
+            #[cfg(locales_en)]
             "en_KY" => en_KY,
+            #[cfg(locales_en)]
             "en-LC" => en_LC,
+            #[cfg(locales_en)]
             "en_LC" => en_LC,
+            #[cfg(locales_en)]
             "en-LR" => en_LR,
+            #[cfg(locales_en)]
             "en_LR" => en_LR,
+            #[cfg(locales_en)]
             "en-LS" => en_LS,
+            #[cfg(locales_en)]
             "en_LS" => en_LS,
+            #[cfg(locales_en)]
             "en-MG" => en_MG,
+            #[cfg(locales_en)]
             "en_MG" => en_MG,
+            #[cfg(locales_en)]
             "en-MH" => en_MH,
+            #[cfg(locales_en)]
             "en_MH" => en_MH,
+            #[cfg(locales_en)]
             "en-MO" => en_MO,
+            #[cfg(locales_en)]
             "en_MO" => en_MO,
+            #[cfg(locales_en)]
             "en-MP" => en_MP,
+            #[cfg(locales_en)]
             "en_MP" => en_MP,
+            #[cfg(locales_en)]
             "en-MS" => en_MS,
+            #[cfg(locales_en)]
             "en_MS" => en_MS,
+            #[cfg(locales_en)]
             "en-MT" => en_MT,
+            #[cfg(locales_en)]
             "en_MT" => en_MT,
+            #[cfg(locales_en)]
             "en-MU" => en_MU,
+            #[cfg(locales_en)]
             "en_MU" => en_MU,
+            #[cfg(locales_en)]
             "en-MW" => en_MW,
+            #[cfg(locales_en)]
             "en_MW" => en_MW,
+            #[cfg(locales_en)]
             "en-MY" => en_MY,
+            #[cfg(locales_en)]
             "en_MY" => en_MY,
+            #[cfg(locales_en)]
             "en-NA" => en_NA,
+            #[cfg(locales_en)]
             "en_NA" => en_NA,
+            #[cfg(locales_en)]
             "en-NF" => en_NF,
+            #[cfg(locales_en)]
             "en_NF" => en_NF,
+            #[cfg(locales_en)]
             "en-NG" => en_NG,
+            #[cfg(locales_en)]
             "en_NG" => en_NG,
+            #[cfg(locales_en)]
             "en-NL" => en_NL,
+            #[cfg(locales_en)]
             "en_NL" => en_NL,
+            #[cfg(locales_en)]
             "en-NR" => en_NR,
+            #[cfg(locales_en)]
             "en_NR" => en_NR,
+            #[cfg(locales_en)]
             "en-NU" => en_NU,
+            #[cfg(locales_en)]
             "en_NU" => en_NU,
+            #[cfg(locales_en)]
             "en-NZ" => en_NZ,
+            #[cfg(locales_en)]
             "en_NZ" => en_NZ,
+            #[cfg(locales_en)]
             "en-PG" => en_PG,
+            #[cfg(locales_en)]
             "en_PG" => en_PG,
+            #[cfg(locales_en)]
             "en-PH" => en_PH,
+            #[cfg(locales_en)]
             "en_PH" => en_PH,
+            #[cfg(locales_en)]
             "en-PK" => en_PK,
+            #[cfg(locales_en)]
             "en_PK" => en_PK,
+            #[cfg(locales_en)]
             "en-PN" => en_PN,
+            #[cfg(locales_en)]
             "en_PN" => en_PN,
+            #[cfg(locales_en)]
             "en-PR" => en_PR,
+            #[cfg(locales_en)]
             "en_PR" => en_PR,
+            #[cfg(locales_en)]
             "en-PW" => en_PW,
+            #[cfg(locales_en)]
             "en_PW" => en_PW,
+            #[cfg(locales_en)]
             "en-RW" => en_RW,
+            #[cfg(locales_en)]
             "en_RW" => en_RW,
+            #[cfg(locales_en)]
             "en-SB" => en_SB,
+            #[cfg(locales_en)]
             "en_SB" => en_SB,
+            #[cfg(locales_en)]
             "en-SC" => en_SC,
+            #[cfg(locales_en)]
             "en_SC" => en_SC,
+            #[cfg(locales_en)]
             "en-SD" => en_SD,
+            #[cfg(locales_en)]
             "en_SD" => en_SD,
+            #[cfg(locales_en)]
             "en-SE" => en_SE,
+            #[cfg(locales_en)]
             "en_SE" => en_SE,
+            #[cfg(locales_en)]
             "en-SG" => en_SG,
+            #[cfg(locales_en)]
             "en_SG" => en_SG,
+            #[cfg(locales_en)]
             "en-SH" => en_SH,
+            #[cfg(locales_en)]
             "en_SH" => en_SH,
+            #[cfg(locales_en)]
             "en-SI" => en_SI,
+            #[cfg(locales_en)]
             "en_SI" => en_SI,
+            #[cfg(locales_en)]
             "en-SL" => en_SL,
+            #[cfg(locales_en)]
             "en_SL" => en_SL,
+            #[cfg(locales_en)]
             "en-SS" => en_SS,
+            #[cfg(locales_en)]
             "en_SS" => en_SS,
+            #[cfg(locales_en)]
             "en-SX" => en_SX,
+            #[cfg(locales_en)]
             "en_SX" => en_SX,
+            #[cfg(locales_en)]
             "en-SZ" => en_SZ,
+            #[cfg(locales_en)]
             "en_SZ" => en_SZ,
+            #[cfg(locales_en)]
             "en-TC" => en_TC,
+            #[cfg(locales_en)]
             "en_TC" => en_TC,
+            #[cfg(locales_en)]
             "en-TK" => en_TK,
+            #[cfg(locales_en)]
             "en_TK" => en_TK,
+            #[cfg(locales_en)]
             "en-TO" => en_TO,
+            #[cfg(locales_en)]
             "en_TO" => en_TO,
+            #[cfg(locales_en)]
             "en-TT" => en_TT,
+            #[cfg(locales_en)]
             "en_TT" => en_TT,
+            #[cfg(locales_en)]
             "en-TV" => en_TV,
+            #[cfg(locales_en)]
             "en_TV" => en_TV,
+            #[cfg(locales_en)]
             "en-TZ" => en_TZ,
+            #[cfg(locales_en)]
             "en_TZ" => en_TZ,
+            #[cfg(locales_en)]
             "en-UG" => en_UG,
+            #[cfg(locales_en)]
             "en_UG" => en_UG,
+            #[cfg(locales_en)]
             "en-UM" => en_UM,
+            #[cfg(locales_en)]
             "en_UM" => en_UM,
+            #[cfg(locales_en)]
             "en-US-POSIX" => en_US_POSIX,
+            #[cfg(locales_en)]
             "en_US_POSIX" => en_US_POSIX,
+            #[cfg(locales_en)]
             "en-VC" => en_VC,
+            #[cfg(locales_en)]
             "en_VC" => en_VC,
+            #[cfg(locales_en)]
             "en-VG" => en_VG,
+            #[cfg(locales_en)]
             "en_VG" => en_VG,
+            #[cfg(locales_en)]
             "en-VI" => en_VI,
+            #[cfg(locales_en)]
             "en_VI" => en_VI,
+            #[cfg(locales_en)]
             "en-VU" => en_VU,
+            #[cfg(locales_en)]
             "en_VU" => en_VU,
+            #[cfg(locales_en)]
             "en-WS" => en_WS,
+            #[cfg(locales_en)]
             "en_WS" => en_WS,
+            #[cfg(locales_en)]
             "en-ZA" => en_ZA,
+            #[cfg(locales_en)]
             "en_ZA" => en_ZA,
+            #[cfg(locales_en)]
             "en-ZM" => en_ZM,
+            #[cfg(locales_en)]
             "en_ZM" => en_ZM,
+            #[cfg(locales_en)]
             "en-ZW" => en_ZW,
+            #[cfg(locales_en)]
             "en_ZW" => en_ZW,
+            #[cfg(locales_other)]
             "eo" => eo,
+            #[cfg(locales_eu)]
             "es" => es,
+            #[cfg(locales_eu)]
             "es-419" => es_419,
+            #[cfg(locales_eu)]
             "es_419" => es_419,
+            #[cfg(locales_eu)]
             "es-AR" => es_AR,
+            #[cfg(locales_eu)]
             "es_AR" => es_AR,
+            #[cfg(locales_eu)]
             "es-BO" => es_BO,
+            #[cfg(locales_eu)]
             "es_BO" => es_BO,
+            #[cfg(locales_eu)]
             "es-BR" => es_BR,
+            #[cfg(locales_eu)]
             "es_BR" => es_BR,
+            #[cfg(locales_eu)]
             "es-BZ" => es_BZ,
+            #[cfg(locales_eu)]
             "es_BZ" => es_BZ,
+            #[cfg(locales_eu)]
             "es-CL" => es_CL,
+            #[cfg(locales_eu)]
             "es_CL" => es_CL,
+            #[cfg(locales_eu)]
             "es-CO" => es_CO,
+            #[cfg(locales_eu)]
             "es_CO" => es_CO,
+            #[cfg(locales_eu)]
             "es-CR" => es_CR,
+            #[cfg(locales_eu)]
             "es_CR" => es_CR,
+            #[cfg(locales_eu)]
             "es-CU" => es_CU,
+            #[cfg(locales_eu)]
             "es_CU" => es_CU,
+            #[cfg(locales_eu)]
             "es-DO" => es_DO,
+            #[cfg(locales_eu)]
             "es_DO" => es_DO,
+            #[cfg(locales_eu)]
             "es-EA" => es_EA,
+            #[cfg(locales_eu)]
             "es_EA" => es_EA,
+            #[cfg(locales_eu)]
             "es-EC" => es_EC,
+            #[cfg(locales_eu)]
             "es_EC" => es_EC,
+            #[cfg(locales_eu)]
             "es-GQ" => es_GQ,
+            #[cfg(locales_eu)]
             "es_GQ" => es_GQ,
+            #[cfg(locales_eu)]
             "es-GT" => es_GT,
+            #[cfg(locales_eu)]
             "es_GT" => es_GT,
+            #[cfg(locales_eu)]
             "es-HN" => es_HN,
+            #[cfg(locales_eu)]
             "es_HN" => es_HN,
+            #[cfg(locales_eu)]
             "es-IC" => es_IC,
+            #[cfg(locales_eu)]
             "es_IC" => es_IC,
+            #[cfg(locales_eu)]
             "es-MX" => es_MX,
+            #[cfg(locales_eu)]
             "es_MX" => es_MX,
+            #[cfg(locales_eu)]
             "es-NI" => es_NI,
+            #[cfg(locales_eu)]
             "es_NI" => es_NI,
+            #[cfg(locales_eu)]
             "es-PA" => es_PA,
+            #[cfg(locales_eu)]
             "es_PA" => es_PA,
+            #[cfg(locales_eu)]
             "es-PE" => es_PE,
+            #[cfg(locales_eu)]
             "es_PE" => es_PE,
+            #[cfg(locales_eu)]
             "es-PH" => es_PH,
+            #[cfg(locales_eu)]
             "es_PH" => es_PH,
+            #[cfg(locales_eu)]
             "es-PR" => es_PR,
+            #[cfg(locales_eu)]
             "es_PR" => es_PR,
+            #[cfg(locales_eu)]
             "es-PY" => es_PY,
+            #[cfg(locales_eu)]
             "es_PY" => es_PY,
+            #[cfg(locales_eu)]
             "es-SV" => es_SV,
+            #[cfg(locales_eu)]
             "es_SV" => es_SV,
+            #[cfg(locales_eu)]
             "es-US" => es_US,
+            #[cfg(locales_eu)]
             "es_US" => es_US,
+            #[cfg(locales_eu)]
             "es-UY" => es_UY,
+            #[cfg(locales_eu)]
             "es_UY" => es_UY,
+            #[cfg(locales_eu)]
             "es-VE" => es_VE,
+            #[cfg(locales_eu)]
             "es_VE" => es_VE,
+            #[cfg(locales_eu)]
             "et" => et,
+            #[cfg(locales_other)]
             "eu" => eu,
+            #[cfg(locales_other)]
             "ewo" => ewo,
+            #[cfg(locales_other)]
             "fa" => fa,
+            #[cfg(locales_other)]
             "fa-AF" => fa_AF,
+            #[cfg(locales_other)]
             "fa_AF" => fa_AF,
+            #[cfg(locales_other)]
             "ff" => ff,
+            #[cfg(locales_other)]
             "ff-Latn" => ff_Latn,
+            #[cfg(locales_other)]
             "ff_Latn" => ff_Latn,
+            #[cfg(locales_other)]
             "ff-Latn-BF" => ff_Latn_BF,
+            #[cfg(locales_other)]
             "ff_Latn_BF" => ff_Latn_BF,
+            #[cfg(locales_other)]
             "ff-Latn-CM" => ff_Latn_CM,
+            #[cfg(locales_other)]
             "ff_Latn_CM" => ff_Latn_CM,
+            #[cfg(locales_other)]
             "ff-Latn-GH" => ff_Latn_GH,
+            #[cfg(locales_other)]
             "ff_Latn_GH" => ff_Latn_GH,
+            #[cfg(locales_other)]
             "ff-Latn-GM" => ff_Latn_GM,
+            #[cfg(locales_other)]
             "ff_Latn_GM" => ff_Latn_GM,
+            #[cfg(locales_other)]
             "ff-Latn-GN" => ff_Latn_GN,
+            #[cfg(locales_other)]
             "ff_Latn_GN" => ff_Latn_GN,
+            #[cfg(locales_other)]
             "ff-Latn-GW" => ff_Latn_GW,
+            #[cfg(locales_other)]
             "ff_Latn_GW" => ff_Latn_GW,
+            #[cfg(locales_other)]
             "ff-Latn-LR" => ff_Latn_LR,
+            #[cfg(locales_other)]
             "ff_Latn_LR" => ff_Latn_LR,
+            #[cfg(locales_other)]
             "ff-Latn-MR" => ff_Latn_MR,
+            #[cfg(locales_other)]
             "ff_Latn_MR" => ff_Latn_MR,
+            #[cfg(locales_other)]
             "ff-Latn-NE" => ff_Latn_NE,
+            #[cfg(locales_other)]
             "ff_Latn_NE" => ff_Latn_NE,
+            #[cfg(locales_other)]
             "ff-Latn-NG" => ff_Latn_NG,
+            #[cfg(locales_other)]
             "ff_Latn_NG" => ff_Latn_NG,
+            #[cfg(locales_other)]
             "ff-Latn-SL" => ff_Latn_SL,
+            #[cfg(locales_other)]
             "ff_Latn_SL" => ff_Latn_SL,
+            #[cfg(locales_eu)]
             "fi" => fi,
+            #[cfg(locales_other)]
             "fil" => fil,
+            #[cfg(locales_other)]
             "fo" => fo,
+            #[cfg(locales_other)]
             "fo-DK" => fo_DK,
+            #[cfg(locales_other)]
             "fo_DK" => fo_DK,
+            #[cfg(locales_eu)]
             "fr" => fr,
+            #[cfg(locales_eu)]
             "fr-BE" => fr_BE,
+            #[cfg(locales_eu)]
             "fr_BE" => fr_BE,
+            #[cfg(locales_eu)]
             "fr-BF" => fr_BF,
+            #[cfg(locales_eu)]
             "fr_BF" => fr_BF,
+            #[cfg(locales_eu)]
             "fr-BI" => fr_BI,
+            #[cfg(locales_eu)]
             "fr_BI" => fr_BI,
+            #[cfg(locales_eu)]
             "fr-BJ" => fr_BJ,
+            #[cfg(locales_eu)]
             "fr_BJ" => fr_BJ,
+            #[cfg(locales_eu)]
             "fr-BL" => fr_BL,
+            #[cfg(locales_eu)]
             "fr_BL" => fr_BL,
+            #[cfg(locales_eu)]
             "fr-CA" => fr_CA,
+            #[cfg(locales_eu)]
             "fr_CA" => fr_CA,
+            #[cfg(locales_eu)]
             "fr-CD" => fr_CD,
+            #[cfg(locales_eu)]
             "fr_CD" => fr_CD,
+            #[cfg(locales_eu)]
             "fr-CF" => fr_CF,
+            #[cfg(locales_eu)]
             "fr_CF" => fr_CF,
+            #[cfg(locales_eu)]
             "fr-CG" => fr_CG,
+            #[cfg(locales_eu)]
             "fr_CG" => fr_CG,
+            #[cfg(locales_eu)]
             "fr-CH" => fr_CH,
+            #[cfg(locales_eu)]
             "fr_CH" => fr_CH,
+            #[cfg(locales_eu)]
             "fr-CI" => fr_CI,
+            #[cfg(locales_eu)]
             "fr_CI" => fr_CI,
+            #[cfg(locales_eu)]
             "fr-CM" => fr_CM,
+            #[cfg(locales_eu)]
             "fr_CM" => fr_CM,
+            #[cfg(locales_eu)]
             "fr-DJ" => fr_DJ,
+            #[cfg(locales_eu)]
             "fr_DJ" => fr_DJ,
+            #[cfg(locales_eu)]
             "fr-DZ" => fr_DZ,
+            #[cfg(locales_eu)]
             "fr_DZ" => fr_DZ,
+            #[cfg(locales_eu)]
             "fr-GA" => fr_GA,
+            #[cfg(locales_eu)]
             "fr_GA" => fr_GA,
+            #[cfg(locales_eu)]
             "fr-GF" => fr_GF,
+            #[cfg(locales_eu)]
             "fr_GF" => fr_GF,
+            #[cfg(locales_eu)]
             "fr-GN" => fr_GN,
+            #[cfg(locales_eu)]
             "fr_GN" => fr_GN,
+            #[cfg(locales_eu)]
             "fr-GP" => fr_GP,
+            #[cfg(locales_eu)]
             "fr_GP" => fr_GP,
+            #[cfg(locales_eu)]
             "fr-GQ" => fr_GQ,
+            #[cfg(locales_eu)]
             "fr_GQ" => fr_GQ,
+            #[cfg(locales_eu)]
             "fr-HT" => fr_HT,
+            #[cfg(locales_eu)]
             "fr_HT" => fr_HT,
+            #[cfg(locales_eu)]
             "fr-KM" => fr_KM,
+            #[cfg(locales_eu)]
             "fr_KM" => fr_KM,
+            #[cfg(locales_eu)]
             "fr-LU" => fr_LU,
+            #[cfg(locales_eu)]
             "fr_LU" => fr_LU,
+            #[cfg(locales_eu)]
             "fr-MA" => fr_MA,
+            #[cfg(locales_eu)]
             "fr_MA" => fr_MA,
+            #[cfg(locales_eu)]
             "fr-MC" => fr_MC,
+            #[cfg(locales_eu)]
             "fr_MC" => fr_MC,
+            #[cfg(locales_eu)]
             "fr-MF" => fr_MF,
+            #[cfg(locales_eu)]
             "fr_MF" => fr_MF,
+            #[cfg(locales_eu)]
             "fr-MG" => fr_MG,
+            #[cfg(locales_eu)]
             "fr_MG" => fr_MG,
+            #[cfg(locales_eu)]
             "fr-ML" => fr_ML,
+            #[cfg(locales_eu)]
             "fr_ML" => fr_ML,
+            #[cfg(locales_eu)]
             "fr-MQ" => fr_MQ,
+            #[cfg(locales_eu)]
             "fr_MQ" => fr_MQ,
+            #[cfg(locales_eu)]
             "fr-MR" => fr_MR,
+            #[cfg(locales_eu)]
             "fr_MR" => fr_MR,
+            #[cfg(locales_eu)]
             "fr-MU" => fr_MU,
+            #[cfg(locales_eu)]
             "fr_MU" => fr_MU,
+            #[cfg(locales_eu)]
             "fr-NC" => fr_NC,
+            #[cfg(locales_eu)]
             "fr_NC" => fr_NC,
+            #[cfg(locales_eu)]
             "fr-NE" => fr_NE,
+            #[cfg(locales_eu)]
             "fr_NE" => fr_NE,
+            #[cfg(locales_eu)]
             "fr-PF" => fr_PF,
+            #[cfg(locales_eu)]
             "fr_PF" => fr_PF,
+            #[cfg(locales_eu)]
             "fr-PM" => fr_PM,
+            #[cfg(locales_eu)]
             "fr_PM" => fr_PM,
+            #[cfg(locales_eu)]
             "fr-RE" => fr_RE,
+            #[cfg(locales_eu)]
             "fr_RE" => fr_RE,
+            #[cfg(locales_eu)]
             "fr-RW" => fr_RW,
+            #[cfg(locales_eu)]
             "fr_RW" => fr_RW,
+            #[cfg(locales_eu)]
             "fr-SC" => fr_SC,
+            #[cfg(locales_eu)]
             "fr_SC" => fr_SC,
+            #[cfg(locales_eu)]
             "fr-SN" => fr_SN,
+            #[cfg(locales_eu)]
             "fr_SN" => fr_SN,
+            #[cfg(locales_eu)]
             "fr-SY" => fr_SY,
+            #[cfg(locales_eu)]
             "fr_SY" => fr_SY,
+            #[cfg(locales_eu)]
             "fr-TD" => fr_TD,
+            #[cfg(locales_eu)]
             "fr_TD" => fr_TD,
+            #[cfg(locales_eu)]
             "fr-TG" => fr_TG,
+            #[cfg(locales_eu)]
             "fr_TG" => fr_TG,
+            #[cfg(locales_eu)]
             "fr-TN" => fr_TN,
+            #[cfg(locales_eu)]
             "fr_TN" => fr_TN,
+            #[cfg(locales_eu)]
             "fr-VU" => fr_VU,
+            #[cfg(locales_eu)]
             "fr_VU" => fr_VU,
+            #[cfg(locales_eu)]
             "fr-WF" => fr_WF,
+            #[cfg(locales_eu)]
             "fr_WF" => fr_WF,
+            #[cfg(locales_eu)]
             "fr-YT" => fr_YT,
+            #[cfg(locales_eu)]
             "fr_YT" => fr_YT,
+            #[cfg(locales_other)]
             "fur" => fur,
+            #[cfg(locales_other)]
             "fy" => fy,
+            #[cfg(locales_eu)]
             "ga" => ga,
+            #[cfg(locales_eu)]
             "ga-GB" => ga_GB,
+            #[cfg(locales_eu)]
             "ga_GB" => ga_GB,
+            #[cfg(locales_other)]
             "gd" => gd,
+            #[cfg(locales_other)]
             "gl" => gl,
+            #[cfg(locales_other)]
             "gsw" => gsw,
+            #[cfg(locales_other)]
             "gsw-FR" => gsw_FR,
+            #[cfg(locales_other)]
             "gsw_FR" => gsw_FR,
+            #[cfg(locales_other)]
             "gsw-LI" => gsw_LI,
+            #[cfg(locales_other)]
             "gsw_LI" => gsw_LI,
+            #[cfg(locales_other)]
             "gu" => gu,
+            #[cfg(locales_other)]
             "guz" => guz,
+            #[cfg(locales_other)]
             "gv" => gv,
+            #[cfg(locales_other)]
             "ha" => ha,
+            #[cfg(locales_other)]
             "ha-GH" => ha_GH,
+            #[cfg(locales_other)]
             "ha_GH" => ha_GH,
+            #[cfg(locales_other)]
             "ha-NE" => ha_NE,
+            #[cfg(locales_other)]
             "ha_NE" => ha_NE,
+            #[cfg(locales_other)]
             "haw" => haw,
+            #[cfg(locales_other)]
             "he" => he,
+            #[cfg(locales_other)]
             "hi" => hi,
+            #[cfg(locales_eu)]
             "hr" => hr,
+            #[cfg(locales_eu)]
             "hr-BA" => hr_BA,
+            #[cfg(locales_eu)]
             "hr_BA" => hr_BA,
+            #[cfg(locales_other)]
             "hsb" => hsb,
+            #[cfg(locales_eu)]
             "hu" => hu,
+            #[cfg(locales_other)]
             "hy" => hy,
+            #[cfg(locales_other)]
             "ia" => ia,
+            #[cfg(locales_other)]
             "id" => id,
+            #[cfg(locales_other)]
             "ig" => ig,
+            #[cfg(locales_other)]
             "ii" => ii,
+            #[cfg(locales_other)]
             "is" => is,
+            #[cfg(locales_eu)]
             "it" => it,
+            #[cfg(locales_eu)]
             "it-CH" => it_CH,
+            #[cfg(locales_eu)]
             "it_CH" => it_CH,
+            #[cfg(locales_eu)]
             "it-SM" => it_SM,
+            #[cfg(locales_eu)]
             "it_SM" => it_SM,
+            #[cfg(locales_eu)]
             "it-VA" => it_VA,
+            #[cfg(locales_eu)]
             "it_VA" => it_VA,
+            #[cfg(locales_other)]
             "ja" => ja,
+            #[cfg(locales_other)]
             "jgo" => jgo,
+            #[cfg(locales_other)]
             "jmc" => jmc,
+            #[cfg(locales_other)]
             "jv" => jv,
+            #[cfg(locales_other)]
             "ka" => ka,
+            #[cfg(locales_other)]
             "kab" => kab,
+            #[cfg(locales_other)]
             "kam" => kam,
+            #[cfg(locales_other)]
             "kde" => kde,
+            #[cfg(locales_other)]
             "kea" => kea,
+            #[cfg(locales_other)]
             "khq" => khq,
+            #[cfg(locales_other)]
             "ki" => ki,
+            #[cfg(locales_other)]
             "kk" => kk,
+            #[cfg(locales_other)]
             "kkj" => kkj,
+            #[cfg(locales_other)]
             "kl" => kl,
+            #[cfg(locales_other)]
             "kln" => kln,
+            #[cfg(locales_other)]
             "km" => km,
+            #[cfg(locales_other)]
             "kn" => kn,
+            #[cfg(locales_other)]
             "ko" => ko,
+            #[cfg(locales_other)]
             "ko-KP" => ko_KP,
+            #[cfg(locales_other)]
             "ko_KP" => ko_KP,
+            #[cfg(locales_other)]
             "kok" => kok,
+            #[cfg(locales_other)]
             "ks" => ks,
+            #[cfg(locales_other)]
             "ksb" => ksb,
+            #[cfg(locales_other)]
             "ksf" => ksf,
+            #[cfg(locales_other)]
             "ksh" => ksh,
+            #[cfg(locales_other)]
             "ku" => ku,
+            #[cfg(locales_other)]
             "kw" => kw,
+            #[cfg(locales_other)]
             "ky" => ky,
+            #[cfg(locales_other)]
             "lag" => lag,
+            #[cfg(locales_other)]
             "lb" => lb,
+            #[cfg(locales_other)]
             "lg" => lg,
+            #[cfg(locales_other)]
             "lkt" => lkt,
+            #[cfg(locales_other)]
             "ln" => ln,
+            #[cfg(locales_other)]
             "ln-AO" => ln_AO,
+            #[cfg(locales_other)]
             "ln_AO" => ln_AO,
+            #[cfg(locales_other)]
             "ln-CF" => ln_CF,
+            #[cfg(locales_other)]
             "ln_CF" => ln_CF,
+            #[cfg(locales_other)]
             "ln-CG" => ln_CG,
+            #[cfg(locales_other)]
             "ln_CG" => ln_CG,
+            #[cfg(locales_other)]
             "lo" => lo,
+            #[cfg(locales_other)]
             "lrc" => lrc,
+            #[cfg(locales_other)]
             "lrc-IQ" => lrc_IQ,
+            #[cfg(locales_other)]
             "lrc_IQ" => lrc_IQ,
+            #[cfg(locales_eu)]
             "lt" => lt,
+            #[cfg(locales_other)]
             "lu" => lu,
+            #[cfg(locales_other)]
             "luo" => luo,
+            #[cfg(locales_other)]
             "luy" => luy,
+            #[cfg(locales_eu)]
             "lv" => lv,
+            #[cfg(locales_other)]
             "mas" => mas,
+            #[cfg(locales_other)]
             "mas-TZ" => mas_TZ,
+            #[cfg(locales_other)]
             "mas_TZ" => mas_TZ,
+            #[cfg(locales_other)]
             "mer" => mer,
+            #[cfg(locales_other)]
             "mfe" => mfe,
+            #[cfg(locales_other)]
             "mg" => mg,
+            #[cfg(locales_other)]
             "mgh" => mgh,
+            #[cfg(locales_other)]
             "mgo" => mgo,
+            #[cfg(locales_other)]
             "mi" => mi,
+            #[cfg(locales_other)]
             "mk" => mk,
+            #[cfg(locales_other)]
             "ml" => ml,
+            #[cfg(locales_other)]
             "mn" => mn,
+            #[cfg(locales_other)]
             "mr" => mr,
+            #[cfg(locales_other)]
             "ms" => ms,
+            #[cfg(locales_other)]
             "ms-BN" => ms_BN,
+            #[cfg(locales_other)]
             "ms_BN" => ms_BN,
+            #[cfg(locales_other)]
             "ms-SG" => ms_SG,
+            #[cfg(locales_other)]
             "ms_SG" => ms_SG,
+            #[cfg(locales_eu)]
             "mt" => mt,
+            #[cfg(locales_other)]
             "mua" => mua,
+            #[cfg(locales_other)]
             "my" => my,
+            #[cfg(locales_other)]
             "mzn" => mzn,
+            #[cfg(locales_other)]
             "naq" => naq,
+            #[cfg(locales_other)]
             "nb" => nb,
+            #[cfg(locales_other)]
             "nb-SJ" => nb_SJ,
+            #[cfg(locales_other)]
             "nb_SJ" => nb_SJ,
+            #[cfg(locales_other)]
             "nd" => nd,
+            #[cfg(locales_other)]
             "nds" => nds,
+            #[cfg(locales_other)]
             "nds-NL" => nds_NL,
+            #[cfg(locales_other)]
             "nds_NL" => nds_NL,
+            #[cfg(locales_other)]
             "ne" => ne,
+            #[cfg(locales_other)]
             "ne-IN" => ne_IN,
+            #[cfg(locales_other)]
             "ne_IN" => ne_IN,
+            #[cfg(locales_eu)]
             "nl" => nl,
+            #[cfg(locales_eu)]
             "nl-AW" => nl_AW,
+            #[cfg(locales_eu)]
             "nl_AW" => nl_AW,
+            #[cfg(locales_eu)]
             "nl-BE" => nl_BE,
+            #[cfg(locales_eu)]
             "nl_BE" => nl_BE,
+            #[cfg(locales_eu)]
             "nl-BQ" => nl_BQ,
+            #[cfg(locales_eu)]
             "nl_BQ" => nl_BQ,
+            #[cfg(locales_eu)]
             "nl-CW" => nl_CW,
+            #[cfg(locales_eu)]
             "nl_CW" => nl_CW,
+            #[cfg(locales_eu)]
             "nl-SR" => nl_SR,
+            #[cfg(locales_eu)]
             "nl_SR" => nl_SR,
+            #[cfg(locales_eu)]
             "nl-SX" => nl_SX,
+            #[cfg(locales_eu)]
             "nl_SX" => nl_SX,
+            #[cfg(locales_other)]
             "nmg" => nmg,
+            #[cfg(locales_other)]
             "nn" => nn,
+            #[cfg(locales_other)]
             "nnh" => nnh,
+            #[cfg(locales_other)]
             "nus" => nus,
+            #[cfg(locales_other)]
             "nyn" => nyn,
+            #[cfg(locales_other)]
             "om" => om,
+            #[cfg(locales_other)]
             "om-KE" => om_KE,
+            #[cfg(locales_other)]
             "om_KE" => om_KE,
+            #[cfg(locales_other)]
             "or" => or,
+            #[cfg(locales_other)]
             "os" => os,
+            #[cfg(locales_other)]
             "os-RU" => os_RU,
+            #[cfg(locales_other)]
             "os_RU" => os_RU,
+            #[cfg(locales_other)]
             "pa" => pa,
+            #[cfg(locales_other)]
             "pa-Arab" => pa_Arab,
+            #[cfg(locales_other)]
             "pa_Arab" => pa_Arab,
+            #[cfg(locales_other)]
             "pa-Guru" => pa_Guru,
+            #[cfg(locales_other)]
             "pa_Guru" => pa_Guru,
+            #[cfg(locales_eu)]
             "pl" => pl,
+            #[cfg(locales_other)]
             "prg" => prg,
+            #[cfg(locales_other)]
             "ps" => ps,
+            #[cfg(locales_other)]
             "ps-PK" => ps_PK,
+            #[cfg(locales_other)]
             "ps_PK" => ps_PK,
+            #[cfg(locales_eu)]
             "pt" => pt,
+            #[cfg(locales_eu)]
             "pt-AO" => pt_AO,
+            #[cfg(locales_eu)]
             "pt_AO" => pt_AO,
+            #[cfg(locales_eu)]
             "pt-CH" => pt_CH,
+            #[cfg(locales_eu)]
             "pt_CH" => pt_CH,
+            #[cfg(locales_eu)]
             "pt-CV" => pt_CV,
+            #[cfg(locales_eu)]
             "pt_CV" => pt_CV,
+            #[cfg(locales_eu)]
             "pt-GQ" => pt_GQ,
+            #[cfg(locales_eu)]
             "pt_GQ" => pt_GQ,
+            #[cfg(locales_eu)]
             "pt-GW" => pt_GW,
+            #[cfg(locales_eu)]
             "pt_GW" => pt_GW,
+            #[cfg(locales_eu)]
             "pt-LU" => pt_LU,
+            #[cfg(locales_eu)]
             "pt_LU" => pt_LU,
+            #[cfg(locales_eu)]
             "pt-MO" => pt_MO,
+            #[cfg(locales_eu)]
             "pt_MO" => pt_MO,
+            #[cfg(locales_eu)]
             "pt-MZ" => pt_MZ,
+            #[cfg(locales_eu)]
             "pt_MZ" => pt_MZ,
+            #[cfg(locales_eu)]
             "pt-PT" => pt_PT,
+            #[cfg(locales_eu)]
             "pt_PT" => pt_PT,
+            #[cfg(locales_eu)]
             "pt-ST" => pt_ST,
+            #[cfg(locales_eu)]
             "pt_ST" => pt_ST,
+            #[cfg(locales_eu)]
             "pt-TL" => pt_TL,
+            #[cfg(locales_eu)]
             "pt_TL" => pt_TL,
+            #[cfg(locales_other)]
             "qu" => qu,
+            #[cfg(locales_other)]
             "qu-BO" => qu_BO,
+            #[cfg(locales_other)]
             "qu_BO" => qu_BO,
+            #[cfg(locales_other)]
             "qu-EC" => qu_EC,
+            #[cfg(locales_other)]
             "qu_EC" => qu_EC,
+            #[cfg(locales_other)]
             "rm" => rm,
+            #[cfg(locales_other)]
             "rn" => rn,
+            #[cfg(locales_eu)]
             "ro" => ro,
+            #[cfg(locales_eu)]
             "ro-MD" => ro_MD,
+            #[cfg(locales_eu)]
             "ro_MD" => ro_MD,
+            #[cfg(locales_other)]
             "rof" => rof,
+            #[cfg(locales_other)]
             "root" => root,
+            #[cfg(locales_other)]
             "ru" => ru,
+            #[cfg(locales_other)]
             "ru-BY" => ru_BY,
+            #[cfg(locales_other)]
             "ru_BY" => ru_BY,
+            #[cfg(locales_other)]
             "ru-KG" => ru_KG,
+            #[cfg(locales_other)]
             "ru_KG" => ru_KG,
+            #[cfg(locales_other)]
             "ru-KZ" => ru_KZ,
+            #[cfg(locales_other)]
             "ru_KZ" => ru_KZ,
+            #[cfg(locales_other)]
             "ru-MD" => ru_MD,
+            #[cfg(locales_other)]
             "ru_MD" => ru_MD,
+            #[cfg(locales_other)]
             "ru-UA" => ru_UA,
+            #[cfg(locales_other)]
             "ru_UA" => ru_UA,
+            #[cfg(locales_other)]
             "rw" => rw,
+            #[cfg(locales_other)]
             "rwk" => rwk,
+            #[cfg(locales_other)]
             "sah" => sah,
+            #[cfg(locales_other)]
             "saq" => saq,
+            #[cfg(locales_other)]
             "sbp" => sbp,
+            #[cfg(locales_other)]
             "sd" => sd,
+            #[cfg(locales_other)]
             "se" => se,
+            #[cfg(locales_other)]
             "se-FI" => se_FI,
+            #[cfg(locales_other)]
             "se_FI" => se_FI,
+            #[cfg(locales_other)]
             "se-SE" => se_SE,
+            #[cfg(locales_other)]
             "se_SE" => se_SE,
+            #[cfg(locales_other)]
             "seh" => seh,
+            #[cfg(locales_other)]
             "ses" => ses,
+            #[cfg(locales_other)]
             "sg" => sg,
+            #[cfg(locales_other)]
             "shi" => shi,
+            #[cfg(locales_other)]
             "shi-Latn" => shi_Latn,
+            #[cfg(locales_other)]
             "shi_Latn" => shi_Latn,
+            #[cfg(locales_other)]
             "shi-Tfng" => shi_Tfng,
+            #[cfg(locales_other)]
             "shi_Tfng" => shi_Tfng,
+            #[cfg(locales_other)]
             "si" => si,
+            #[cfg(locales_eu)]
             "sk" => sk,
+            #[cfg(locales_eu)]
             "sl" => sl,
+            #[cfg(locales_other)]
             "smn" => smn,
+            #[cfg(locales_other)]
             "sn" => sn,
+            #[cfg(locales_other)]
             "so" => so,
+            #[cfg(locales_other)]
             "so-DJ" => so_DJ,
+            #[cfg(locales_other)]
             "so_DJ" => so_DJ,
+            #[cfg(locales_other)]
             "so-ET" => so_ET,
+            #[cfg(locales_other)]
             "so_ET" => so_ET,
+            #[cfg(locales_other)]
             "so-KE" => so_KE,
+            #[cfg(locales_other)]
             "so_KE" => so_KE,
+            #[cfg(locales_other)]
             "sq" => sq,
+            #[cfg(locales_other)]
             "sq-MK" => sq_MK,
+            #[cfg(locales_other)]
             "sq_MK" => sq_MK,
+            #[cfg(locales_other)]
             "sq-XK" => sq_XK,
+            #[cfg(locales_other)]
             "sq_XK" => sq_XK,
+            #[cfg(locales_other)]
             "sr" => sr,
+            #[cfg(locales_other)]
             "sr-Cyrl" => sr_Cyrl,
+            #[cfg(locales_other)]
             "sr_Cyrl" => sr_Cyrl,
+            #[cfg(locales_other)]
             "sr-Cyrl-BA" => sr_Cyrl_BA,
+            #[cfg(locales_other)]
             "sr_Cyrl_BA" => sr_Cyrl_BA,
+            #[cfg(locales_other)]
             "sr-Cyrl-ME" => sr_Cyrl_ME,
+            #[cfg(locales_other)]
             "sr_Cyrl_ME" => sr_Cyrl_ME,
+            #[cfg(locales_other)]
             "sr-Cyrl-XK" => sr_Cyrl_XK,
+            #[cfg(locales_other)]
             "sr_Cyrl_XK" => sr_Cyrl_XK,
+            #[cfg(locales_other)]
             "sr-Latn" => sr_Latn,
+            #[cfg(locales_other)]
             "sr_Latn" => sr_Latn,
+            #[cfg(locales_other)]
             "sr-Latn-BA" => sr_Latn_BA,
+            #[cfg(locales_other)]
             "sr_Latn_BA" => sr_Latn_BA,
+            #[cfg(locales_other)]
             "sr-Latn-ME" => sr_Latn_ME,
+            #[cfg(locales_other)]
             "sr_Latn_ME" => sr_Latn_ME,
+            #[cfg(locales_other)]
             "sr-Latn-XK" => sr_Latn_XK,
+            #[cfg(locales_other)]
             "sr_Latn_XK" => sr_Latn_XK,
+            #[cfg(locales_eu)]
             "sv" => sv,
+            #[cfg(locales_eu)]
             "sv-AX" => sv_AX,
+            #[cfg(locales_eu)]
             "sv_AX" => sv_AX,
+            #[cfg(locales_eu)]
             "sv-FI" => sv_FI,
+            #[cfg(locales_eu)]
             "sv_FI" => sv_FI,
+            #[cfg(locales_other)]
             "sw" => sw,
+            #[cfg(locales_other)]
             "sw-CD" => sw_CD,
+            #[cfg(locales_other)]
             "sw_CD" => sw_CD,
+            #[cfg(locales_other)]
             "sw-KE" => sw_KE,
+            #[cfg(locales_other)]
             "sw_KE" => sw_KE,
+            #[cfg(locales_other)]
             "sw-UG" => sw_UG,
+            #[cfg(locales_other)]
             "sw_UG" => sw_UG,
+            #[cfg(locales_other)]
             "ta" => ta,
+            #[cfg(locales_other)]
             "ta-LK" => ta_LK,
+            #[cfg(locales_other)]
             "ta_LK" => ta_LK,
+            #[cfg(locales_other)]
             "ta-MY" => ta_MY,
+            #[cfg(locales_other)]
             "ta_MY" => ta_MY,
+            #[cfg(locales_other)]
             "ta-SG" => ta_SG,
+            #[cfg(locales_other)]
             "ta_SG" => ta_SG,
+            #[cfg(locales_other)]
             "te" => te,
+            #[cfg(locales_other)]
             "teo" => teo,
+            #[cfg(locales_other)]
             "teo-KE" => teo_KE,
+            #[cfg(locales_other)]
             "teo_KE" => teo_KE,
+            #[cfg(locales_other)]
             "tg" => tg,
+            #[cfg(locales_other)]
             "th" => th,
+            #[cfg(locales_other)]
             "ti" => ti,
+            #[cfg(locales_other)]
             "ti-ER" => ti_ER,
+            #[cfg(locales_other)]
             "ti_ER" => ti_ER,
+            #[cfg(locales_other)]
             "tk" => tk,
+            #[cfg(locales_other)]
             "to" => to,
+            #[cfg(locales_other)]
             "tr" => tr,
+            #[cfg(locales_other)]
             "tr-CY" => tr_CY,
+            #[cfg(locales_other)]
             "tr_CY" => tr_CY,
+            #[cfg(locales_other)]
             "tt" => tt,
+            #[cfg(locales_other)]
             "twq" => twq,
+            #[cfg(locales_other)]
             "tzm" => tzm,
+            #[cfg(locales_other)]
             "ug" => ug,
+            #[cfg(locales_other)]
             "uk" => uk,
+            #[cfg(locales_other)]
             "ur" => ur,
+            #[cfg(locales_other)]
             "ur-IN" => ur_IN,
+            #[cfg(locales_other)]
             "ur_IN" => ur_IN,
+            #[cfg(locales_other)]
             "uz" => uz,
+            #[cfg(locales_other)]
             "uz-Arab" => uz_Arab,
+            #[cfg(locales_other)]
             "uz_Arab" => uz_Arab,
+            #[cfg(locales_other)]
             "uz-Cyrl" => uz_Cyrl,
+            #[cfg(locales_other)]
             "uz_Cyrl" => uz_Cyrl,
+            #[cfg(locales_other)]
             "uz-Latn" => uz_Latn,
+            #[cfg(locales_other)]
             "uz_Latn" => uz_Latn,
+            #[cfg(locales_other)]
             "vai" => vai,
+            #[cfg(locales_other)]
             "vai-Latn" => vai_Latn,
+            #[cfg(locales_other)]
             "vai_Latn" => vai_Latn,
+            #[cfg(locales_other)]
             "vai-Vaii" => vai_Vaii,
+            #[cfg(locales_other)]
             "vai_Vaii" => vai_Vaii,
+            #[cfg(locales_other)]
             "vi" => vi,
+            #[cfg(locales_other)]
             "vo" => vo,
+            #[cfg(locales_other)]
             "vun" => vun,
+            #[cfg(locales_other)]
             "wae" => wae,
+            #[cfg(locales_other)]
             "wo" => wo,
+            #[cfg(locales_other)]
             "xh" => xh,
+            #[cfg(locales_other)]
             "xog" => xog,
+            #[cfg(locales_other)]
             "yav" => yav,
+            #[cfg(locales_other)]
             "yi" => yi,
+            #[cfg(locales_other)]
             "yo" => yo,
+            #[cfg(locales_other)]
             "yo-BJ" => yo_BJ,
+            #[cfg(locales_other)]
             "yo_BJ" => yo_BJ,
+            #[cfg(locales_other)]
             "yue" => yue,
+            #[cfg(locales_other)]
             "yue-Hans" => yue_Hans,
+            #[cfg(locales_other)]
             "yue_Hans" => yue_Hans,
+            #[cfg(locales_other)]
             "yue-Hant" => yue_Hant,
+            #[cfg(locales_other)]
             "yue_Hant" => yue_Hant,
+            #[cfg(locales_other)]
             "zgh" => zgh,
+            #[cfg(locales_other)]
             "zh" => zh,
+            #[cfg(locales_other)]
             "zh-Hans" => zh_Hans,
+            #[cfg(locales_other)]
             "zh_Hans" => zh_Hans,
+            #[cfg(locales_other)]
             "zh-Hans-HK" => zh_Hans_HK,
+            #[cfg(locales_other)]
             "zh_Hans_HK" => zh_Hans_HK,
+            #[cfg(locales_other)]
             "zh-Hans-MO" => zh_Hans_MO,
+            #[cfg(locales_other)]
             "zh_Hans_MO" => zh_Hans_MO,
+            #[cfg(locales_other)]
             "zh-Hans-SG" => zh_Hans_SG,
+            #[cfg(locales_other)]
             "zh_Hans_SG" => zh_Hans_SG,
+            #[cfg(locales_other)]
             "zh-Hant" => zh_Hant,
+            #[cfg(locales_other)]
             "zh_Hant" => zh_Hant,
+            #[cfg(locales_other)]
             "zh-Hant-HK" => zh_Hant_HK,
+            #[cfg(locales_other)]
             "zh_Hant_HK" => zh_Hant_HK,
+            #[cfg(locales_other)]
             "zh-Hant-MO" => zh_Hant_MO,
+            #[cfg(locales_other)]
             "zh_Hant_MO" => zh_Hant_MO,
+            #[cfg(locales_other)]
             "zu" => zu,
-            _ => return Err(Error::parse_locale(s)),
+            _ => return None,
         };
-        Ok(locale)
+        Some(locale)
+    }
+    #[doc = r" Returns a static slice of all available names."]
+    pub fn available_names() -> &'static [&'static str] {
+        AVAILABLE_NAMES
+    }
+    #[doc = r" Returns the locale's decimal representation."]
+    pub fn decimal(&self) -> &'static str {
+        self.symbols().decimal
+    }
+    #[doc = r" Returns the locale's name in English, from CLDR's display names (e.g."]
+    #[doc = r#" "Austrian German" for `de-AT`)."#]
+    pub fn english_name(&self) -> &'static str {
+        ENGLISH_NAMES[*self as usize]
+    }
+    #[doc = r" Returns the locale's [`Grouping`]."]
+    #[doc = r""]
+    #[doc = r" [`Grouping`]: enum.Grouping.html"]
+    pub fn grouping(&self) -> Grouping {
+        self.symbols().grouping
+    }
+    #[doc = r" Returns the locale's infinity representation."]
+    pub fn infinity(&self) -> &'static str {
+        self.symbols().infinity
+    }
+    #[doc = r" Returns an iterator over all locales, in the order in which they are declared."]
+    pub fn iter() -> impl DoubleEndedIterator<Item = Locale> + ExactSizeIterator {
+        ALL.iter().copied()
+    }
+    #[doc = r#" Returns the locale's language subtag (e.g. "sr" for `sr-Latn-BA`)."#]
+    pub fn language(&self) -> &'static str {
+        self.subtags().language
+    }
+    #[doc = r" Returns the locale's minus sign representation."]
+    pub fn minus_sign(&self) -> &'static str {
+        self.symbols().minus_sign
+    }
+    #[doc = r" Returns the locale's name."]
+    pub fn name(&self) -> &'static str {
+        NAMES[*self as usize]
+    }
+    #[doc = r" Returns the locale's NaN representation."]
+    pub fn nan(&self) -> &'static str {
+        self.symbols().nan
+    }
+    #[doc = r" Returns the locale's name in its own language, from CLDR's display names (e.g."]
+    #[doc = r#" "Österreichisches Deutsch" for `de-AT`). Falls back to the English name for the"#]
+    #[doc = r" few locales CLDR has no display names for."]
+    pub fn native_name(&self) -> &'static str {
+        NATIVE_NAMES[*self as usize]
+    }
+    #[doc = r" Returns the ordinal plural category of `n` in the locale's language, from CLDR's"]
+    #[doc = r" ordinal plural rules (e.g. [`PluralCategory::Two`] for 22 in English, which is why"]
+    #[doc = r#" it is written "22nd"). The sign of `n` is ignored."#]
+    #[doc = r""]
+    #[doc = r" # Example"]
+    #[doc = r" ```"]
+    #[doc = r" use num_format::{Locale, PluralCategory};"]
+    #[doc = r""]
+    #[doc = r" assert_eq!(Locale::en.ordinal_category(&22), PluralCategory::Two);"]
+    #[doc = r" assert_eq!(Locale::en.ordinal_category(&12), PluralCategory::Other);"]
+    #[doc = r" assert_eq!(Locale::fr.ordinal_category(&1), PluralCategory::One);"]
+    #[doc = r" ```"]
+    #[doc = r""]
+    #[doc = r" [`PluralCategory::Two`]: enum.PluralCategory.html#variant.Two"]
+    pub fn ordinal_category<N>(&self, n: &N) -> PluralCategory
+    where
+        N: Integer,
+    {
+        self.ordinal_category_of(n.magnitude())
+    }
+    pub(crate) fn ordinal_category_of(&self, n: u128) -> PluralCategory {
+        ordinal_category(ORDINAL_PLURAL_RULES[*self as usize], n)
+    }
+    pub(crate) fn ordinal_rules(&self) -> &'static [OrdinalRule] {
+        ordinal_rule_set(ORDINAL_INDICES[*self as usize])
+    }
+    #[doc = r" Returns the plural category of `n` in the locale's language, from CLDR's cardinal"]
+    #[doc = r" plural rules (e.g. [`PluralCategory::One`] for 1 in English, which is why it is"]
+    #[doc = r#" "1 day" but "2 days"). The sign of `n` is ignored."#]
+    #[doc = r""]
+    #[doc = r" `n` can be any integer (or, with the `with-num-bigint` feature, a `BigInt` or"]
+    #[doc = r" `BigUint`) or a decimal given as [`PluralOperands`]. In many languages a decimal's"]
+    #[doc = r" category depends on how many fraction digits it is written with, so those should"]
+    #[doc = r#" be the digits it is actually formatted with (e.g. "1.0" is [`PluralCategory::Other`]"#]
+    #[doc = r" in English)."]
+    #[doc = r""]
+    #[doc = r" # Example"]
+    #[doc = r" ```"]
+    #[doc = r" use num_format::{Locale, PluralCategory, PluralOperands};"]
+    #[doc = r""]
+    #[doc = r" assert_eq!(Locale::en.plural_category(&1), PluralCategory::One);"]
+    #[doc = r" assert_eq!(Locale::en.plural_category(&2), PluralCategory::Other);"]
+    #[doc = r" assert_eq!(Locale::fr.plural_category(&0), PluralCategory::One);"]
+    #[doc = r" assert_eq!(Locale::ru.plural_category(&21), PluralCategory::One);"]
+    #[doc = r" assert_eq!(Locale::ru.plural_category(&25), PluralCategory::Many);"]
+    #[doc = r""]
+    #[doc = r#" let one = "1.0".parse::<PluralOperands>().unwrap();"#]
+    #[doc = r" assert_eq!(Locale::en.plural_category(&one), PluralCategory::Other);"]
+    #[doc = r" let one_and_a_half = PluralOperands::from_f64(1.5, 1).unwrap();"]
+    #[doc = r" assert_eq!(Locale::ru.plural_category(&one_and_a_half), PluralCategory::Other);"]
+    #[doc = r" ```"]
+    #[doc = r""]
+    #[doc = r" [`PluralCategory::One`]: enum.PluralCategory.html#variant.One"]
+    #[doc = r" [`PluralCategory::Other`]: enum.PluralCategory.html#variant.Other"]
+    #[doc = r" [`PluralOperands`]: struct.PluralOperands.html"]
+    pub fn plural_category<N>(&self, n: &N) -> PluralCategory
+    where
+        N: ToPluralOperands,
+    {
+        plural_category(PLURAL_RULES[*self as usize], &n.to_plural_operands())
+    }
+    #[doc = r" Returns the locale's plus sign representation."]
+    pub fn plus_sign(&self) -> &'static str {
+        self.symbols().plus_sign
+    }
+    #[doc = r#" Returns the locale's region subtag, if any (e.g. "BA" for `sr-Latn-BA`)."#]
+    pub fn region(&self) -> Option<&'static str> {
+        self.subtags().region
+    }
+    #[doc = r#" Returns the locale's script subtag, if any (e.g. "Latn" for `sr-Latn-BA`)."#]
+    pub fn script(&self) -> Option<&'static str> {
+        self.subtags().script
+    }
+    #[doc = r" Returns the locale's separator representation, if any."]
+    pub fn separator(&self) -> &'static str {
+        self.symbols().separator
+    }
+    #[doc = r#" Returns the locale's variant subtag, if any (e.g. "POSIX" for `en-US-POSIX`)."#]
+    pub fn variant(&self) -> Option<&'static str> {
+        self.subtags().variant
+    }
+    fn subtags(&self) -> &'static Subtags {
+        &SUBTAGS[*self as usize]
+    }
+    #[doc = r" Returns the symbols of the locale's default numbering system."]
+    pub(crate) fn symbols(&self) -> &'static Symbols {
+        &SYMBOLS[usize::from(SYMBOL_INDICES[*self as usize])]
+    }
+    #[doc = r" Returns the symbols of the locale in the given numbering system, which are those"]
+    #[doc = r" of its default numbering system unless CLDR has others."]
+    pub(crate) fn symbols_in(&self, numbering_system: NumberingSystem) -> &'static Symbols {
+        let key = (*self, numbering_system as u8);
+        match NUMBERING_SYSTEM_SYMBOLS
+            .binary_search_by_key(&key, |&(locale, numbering_system, _)| {
+                (locale, numbering_system as u8)
+            }) {
+            Ok(i) => &SYMBOLS[usize::from(NUMBERING_SYSTEM_SYMBOLS[i].2)],
+            Err(_) => self.symbols(),
+        }
+    }
+}
+impl Format for Locale {
+    #[inline(always)]
+    fn decimal(&self) -> DecimalStr<'_> {
+        DecimalStr::new(self.decimal()).unwrap()
+    }
+    #[inline(always)]
+    fn grouping(&self) -> Grouping {
+        self.grouping()
+    }
+    #[inline(always)]
+    fn infinity(&self) -> InfinityStr<'_> {
+        InfinityStr::new(self.infinity()).unwrap()
+    }
+    #[inline(always)]
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        MinusSignStr::new(self.minus_sign()).unwrap()
+    }
+    #[inline(always)]
+    fn nan(&self) -> NanStr<'_> {
+        NanStr::new(self.nan()).unwrap()
+    }
+    #[inline(always)]
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        PlusSignStr::new(self.plus_sign()).unwrap()
+    }
+    #[inline(always)]
+    fn separator(&self) -> SeparatorStr<'_> {
+        SeparatorStr::new(self.separator()).unwrap()
+    }
+}
+impl FromStr for Locale {
+    type Err = Error;
+    #[doc = r" Same as [`from_name`]."]
+    #[doc = r""]
+    #[doc = r" [`from_name`]: enum.Locale.html#method.from_name"]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Locale::from_name(s)
     }
 }
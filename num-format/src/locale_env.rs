@@ -0,0 +1,52 @@
+#![cfg(feature = "std")]
+
+use std::env;
+
+use crate::error::Error;
+use crate::locale::Locale;
+
+/// The environment variables that name the locale of numbers, from most to least important (see
+/// [the POSIX standard](https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html)).
+const VARS: [&str; 3] = ["LC_ALL", "LC_NUMERIC", "LANG"];
+
+impl Locale {
+    /// Returns the CLDR [`Locale`] named by the `LC_ALL`, `LC_NUMERIC` or `LANG` environment
+    /// variable, the first of them that is set and not empty, as POSIX programs choose their
+    /// locale for numbers. The name is resolved as in [`from_name`] (e.g. "de_DE.UTF-8" to
+    /// `Locale::de` and "C" to `Locale::en_US_POSIX`); if none of them is set, the locale is
+    /// `Locale::en_US_POSIX`, as the "C" locale is the default.
+    ///
+    /// Unlike [`SystemLocale`], this reads nothing but the environment, so it works where the
+    /// operating system's locales are not installed (e.g. in containers) and on any operating
+    /// system, and it does not need the `with-system-locale` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable's value cannot be parsed into a [`Locale`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use num_format::{Locale, ToFormattedString};
+    ///
+    /// fn main() {
+    ///     std::env::set_var("LC_ALL", "de_DE.UTF-8");
+    ///     let locale = Locale::from_env().unwrap();
+    ///     assert_eq!(locale, Locale::de);
+    ///     assert_eq!(1000000.to_formatted_string(&locale), "1.000.000");
+    /// }
+    /// ```
+    ///
+    /// [`from_name`]: enum.Locale.html#method.from_name
+    /// [`Locale`]: enum.Locale.html
+    /// [`SystemLocale`]: struct.SystemLocale.html
+    pub fn from_env() -> Result<Locale, Error> {
+        let name = VARS
+            .iter()
+            .filter_map(env::var_os)
+            .find(|name| !name.is_empty());
+        match name {
+            Some(name) => Locale::from_name(name.to_string_lossy()),
+            None => Ok(Locale::en_US_POSIX),
+        }
+    }
+}
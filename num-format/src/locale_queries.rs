@@ -0,0 +1,94 @@
+use crate::grouping::Grouping;
+use crate::locale::Locale;
+
+impl Locale {
+    /// Returns an iterator over the locales whose decimal is `decimal`.
+    ///
+    /// # Example
+    /// ```
+    /// use num_format::Locale;
+    ///
+    /// fn main() {
+    ///     assert!(Locale::with_decimal(",").any(|locale| locale == Locale::de));
+    ///     assert!(Locale::with_decimal(",").all(|locale| locale.decimal() == ","));
+    /// }
+    /// ```
+    pub fn with_decimal(decimal: &str) -> impl Iterator<Item = Locale> + '_ {
+        Locale::iter().filter(move |locale| locale.decimal() == decimal)
+    }
+
+    /// Returns an iterator over the locales that use `grouping`.
+    ///
+    /// # Example
+    /// ```
+    /// use num_format::{Grouping, Locale};
+    ///
+    /// fn main() {
+    ///     assert!(Locale::with_grouping(Grouping::Indian).any(|locale| locale == Locale::hi));
+    /// }
+    /// ```
+    pub fn with_grouping(grouping: Grouping) -> impl Iterator<Item = Locale> {
+        Locale::iter().filter(move |locale| locale.grouping() == grouping)
+    }
+
+    /// Returns an iterator over the locales whose separator is `separator`.
+    pub fn with_separator(separator: &str) -> impl Iterator<Item = Locale> + '_ {
+        Locale::iter().filter(move |locale| locale.separator() == separator)
+    }
+
+    /// Returns `true` if `self` and `other` format numbers identically, i.e. they have the same
+    /// decimal, grouping, infinity, minus sign, NaN, plus sign and separator.
+    pub fn has_same_format(&self, other: &Locale) -> bool {
+        self.decimal() == other.decimal()
+            && self.grouping() == other.grouping()
+            && self.infinity() == other.infinity()
+            && self.minus_sign() == other.minus_sign()
+            && self.nan() == other.nan()
+            && self.plus_sign() == other.plus_sign()
+            && self.separator() == other.separator()
+    }
+
+    /// Returns the first locale (in the order of [`iter`]) that formats numbers identically to
+    /// `self` (see [`has_same_format`]). All locales with the same format share a representative,
+    /// so it can serve as a cache key or to pick one test locale per distinct format.
+    ///
+    /// # Example
+    /// ```
+    /// use num_format::Locale;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Locale::en_GB.representative(), Locale::en_AU.representative());
+    ///     assert_ne!(Locale::en.representative(), Locale::de.representative());
+    /// }
+    /// ```
+    ///
+    /// [`has_same_format`]: enum.Locale.html#method.has_same_format
+    /// [`iter`]: enum.Locale.html#method.iter
+    pub fn representative(&self) -> Locale {
+        Locale::iter()
+            .find(|locale| locale.has_same_format(self))
+            .unwrap()
+    }
+
+    /// Returns every locale grouped by format: each inner `Vec` holds locales that format numbers
+    /// identically (see [`has_same_format`]), starting with their [`representative`]. Groups are
+    /// in the order of their representatives in [`iter`].
+    ///
+    /// [`has_same_format`]: enum.Locale.html#method.has_same_format
+    /// [`iter`]: enum.Locale.html#method.iter
+    /// [`representative`]: enum.Locale.html#method.representative
+    #[cfg(feature = "std")]
+    pub fn format_groups() -> Vec<Vec<Locale>> {
+        let mut groups: Vec<Vec<Locale>> = Vec::new();
+        for locale in Locale::iter() {
+            match groups
+                .iter_mut()
+                .find(|group| group[0].has_same_format(&locale))
+            {
+                Some(group) => group.push(locale),
+                None => groups.push(vec![locale]),
+            }
+        }
+        groups
+    }
+}
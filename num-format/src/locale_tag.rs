@@ -0,0 +1,399 @@
+//! Parsing of BCP 47 language tags and POSIX locale names, and CLDR-style fallback from a tag to
+//! the closest available [`Locale`].
+
+use crate::locale::{Locale, LANGUAGE_ALIASES, LIKELY_SCRIPTS, PARENT_LOCALES};
+
+/// POSIX locale name modifiers (e.g. the "latin" of `sr_RS@latin`) that select a script. Unlike
+/// the tables of `locale.rs`, these are not CLDR data.
+const MODIFIER_SCRIPTS: &[(&str, &str)] = &[
+    ("cyrillic", "Cyrl"),
+    ("devanagari", "Deva"),
+    ("latin", "Latn"),
+];
+
+/// The subtags of a locale tag that matter for choosing a [`Locale`], along with the Unicode
+/// extension keywords we understand. Subtags are kept as they were written; comparisons ignore
+/// ASCII case.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Tag<'a> {
+    language: &'a str,
+    script: Option<&'a str>,
+    region: Option<&'a str>,
+    variant: Option<&'a str>,
+    /// Value of the `-u-cu-` keyword (e.g. `"eur"`).
+    pub(crate) currency: Option<&'a str>,
+    /// Value of the `-u-nu-` keyword (e.g. `"arab"`).
+    pub(crate) numbering_system: Option<&'a str>,
+}
+
+impl<'a> Tag<'a> {
+    /// Parses a BCP 47 language tag (e.g. `zh-Hant-TW` or `de-DE-u-nu-latn`) or a POSIX locale
+    /// name (e.g. `en_US.UTF-8` or `sr_RS@latin`). Returns `None` if `s` is neither.
+    pub(crate) fn parse(s: &'a str) -> Option<Tag<'a>> {
+        // POSIX names are language[_territory][.codeset][@modifier]
+        let s = s.trim();
+        let (s, modifier) = match s.find('@') {
+            Some(i) => (&s[..i], Some(&s[i + 1..])),
+            None => (s, None),
+        };
+        let s = match s.find('.') {
+            Some(i) => &s[..i],
+            None => s,
+        };
+        if s.eq_ignore_ascii_case("C") || s.eq_ignore_ascii_case("POSIX") {
+            return Some(Tag {
+                language: "en",
+                script: None,
+                region: Some("US"),
+                variant: Some("POSIX"),
+                currency: None,
+                numbering_system: None,
+            });
+        }
+
+        let mut subtags = s.split(&['-', '_'][..]).peekable();
+        let language = subtags.next().filter(|s| is_alpha(s, 2, 8))?;
+        let mut tag = Tag {
+            language,
+            script: None,
+            region: None,
+            variant: None,
+            currency: None,
+            numbering_system: None,
+        };
+
+        // Extended language subtags (e.g. the "yue" of "zh-yue") don't affect the choice
+        while matches!(subtags.peek(), Some(s) if is_alpha(s, 3, 3)) {
+            let _ = subtags.next();
+        }
+        if matches!(subtags.peek(), Some(s) if is_alpha(s, 4, 4)) {
+            tag.script = subtags.next();
+        }
+        if matches!(subtags.peek(), Some(s) if is_alpha(s, 2, 2) || is_digit(s, 3)) {
+            tag.region = subtags.next();
+        }
+        while matches!(subtags.peek(), Some(s) if is_variant(s)) {
+            let variant = subtags.next();
+            if tag.variant.is_none() {
+                tag.variant = variant;
+            }
+        }
+
+        // Of the extensions and private use subtags, only the "cu" and "nu" keywords of the
+        // Unicode extension (e.g. "-u-cu-eur-nu-arab") are used; the rest need only be well-formed
+        while let Some(singleton) = subtags.next() {
+            if singleton.len() != 1 || !is_alphanumeric(singleton, 1, 1) {
+                return None;
+            }
+            let is_unicode = singleton.eq_ignore_ascii_case("u");
+            let (min, max) = if singleton.eq_ignore_ascii_case("x") {
+                (1, 8)
+            } else {
+                (2, 8)
+            };
+            let mut count = 0;
+            let mut key = None;
+            while let Some(subtag) = subtags.next_if(|s| is_alphanumeric(s, min, max)) {
+                count += 1;
+                if !is_unicode {
+                    continue;
+                }
+                if subtag.len() == 2 {
+                    key = Some(subtag);
+                    continue;
+                }
+                match key.take() {
+                    Some(key) if key.eq_ignore_ascii_case("cu") && tag.currency.is_none() => {
+                        tag.currency = Some(subtag)
+                    }
+                    Some(key)
+                        if key.eq_ignore_ascii_case("nu") && tag.numbering_system.is_none() =>
+                    {
+                        tag.numbering_system = Some(subtag)
+                    }
+                    _ => (),
+                }
+            }
+            if count == 0 {
+                return None;
+            }
+        }
+
+        if let Some(modifier) = modifier {
+            if tag.script.is_none() {
+                tag.script = MODIFIER_SCRIPTS
+                    .iter()
+                    .find(|(m, _)| m.eq_ignore_ascii_case(modifier))
+                    .map(|&(_, script)| script);
+            }
+            if tag.variant.is_none() && modifier.eq_ignore_ascii_case("valencia") {
+                tag.variant = Some("VALENCIA");
+            }
+            if tag.currency.is_none() && modifier.eq_ignore_ascii_case("euro") {
+                tag.currency = Some("EUR");
+            }
+        }
+
+        if let Some(&(_, language)) = LANGUAGE_ALIASES
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(tag.language))
+        {
+            tag.language = language;
+        }
+
+        if let (None, Some(region)) = (tag.script, tag.region) {
+            tag.script = LIKELY_SCRIPTS
+                .iter()
+                .find(|(language, r, _)| {
+                    language.eq_ignore_ascii_case(tag.language) && r.eq_ignore_ascii_case(region)
+                })
+                .map(|&(_, _, script)| script);
+        }
+
+        Some(tag)
+    }
+
+    /// Returns the closest available [`Locale`], falling back through the tag's parents.
+    pub(crate) fn resolve(&self) -> Option<Locale> {
+        let mut tag = *self;
+        loop {
+            if let Some(locale) = tag.locale() {
+                return Some(locale);
+            }
+            tag = tag.parent()?;
+        }
+    }
+
+    /// Returns the tag to try next if this one has no [`Locale`].
+    fn parent(&self) -> Option<Tag<'a>> {
+        if self.variant.is_some() {
+            return Some(Tag {
+                variant: None,
+                ..*self
+            });
+        }
+        if let Some(&(_, parent)) = PARENT_LOCALES.iter().find(|(child, _)| self.is(child)) {
+            if parent == "root" {
+                return None;
+            }
+            return Tag::parse(parent);
+        }
+        if self.region.is_some() {
+            return Some(Tag {
+                region: None,
+                ..*self
+            });
+        }
+        if self.script.is_some() {
+            return Some(Tag {
+                script: None,
+                ..*self
+            });
+        }
+        None
+    }
+
+    /// Returns the [`Locale`] whose name is exactly this tag (ignoring case), if any.
+    fn locale(&self) -> Option<Locale> {
+        Locale::available_names()
+            .iter()
+            .find(|name| self.is(name))
+            .and_then(|name| Locale::from_exact_name(name))
+    }
+
+    /// Returns `true` if `name` (a hyphen-separated CLDR locale name) consists of exactly this
+    /// tag's subtags.
+    fn is(&self, name: &str) -> bool {
+        let mut parts = name.split('-');
+        let subtags = [Some(self.language), self.script, self.region, self.variant];
+        for subtag in subtags.iter().flatten() {
+            match parts.next() {
+                Some(part) if part.eq_ignore_ascii_case(subtag) => (),
+                _ => return false,
+            }
+        }
+        parts.next().is_none()
+    }
+}
+
+fn is_alpha(s: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&s.len()) && s.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_alphanumeric(s: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&s.len()) && s.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_digit(s: &str, len: usize) -> bool {
+    s.len() == len && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_variant(s: &str) -> bool {
+    is_alphanumeric(s, 5, 8) || (is_alphanumeric(s, 4, 4) && s.as_bytes()[0].is_ascii_digit())
+}
+
+/// Resolves a BCP 47 language tag or POSIX locale name to the closest available [`Locale`],
+/// falling back through the tag's parents.
+pub(crate) fn resolve(name: &str) -> Option<Locale> {
+    Tag::parse(name)?.resolve()
+}
+
+impl Locale {
+    /// Returns the available [`Locale`] that best matches a list of BCP 47 language tags or
+    /// POSIX locale names, ordered from most to least preferred (e.g. the languages of an HTTP
+    /// `Accept-Language` header sorted by quality, or the entries of `$LANGUAGE`).
+    ///
+    /// Each tag is resolved as by [`from_name`], falling back through its parents, and the first
+    /// tag that resolves wins. Tags that don't resolve, including the wildcard `*`, are skipped.
+    /// Returns `None` if no tag resolves.
+    ///
+    /// # Example
+    /// ```
+    /// use num_format::Locale;
+    ///
+    /// fn main() {
+    ///     let locale = Locale::negotiate(&["tlh", "fr-CA", "en-US"]);
+    ///     assert_eq!(locale, Some(Locale::fr_CA));
+    ///
+    ///     let locale = Locale::negotiate(&["pt-BR", "en"]);
+    ///     assert_eq!(locale, Some(Locale::pt));
+    ///
+    ///     let locale = Locale::negotiate(&["*"]);
+    ///     assert_eq!(locale, None);
+    /// }
+    /// ```
+    ///
+    /// [`from_name`]: enum.Locale.html#method.from_name
+    /// [`Locale`]: enum.Locale.html
+    pub fn negotiate<I>(requested: I) -> Option<Locale>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        requested.into_iter().find_map(|name| {
+            let name = name.as_ref();
+            Locale::from_exact_name(name).or_else(|| resolve(name))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let test_cases: &[(&str, Locale)] = &[
+            ("en-US", Locale::en),
+            ("EN-us", Locale::en),
+            ("en_US.UTF-8", Locale::en),
+            ("de_DE.ISO-8859-1@euro", Locale::de),
+            ("de-DE-u-nu-latn", Locale::de),
+            ("en-GB-x-private", Locale::en_GB),
+            ("pt-br", Locale::pt),
+            ("pt_AO", Locale::pt_AO),
+            ("es-419", Locale::es_419),
+            ("es-XX", Locale::es),
+            ("en-150", Locale::en_150),
+            #[cfg(locales_other)]
+            ("zh-Hant-TW", Locale::zh_Hant),
+            #[cfg(locales_other)]
+            ("zh-TW", Locale::zh_Hant),
+            #[cfg(locales_other)]
+            ("zh_HK", Locale::zh_Hant_HK),
+            #[cfg(locales_other)]
+            ("zh-CN", Locale::zh),
+            #[cfg(locales_other)]
+            ("zh-yue-HK", Locale::zh_Hant_HK),
+            #[cfg(locales_other)]
+            ("sr-ME", Locale::sr_Latn_ME),
+            #[cfg(locales_other)]
+            ("sr_RS@latin", Locale::sr_Latn),
+            #[cfg(locales_other)]
+            ("ca_ES@valencia", Locale::ca_ES_VALENCIA),
+            #[cfg(locales_other)]
+            ("ca-ES-valencia", Locale::ca_ES_VALENCIA),
+            #[cfg(locales_other)]
+            ("iw-IL", Locale::he),
+            #[cfg(locales_other)]
+            ("tl", Locale::fil),
+            ("deu-AT", Locale::de_AT),
+            ("C", Locale::en_US_POSIX),
+            ("C.UTF-8", Locale::en_US_POSIX),
+            ("POSIX", Locale::en_US_POSIX),
+            (" fr-CA ", Locale::fr_CA),
+        ];
+        for (name, expected) in test_cases {
+            assert_eq!(resolve(name), Some(*expected), "name: {:?}", name);
+            assert_eq!(
+                Locale::from_name(name).unwrap(),
+                *expected,
+                "name: {:?}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(all(locales_eu, not(locales_other)))]
+    fn test_resolve_subset() {
+        // locales that are left out exist, but cannot be looked up by name
+        assert!(Locale::from_name("ja").is_err());
+        assert!(resolve("zh-TW").is_none());
+        assert_eq!(Locale::negotiate(&["ja", "fr-CA"]), Some(Locale::fr_CA));
+        assert!(Locale::iter().any(|locale| locale == Locale::ja));
+        assert_eq!(Locale::ja.name(), "ja");
+    }
+
+    #[test]
+    fn test_resolve_failures() {
+        let test_cases = &[
+            "",
+            "e",
+            "123456789",
+            "en-",
+            "en--US",
+            "en-US-u",
+            "en-US-x",
+            "en-US-u-x",
+            "en US",
+            "xx",
+            // parents of "root" don't fall back to the language's default script
+            "hi-Latn",
+            "az-Arab-IR",
+            "az-IR",
+        ];
+        for name in test_cases {
+            assert_eq!(resolve(name), None, "name: {:?}", name);
+            assert!(Locale::from_name(name).is_err(), "name: {:?}", name);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_resolve_available_names() {
+        for name in Locale::available_names() {
+            let locale = Locale::from_name(name).unwrap();
+            assert_eq!(locale.name(), *name);
+            assert_eq!(resolve(name), Some(locale), "name: {}", name);
+            let posix = name.replace('-', "_");
+            assert_eq!(resolve(&posix), Some(locale), "name: {}", posix);
+            let lowercase = name.to_lowercase();
+            assert_eq!(resolve(&lowercase), Some(locale), "name: {}", lowercase);
+        }
+    }
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(
+            Locale::negotiate(&["*", "xx-YY", "de-AT"]),
+            Some(Locale::de_AT)
+        );
+        assert_eq!(
+            Locale::negotiate(&["de-CH-1996", "fr"]),
+            Some(Locale::de_CH)
+        );
+        assert_eq!(Locale::negotiate(&["*", "xx"]), None);
+        assert_eq!(Locale::negotiate(core::iter::empty::<&str>()), None);
+    }
+}
@@ -0,0 +1,170 @@
+/// Decimal numbering systems that can be chosen with a `-u-nu-` extension of a locale tag (see
+/// [`TaggedLocale`]).
+///
+/// [`TaggedLocale::format`] writes a numbering system's own digits, and [`transliterate`] turns
+/// the ASCII digits of any formatted number into them. Parsing accepts the digits of every
+/// supported numbering system, as long as a number doesn't mix digits of different systems.
+///
+/// [`TaggedLocale`]: struct.TaggedLocale.html
+/// [`TaggedLocale::format`]: struct.TaggedLocale.html#method.format
+/// [`transliterate`]: enum.NumberingSystem.html#method.transliterate
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum NumberingSystem {
+    /// Arabic-Indic digits (e.g. ١٢٣)
+    Arab,
+    /// Extended Arabic-Indic digits, used for Persian and Urdu (e.g. ۱۲۳)
+    Arabext,
+    /// Bengali digits (e.g. ১২৩)
+    Beng,
+    /// Devanagari digits (e.g. १२३)
+    Deva,
+    /// Full-width digits (e.g. １２３)
+    Fullwide,
+    /// Gujarati digits (e.g. ૧૨૩)
+    Gujr,
+    /// Gurmukhi digits (e.g. ੧੨੩)
+    Guru,
+    /// Khmer digits (e.g. ១២៣)
+    Khmr,
+    /// Kannada digits (e.g. ೧೨೩)
+    Knda,
+    /// Lao digits (e.g. ໑໒໓)
+    Laoo,
+    /// Latin (ASCII) digits (e.g. 123)
+    Latn,
+    /// Malayalam digits (e.g. ൧൨൩)
+    Mlym,
+    /// Myanmar digits (e.g. ၁၂၃)
+    Mymr,
+    /// Odia digits (e.g. ୧୨୩)
+    Orya,
+    /// Tamil decimal digits (e.g. ௧௨௩)
+    Tamldec,
+    /// Telugu digits (e.g. ౧౨౩)
+    Telu,
+    /// Thai digits (e.g. ๑๒๓)
+    Thai,
+    /// Tibetan digits (e.g. ༡༢༣)
+    Tibt,
+}
+
+/// Every supported numbering system.
+const ALL: [NumberingSystem; 18] = {
+    use self::NumberingSystem::*;
+    [
+        Arab, Arabext, Beng, Deva, Fullwide, Gujr, Guru, Khmr, Knda, Laoo, Latn, Mlym, Mymr, Orya,
+        Tamldec, Telu, Thai, Tibt,
+    ]
+};
+
+impl NumberingSystem {
+    /// Returns the numbering system with the given CLDR identifier (e.g. `"arab"`), ignoring
+    /// case, if it is one of the supported decimal numbering systems.
+    pub fn from_name<S>(name: S) -> Option<NumberingSystem>
+    where
+        S: AsRef<str>,
+    {
+        let name = name.as_ref();
+        ALL.iter()
+            .find(|system| system.name().eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// Returns the numbering system `c` is a digit of, and the digit's value, if `c` is a digit
+    /// of one of the supported numbering systems.
+    pub(crate) fn from_digit(c: char) -> Option<(NumberingSystem, u8)> {
+        ALL.iter().find_map(|system| {
+            let n = u32::from(c).checked_sub(system.zero())?;
+            if n < 10 {
+                Some((*system, n as u8))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the numbering system's CLDR identifier (e.g. `"arab"`).
+    pub fn name(&self) -> &'static str {
+        use self::NumberingSystem::*;
+        match self {
+            Arab => "arab",
+            Arabext => "arabext",
+            Beng => "beng",
+            Deva => "deva",
+            Fullwide => "fullwide",
+            Gujr => "gujr",
+            Guru => "guru",
+            Khmr => "khmr",
+            Knda => "knda",
+            Laoo => "laoo",
+            Latn => "latn",
+            Mlym => "mlym",
+            Mymr => "mymr",
+            Orya => "orya",
+            Tamldec => "tamldec",
+            Telu => "telu",
+            Thai => "thai",
+            Tibt => "tibt",
+        }
+    }
+
+    /// Returns the numbering system's digit for `n`, which must be less than 10.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 10 or greater.
+    pub fn digit(&self, n: u8) -> char {
+        assert!(n < 10, "{} is not a decimal digit", n);
+        core::char::from_u32(self.zero() + u32::from(n)).unwrap()
+    }
+
+    /// Returns a copy of `s` with its ASCII digits replaced by this numbering system's digits.
+    ///
+    /// # Example
+    /// ```
+    /// use num_format::{Locale, NumberingSystem, ToFormattedString};
+    ///
+    /// fn main() {
+    ///     let s = 1_234_567.to_formatted_string(&Locale::ar);
+    ///     assert_eq!(
+    ///         NumberingSystem::Arab.transliterate(&s),
+    ///         "١\u{66c}٢٣٤\u{66c}٥٦٧",
+    ///     );
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn transliterate(&self, s: &str) -> String {
+        s.chars()
+            .map(|c| match c.to_digit(10) {
+                Some(n) if c.is_ascii_digit() => self.digit(n as u8),
+                _ => c,
+            })
+            .collect()
+    }
+
+    /// Code point of the digit zero; every supported system has its digits in order after it.
+    fn zero(&self) -> u32 {
+        use self::NumberingSystem::*;
+        match self {
+            Arab => 0x0660,
+            Arabext => 0x06f0,
+            Beng => 0x09e6,
+            Deva => 0x0966,
+            Fullwide => 0xff10,
+            Gujr => 0x0ae6,
+            Guru => 0x0a66,
+            Khmr => 0x17e0,
+            Knda => 0x0ce6,
+            Laoo => 0x0ed0,
+            Latn => 0x0030,
+            Mlym => 0x0d66,
+            Mymr => 0x1040,
+            Orya => 0x0b66,
+            Tamldec => 0x0be6,
+            Telu => 0x0c66,
+            Thai => 0x0e50,
+            Tibt => 0x0f20,
+        }
+    }
+}
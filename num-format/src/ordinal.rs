@@ -0,0 +1,101 @@
+use core::fmt;
+
+use crate::buffer::Buffer;
+use crate::integer::Integer;
+use crate::locale::{ordinal_rule_set, Locale};
+
+/// A rule of one of CLDR's rule-based number formats for ordinals (e.g. `20: →→;`), which
+/// applies to the numbers from its base value up to the base value of the next rule.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct OrdinalRule {
+    pub(crate) base: u64,
+    pub(crate) pieces: &'static [OrdinalPiece],
+}
+
+/// The parts an [`OrdinalRule`] is written with.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum OrdinalPiece {
+    /// The number, formatted with the grouping of the locale.
+    Number,
+    /// Literal text.
+    Text(&'static str),
+    /// Text chosen by the ordinal plural category of the number, one per category in the order
+    /// of `PluralCategory`.
+    Plural([&'static str; 6]),
+    /// The number written with another rule set.
+    RuleSet(u8),
+    /// The remainder of dividing the number by `divisor` written with a rule set.
+    Remainder { rule_set: u8, divisor: u64 },
+}
+
+impl Locale {
+    /// Writes `n` as an ordinal in the style of the locale (e.g. "1,001st" for `Locale::en`) into
+    /// any [`fmt::Write`], without heap allocation. See [`to_ordinal_string`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer does.
+    ///
+    /// # Example
+    /// ```
+    /// use core::fmt::Write;
+    ///
+    /// use num_format::Locale;
+    ///
+    /// let mut s = String::new();
+    /// Locale::en.write_ordinal(&mut s, &22)?;
+    /// s.push_str(" and ");
+    /// Locale::de.write_ordinal(&mut s, &1001)?;
+    /// assert_eq!(s, "22nd and 1.001.");
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    ///
+    /// [`fmt::Write`]: https://doc.rust-lang.org/core/fmt/trait.Write.html
+    /// [`to_ordinal_string`]: trait.ToOrdinalString.html#method.to_ordinal_string
+    pub fn write_ordinal<W, N>(&self, w: &mut W, n: &N) -> Result<(), fmt::Error>
+    where
+        W: fmt::Write,
+        N: Integer,
+    {
+        let mut buf = Buffer::new();
+        let _ = buf.write_formatted(n, self);
+        write_rules(w, self, self.ordinal_rules(), n.magnitude(), buf.as_str())
+    }
+}
+
+fn write_rules<W>(
+    w: &mut W,
+    locale: &Locale,
+    rules: &[OrdinalRule],
+    n: u128,
+    formatted: &str,
+) -> Result<(), fmt::Error>
+where
+    W: fmt::Write,
+{
+    // Rule sets always have a rule for 0, so one applies
+    let rule = match rules.iter().rev().find(|rule| u128::from(rule.base) <= n) {
+        Some(rule) => rule,
+        None => return Ok(()),
+    };
+    for piece in rule.pieces {
+        match *piece {
+            OrdinalPiece::Number => w.write_str(formatted)?,
+            OrdinalPiece::Text(s) => w.write_str(s)?,
+            OrdinalPiece::Plural(ref texts) => {
+                w.write_str(texts[locale.ordinal_category_of(n) as usize])?
+            }
+            OrdinalPiece::RuleSet(index) => {
+                write_rules(w, locale, ordinal_rule_set(index), n, formatted)?
+            }
+            OrdinalPiece::Remainder { rule_set, divisor } => write_rules(
+                w,
+                locale,
+                ordinal_rule_set(rule_set),
+                n % u128::from(divisor),
+                formatted,
+            )?,
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,19 @@
 //! Module with traits for parsing a formatted string into a number.
 //!
+//! A leading minus or plus sign is recognized in the format's own representation, in that
+//! representation stripped of bidirectional marks (e.g. `"-"` for `Locale::ar`'s `"\u{61c}-"`),
+//! or as a plain `'-'`, `'\u{2212}'` or `'+'`. A sign anywhere else is an error.
+//!
+//! To pull every number out of a larger piece of text, such as a log file, use
+//! [`find_formatted`].
+//!
+//! Input held as bytes can be parsed with [`from_formatted_bytes`] without first validating it as
+//! UTF-8, and a stream of delimited numbers can be parsed from any [`io::BufRead`] or
+//! [`io::Read`] with [`FormattedReader`].
+//!
+//! Negative numbers written in other styles, such as `"1,234-"` or `"(1,234)"`, can be parsed by
+//! choosing a [`NegativePattern`] and calling [`parse_formatted_with`].
+//!
 //! # Examples
 //! ```
 //! use num_format::Locale;
@@ -11,6 +25,17 @@
 //!     assert_eq!(n, 1_000_000);
 //! }
 //! ```
+//!
+//! [`find_formatted`]: trait.ParseFormatted.html#method.find_formatted
+//! [`FormattedReader`]: struct.FormattedReader.html
+//! [`from_formatted_bytes`]: trait.FromFormattedStr.html#method.from_formatted_bytes
+//! [`io::BufRead`]: https://doc.rust-lang.org/stable/std/io/trait.BufRead.html
+//! [`io::Read`]: https://doc.rust-lang.org/stable/std/io/trait.Read.html
+//! [`NegativePattern`]: enum.NegativePattern.html
+//! [`parse_formatted_with`]: trait.ParseFormatted.html#method.parse_formatted_with
+
+mod formatted_numbers;
+mod formatted_reader;
 
 use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
 use core::str;
@@ -18,8 +43,13 @@ use core::str;
 use crate::constants::*;
 use crate::error::Error;
 use crate::format::Format;
+use crate::numbering_system::NumberingSystem;
 use crate::sealed::Sealed;
 
+pub use self::formatted_numbers::FormattedNumbers;
+#[cfg(feature = "std")]
+pub use self::formatted_reader::FormattedReader;
+
 /// Trait that provides string-like types with a [`parse_formatted`]
 /// method, allowing conversion from a formatted string into a number.
 ///
@@ -40,10 +70,86 @@ pub trait ParseFormatted {
     /// Converts `self` (typically a formatted string) into a number (see [Examples] above).
     ///
     /// [Examples]: trait.ParseFormatted.html#examples
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     fn parse_formatted<F, N>(&self, format: &F) -> Result<N, Error>
     where
         F: Format,
         N: FromFormattedStr;
+
+    /// Same as [`parse_formatted`], but recognizes negative numbers written according to the
+    /// provided [`NegativePattern`].
+    ///
+    /// This has a default implementation for types that implement `AsRef<str>`, so other
+    /// implementations of this trait need not provide it.
+    ///
+    /// # Examples
+    /// ```
+    /// use num_format::Locale;
+    /// use num_format::parsing::{NegativePattern, ParseFormatted};
+    ///
+    /// fn main() {
+    ///     let n = "1.234-"
+    ///         .parse_formatted_with::<_, i32>(&Locale::de, NegativePattern::Suffix)
+    ///         .unwrap();
+    ///     assert_eq!(n, -1_234);
+    ///
+    ///     let n = "(1,234)"
+    ///         .parse_formatted_with::<_, i32>(&Locale::en, NegativePattern::PrefixOrParentheses)
+    ///         .unwrap();
+    ///     assert_eq!(n, -1_234);
+    /// }
+    /// ```
+    ///
+    /// [`NegativePattern`]: enum.NegativePattern.html
+    /// [`parse_formatted`]: trait.ParseFormatted.html#tymethod.parse_formatted
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
+    fn parse_formatted_with<F, N>(&self, format: &F, pattern: NegativePattern) -> Result<N, Error>
+    where
+        Self: AsRef<str>,
+        F: Format,
+        N: FromFormattedStr,
+    {
+        FromFormattedStr::from_formatted_str_with(self.as_ref(), format, pattern)
+    }
+
+    /// Returns an iterator over all of the formatted numbers embedded in `self` (typically a
+    /// larger piece of text), yielding each number's byte range along with the result of parsing
+    /// it (see [`FormattedNumbers`]).
+    ///
+    /// Like [`parse_formatted_with`], this has a default implementation for types that implement
+    /// `AsRef<str>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use num_format::Locale;
+    /// use num_format::parsing::ParseFormatted;
+    ///
+    /// fn main() {
+    ///     let s = "Revenue grew from 1,234,567 to 2,345,678.";
+    ///     let mut iter = s.find_formatted::<_, u64>(&Locale::en);
+    ///
+    ///     let (range, n) = iter.next().unwrap();
+    ///     assert_eq!(&s[range], "1,234,567");
+    ///     assert_eq!(n.unwrap(), 1_234_567);
+    ///
+    ///     let (range, n) = iter.next().unwrap();
+    ///     assert_eq!(&s[range], "2,345,678");
+    ///     assert_eq!(n.unwrap(), 2_345_678);
+    ///
+    ///     assert!(iter.next().is_none());
+    /// }
+    /// ```
+    ///
+    /// [`FormattedNumbers`]: struct.FormattedNumbers.html
+    /// [`parse_formatted_with`]: trait.ParseFormatted.html#method.parse_formatted_with
+    fn find_formatted<'a, F, N>(&'a self, format: &'a F) -> FormattedNumbers<'a, F, N>
+    where
+        Self: AsRef<str>,
+        F: Format,
+        N: FromFormattedStr,
+    {
+        FormattedNumbers::new(self.as_ref(), format)
+    }
 }
 
 impl<S> ParseFormatted for S
@@ -59,6 +165,68 @@ where
     }
 }
 
+/// Type for specifying how negative numbers are written in the input to
+/// [`parse_formatted_with`].
+///
+/// In every pattern, the minus sign may be the format's own minus sign, that sign stripped of
+/// bidirectional marks, or a plain `'-'` or `'\u{2212}'`.
+///
+/// Whitespace at either end of the input is ignored before looking for the pattern, so a
+/// trailing minus sign or closing parenthesis may be followed by whitespace (e.g. `"1,234- "`).
+///
+/// [`parse_formatted_with`]: trait.ParseFormatted.html#method.parse_formatted_with
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum NegativePattern {
+    /// The minus sign precedes the number (e.g. -1,234). This is what [`parse_formatted`] uses.
+    ///
+    /// [`parse_formatted`]: trait.ParseFormatted.html#tymethod.parse_formatted
+    Prefix,
+    /// The minus sign follows the number (e.g. 1,234-), as in many ERP and mainframe exports.
+    Suffix,
+    /// The number is wrapped in parentheses (e.g. (1,234)).
+    Parentheses,
+    /// Either a preceding minus sign or parentheses (e.g. -1,234 or (1,234)).
+    ///
+    /// This is a fixed combination, not a locale's CLDR accounting pattern: many locales write
+    /// negative amounts in accounting formats with a minus sign rather than parentheses, so input
+    /// that uses parentheses for a locale that doesn't is accepted too.
+    PrefixOrParentheses,
+    /// Any of the above.
+    Any,
+}
+
+impl NegativePattern {
+    fn allows_prefix(self) -> bool {
+        matches!(
+            self,
+            NegativePattern::Prefix | NegativePattern::PrefixOrParentheses | NegativePattern::Any
+        )
+    }
+
+    fn allows_suffix(self) -> bool {
+        matches!(self, NegativePattern::Suffix | NegativePattern::Any)
+    }
+
+    fn allows_parentheses(self) -> bool {
+        matches!(
+            self,
+            NegativePattern::Parentheses
+                | NegativePattern::PrefixOrParentheses
+                | NegativePattern::Any
+        )
+    }
+}
+
+impl Default for NegativePattern {
+    /// Returns [`NegativePattern::Prefix`].
+    ///
+    /// [`NegativePattern::Prefix`]: enum.NegativePattern.html#variant.Prefix
+    fn default() -> Self {
+        NegativePattern::Prefix
+    }
+}
+
 /// Marker trait for number types (e.g. `u32`) that string-like types can be parsed
 /// into via the [`ParseFormatted`] trait.
 ///
@@ -67,7 +235,62 @@ where
 /// [`ParseFormatted`]: trait.ParseFormatted.html
 pub trait FromFormattedStr: Sealed + Sized {
     #[allow(missing_docs)]
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     fn from_formatted_str<F>(s: &str, format: &F) -> Result<Self, Error>
+    where
+        F: Format,
+    {
+        Self::from_formatted_bytes_with(s.as_bytes(), format, NegativePattern::Prefix)
+    }
+
+    #[doc(hidden)]
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
+    fn from_formatted_str_with<F>(
+        s: &str,
+        format: &F,
+        pattern: NegativePattern,
+    ) -> Result<Self, Error>
+    where
+        F: Format,
+    {
+        Self::from_formatted_bytes_with(s.as_bytes(), format, pattern)
+    }
+
+    /// Same as [`from_formatted_str`], but for input that is held as bytes (e.g. a field of a
+    /// CSV file), which saves having to validate the whole input as UTF-8 up front. Multi-byte
+    /// sequences in `bytes` that aren't part of the format's signs or separator must be valid
+    /// UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use num_format::Locale;
+    /// use num_format::parsing::FromFormattedStr;
+    ///
+    /// fn main() {
+    ///     let n = u32::from_formatted_bytes(b"1,000,000", &Locale::en).unwrap();
+    ///     assert_eq!(n, 1_000_000);
+    ///
+    ///     let n = i32::from_formatted_bytes("-1\u{202f}000".as_bytes(), &Locale::fr).unwrap();
+    ///     assert_eq!(n, -1_000);
+    /// }
+    /// ```
+    ///
+    /// [`from_formatted_str`]: trait.FromFormattedStr.html#method.from_formatted_str
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
+    fn from_formatted_bytes<F>(bytes: &[u8], format: &F) -> Result<Self, Error>
+    where
+        F: Format,
+    {
+        Self::from_formatted_bytes_with(bytes, format, NegativePattern::Prefix)
+    }
+
+    #[doc(hidden)]
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
+    fn from_formatted_bytes_with<F>(
+        bytes: &[u8],
+        format: &F,
+        pattern: NegativePattern,
+    ) -> Result<Self, Error>
     where
         F: Format;
 }
@@ -75,37 +298,44 @@ pub trait FromFormattedStr: Sealed + Sized {
 macro_rules! impl_from_formatted_str {
     ($type:ty, $max_len:expr) => {
         impl FromFormattedStr for $type {
-            fn from_formatted_str<F>(s: &str, format: &F) -> Result<Self, Error>
+            #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
+            fn from_formatted_bytes_with<F>(
+                s: &[u8],
+                format: &F,
+                pattern: NegativePattern,
+            ) -> Result<Self, Error>
             where
                 F: Format,
             {
                 const BUF_LEN: usize = $max_len;
-                let mut buf: [u8; BUF_LEN] = [0; BUF_LEN];
+                // buf[0] is reserved for a minus sign
+                let mut buf: [u8; BUF_LEN + 1] = [0; BUF_LEN + 1];
 
-                let minus_sign = format.minus_sign().into_str();
-                let is_negative = s.starts_with(minus_sign);
-
-                let mut index = 0;
-                if is_negative {
-                    buf[index] = '-' as u8;
-                    index += 1;
-                }
-                for c in s.chars() {
-                    if c.is_numeric() {
-                        if index > BUF_LEN {
-                            return Err(Error::parse_number(&s));
-                        }
-                        buf[index] = c as u8;
-                        index += 1;
+                let mut index = 1;
+                let is_negative = parse_digits(s, format, pattern, |digit| {
+                    if index > BUF_LEN {
+                        return Err(Error::parse_number(utf8_prefix(s)));
                     }
-                }
+                    buf[index] = digit;
+                    index += 1;
+                    Ok(())
+                })?;
 
-                if index == 0 {
-                    return Err(Error::parse_number(&s));
+                if index == 1 {
+                    return Err(Error::parse_number(utf8_prefix(s)));
                 }
 
-                let s2 = unsafe { str::from_utf8_unchecked(&buf[..index]) };
-                let n = s2.parse::<$type>().map_err(|_| Error::parse_locale(&s))?;
+                let start = if is_negative {
+                    buf[0] = b'-';
+                    0
+                } else {
+                    1
+                };
+
+                let s2 = unsafe { str::from_utf8_unchecked(&buf[start..index]) };
+                let n = s2
+                    .parse::<$type>()
+                    .map_err(|_| Error::parse_locale(utf8_prefix(s)))?;
 
                 Ok(n)
             }
@@ -130,12 +360,16 @@ impl_from_formatted_str!(i128, I128_MAX_LEN);
 macro_rules! impl_from_formatted_str_non_zero {
     ($type:ty, $related_type:ty, $max_len:expr) => {
         impl FromFormattedStr for $type {
-            fn from_formatted_str<F>(s: &str, format: &F) -> Result<Self, Error>
+            fn from_formatted_bytes_with<F>(
+                s: &[u8],
+                format: &F,
+                pattern: NegativePattern,
+            ) -> Result<Self, Error>
             where
                 F: Format,
             {
-                let n = s.parse_formatted::<_, $related_type>(format)?;
-                let n = Self::new(n).ok_or_else(|| Error::parse_number(s))?;
+                let n = <$related_type>::from_formatted_bytes_with(s, format, pattern)?;
+                let n = Self::new(n).ok_or_else(|| Error::parse_number(utf8_prefix(s)))?;
                 Ok(n)
             }
         }
@@ -149,6 +383,253 @@ impl_from_formatted_str_non_zero!(NonZeroUsize, usize, USIZE_MAX_LEN);
 impl_from_formatted_str_non_zero!(NonZeroU64, u64, U64_MAX_LEN);
 impl_from_formatted_str_non_zero!(NonZeroU128, u128, U128_MAX_LEN);
 
+/// Bidirectional formatting marks (LEFT-TO-RIGHT MARK, RIGHT-TO-LEFT MARK and ARABIC LETTER MARK)
+/// that some locales (e.g. `ar`, `fa` and `he`) put around their minus and plus signs.
+const BIDI_MARKS: &[char] = &['\u{200e}', '\u{200f}', '\u{61c}'];
+
+/// Minus signs that are recognized regardless of format: HYPHEN-MINUS and MINUS SIGN (U+2212).
+const MINUS_SIGNS: &[&str] = &["-", "\u{2212}"];
+
+/// Plus signs that are recognized regardless of format.
+const PLUS_SIGNS: &[&str] = &["+"];
+
+/// Walks `s`, passing each digit (as an ASCII byte) to `push`. Returns `true` if `s` is written
+/// as a negative number according to `pattern`.
+///
+/// Digits may be those of any supported [`NumberingSystem`] (e.g. `"\u{661}\u{662}"` for 12),
+/// but all digits of `s` must belong to the same one.
+///
+/// A sign is recognized if it is the format's sign exactly, the format's sign with its
+/// bidirectional marks removed, or one of the format-independent signs above (e.g. an ASCII
+/// hyphen typed by a user in an Arabic locale). A sign or parenthesis anywhere other than where
+/// `pattern` allows is an error.
+///
+/// Works on bytes so that callers holding `&[u8]` need not validate all of their input as UTF-8;
+/// only multi-byte sequences that aren't part of a sign or separator are decoded (and rejected
+/// if invalid).
+#[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
+fn parse_digits<F, P>(
+    s: &[u8],
+    format: &F,
+    pattern: NegativePattern,
+    mut push: P,
+) -> Result<bool, Error>
+where
+    F: Format,
+    P: FnMut(u8) -> Result<(), Error>,
+{
+    let minus_sign = format.minus_sign().into_str();
+    let plus_sign = format.plus_sign().into_str();
+    let separator = format.separator().into_str().as_bytes();
+
+    let trimmed = trim(s);
+    let (is_negative, mut rest) = if let Some(rest) = strip_parentheses(trimmed, pattern) {
+        (true, rest)
+    } else if let Some(rest) = strip_sign(
+        trimmed,
+        minus_sign,
+        MINUS_SIGNS,
+        pattern.allows_prefix(),
+        pattern.allows_suffix(),
+    ) {
+        (true, rest)
+    } else if let Some(rest) = strip_sign(
+        trimmed,
+        plus_sign,
+        PLUS_SIGNS,
+        true,
+        pattern.allows_suffix(),
+    ) {
+        (false, rest)
+    } else {
+        (false, trimmed)
+    };
+
+    let mut system = None;
+    let mut push_digit = |digit_system: NumberingSystem, n: u8| {
+        if *system.get_or_insert(digit_system) != digit_system {
+            return Err(Error::parse_number(utf8_prefix(s)));
+        }
+        push(b'0' + n)
+    };
+
+    while let Some(&byte) = rest.first() {
+        if !separator.is_empty() && rest.starts_with(separator) {
+            rest = &rest[separator.len()..];
+            continue;
+        }
+        if byte == b'('
+            || byte == b')'
+            || strip_prefix(rest, minus_sign, MINUS_SIGNS).is_some()
+            || strip_prefix(rest, plus_sign, PLUS_SIGNS).is_some()
+        {
+            return Err(Error::parse_number(utf8_prefix(s)));
+        }
+        if byte.is_ascii() {
+            if byte.is_ascii_digit() {
+                push_digit(NumberingSystem::Latn, byte - b'0')?;
+            }
+            rest = &rest[1..];
+            continue;
+        }
+        // Numeric characters other than the digits of a supported numbering system would
+        // otherwise be silently dropped or misread
+        match decode_char(rest) {
+            Some((c, len)) => {
+                if let Some((digit_system, n)) = NumberingSystem::from_digit(c) {
+                    push_digit(digit_system, n)?;
+                } else if c.is_numeric() {
+                    return Err(Error::parse_number(utf8_prefix(s)));
+                }
+                rest = &rest[len..];
+            }
+            None => return Err(Error::parse_number(utf8_prefix(s))),
+        }
+    }
+
+    Ok(is_negative)
+}
+
+/// If `pattern` allows parentheses and `s` is wrapped in them, returns what's inside.
+fn strip_parentheses(s: &[u8], pattern: NegativePattern) -> Option<&[u8]> {
+    if !pattern.allows_parentheses() || !s.starts_with(b"(") || !s.ends_with(b")") {
+        return None;
+    }
+    Some(trim(&s[1..s.len() - 1]))
+}
+
+/// If `s` starts with `sign` (when `prefix` is `true`) or ends with it (when `suffix` is `true`),
+/// returns the rest of `s`.
+fn strip_sign<'a>(
+    s: &'a [u8],
+    sign: &str,
+    fallbacks: &[&str],
+    prefix: bool,
+    suffix: bool,
+) -> Option<&'a [u8]> {
+    if prefix {
+        if let Some(rest) = strip_prefix(s, sign, fallbacks) {
+            return Some(rest);
+        }
+    }
+    if suffix {
+        if let Some(rest) = strip_suffix(s, sign, fallbacks) {
+            return Some(rest);
+        }
+    }
+    None
+}
+
+/// If `s` starts with `sign` (or with `sign` stripped of bidirectional marks, or with one of the
+/// `fallbacks`), returns the rest of `s` after the sign and any bidirectional marks following it.
+fn strip_prefix<'a>(s: &'a [u8], sign: &str, fallbacks: &[&str]) -> Option<&'a [u8]> {
+    let rest = if !sign.is_empty() && s.starts_with(sign.as_bytes()) {
+        &s[sign.len()..]
+    } else {
+        let s = trim_start_marks(s);
+        let sign = sign.trim_matches(BIDI_MARKS);
+        if !sign.is_empty() && s.starts_with(sign.as_bytes()) {
+            &s[sign.len()..]
+        } else {
+            let fallback = fallbacks
+                .iter()
+                .find(|fallback| s.starts_with(fallback.as_bytes()))?;
+            &s[fallback.len()..]
+        }
+    };
+    Some(trim_start_marks(rest))
+}
+
+/// Mirror image of [`strip_prefix`] for signs that follow the number.
+fn strip_suffix<'a>(s: &'a [u8], sign: &str, fallbacks: &[&str]) -> Option<&'a [u8]> {
+    let rest = if !sign.is_empty() && s.ends_with(sign.as_bytes()) {
+        &s[..s.len() - sign.len()]
+    } else {
+        let s = trim_end_marks(s);
+        let sign = sign.trim_matches(BIDI_MARKS);
+        if !sign.is_empty() && s.ends_with(sign.as_bytes()) {
+            &s[..s.len() - sign.len()]
+        } else {
+            let fallback = fallbacks
+                .iter()
+                .find(|fallback| s.ends_with(fallback.as_bytes()))?;
+            &s[..s.len() - fallback.len()]
+        }
+    };
+    Some(trim_end_marks(rest))
+}
+
+fn trim(mut s: &[u8]) -> &[u8] {
+    while let Some((c, len)) = decode_char(s) {
+        if !c.is_whitespace() {
+            break;
+        }
+        s = &s[len..];
+    }
+    while let Some((c, len)) = decode_last_char(s) {
+        if !c.is_whitespace() {
+            break;
+        }
+        s = &s[..s.len() - len];
+    }
+    s
+}
+
+fn trim_start_marks(mut s: &[u8]) -> &[u8] {
+    while let Some((c, len)) = decode_char(s) {
+        if !BIDI_MARKS.contains(&c) {
+            break;
+        }
+        s = &s[len..];
+    }
+    s
+}
+
+fn trim_end_marks(mut s: &[u8]) -> &[u8] {
+    while let Some((c, len)) = decode_last_char(s) {
+        if !BIDI_MARKS.contains(&c) {
+            break;
+        }
+        s = &s[..s.len() - len];
+    }
+    s
+}
+
+/// Decodes the first character of `s` along with its length in bytes. Returns `None` if `s` is
+/// empty or does not start with valid UTF-8.
+fn decode_char(s: &[u8]) -> Option<(char, usize)> {
+    let len = match *s.first()? {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => return None,
+    };
+    let c = str::from_utf8(s.get(..len)?).ok()?.chars().next()?;
+    Some((c, len))
+}
+
+/// Decodes the last character of `s` along with its length in bytes. Returns `None` if `s` is
+/// empty or does not end with valid UTF-8.
+fn decode_last_char(s: &[u8]) -> Option<(char, usize)> {
+    let start = (s.len().saturating_sub(4)..s.len())
+        .rev()
+        .find(|i| s[*i] & 0xc0 != 0x80)?;
+    let (c, len) = decode_char(&s[start..])?;
+    if start + len != s.len() {
+        return None;
+    }
+    Some((c, len))
+}
+
+/// Returns the longest prefix of `s` that is valid UTF-8, for use in error messages.
+fn utf8_prefix(s: &[u8]) -> &str {
+    match str::from_utf8(s) {
+        Ok(s) => s,
+        Err(e) => str::from_utf8(&s[..e.valid_up_to()]).unwrap(),
+    }
+}
+
 #[cfg(feature = "with-num-bigint")]
 mod num {
     use num_bigint::{BigInt, BigUint};
@@ -158,30 +639,31 @@ mod num {
     macro_rules! impl_from_formatted_str_num_bigint {
         ($type:ty) => {
             impl FromFormattedStr for $type {
-                fn from_formatted_str<F>(s: &str, format: &F) -> Result<Self, Error>
+                fn from_formatted_bytes_with<F>(
+                    s: &[u8],
+                    format: &F,
+                    pattern: NegativePattern,
+                ) -> Result<Self, Error>
                 where
                     F: Format,
                 {
-                    let mut buf = Vec::new();
+                    let mut buf = vec![b'-'];
 
-                    let minus_sign = format.minus_sign().into_str();
-                    let is_negative = s.starts_with(minus_sign);
+                    let is_negative = parse_digits(s, format, pattern, |digit| {
+                        buf.push(digit);
+                        Ok(())
+                    })?;
 
-                    if is_negative {
-                        buf.push('-' as u8);
-                    }
-                    for c in s.chars() {
-                        if c.is_numeric() {
-                            buf.push(c as u8);
-                        }
+                    if buf.len() == 1 {
+                        return Err(Error::parse_number(utf8_prefix(s)));
                     }
 
-                    if buf.is_empty() {
-                        return Err(Error::parse_number(&s));
-                    }
+                    let start = if is_negative { 0 } else { 1 };
 
-                    let s2 = unsafe { str::from_utf8_unchecked(&buf[..]) };
-                    let n = s2.parse::<$type>().map_err(|_| Error::parse_locale(&s))?;
+                    let s2 = unsafe { str::from_utf8_unchecked(&buf[start..]) };
+                    let n = s2
+                        .parse::<$type>()
+                        .map_err(|_| Error::parse_locale(utf8_prefix(s)))?;
 
                     Ok(n)
                 }
@@ -213,6 +695,218 @@ mod num {
                     .unwrap(),
                 (-1_000_000).to_bigint().unwrap()
             );
+            assert_eq!(
+                "1,000,000-"
+                    .parse_formatted_with::<_, BigInt>(&Locale::en, NegativePattern::Suffix)
+                    .unwrap(),
+                (-1_000_000).to_bigint().unwrap()
+            );
+            assert_eq!(
+                "(1,000,000)"
+                    .parse_formatted_with::<_, BigInt>(
+                        &Locale::en,
+                        NegativePattern::PrefixOrParentheses
+                    )
+                    .unwrap(),
+                (-1_000_000).to_bigint().unwrap()
+            );
+            assert!("(1,000,000)"
+                .parse_formatted::<_, BigInt>(&Locale::en)
+                .is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomFormat, Locale};
+
+    #[test]
+    fn test_parsing_signs() {
+        let test_cases: &[(&str, i32, Locale)] = &[
+            // exact locale signs
+            ("\u{61c}-1\u{66c}000", -1_000, Locale::ar),
+            ("\u{200e}\u{2212}1\u{66c}000", -1_000, Locale::fa),
+            ("\u{200e}-1,000", -1_000, Locale::he),
+            ("\u{200f}-1\u{66c}000", -1_000, Locale::ckb),
+            ("\u{61c}+1\u{66c}000", 1_000, Locale::ar),
+            // locale signs without their bidi marks
+            ("-1\u{66c}000", -1_000, Locale::ar),
+            ("\u{2212}1\u{66c}000", -1_000, Locale::fa),
+            ("-1,000", -1_000, Locale::he),
+            ("+1\u{66c}000", 1_000, Locale::ar),
+            // format-independent signs
+            ("-1\u{66c}000", -1_000, Locale::fa),
+            ("\u{2212}1,000", -1_000, Locale::en),
+            ("\u{2212}1\u{a0}000", -1_000, Locale::fi),
+            ("-1\u{a0}000", -1_000, Locale::fi),
+            ("\u{200f}-1,000", -1_000, Locale::en),
+            // leading whitespace
+            ("  -1,000", -1_000, Locale::en),
+            ("  +1,000", 1_000, Locale::en),
+        ];
+        for (input, expected, locale) in test_cases {
+            let n = input.parse_formatted::<_, i32>(locale).unwrap_or_else(|e| {
+                panic!(
+                    "locale: {}, input: {:?}, error: {}",
+                    locale.name(),
+                    input,
+                    e
+                )
+            });
+            assert_eq!(
+                *expected,
+                n,
+                "locale: {}, input: {:?}",
+                locale.name(),
+                input
+            );
+        }
+
+        let should_fail: &[(&str, Locale)] = &[
+            ("1,000-", Locale::en),
+            ("1-000", Locale::en),
+            ("--1,000", Locale::en),
+            ("+-1,000", Locale::en),
+            ("1+000", Locale::en),
+            ("1,000+", Locale::en),
+            ("++1,000", Locale::en),
+            ("1\u{66c}000\u{61c}+", Locale::ar),
+            ("1\u{66c}000\u{61c}-", Locale::ar),
+            ("1\u{a0}000\u{2212}", Locale::fi),
+            ("(1,000)", Locale::en),
+        ];
+        for (input, locale) in should_fail {
+            if input.parse_formatted::<_, i32>(locale).is_ok() {
+                panic!("parsed {:?} for locale {}", input, locale.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parsing_negative_patterns() {
+        use NegativePattern::*;
+
+        let test_cases: &[(&str, i32, Locale, NegativePattern)] = &[
+            ("1.234-", -1_234, Locale::de, Suffix),
+            ("1.234\u{2212}", -1_234, Locale::de, Suffix),
+            ("1\u{66c}234\u{61c}-", -1_234, Locale::ar, Suffix),
+            ("1\u{66c}234-", -1_234, Locale::ar, Suffix),
+            ("1.234+", 1_234, Locale::de, Suffix),
+            ("+1.234", 1_234, Locale::de, Suffix),
+            ("1.234", 1_234, Locale::de, Suffix),
+            ("(1,234)", -1_234, Locale::en, Parentheses),
+            ("( 1,234 )", -1_234, Locale::en, Parentheses),
+            ("1,234", 1_234, Locale::en, Parentheses),
+            ("(1,234)", -1_234, Locale::en, PrefixOrParentheses),
+            ("-1,234", -1_234, Locale::en, PrefixOrParentheses),
+            ("(1,234)", -1_234, Locale::en, Any),
+            ("-1,234", -1_234, Locale::en, Any),
+            ("1,234-", -1_234, Locale::en, Any),
+            ("1,234", 1_234, Locale::en, Any),
+        ];
+        for (input, expected, locale, pattern) in test_cases {
+            let n = input
+                .parse_formatted_with::<_, i32>(locale, *pattern)
+                .unwrap_or_else(|e| {
+                    panic!("input: {:?}, pattern: {:?}, error: {}", input, pattern, e)
+                });
+            assert_eq!(*expected, n, "input: {:?}, pattern: {:?}", input, pattern);
         }
+
+        let should_fail: &[(&str, NegativePattern)] = &[
+            ("-1,234", Suffix),
+            ("1,234-", PrefixOrParentheses),
+            ("-1,234", Parentheses),
+            ("(-1,234)", Parentheses),
+            ("(1,234", PrefixOrParentheses),
+            ("-1,234-", Any),
+            ("(1,234)-", Any),
+        ];
+        for (input, pattern) in should_fail {
+            if input
+                .parse_formatted_with::<_, i32>(&Locale::en, *pattern)
+                .is_ok()
+            {
+                panic!("parsed {:?} with pattern {:?}", input, pattern);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parsing_separator_that_looks_like_a_minus_sign() {
+        let format = CustomFormat::builder().separator("-").build().unwrap();
+        assert_eq!(
+            "-1-000-000".parse_formatted::<_, i32>(&format).unwrap(),
+            -1_000_000
+        );
+    }
+
+    #[test]
+    fn test_parsing_too_many_digits() {
+        assert!("1,000".parse_formatted::<_, u8>(&Locale::en).is_err());
+        assert!("-1,000".parse_formatted::<_, i8>(&Locale::en).is_err());
+    }
+
+    #[test]
+    fn test_parsing_bytes() {
+        assert_eq!(
+            u32::from_formatted_bytes(b"1,000,000", &Locale::en).unwrap(),
+            1_000_000
+        );
+        assert_eq!(
+            i32::from_formatted_bytes("\u{61c}-1\u{66c}000".as_bytes(), &Locale::ar).unwrap(),
+            -1_000
+        );
+        assert_eq!(
+            i32::from_formatted_bytes("  \u{2212}1\u{a0}000\u{a0}".as_bytes(), &Locale::fr)
+                .unwrap(),
+            -1_000
+        );
+
+        // invalid UTF-8 outside of signs and separators
+        assert!(u32::from_formatted_bytes(b"1,\xff000", &Locale::en).is_err());
+        assert!(u32::from_formatted_bytes(b"1,000\xe2\x88", &Locale::en).is_err());
+        assert!(u32::from_formatted_bytes(b"\xe2\x881,000", &Locale::en).is_err());
+    }
+
+    #[test]
+    fn test_parsing_non_ascii_digits() {
+        let n: u32 = "\u{661}\u{66c}\u{662}\u{663}\u{664}"
+            .parse_formatted(&Locale::ar)
+            .unwrap();
+        assert_eq!(n, 1_234);
+        let n: i32 = "-\u{967}\u{968}".parse_formatted(&Locale::en).unwrap();
+        assert_eq!(n, -12);
+
+        // digits of different numbering systems are not mixed
+        assert!("1\u{662}".parse_formatted::<_, u32>(&Locale::en).is_err());
+        assert!("\u{661}\u{6f2}"
+            .parse_formatted::<_, u32>(&Locale::fa)
+            .is_err());
+        // other numeric characters (Chakma digits, vulgar fractions) must not be silently dropped
+        assert!("\u{11137}".parse_formatted::<_, u32>(&Locale::en).is_err());
+        assert!("1\u{bd}".parse_formatted::<_, u32>(&Locale::en).is_err());
+    }
+
+    #[test]
+    fn test_parsing_implementation_outside_the_crate() {
+        // implementations written before parse_formatted_with and find_formatted were added
+        // only need to provide parse_formatted
+        struct Digits(&'static str);
+
+        impl ParseFormatted for Digits {
+            fn parse_formatted<F, N>(&self, format: &F) -> Result<N, Error>
+            where
+                F: Format,
+                N: FromFormattedStr,
+            {
+                self.0.parse_formatted(format)
+            }
+        }
+
+        let n: u32 = Digits("1,000").parse_formatted(&Locale::en).unwrap();
+        assert_eq!(n, 1_000);
     }
 }
@@ -0,0 +1,270 @@
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use super::{strip_prefix, FromFormattedStr, NegativePattern, MINUS_SIGNS, PLUS_SIGNS};
+use crate::error::Error;
+use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::numbering_system::NumberingSystem;
+
+/// Iterator over the formatted numbers found in a larger piece of text (see
+/// [`find_formatted`]).
+///
+/// Each item is the byte range of a number within the text along with the result of parsing it.
+/// The format's separator and [`Grouping`] decide where a number ends: `"1,234,567"` is one
+/// number in `Locale::en`, whereas `"1,2,3"` is three. A number followed by the format's decimal
+/// and more digits is yielded as a single span whose parse result is an error, since it is not an
+/// integer.
+///
+/// Digits may be those of any supported [`NumberingSystem`] (e.g. `"\u{661}\u{66c}\u{662}"`).
+///
+/// A minus or plus sign directly in front of the digits is part of the number, unless the sign
+/// itself directly follows a letter or digit (so `"10-20"` yields `10` and `20`).
+///
+/// [`find_formatted`]: trait.ParseFormatted.html#method.find_formatted
+/// [`Grouping`]: ../enum.Grouping.html
+/// [`NumberingSystem`]: ../enum.NumberingSystem.html
+#[derive(Clone, Debug)]
+pub struct FormattedNumbers<'a, F, N> {
+    format: &'a F,
+    phantom: PhantomData<fn() -> N>,
+    pos: usize,
+    s: &'a str,
+}
+
+impl<'a, F, N> FormattedNumbers<'a, F, N>
+where
+    F: Format,
+    N: FromFormattedStr,
+{
+    pub(crate) fn new(s: &'a str, format: &'a F) -> Self {
+        FormattedNumbers {
+            format,
+            phantom: PhantomData,
+            pos: 0,
+            s,
+        }
+    }
+
+    /// Returns the range of the next number along with whether it has a fractional part.
+    fn next_span(&mut self) -> Option<(Range<usize>, bool)> {
+        let minus_sign = self.format.minus_sign().into_str();
+        let plus_sign = self.format.plus_sign().into_str();
+
+        let mut prev = self.s[..self.pos].chars().next_back();
+        while let Some(c) = self.s[self.pos..].chars().next() {
+            let start = self.pos;
+            let rest = &self.s[start..];
+            let follows_word = match prev {
+                Some(prev) => prev.is_alphanumeric(),
+                None => false,
+            };
+            let digits_start = if is_digit(c) {
+                Some(start)
+            } else if !follows_word {
+                let rest = rest.as_bytes();
+                strip_prefix(rest, minus_sign, MINUS_SIGNS)
+                    .or_else(|| strip_prefix(rest, plus_sign, PLUS_SIGNS))
+                    .map(|after| self.s.len() - after.len())
+                    .filter(|&digits_start| {
+                        matches!(self.s[digits_start..].chars().next(), Some(c) if is_digit(c))
+                    })
+            } else {
+                None
+            };
+            if let Some(digits_start) = digits_start {
+                let (end, has_fraction) = self.end_of_number(digits_start);
+                self.pos = end;
+                return Some((start..end, has_fraction));
+            }
+            prev = Some(c);
+            self.pos += c.len_utf8();
+        }
+        None
+    }
+
+    /// Returns the end of the number whose digits begin at `start`, following separators only
+    /// where they make up valid groups, along with whether the number has a fractional part.
+    fn end_of_number(&self, start: usize) -> (usize, bool) {
+        let decimal = self.format.decimal().into_str();
+        let separator = self.format.separator().into_str();
+        let grouping = self.format.grouping();
+
+        let (leading_end, leading_len) = end_of_digits(self.s, start);
+
+        let mut end = leading_end;
+        if !separator.is_empty() && grouping != Grouping::Posix {
+            let mut group_end = leading_end;
+            while self.s[group_end..].starts_with(separator) {
+                let group_start = group_end + separator.len();
+                let (end_of_group, group_len) = end_of_digits(self.s, group_start);
+                group_end = end_of_group;
+                match (grouping, leading_len, group_len) {
+                    (Grouping::Standard, 1..=3, 3) => end = group_end,
+                    // Indian groups are pairs of digits, except for the final group of three
+                    (Grouping::Indian, 1..=2, 2) => continue,
+                    (Grouping::Indian, 1..=2, 3) => {
+                        end = group_end;
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        if !decimal.is_empty() && self.s[end..].starts_with(decimal) {
+            let fraction_start = end + decimal.len();
+            let (fraction_end, _) = end_of_digits(self.s, fraction_start);
+            if fraction_end > fraction_start {
+                return (fraction_end, true);
+            }
+        }
+
+        (end, false)
+    }
+}
+
+impl<'a, F, N> Iterator for FormattedNumbers<'a, F, N>
+where
+    F: Format,
+    N: FromFormattedStr,
+{
+    type Item = (Range<usize>, Result<N, Error>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, has_fraction) = self.next_span()?;
+        let s = &self.s[range.clone()];
+        let value = if has_fraction {
+            Err(Error::parse_number(s))
+        } else {
+            N::from_formatted_str_with(s, self.format, NegativePattern::Prefix)
+        };
+        Some((range, value))
+    }
+}
+
+/// Returns `true` if `c` is a digit of a supported numbering system.
+fn is_digit(c: char) -> bool {
+    c.is_ascii_digit() || NumberingSystem::from_digit(c).is_some()
+}
+
+/// Returns the end of the run of digits in `s` beginning at `start`, along with the number of
+/// digits in it.
+fn end_of_digits(s: &str, start: usize) -> (usize, usize) {
+    s[start..]
+        .chars()
+        .take_while(|&c| is_digit(c))
+        .fold((start, 0), |(end, count), c| {
+            (end + c.len_utf8(), count + 1)
+        })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::parsing::ParseFormatted;
+    use crate::Locale;
+
+    fn find<'a, F>(s: &'a str, format: &F) -> Vec<(&'a str, Option<i64>)>
+    where
+        F: Format,
+    {
+        s.find_formatted::<_, i64>(format)
+            .map(|(range, value)| (&s[range], value.ok()))
+            .collect()
+    }
+
+    #[test]
+    fn test_find_formatted_standard() {
+        let s = "Revenue grew from 1,234,567 to 2,345,678 (up -3 points), see pages 10-20.";
+        assert_eq!(
+            find(s, &Locale::en),
+            vec![
+                ("1,234,567", Some(1_234_567)),
+                ("2,345,678", Some(2_345_678)),
+                ("-3", Some(-3)),
+                ("10", Some(10)),
+                ("20", Some(20)),
+            ]
+        );
+
+        assert_eq!(
+            find("1,2,3 and 12,34 and 1234,567", &Locale::en),
+            vec![
+                ("1", Some(1)),
+                ("2", Some(2)),
+                ("3", Some(3)),
+                ("12", Some(12)),
+                ("34", Some(34)),
+                ("1234", Some(1_234)),
+                ("567", Some(567)),
+            ]
+        );
+
+        assert_eq!(
+            find("It costs 1,000. Or 3.14 and 1.234,5", &Locale::en),
+            vec![
+                ("1,000", Some(1_000)),
+                ("3.14", None),
+                ("1.234", None),
+                ("5", Some(5)),
+            ]
+        );
+
+        assert_eq!(
+            find("Preis: 1.234,50 € oder 1.000.000 €", &Locale::de),
+            vec![("1.234,50", None), ("1.000.000", Some(1_000_000))]
+        );
+    }
+
+    #[test]
+    fn test_find_formatted_indian() {
+        assert_eq!(
+            find("1,00,00,000 and 1,000 and 1,00", &Locale::en_IN),
+            vec![
+                ("1,00,00,000", Some(10_000_000)),
+                ("1,000", Some(1_000)),
+                ("1", Some(1)),
+                ("00", Some(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_formatted_signs() {
+        assert_eq!(
+            find("\u{61c}-1\u{66c}234 / -5 / +6", &Locale::ar),
+            vec![
+                ("\u{61c}-1\u{66c}234", Some(-1_234)),
+                ("-5", Some(-5)),
+                ("+6", Some(6)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_formatted_numbering_systems() {
+        assert_eq!(
+            find(
+                "\u{61c}-\u{661}\u{66c}\u{662}\u{663}\u{664} and \u{967}\u{968}",
+                &Locale::ar
+            ),
+            vec![
+                ("\u{61c}-\u{661}\u{66c}\u{662}\u{663}\u{664}", Some(-1_234)),
+                ("\u{967}\u{968}", Some(12)),
+            ]
+        );
+        // a run that mixes numbering systems is found as one number, but doesn't parse
+        assert_eq!(find("1\u{662}", &Locale::en), vec![("1\u{662}", None)]);
+    }
+
+    #[test]
+    fn test_find_formatted_overflow() {
+        let s = "255 and 1,000";
+        let found = s
+            .find_formatted::<_, u8>(&Locale::en)
+            .map(|(range, value)| (range, value.ok()))
+            .collect::<Vec<_>>();
+        assert_eq!(found, vec![(0..3, Some(255)), (8..13, None)]);
+    }
+}
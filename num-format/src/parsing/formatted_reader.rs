@@ -0,0 +1,208 @@
+#![cfg(feature = "std")]
+
+use std::io::{self, BufRead, BufReader, Read};
+use std::marker::PhantomData;
+
+use super::{FromFormattedStr, NegativePattern};
+use crate::format::Format;
+
+/// Iterator that parses one formatted number per record of an [`io::BufRead`] (or of an
+/// [`io::Read`], through [`from_read`]), reusing a single buffer rather than allocating a
+/// `String` per record.
+///
+/// Records are separated by a delimiter byte (e.g. `b'\n'`). Each record is parsed with
+/// [`from_formatted_bytes`], so only the parts of it that aren't ASCII need to be valid UTF-8.
+/// A record that fails to parse is yielded as an [`io::Error`] of kind
+/// [`io::ErrorKind::InvalidData`] wrapping the parsing [`Error`]; iteration carries on with the
+/// next record. An error from the underlying reader ends the iteration.
+///
+/// # Examples
+/// ```
+/// use num_format::Locale;
+/// use num_format::parsing::FormattedReader;
+///
+/// fn main() {
+///     let input = "1,000\n-2,500\n1,000,000\n".as_bytes();
+///     let numbers = FormattedReader::<_, _, i32>::new(input, b'\n', &Locale::en)
+///         .collect::<Result<Vec<_>, _>>()
+///         .unwrap();
+///     assert_eq!(numbers, vec![1_000, -2_500, 1_000_000]);
+/// }
+/// ```
+///
+/// [`Error`]: ../struct.Error.html
+/// [`from_formatted_bytes`]: trait.FromFormattedStr.html#method.from_formatted_bytes
+/// [`from_read`]: struct.FormattedReader.html#method.from_read
+/// [`io::BufRead`]: https://doc.rust-lang.org/stable/std/io/trait.BufRead.html
+/// [`io::Error`]: https://doc.rust-lang.org/stable/std/io/struct.Error.html
+/// [`io::ErrorKind::InvalidData`]: https://doc.rust-lang.org/stable/std/io/enum.ErrorKind.html#variant.InvalidData
+/// [`io::Read`]: https://doc.rust-lang.org/stable/std/io/trait.Read.html
+#[derive(Debug)]
+pub struct FormattedReader<'a, R, F, N> {
+    buf: Vec<u8>,
+    delimiter: u8,
+    done: bool,
+    format: &'a F,
+    pattern: NegativePattern,
+    phantom: PhantomData<fn() -> N>,
+    reader: R,
+}
+
+impl<'a, R, F, N> FormattedReader<'a, R, F, N>
+where
+    R: BufRead,
+    F: Format,
+    N: FromFormattedStr,
+{
+    /// Constructs a [`FormattedReader`] that splits `reader` on `delimiter` and parses each
+    /// record with `format`.
+    ///
+    /// To parse an [`io::Read`] that isn't buffered, use [`from_read`].
+    ///
+    /// [`FormattedReader`]: struct.FormattedReader.html
+    /// [`from_read`]: struct.FormattedReader.html#method.from_read
+    /// [`io::Read`]: https://doc.rust-lang.org/stable/std/io/trait.Read.html
+    pub fn new(reader: R, delimiter: u8, format: &'a F) -> Self {
+        FormattedReader {
+            buf: Vec::new(),
+            delimiter,
+            done: false,
+            format,
+            pattern: NegativePattern::default(),
+            phantom: PhantomData,
+            reader,
+        }
+    }
+
+    /// Sets the [`NegativePattern`] used to recognize negative numbers (the default is
+    /// [`NegativePattern::Prefix`]).
+    ///
+    /// [`NegativePattern`]: enum.NegativePattern.html
+    /// [`NegativePattern::Prefix`]: enum.NegativePattern.html#variant.Prefix
+    pub fn negative_pattern(mut self, pattern: NegativePattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<'a, R, F, N> FormattedReader<'a, BufReader<R>, F, N>
+where
+    R: Read,
+    F: Format,
+    N: FromFormattedStr,
+{
+    /// Constructs a [`FormattedReader`] over an [`io::Read`] that isn't buffered (e.g. a
+    /// [`File`]), by wrapping it in a [`BufReader`].
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::{self, Read};
+    ///
+    /// use num_format::Locale;
+    /// use num_format::parsing::FormattedReader;
+    ///
+    /// fn main() {
+    ///     let input = io::repeat(b'7').take(3).chain("\n".as_bytes());
+    ///     let numbers = FormattedReader::<_, _, u32>::from_read(input, b'\n', &Locale::en)
+    ///         .collect::<Result<Vec<_>, _>>()
+    ///         .unwrap();
+    ///     assert_eq!(numbers, vec![777]);
+    /// }
+    /// ```
+    ///
+    /// [`BufReader`]: https://doc.rust-lang.org/stable/std/io/struct.BufReader.html
+    /// [`File`]: https://doc.rust-lang.org/stable/std/fs/struct.File.html
+    /// [`FormattedReader`]: struct.FormattedReader.html
+    /// [`io::Read`]: https://doc.rust-lang.org/stable/std/io/trait.Read.html
+    pub fn from_read(reader: R, delimiter: u8, format: &'a F) -> Self {
+        FormattedReader::new(BufReader::new(reader), delimiter, format)
+    }
+}
+
+impl<'a, R, F, N> Iterator for FormattedReader<'a, R, F, N>
+where
+    R: BufRead,
+    F: Format,
+    N: FromFormattedStr,
+{
+    type Item = Result<N, io::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.buf.clear();
+        match self.reader.read_until(self.delimiter, &mut self.buf) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => (),
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+        let record = match self.buf.split_last() {
+            Some((last, record)) if *last == self.delimiter => record,
+            _ => &self.buf[..],
+        };
+        let n = N::from_formatted_bytes_with(record, self.format, self.pattern)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+        Some(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Locale;
+
+    #[test]
+    fn test_formatted_reader() {
+        let input = "1,000\n\u{2212}2,500\r\n\n1,000,000";
+        let numbers = FormattedReader::<_, _, i64>::new(input.as_bytes(), b'\n', &Locale::en)
+            .map(|n| n.ok())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            numbers,
+            vec![Some(1_000), Some(-2_500), None, Some(1_000_000)]
+        );
+
+        let input = "1.000;(2.500);3.000-";
+        let numbers = FormattedReader::<_, _, i64>::new(input.as_bytes(), b';', &Locale::de)
+            .negative_pattern(NegativePattern::Any)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(numbers, vec![1_000, -2_500, -3_000]);
+    }
+
+    #[test]
+    fn test_formatted_reader_errors() {
+        let input = b"1,000\n2,\xff00\n3,000\n";
+        let mut reader = FormattedReader::<_, _, u32>::new(&input[..], b'\n', &Locale::en);
+        assert_eq!(reader.next().unwrap().unwrap(), 1_000);
+        let e = reader.next().unwrap().unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(reader.next().unwrap().unwrap(), 3_000);
+        assert!(reader.next().is_none());
+
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::BrokenPipe, "failing"))
+            }
+        }
+        let mut reader = FormattedReader::<_, _, u32>::from_read(Failing, b'\n', &Locale::en);
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            io::ErrorKind::BrokenPipe
+        );
+        assert!(reader.next().is_none());
+    }
+}
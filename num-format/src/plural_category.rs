@@ -0,0 +1,46 @@
+/// The plural categories of [CLDR's plural rules], which choose between the forms a language
+/// has for a word that goes with a number (e.g. "day" in "1 day" but "days" in "2 days", or the
+/// "st" of "1st" and the "nd" of "2nd"). Cardinal categories (see [`Locale::plural_category`])
+/// and ordinal categories (see [`Locale::ordinal_category`]) come from separate rules.
+///
+/// Every language uses [`Other`], and only the categories it distinguishes besides that (English
+/// ordinals use [`One`], [`Two`] and [`Few`]; German ordinals none).
+///
+/// [CLDR's plural rules]: https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html
+/// [`Few`]: enum.PluralCategory.html#variant.Few
+/// [`Locale::ordinal_category`]: enum.Locale.html#method.ordinal_category
+/// [`Locale::plural_category`]: enum.Locale.html#method.plural_category
+/// [`One`]: enum.PluralCategory.html#variant.One
+/// [`Other`]: enum.PluralCategory.html#variant.Other
+/// [`Two`]: enum.PluralCategory.html#variant.Two
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum PluralCategory {
+    /// The "zero" category (e.g. 0 in Welsh ordinals)
+    Zero,
+    /// The "one" category (e.g. 1, 21, 31 etc. in English ordinals)
+    One,
+    /// The "two" category (e.g. 2, 22, 32 etc. in English ordinals)
+    Two,
+    /// The "few" category (e.g. 3, 23, 33 etc. in English ordinals)
+    Few,
+    /// The "many" category (e.g. 6, 9, 10 etc. in Kazakh ordinals)
+    Many,
+    /// The category of every number no other category applies to
+    Other,
+}
+
+impl PluralCategory {
+    /// Returns the category's name in CLDR (e.g. "one").
+    pub fn as_str(&self) -> &'static str {
+        use self::PluralCategory::*;
+        match self {
+            Zero => "zero",
+            One => "one",
+            Two => "two",
+            Few => "few",
+            Many => "many",
+            Other => "other",
+        }
+    }
+}
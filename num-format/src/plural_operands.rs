@@ -0,0 +1,203 @@
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::error::Error;
+use crate::integer::Integer;
+
+/// 10<sup>36</sup>. Operands that are larger are kept modulo this, plus this, which keeps them
+/// larger than any number CLDR's plural rules compare with and their remainders by the powers of
+/// ten the rules divide by.
+pub(crate) const LIMIT: u128 = 1_000_000_000_000_000_000_000_000_000_000_000_000;
+
+/// The operands of a number that [CLDR's plural rules] test to pick its [`PluralCategory`] (see
+/// [`Locale::plural_category`]): its integer digits and, for a decimal, its fraction digits as
+/// written, including trailing zeros.
+///
+/// Integers convert to operands through [`ToPluralOperands`]. A decimal's operands come from the
+/// digits it is written with, because in many languages "1" and "1.0" are in different
+/// categories: either parse them from its plain decimal representation (e.g. "1.50" or "-0.5")
+/// or get them from an `f64` and the number of fraction digits it is formatted with.
+///
+/// # Example
+/// ```
+/// use num_format::{Locale, PluralCategory, PluralOperands};
+///
+/// let operands = "1.50".parse::<PluralOperands>().unwrap();
+/// assert_eq!(operands, PluralOperands::from_f64(1.5, 2).unwrap());
+/// assert_eq!(Locale::en.plural_category(&operands), PluralCategory::Other);
+/// assert_eq!(Locale::fr.plural_category(&operands), PluralCategory::One);
+/// ```
+///
+/// [CLDR's plural rules]: https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html
+/// [`Locale::plural_category`]: enum.Locale.html#method.plural_category
+/// [`PluralCategory`]: enum.PluralCategory.html
+/// [`ToPluralOperands`]: trait.ToPluralOperands.html
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct PluralOperands {
+    /// The integer digits
+    pub(crate) i: u128,
+    /// The number of fraction digits
+    pub(crate) v: usize,
+    /// The number of fraction digits without trailing zeros
+    pub(crate) w: usize,
+    /// The fraction digits
+    pub(crate) f: u128,
+    /// The fraction digits without trailing zeros
+    pub(crate) t: u128,
+}
+
+impl PluralOperands {
+    /// Returns the operands of `n` written with `fraction_digits` digits after the decimal point
+    /// (rounded as `format!("{:.*}", fraction_digits, n)` rounds). The sign of `n` is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n` is infinite or NaN.
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
+    pub fn from_f64(n: f64, fraction_digits: usize) -> Result<PluralOperands, Error> {
+        if !n.is_finite() {
+            return Err(Error::parse_number(if n.is_nan() { "NaN" } else { "inf" }));
+        }
+        // f64::abs is not in core before Rust 1.85
+        let n = if n.is_sign_negative() { -n } else { n };
+        let mut parser = Parser::default();
+        write!(parser, "{:.*}", fraction_digits, n).map_err(|_| Error::parse_number("f64"))?;
+        parser.finish().ok_or_else(|| Error::parse_number("f64"))
+    }
+
+    pub(crate) fn from_integer(n: u128) -> PluralOperands {
+        PluralOperands {
+            i: if n < LIMIT { n } else { LIMIT + n % LIMIT },
+            ..PluralOperands::default()
+        }
+    }
+}
+
+impl FromStr for PluralOperands {
+    type Err = Error;
+
+    /// Parses a number in its plain decimal representation: ASCII digits with an optional
+    /// decimal point and sign (e.g. "1", "-1.50" or "+0.5"), with neither separators nor an
+    /// exponent.
+    fn from_str(s: &str) -> Result<PluralOperands, Error> {
+        let digits = s.strip_prefix(&['-', '+'][..]).unwrap_or(s);
+        let mut parser = Parser::default();
+        parser
+            .write_str(digits)
+            .ok()
+            .and_then(|_| parser.finish())
+            .ok_or_else(|| Error::parse_number(s))
+    }
+}
+
+/// Types that have [`PluralOperands`], and so a [`PluralCategory`] in each locale (see
+/// [`Locale::plural_category`]): the integer types, [`PluralOperands`] themselves (for
+/// decimals) and, with the `with-num-bigint` feature, `BigInt` and `BigUint`.
+///
+/// [`Locale::plural_category`]: enum.Locale.html#method.plural_category
+/// [`PluralCategory`]: enum.PluralCategory.html
+/// [`PluralOperands`]: struct.PluralOperands.html
+pub trait ToPluralOperands {
+    /// Returns the operands of the number, ignoring its sign.
+    fn to_plural_operands(&self) -> PluralOperands;
+}
+
+impl<T> ToPluralOperands for T
+where
+    T: Integer,
+{
+    fn to_plural_operands(&self) -> PluralOperands {
+        PluralOperands::from_integer(self.magnitude())
+    }
+}
+
+impl ToPluralOperands for PluralOperands {
+    fn to_plural_operands(&self) -> PluralOperands {
+        *self
+    }
+}
+
+/// Digits read one at a time, as a number kept like the operands (see [`LIMIT`]).
+#[derive(Copy, Clone, Debug, Default)]
+struct Digits {
+    value: u128,
+    large: bool,
+}
+
+impl Digits {
+    fn push(&mut self, digit: u8) {
+        let value = self.value * 10 + u128::from(digit);
+        self.large |= value >= LIMIT;
+        self.value = value % LIMIT;
+    }
+
+    fn get(self) -> u128 {
+        if self.large {
+            LIMIT + self.value
+        } else {
+            self.value
+        }
+    }
+}
+
+/// Reads the operands from a plain decimal representation, which may be written in pieces.
+#[derive(Debug, Default)]
+struct Parser {
+    integer: Digits,
+    integer_len: usize,
+    point: bool,
+    /// The fraction digits up to the last nonzero one
+    fraction: Digits,
+    /// The number of zeros after the last nonzero fraction digit
+    zeros: usize,
+    v: usize,
+    w: usize,
+}
+
+impl Parser {
+    fn finish(self) -> Option<PluralOperands> {
+        if self.integer_len == 0 {
+            return None;
+        }
+        let mut f = self.fraction;
+        for _ in 0..self.zeros {
+            f.push(0);
+        }
+        Some(PluralOperands {
+            i: self.integer.get(),
+            v: self.v,
+            w: self.w,
+            f: f.get(),
+            t: self.fraction.get(),
+        })
+    }
+}
+
+impl fmt::Write for Parser {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for b in s.bytes() {
+            match b {
+                b'.' if !self.point => self.point = true,
+                b'0'..=b'9' if !self.point => {
+                    self.integer.push(b - b'0');
+                    self.integer_len += 1;
+                }
+                b'0' => {
+                    self.zeros += 1;
+                    self.v += 1;
+                }
+                b'1'..=b'9' => {
+                    for _ in 0..self.zeros {
+                        self.fraction.push(0);
+                    }
+                    self.fraction.push(b - b'0');
+                    self.zeros = 0;
+                    self.v += 1;
+                    self.w = self.v;
+                }
+                _ => return Err(fmt::Error),
+            }
+        }
+        Ok(())
+    }
+}
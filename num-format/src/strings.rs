@@ -30,6 +30,7 @@ impl<'a> DecimalStr<'a> {
     /// Returns an error if the provided `&str`'s length is more than 8 bytes.
     ///
     /// [`DecimalStr`]: struct.DecimalStr.html
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     pub fn new(s: &'a str) -> Result<DecimalStr<'a>, Error> {
         Self::_new(s)
     }
@@ -49,6 +50,7 @@ impl<'a> InfinityStr<'a> {
     /// Returns an error if the provided `&str`'s length is more than 128 bytes.
     ///
     /// [`InfinityStr`]: struct.InfinityStr.html
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     pub fn new(s: &'a str) -> Result<InfinityStr<'a>, Error> {
         Self::_new(s)
     }
@@ -68,6 +70,7 @@ impl<'a> MinusSignStr<'a> {
     /// Returns an error if the provided `&str`'s length is more than 7 bytes.
     ///
     /// [`MinusSignStr`]: struct.MinusSignStr.html
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     pub fn new(s: &'a str) -> Result<MinusSignStr<'a>, Error> {
         Self::_new(s)
     }
@@ -87,6 +90,7 @@ impl<'a> NanStr<'a> {
     /// Returns an error if the provided `&str`'s length is more than 64 bytes.
     ///
     /// [`NanStr`]: struct.NanStr.html
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     pub fn new(s: &'a str) -> Result<NanStr<'a>, Error> {
         Self::_new(s)
     }
@@ -106,6 +110,7 @@ impl<'a> PlusSignStr<'a> {
     /// Returns an error if the provided `&str`'s length is more than 8 bytes.
     ///
     /// [`PlusSignStr`]: struct.PlusSignStr.html
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     pub fn new(s: &'a str) -> Result<PlusSignStr<'a>, Error> {
         Self::_new(s)
     }
@@ -125,6 +130,7 @@ impl<'a> SeparatorStr<'a> {
     /// Returns an error if the provided `&str`'s length is more than 8 bytes.
     ///
     /// [`SeparatorStr`]: struct.SeparatorStr.html
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
     pub fn new(s: &'a str) -> Result<SeparatorStr<'a>, Error> {
         Self::_new(s)
     }
@@ -140,6 +146,7 @@ macro_rules! create_impls {
             }
 
             #[inline(always)]
+            #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
             fn _new(s: &'a str) -> Result<$name<'a>, Error> {
                 let len = s.len();
                 if len > $max_len {
@@ -184,6 +191,7 @@ macro_rules! create_string {
 
         impl $name {
             #[allow(dead_code)]
+            #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
             pub(crate) fn new<S>(s: S) -> Result<Self, Error>
             where
                 S: AsRef<str>,
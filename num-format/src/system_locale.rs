@@ -1,13 +1,18 @@
 #![cfg(all(feature = "with-system-locale", any(unix, windows)))]
 
-mod nix;
+mod cldr;
+mod definition;
+pub(crate) mod nix;
 mod windows;
 
+pub use self::cldr::{FormatSymbol, SymbolDifference};
+
 use std::collections::HashSet;
 
 use crate::error::Error;
 use crate::format::Format;
 use crate::grouping::Grouping;
+use crate::locale::Locale;
 use crate::strings::{
     DecString, DecimalStr, InfString, InfinityStr, MinString, MinusSignStr, NanStr, NanString,
     PlusSignStr, PlusString, SepString, SeparatorStr,
@@ -74,6 +79,7 @@ impl SystemLocale {
     /// pointer).
     ///
     /// [`SystemLocale`]: struct.SystemLocale.html
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Result<SystemLocale, Error> {
         #[cfg(unix)]
         return self::nix::new(None);
@@ -111,19 +117,24 @@ impl SystemLocale {
 
     /// Returns a set of the locale names available on your operating system.
     ///
-    /// * **Unix-based systems (including macOS)**: The underlying implementation uses the
-    ///   [`locale`] command.
+    /// * **Unix-based systems (including macOS)**: The underlying implementation lists the
+    ///   locales in the C library's locale directories (those in the `LOCPATH` environment
+    ///   variable and `/usr/lib/locale` with glibc, those in `PATH_LOCALE` and
+    ///   `/usr/share/locale` on macOS and the BSDs) and, with glibc, its locale archive
+    ///   (`/usr/lib/locale/locale-archive`), along with "C" and "POSIX". Only if none of these
+    ///   can be read does it run the [`locale`] command.
     /// * **Windows**: The underlying implementation uses the [`EnumSystemLocalesEx`] function.
     /// # Errors
     ///
     /// Returns an error if the operating system returned something unexpected (such as a null
-    /// pointer).
+    /// pointer) or, on Unix-based systems, if the names could be read from none of the places
+    /// above.
     ///
     /// [`EnumSystemLocalesEx`]: https://docs.microsoft.com/en-us/windows/desktop/api/winnls/nf-winnls-enumsystemlocalesex
     /// [`locale`]: http://man7.org/linux/man-pages/man1/locale.1.html
     pub fn available_names() -> Result<HashSet<String>, Error> {
         #[cfg(unix)]
-        return Ok(self::nix::available_names());
+        return self::nix::available_names();
 
         #[cfg(windows)]
         return self::windows::available_names();
@@ -145,7 +156,10 @@ impl SystemLocale {
         self.grp
     }
 
-    /// Returns this locale's string representation of infinity.
+    /// Returns this locale's string representation of infinity (on Unix-based systems, that of
+    /// the closest CLDR locale; see [`set_infinity`]).
+    ///
+    /// [`set_infinity`]: struct.SystemLocale.html#method.set_infinity
     pub fn infinity(&self) -> &str {
         &self.inf
     }
@@ -160,7 +174,10 @@ impl SystemLocale {
         &self.name
     }
 
-    /// Returns this locale's string representation of NaN.
+    /// Returns this locale's string representation of NaN (on Unix-based systems, that of the
+    /// closest CLDR locale; see [`set_nan`]).
+    ///
+    /// [`set_nan`]: struct.SystemLocale.html#method.set_nan
     pub fn nan(&self) -> &str {
         &self.nan
     }
@@ -177,12 +194,16 @@ impl SystemLocale {
 
     #[cfg(unix)]
     /// Unix-based operating systems (including macOS) do not provide information on how to
-    /// represent infinity symbols; so num-format uses `"∞"` (U+221E) as a default. This
-    /// method allows you to change that default.
+    /// represent infinity; so num-format uses that of the CLDR [`Locale`] closest to the system
+    /// locale's name (e.g. `"∞"` for "de_DE.UTF-8" and `"INF"` for "C"), falling back to
+    /// [`Locale::en`]'s. This method allows you to change that default.
     ///
     /// # Errors
     ///
     /// Returns an error the provided string is longer than 128 bytes.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    /// [`Locale::en`]: enum.Locale.html#variant.en
     pub fn set_infinity<S>(&mut self, s: S) -> Result<(), Error>
     where
         S: AsRef<str>,
@@ -193,12 +214,16 @@ impl SystemLocale {
 
     #[cfg(unix)]
     /// Unix-based operating systems (including macOS) do not provide information on how to
-    /// represent NaN; so num-format uses `"NaN"` as a default. This method allows you to change
-    /// that default.
+    /// represent NaN; so num-format uses that of the CLDR [`Locale`] closest to the system
+    /// locale's name (e.g. `"не\u{a0}число"` for "ru_RU.UTF-8"), falling back to
+    /// [`Locale::en`]'s `"NaN"`. This method allows you to change that default.
     ///
     /// # Errors
     ///
     /// Returns an error the provided string is longer than 64 bytes.
+    ///
+    /// [`Locale`]: enum.Locale.html
+    /// [`Locale::en`]: enum.Locale.html#variant.en
     pub fn set_nan<S>(&mut self, s: S) -> Result<(), Error>
     where
         S: AsRef<str>,
@@ -249,3 +274,24 @@ impl Format for SystemLocale {
         SeparatorStr::new(self.separator()).unwrap()
     }
 }
+
+/// Returns the CLDR locale closest to the system locale `name` (e.g. `Locale::de` for
+/// "de_DE.UTF-8" and `Locale::en_US_POSIX` for "C"), or `Locale::en` if there is none.
+pub(crate) fn cldr_locale(name: &str) -> Locale {
+    Locale::from_name(name).unwrap_or(Locale::en)
+}
+
+/// Returns the [`Grouping`] of POSIX grouping bytes (as in an lconv's `grouping`): the sizes of
+/// the groups from the right, the last of which repeats, ended by a 0 or by `CHAR_MAX` for no
+/// further grouping.
+///
+/// [`Grouping`]: enum.Grouping.html
+pub(crate) fn posix_grouping(bytes: &[u8]) -> Result<Grouping, Error> {
+    let grouping = match bytes {
+        [3, 2] | [2, 3] => Grouping::Indian,
+        [] | [127] => Grouping::Posix,
+        [3] | [3, 3] => Grouping::Standard,
+        _ => return Err(Error::system_unsupported_grouping(bytes)),
+    };
+    Ok(grouping)
+}
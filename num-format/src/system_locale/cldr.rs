@@ -0,0 +1,223 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::system_locale::SystemLocale;
+
+impl SystemLocale {
+    /// Returns the CLDR [`Locale`] that best matches this system locale's name, for when you
+    /// want the operating system to choose the locale but CLDR's symbols. The name is resolved
+    /// as in [`Locale::from_name`]: its codeset is ignored, the `@latin`, `@cyrillic` and
+    /// `@devanagari` modifiers choose a script and `@valencia` a variant (e.g. `Locale::sr_Latn`
+    /// for "sr_RS@latin", `Locale::ca_ES_VALENCIA` for "ca_ES.UTF-8@valencia"), other modifiers
+    /// (e.g. `@euro`) are ignored, and a name without an exact match falls back to its closest
+    /// available parent (e.g. `Locale::de` for "de_DE.UTF-8@euro", `Locale::en_US_POSIX` for
+    /// "C"). To do the same with any POSIX locale name, such as one your operating system does
+    /// not have, use [`Locale::from_name`] itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no [`Locale`] matches the name.
+    ///
+    /// # Example
+    /// ```rust
+    /// use num_format::{Locale, SystemLocale};
+    ///
+    /// fn main() {
+    ///     let locale = SystemLocale::from_name("C").unwrap();
+    ///     assert_eq!(locale.cldr_locale().unwrap(), Locale::en_US_POSIX);
+    ///
+    ///     assert_eq!(Locale::from_name("en_US.UTF-8@euro").unwrap(), Locale::en);
+    ///     # // with NUM_FORMAT_LOCALES=en,eu, sr and ca cannot be looked up by name
+    ///     # #[cfg(locales_other)]
+    ///     assert_eq!(Locale::from_name("sr_RS@latin").unwrap(), Locale::sr_Latn);
+    ///     # #[cfg(locales_other)]
+    ///     assert_eq!(
+    ///         Locale::from_name("ca_ES.UTF-8@valencia").unwrap(),
+    ///         Locale::ca_ES_VALENCIA
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`Locale`]: enum.Locale.html
+    /// [`Locale::from_name`]: enum.Locale.html#method.from_name
+    pub fn cldr_locale(&self) -> Result<Locale, Error> {
+        Locale::from_name(&self.name)
+    }
+
+    /// Returns the symbols this system locale has that differ from those of its CLDR
+    /// [`Locale`] (see [`cldr_locale`]), in the order of [`FormatSymbol`]'s variants, e.g. the
+    /// separator of "fr_FR.UTF-8" with older versions of glibc, which is `"\u{a0}"` rather than
+    /// CLDR's `"\u{202f}"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no [`Locale`] matches this system locale's name.
+    ///
+    /// # Example
+    /// ```rust
+    /// use num_format::{FormatSymbol, SystemLocale};
+    ///
+    /// fn main() {
+    ///     // unlike CLDR's en_US_POSIX, the "C" locale has no separator or signs
+    ///     let locale = SystemLocale::from_name("C").unwrap();
+    ///     let differences = locale.cldr_differences().unwrap();
+    ///     for difference in &differences {
+    ///         println!(
+    ///             "{:?}: {:?} on this system, {:?} in CLDR",
+    ///             difference.symbol(),
+    ///             difference.system(),
+    ///             difference.cldr(),
+    ///         );
+    ///     }
+    ///     let symbols = differences.iter().map(|d| d.symbol()).collect::<Vec<_>>();
+    ///     assert_eq!(
+    ///         symbols,
+    ///         [FormatSymbol::MinusSign, FormatSymbol::PlusSign, FormatSymbol::Separator]
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`cldr_locale`]: struct.SystemLocale.html#method.cldr_locale
+    /// [`FormatSymbol`]: enum.FormatSymbol.html
+    /// [`Locale`]: enum.Locale.html
+    pub fn cldr_differences(&self) -> Result<Vec<SymbolDifference>, Error> {
+        let locale = self.cldr_locale()?;
+        let grouping = |grouping| format!("{:?}", grouping);
+        let symbols = [
+            (FormatSymbol::Decimal, self.decimal(), locale.decimal()),
+            (
+                FormatSymbol::Grouping,
+                &grouping(self.grouping()),
+                &grouping(locale.grouping()),
+            ),
+            (FormatSymbol::Infinity, self.infinity(), locale.infinity()),
+            (
+                FormatSymbol::MinusSign,
+                self.minus_sign(),
+                locale.minus_sign(),
+            ),
+            (FormatSymbol::Nan, self.nan(), locale.nan()),
+            (FormatSymbol::PlusSign, self.plus_sign(), locale.plus_sign()),
+            (
+                FormatSymbol::Separator,
+                self.separator(),
+                locale.separator(),
+            ),
+        ];
+        Ok(symbols
+            .iter()
+            .filter(|(_, system, cldr)| system != cldr)
+            .map(|&(symbol, system, cldr)| SymbolDifference {
+                cldr: cldr.to_string(),
+                symbol,
+                system: system.to_string(),
+            })
+            .collect())
+    }
+}
+
+/// One of the symbols of a [`Format`].
+///
+/// [`Format`]: trait.Format.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum FormatSymbol {
+    /// The decimal point.
+    Decimal,
+    /// The [`Grouping`].
+    ///
+    /// [`Grouping`]: enum.Grouping.html
+    Grouping,
+    /// The infinity symbol.
+    Infinity,
+    /// The minus sign.
+    MinusSign,
+    /// The NaN symbol.
+    Nan,
+    /// The plus sign.
+    PlusSign,
+    /// The thousands separator.
+    Separator,
+}
+
+/// A symbol that differs between a [`SystemLocale`] and its CLDR [`Locale`], as returned by
+/// [`SystemLocale::cldr_differences`].
+///
+/// [`Locale`]: enum.Locale.html
+/// [`SystemLocale`]: struct.SystemLocale.html
+/// [`SystemLocale::cldr_differences`]: struct.SystemLocale.html#method.cldr_differences
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct SymbolDifference {
+    cldr: String,
+    symbol: FormatSymbol,
+    system: String,
+}
+
+impl SymbolDifference {
+    /// Returns the CLDR [`Locale`]'s value of the symbol ([`Grouping`]s as the names of their
+    /// variants, e.g. `"Standard"`).
+    ///
+    /// [`Grouping`]: enum.Grouping.html
+    /// [`Locale`]: enum.Locale.html
+    pub fn cldr(&self) -> &str {
+        &self.cldr
+    }
+
+    /// Returns which symbol differs.
+    pub fn symbol(&self) -> FormatSymbol {
+        self.symbol
+    }
+
+    /// Returns the [`SystemLocale`]'s value of the symbol ([`Grouping`]s as the names of their
+    /// variants, e.g. `"Posix"`).
+    ///
+    /// [`Grouping`]: enum.Grouping.html
+    /// [`SystemLocale`]: struct.SystemLocale.html
+    pub fn system(&self) -> &str {
+        &self.system
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grouping::Grouping;
+    use crate::strings::{DecString, InfString, MinString, NanString, PlusString, SepString};
+
+    #[test]
+    fn test_cldr_differences() {
+        let mut locale = SystemLocale {
+            dec: DecString::new(",").unwrap(),
+            grp: Grouping::Standard,
+            inf: InfString::new("∞").unwrap(),
+            min: MinString::new("-").unwrap(),
+            name: "fr_FR.UTF-8@euro".to_string(),
+            nan: NanString::new("NaN").unwrap(),
+            plus: PlusString::new("+").unwrap(),
+            sep: SepString::new("\u{a0}").unwrap(),
+        };
+        assert_eq!(locale.cldr_locale().unwrap(), Locale::fr);
+        assert_eq!(
+            locale.cldr_differences().unwrap(),
+            vec![SymbolDifference {
+                cldr: "\u{202f}".to_string(),
+                symbol: FormatSymbol::Separator,
+                system: "\u{a0}".to_string(),
+            }]
+        );
+
+        locale.sep = SepString::new("\u{202f}").unwrap();
+        assert!(locale.cldr_differences().unwrap().is_empty());
+
+        locale.grp = Grouping::Posix;
+        let differences = locale.cldr_differences().unwrap();
+        assert_eq!(differences.len(), 1);
+        assert_eq!(differences[0].symbol(), FormatSymbol::Grouping);
+        assert_eq!(differences[0].system(), "Posix");
+        assert_eq!(differences[0].cldr(), "Standard");
+
+        locale.name = "xx_XX".to_string();
+        assert!(locale.cldr_locale().is_err());
+        assert!(locale.cldr_differences().is_err());
+    }
+}
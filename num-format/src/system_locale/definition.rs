@@ -0,0 +1,293 @@
+use std::fs;
+use std::path::Path;
+
+use crate::error::Error;
+use crate::strings::{DecString, InfString, MinString, NanString, PlusString, SepString};
+use crate::system_locale::{cldr_locale, posix_grouping, SystemLocale};
+
+/// How deeply `copy` directives may nest, which stops a locale from copying itself forever.
+const MAX_COPY_DEPTH: usize = 16;
+
+impl SystemLocale {
+    /// Constructs a [`SystemLocale`] from the POSIX locale definition file at `path` (the source
+    /// that `localedef` compiles, e.g. `/usr/share/i18n/locales/de_DE` on Linux), without
+    /// compiling or installing it. The decimal point, separator and grouping come from the file's
+    /// `LC_NUMERIC` category and the minus and plus signs from its `LC_MONETARY` category, as in
+    /// a [`SystemLocale`] from [`from_name`]; the name is the file's name. A category that
+    /// copies another locale's (`copy "en_US"`) reads it from the file of that name in the same
+    /// directory.
+    ///
+    /// Unlike [`from_name`], this works with locales the operating system cannot load, and on
+    /// any operating system.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file, or one it copies from, cannot be read or is not a valid
+    /// locale definition, if it has no `decimal_point`, or if it groups digits in a way
+    /// num-format does not support.
+    ///
+    /// # Example
+    /// ```rust
+    /// use num_format::{Grouping, SystemLocale};
+    ///
+    /// fn main() {
+    ///     if let Ok(locale) = SystemLocale::from_definition_file("/usr/share/i18n/locales/de_DE") {
+    ///         assert_eq!(locale.name(), "de_DE");
+    ///         assert_eq!(locale.decimal(), ",");
+    ///         assert_eq!(locale.grouping(), Grouping::Standard);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`from_name`]: struct.SystemLocale.html#method.from_name
+    /// [`SystemLocale`]: struct.SystemLocale.html
+    pub fn from_definition_file<P>(path: P) -> Result<SystemLocale, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let numeric = read_category(path, "LC_NUMERIC", 0)?
+            .ok_or_else(|| invalid(path, "no LC_NUMERIC category"))?;
+        let monetary = read_category(path, "LC_MONETARY", 0)?.unwrap_or_default();
+
+        let string = |keywords: &[Keyword], keyword| match find(keywords, keyword) {
+            Some(keyword) => keyword.string(),
+            None => Ok(String::new()),
+        };
+
+        let dec = match find(&numeric, "decimal_point") {
+            Some(keyword) => DecString::new(&keyword.string()?)?,
+            None => return Err(invalid(path, "no decimal_point in LC_NUMERIC")),
+        };
+        let grp = match find(&numeric, "grouping") {
+            Some(keyword) => posix_grouping(&keyword.grouping()?)?,
+            None => posix_grouping(&[])?,
+        };
+        let sep = SepString::new(&string(&numeric, "thousands_sep")?)?;
+        let min = MinString::new(&string(&monetary, "negative_sign")?)?;
+        let plus = PlusString::new(&string(&monetary, "positive_sign")?)?;
+
+        // locale definitions have no infinity and NaN symbols; take them from CLDR
+        let cldr_locale = cldr_locale(&name);
+
+        Ok(SystemLocale {
+            dec,
+            grp,
+            inf: InfString::new(cldr_locale.infinity()).unwrap(),
+            min,
+            name,
+            nan: NanString::new(cldr_locale.nan()).unwrap(),
+            plus,
+            sep,
+        })
+    }
+
+    /// Constructs a [`SystemLocale`] from the POSIX locale definition file `name` (e.g.
+    /// `"de_DE"`) in `dir` (e.g. `/usr/share/i18n/locales` on Linux). See
+    /// [`from_definition_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for any of the reasons [`from_definition_file`] does.
+    ///
+    /// [`from_definition_file`]: struct.SystemLocale.html#method.from_definition_file
+    /// [`SystemLocale`]: struct.SystemLocale.html
+    pub fn from_definition_dir<P, S>(dir: P, name: S) -> Result<SystemLocale, Error>
+    where
+        P: AsRef<Path>,
+        S: AsRef<str>,
+    {
+        SystemLocale::from_definition_file(dir.as_ref().join(name.as_ref()))
+    }
+}
+
+/// A keyword of a category and its operands, with where it is and the escape character of its
+/// file.
+#[derive(Debug)]
+struct Keyword {
+    location: String,
+    name: String,
+    operands: String,
+    escape_char: char,
+}
+
+impl Keyword {
+    /// Returns an error for the keyword's invalid operands.
+    fn invalid(&self) -> Error {
+        Error::system_invalid_definition(format!(
+            "{}: invalid {} {}",
+            self.location, self.name, self.operands
+        ))
+    }
+
+    /// Returns the operands as a single string, e.g. `"<U002C>"` as `","`.
+    fn string(&self) -> Result<String, Error> {
+        let invalid = || self.invalid();
+        let mut s = String::new();
+        let mut chars = self.operands.chars();
+        // quoted strings, or symbolic names such as <U002C> on their own
+        let quoted = self.operands.starts_with('"');
+        if quoted {
+            let _ = chars.next();
+        }
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted => {
+                    return if chars.as_str().trim().is_empty() {
+                        Ok(s)
+                    } else {
+                        Err(invalid())
+                    }
+                }
+                '<' => {
+                    let rest = chars.as_str();
+                    let end = rest.find('>').ok_or_else(invalid)?;
+                    s.push(symbolic_char(&rest[..end]).ok_or_else(invalid)?);
+                    chars = rest[end + 1..].chars();
+                }
+                c if c == self.escape_char => s.push(chars.next().ok_or_else(invalid)?),
+                c if !quoted && c.is_whitespace() => return Err(invalid()),
+                c => s.push(c),
+            }
+        }
+        if quoted {
+            Err(invalid())
+        } else {
+            Ok(s)
+        }
+    }
+
+    /// Returns the operands, sizes of groups separated by semicolons (e.g. `3;3`), as the bytes
+    /// of an lconv's `grouping`: with -1 as `CHAR_MAX`, and ending at a group size of 0.
+    fn grouping(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        for size in self.operands.split(';') {
+            let byte = match size.trim().parse::<i8>() {
+                Ok(-1) => 127,
+                Ok(0) => break,
+                Ok(size) if size > 0 => size as u8,
+                _ => return Err(self.invalid()),
+            };
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Returns the character with a symbolic name: `U` and its code point in hexadecimal (e.g.
+/// `U002C`), or the name of a character of POSIX's portable character set used in locale
+/// definitions for numbers (e.g. `comma`).
+fn symbolic_char(name: &str) -> Option<char> {
+    if let Some(hex) = name.strip_prefix('U') {
+        return u32::from_str_radix(hex, 16).ok().and_then(char::from_u32);
+    }
+    let c = match name {
+        "apostrophe" => '\'',
+        "comma" => ',',
+        "full-stop" | "period" => '.',
+        "hyphen" | "hyphen-minus" => '-',
+        "plus-sign" => '+',
+        "space" => ' ',
+        _ => return None,
+    };
+    Some(c)
+}
+
+fn find<'a>(keywords: &'a [Keyword], name: &str) -> Option<&'a Keyword> {
+    keywords.iter().rev().find(|keyword| keyword.name == name)
+}
+
+fn invalid(path: &Path, message: &str) -> Error {
+    Error::system_invalid_definition(format!("{}: {}", path.display(), message))
+}
+
+/// Returns the keywords of `category` in the locale definition file at `path`, with those of
+/// the categories it copies in their place, or `None` if it has no such category.
+fn read_category(path: &Path, category: &str, depth: usize) -> Result<Option<Vec<Keyword>>, Error> {
+    if depth > MAX_COPY_DEPTH {
+        return Err(invalid(path, "too many nested copy directives"));
+    }
+    let bytes = fs::read(path)
+        .map_err(|e| Error::system_definition_io(path.display().to_string(), e.to_string()))?;
+    let source = String::from_utf8_lossy(&bytes);
+
+    let mut comment_char = '#';
+    let mut escape_char = '\\';
+    let mut keywords: Option<Vec<Keyword>> = None;
+    let mut line = String::new();
+    for (i, physical_line) in source.lines().enumerate() {
+        if line.is_empty() && physical_line.trim_start().starts_with(comment_char) {
+            continue;
+        }
+        // a line ending with the escape character continues on the next one
+        if let Some(start) = physical_line.strip_suffix(escape_char) {
+            line.push_str(start);
+            continue;
+        }
+        line.push_str(physical_line);
+        let logical_line = strip_comment(&line, comment_char, escape_char).to_string();
+        line.clear();
+
+        let mut words = logical_line.splitn(2, char::is_whitespace);
+        let name = words.next().unwrap_or_default();
+        let operands = words.next().unwrap_or_default().trim();
+        let location = format!("{}:{}", path.display(), i + 1);
+        let keyword = Keyword {
+            location,
+            name: name.to_string(),
+            operands: operands.to_string(),
+            escape_char,
+        };
+
+        let category_keywords = match keywords.as_mut() {
+            Some(category_keywords) => category_keywords,
+            None => {
+                match name {
+                    "comment_char" => comment_char = operands.chars().next().unwrap_or('#'),
+                    "escape_char" => escape_char = operands.chars().next().unwrap_or('\\'),
+                    name if name == category => keywords = Some(Vec::new()),
+                    _ => {}
+                }
+                continue;
+            }
+        };
+        match name {
+            "" => {}
+            "END" if operands == category => return Ok(keywords),
+            "END" => return Err(keyword.invalid()),
+            "copy" => {
+                let copied_path = path.with_file_name(keyword.string()?);
+                let copied_keywords = read_category(&copied_path, category, depth + 1)?
+                    .ok_or_else(|| invalid(&copied_path, &format!("no {} category", category)))?;
+                category_keywords.extend(copied_keywords);
+            }
+            _ => category_keywords.push(keyword),
+        }
+    }
+    match keywords {
+        Some(_) => Err(invalid(path, &format!("no END {}", category))),
+        None => Ok(None),
+    }
+}
+
+/// Returns `line` without a comment: the comment character and what follows it, unless it is
+/// within a string or escaped.
+fn strip_comment(line: &str, comment_char: char, escape_char: char) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            c if c == escape_char => escaped = true,
+            '"' => quoted = !quoted,
+            c if c == comment_char && !quoted => return line[..i].trim(),
+            _ => {}
+        }
+    }
+    line.trim()
+}
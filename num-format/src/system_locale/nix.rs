@@ -3,8 +3,10 @@
 mod bsd;
 mod encoding;
 mod linux;
+mod names;
 
 pub(crate) use self::encoding::{Encoding, UTF_8};
+pub(crate) use self::names::available_names;
 
 cfg_if! {
     if #[cfg(any(
@@ -21,82 +23,93 @@ cfg_if! {
     }
 }
 
-use std::collections::HashSet;
 use std::ffi::{CStr, CString};
-use std::process::Command;
 use std::ptr::{self, NonNull};
 
 use libc::{c_char, c_int, c_void};
 
 use crate::error::Error;
 use crate::grouping::Grouping;
-use crate::locale::Locale;
 use crate::strings::{DecString, InfString, MinString, NanString, PlusString, SepString};
-use crate::system_locale::SystemLocale;
+use crate::system_locale::{cldr_locale, posix_grouping, SystemLocale};
+use crate::system_monetary_format::{Placement, SystemMonetaryFormat};
 
 extern "C" {
     pub fn freelocale(locale: *const c_void);
     pub fn newlocale(mask: c_int, name: *const c_char, base: *const c_void) -> *const c_void;
-    pub fn uselocale(locale: *const c_void) -> *const c_void;
 }
 
-pub(crate) fn available_names() -> HashSet<String> {
-    let inner = |bin| {
-        let output = Command::new(bin).arg("-a").output().ok()?;
-        if !output.status.success() {
-            return None;
-        }
-        let stdout = std::str::from_utf8(&output.stdout).ok()?;
-        let set = stdout
-            .lines()
-            .map(|s| s.trim().to_string())
-            .collect::<HashSet<String>>();
-        Some(set)
-    };
-    if let Some(set) = inner("locale") {
-        return set;
-    }
-    HashSet::default()
+pub(crate) fn new(maybe_name: Option<String>) -> Result<SystemLocale, Error> {
+    let (name, lconv) = query(maybe_name, libc::LC_NUMERIC, Lconv::new)?;
+
+    // the operating system has no infinity and NaN symbols; take them from CLDR
+    let cldr_locale = cldr_locale(&name);
+
+    Ok(SystemLocale {
+        dec: lconv.dec,
+        grp: lconv.grp,
+        inf: InfString::new(cldr_locale.infinity()).unwrap(),
+        min: lconv.min,
+        name,
+        nan: NanString::new(cldr_locale.nan()).unwrap(),
+        plus: lconv.plus,
+        sep: lconv.sep,
+    })
 }
 
-pub(crate) fn new(maybe_name: Option<String>) -> Result<SystemLocale, Error> {
+pub(crate) fn new_monetary(maybe_name: Option<String>) -> Result<SystemMonetaryFormat, Error> {
+    let (name, lconv) = query(maybe_name, libc::LC_MONETARY, MonetaryLconv::new)?;
+    let cldr_locale = cldr_locale(&name);
+    Ok(SystemMonetaryFormat {
+        currency_symbol: lconv.currency_symbol,
+        dec: lconv.dec,
+        frac_digits: lconv.frac_digits,
+        grp: lconv.grp,
+        inf: InfString::new(cldr_locale.infinity()).unwrap(),
+        int_curr_symbol: lconv.int_curr_symbol,
+        int_frac_digits: lconv.int_frac_digits,
+        int_negative: lconv.int_negative,
+        int_positive: lconv.int_positive,
+        min: lconv.min,
+        name,
+        nan: NanString::new(cldr_locale.nan()).unwrap(),
+        negative: lconv.negative,
+        plus: lconv.plus,
+        positive: lconv.positive,
+        sep: lconv.sep,
+    })
+}
+
+/// Returns the name of the system locale `maybe_name` (or of the default locale's `category`)
+/// and its lconv, read with `read_lconv`. Reads a locale object of its own rather than the
+/// global locale, which it leaves alone, so it is safe to call from many threads at once. Only
+/// C libraries other than glibc and the BSDs' switch the calling thread to the locale object
+/// while reading it (see `linux::get_lconv`).
+fn query<T>(
+    maybe_name: Option<String>,
+    category: c_int,
+    read_lconv: fn(&libc::lconv, Encoding) -> Result<T, Error>,
+) -> Result<(String, T), Error> {
     // create a new locale object
     let new = new_locale(&maybe_name)?;
 
     let inner = || {
-        // use the new locale object, while saving the initial one
-        let initial = use_locale(new)?;
-
         // get the encoding
         let encoding = get_encoding(new)?;
 
         // get the lconv
-        let lconv = get_lconv(new, encoding)?;
+        let lconv = get_lconv(new, encoding, read_lconv)?;
 
         // get the name
         let mut name = match maybe_name {
             Some(name) => name,
-            None => get_name(new, encoding)?,
+            None => get_name(new, category, encoding)?,
         };
         if &name == "POSIX" {
             name = "C".to_string();
         }
 
-        // reset to the initial locale object
-        let _ = use_locale(initial);
-
-        let system_locale = SystemLocale {
-            dec: lconv.dec,
-            grp: lconv.grp,
-            inf: InfString::new(Locale::en.infinity()).unwrap(),
-            min: lconv.min,
-            name,
-            nan: NanString::new(Locale::en.nan()).unwrap(),
-            plus: lconv.plus,
-            sep: lconv.sep,
-        };
-
-        Ok(system_locale)
+        Ok((name, lconv))
     };
 
     let output = inner();
@@ -134,17 +147,6 @@ fn new_locale(name: &Option<String>) -> Result<*const c_void, Error> {
     Ok(new_locale)
 }
 
-fn use_locale(locale: *const c_void) -> Result<*const c_void, Error> {
-    let old_locale = unsafe { uselocale(locale) };
-    if old_locale.is_null() {
-        return Err(Error::system_invalid_return(
-            "uselocale",
-            "uselocale unexpectedly returned a null pointer.",
-        ));
-    }
-    Ok(old_locale)
-}
-
 pub(crate) struct Lconv {
     pub(crate) dec: DecString,
     pub(crate) grp: Grouping,
@@ -191,6 +193,89 @@ impl Lconv {
     }
 }
 
+/// The monetary fields of an lconv (those for LC_MONETARY).
+pub(crate) struct MonetaryLconv {
+    pub(crate) currency_symbol: String,
+    pub(crate) dec: DecString,
+    pub(crate) frac_digits: u8,
+    pub(crate) grp: Grouping,
+    pub(crate) int_curr_symbol: String,
+    pub(crate) int_frac_digits: u8,
+    pub(crate) int_negative: Placement,
+    pub(crate) int_positive: Placement,
+    pub(crate) min: MinString,
+    pub(crate) negative: Placement,
+    pub(crate) plus: PlusString,
+    pub(crate) positive: Placement,
+    pub(crate) sep: SepString,
+}
+
+impl MonetaryLconv {
+    /// Reads the monetary fields of `lconv`, filling in what the locale leaves unspecified as
+    /// glibc's `strfmon` does: the decimal point of LC_NUMERIC, a minus sign of `"-"` and 2
+    /// fraction digits.
+    pub(crate) fn new(lconv: &libc::lconv, encoding: Encoding) -> Result<MonetaryLconv, Error> {
+        let string = |ptr, field_name| StaticCString::new(ptr, encoding, field_name)?.to_string();
+
+        let dec = {
+            let mut s = string(lconv.mon_decimal_point, "lconv.mon_decimal_point")?;
+            if s.is_empty() {
+                s = string(lconv.decimal_point, "lconv.decimal_point")?;
+            }
+            DecString::new(&s)?
+        };
+
+        let grp = StaticCString::new(lconv.mon_grouping, encoding, "lconv.mon_grouping")?
+            .to_grouping()?;
+
+        let min = {
+            let s = string(lconv.negative_sign, "lconv.negative_sign")?;
+            MinString::new(if s.is_empty() { "-" } else { &s })?
+        };
+
+        let plus = PlusString::new(string(lconv.positive_sign, "lconv.positive_sign")?)?;
+
+        let sep = SepString::new(string(lconv.mon_thousands_sep, "lconv.mon_thousands_sep")?)?;
+
+        // int_curr_symbol is a three-letter code followed by the character that separates it
+        // from the amount (e.g. "USD "), which int_p_sep_by_space and int_n_sep_by_space replace
+        let mut int_curr_symbol = string(lconv.int_curr_symbol, "lconv.int_curr_symbol")?;
+        if int_curr_symbol.chars().count() == 4 {
+            let _ = int_curr_symbol.pop();
+        }
+
+        // c_char is i8 on some targets and u8 on others
+        let frac_digits = |digits: c_char| match digits {
+            c_char::MAX => 2,
+            digits => u8::from_ne_bytes(digits.to_ne_bytes()),
+        };
+
+        Ok(MonetaryLconv {
+            currency_symbol: string(lconv.currency_symbol, "lconv.currency_symbol")?,
+            dec,
+            frac_digits: frac_digits(lconv.frac_digits),
+            grp,
+            int_curr_symbol,
+            int_frac_digits: frac_digits(lconv.int_frac_digits),
+            int_negative: Placement::new(
+                lconv.int_n_cs_precedes,
+                lconv.int_n_sep_by_space,
+                lconv.int_n_sign_posn,
+            ),
+            int_positive: Placement::new(
+                lconv.int_p_cs_precedes,
+                lconv.int_p_sep_by_space,
+                lconv.int_p_sign_posn,
+            ),
+            min,
+            negative: Placement::new(lconv.n_cs_precedes, lconv.n_sep_by_space, lconv.n_sign_posn),
+            plus,
+            positive: Placement::new(lconv.p_cs_precedes, lconv.p_sep_by_space, lconv.p_sign_posn),
+            sep,
+        })
+    }
+}
+
 /// Invariants: nul terminated, static lifetime
 pub(crate) struct StaticCString {
     encoding: Encoding,
@@ -215,14 +300,7 @@ impl StaticCString {
     pub(crate) fn to_grouping(&self) -> Result<Grouping, Error> {
         let ptr = self.non_null.as_ptr();
         let cstr = unsafe { CStr::from_ptr(ptr) };
-        let bytes = cstr.to_bytes();
-        let grouping = match bytes {
-            [3, 2] | [2, 3] => Grouping::Indian,
-            [] | [127] => Grouping::Posix,
-            [3] | [3, 3] => Grouping::Standard,
-            _ => return Err(Error::system_unsupported_grouping(bytes)),
-        };
-        Ok(grouping)
+        posix_grouping(cstr.to_bytes())
     }
 
     pub(crate) fn to_string(&self) -> Result<String, Error> {
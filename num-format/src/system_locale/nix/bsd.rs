@@ -14,7 +14,7 @@
 use libc::{c_char, c_int, c_void};
 
 use crate::error::Error;
-use crate::system_locale::nix::{Encoding, Lconv, StaticCString, UTF_8};
+use crate::system_locale::nix::{Encoding, StaticCString, UTF_8};
 
 extern "C" {
     fn localeconv_l(locale: *const c_void) -> *const libc::lconv;
@@ -30,7 +30,11 @@ pub(crate) fn get_encoding(locale: *const c_void) -> Result<Encoding, Error> {
     Ok(encoding)
 }
 
-pub(crate) fn get_lconv(locale: *const c_void, encoding: Encoding) -> Result<Lconv, Error> {
+pub(crate) fn get_lconv<T>(
+    locale: *const c_void,
+    encoding: Encoding,
+    read_lconv: fn(&libc::lconv, Encoding) -> Result<T, Error>,
+) -> Result<T, Error> {
     let lconv_ptr = unsafe { localeconv_l(locale) };
     if lconv_ptr.is_null() {
         return Err(Error::system_invalid_return(
@@ -39,12 +43,19 @@ pub(crate) fn get_lconv(locale: *const c_void, encoding: Encoding) -> Result<Lco
         ));
     }
     let lconv: &libc::lconv = unsafe { lconv_ptr.as_ref() }.unwrap();
-    let lconv = Lconv::new(lconv, encoding)?;
-    Ok(lconv)
+    read_lconv(lconv, encoding)
 }
 
-pub(crate) fn get_name(locale: *const c_void, encoding: Encoding) -> Result<String, Error> {
-    let mask = libc::LC_CTYPE_MASK | libc::LC_MONETARY_MASK | libc::LC_NUMERIC_MASK;
+pub(crate) fn get_name(
+    locale: *const c_void,
+    category: c_int,
+    encoding: Encoding,
+) -> Result<String, Error> {
+    let mask = if category == libc::LC_MONETARY {
+        libc::LC_MONETARY_MASK
+    } else {
+        libc::LC_NUMERIC_MASK
+    };
     let name_ptr = unsafe { querylocale(mask, locale) };
     let name_static_c_string = StaticCString::new(name_ptr, encoding, "querylocale")?;
     let name = name_static_c_string.to_string()?;
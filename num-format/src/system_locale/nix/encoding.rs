@@ -6,17 +6,71 @@ lazy_static! {
     pub(crate) static ref UTF_8: Encoding = Encoding::from_bytes(b"UTF-8").unwrap();
 }
 
-// See https://docs.rs/encoding_rs/0.8.16/encoding_rs/
-static LATIN_1: &encoding_rs::Encoding = encoding_rs::WINDOWS_1252;
+/// Names of ASCII, as glibc (`ANSI_X3.4-1968`, the codeset of its "C" locale), macOS and the
+/// BSDs (`US-ASCII`) and others call it, normalized (see `normalize`). An empty codeset is
+/// taken to be ASCII too.
+const ASCII_NAMES: &[&str] = &["", "646", "ANSIX341968", "ASCII", "ISO646US", "USASCII"];
 
+/// Codesets of the C libraries that `encoding_rs` has no labels for, normalized, and the labels
+/// of the `encoding_rs` encodings to decode them with. Some are the same encodings under another
+/// name; others only agree on the characters locales use in numbers and currency symbols (e.g.
+/// PT154 and RK1048 with windows-1251 on the no-break space and the Cyrillic alphabet).
+///
+/// Helpful: https://github.com/servo/libparserutils/blob/master/build/Aliases
+const ALIASES: &[(&str, &str)] = &[
+    ("BIG5HKSCS", "big5"),
+    ("CP949", "euc-kr"),
+    // See https://en.wikipedia.org/wiki/GB_18030 and
+    // https://www.ibm.com/support/knowledgecenter/en/ssw_aix_72/com.ibm.aix.nlsgdrf/ibm-eucCN.htm
+    ("EUCCN", "gb18030"),
+    ("EUCJP", "euc-jp"),
+    ("EUCKR", "euc-kr"),
+    ("KOI8R", "koi8-r"),
+    ("KOI8RU", "koi8-u"),
+    ("KOI8T", "koi8-r"),
+    ("KOI8U", "koi8-u"),
+    ("PT154", "windows-1251"),
+    ("RK1048", "windows-1251"),
+    ("SHIFTJIS", "shift_jis"),
+    ("SJIS", "shift_jis"),
+    ("TIS620", "windows-874"),
+    ("UTF8", "utf-8"),
+];
+
+/// Codesets that neither `encoding_rs` nor a close relative of theirs in it can decode, but
+/// which are ASCII-compatible, normalized, and their names. Only their ASCII bytes are decoded,
+/// which covers the symbols of the locales that use them.
+const ASCII_COMPATIBLE: &[(&str, &str)] = &[
+    ("ARMSCII8", "ARMSCII-8"),
+    ("CP1131", "CP1131"),
+    ("EUCTW", "EUC-TW"),
+    ("GEORGIANPS", "GEORGIAN-PS"),
+    ("ISCIIDEV", "ISCII-DEV"),
+];
+
+/// The encoding of a system locale's strings (its codeset).
 #[derive(Copy, Clone, Debug)]
-pub(crate) struct Encoding(&'static encoding_rs::Encoding);
+pub(crate) enum Encoding {
+    /// ASCII, or the named ASCII-compatible codeset, of which only the ASCII bytes are decoded.
+    Ascii(&'static str),
+    /// An encoding `encoding_rs` decodes.
+    EncodingRs(&'static encoding_rs::Encoding),
+}
 
 impl Encoding {
     pub(crate) fn decode(&self, bytes: &[u8]) -> Result<String, Error> {
-        let (cow, _encoding, is_err) = self.0.decode(bytes);
-        if is_err {
-            return Err(Error::system_invalid_return(
+        let decoded = match self {
+            Encoding::Ascii(_) if bytes.is_ascii() => {
+                Some(String::from_utf8_lossy(bytes).into_owned())
+            }
+            Encoding::Ascii(_) => None,
+            Encoding::EncodingRs(encoding) => match encoding.decode(bytes) {
+                (_, _, true) => None,
+                (cow, _, false) => Some(cow.into_owned()),
+            },
+        };
+        decoded.ok_or_else(|| {
+            Error::system_invalid_return(
                 "nl_langinfo",
                 format!(
                     "nl_langinfo unexpectedly returned data that could not be decoded \
@@ -24,50 +78,127 @@ impl Encoding {
                     self.name(),
                     bytes
                 ),
-            ));
-        }
-        Ok(cow.into())
+            )
+        })
     }
 
     pub(crate) fn name(&self) -> &'static str {
-        self.0.name()
+        match self {
+            Encoding::Ascii(name) => name,
+            Encoding::EncodingRs(encoding) => encoding.name(),
+        }
     }
 }
 
 impl Encoding {
+    /// Returns the encoding of a codeset name, as the C library's `nl_langinfo(CODESET)` gives it
+    /// (e.g. `UTF-8`, `ISO-8859-15` with glibc or `ISO8859-15` with macOS and the BSDs).
     pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Encoding, Error> {
-        if let Some(encoding) = encoding_rs::Encoding::for_label_no_replacement(bytes) {
-            return Ok(Encoding(encoding));
+        let name = String::from_utf8_lossy(bytes);
+        let normalized = normalize(&name);
+
+        if ASCII_NAMES.contains(&normalized.as_str()) {
+            return Ok(Encoding::Ascii("US-ASCII"));
+        }
+        if let Some(&(_, name)) = ASCII_COMPATIBLE.iter().find(|(n, _)| *n == normalized) {
+            return Ok(Encoding::Ascii(name));
         }
 
-        // Helpful: https://github.com/servo/libparserutils/blob/master/build/Aliases
-        let encoding = match bytes {
-            // Assume empty bytes means use LATIN-1 ...
-            b"" => LATIN_1, // Is this correct?
-
-            // Naming issues...
-            b"Big5HKSCS" => encoding_rs::BIG5,
-            b"CP949" => encoding_rs::EUC_KR,
-            // See https://en.wikipedia.org/wiki/GB_18030 and
-            // https://www.ibm.com/support/knowledgecenter/en/ssw_aix_72/com.ibm.aix.nlsgdrf/ibm-eucCN.htm
-            b"eucCN" => encoding_rs::GB18030,
-            b"eucJP" => encoding_rs::EUC_JP,
-            b"eucKR" => encoding_rs::EUC_KR,
-
-            // These are not correct, but seem to only
-            // use LATIN-1 characters for number formatting ...
-            b"ARMSCII-8" => LATIN_1,
-            b"CP1131" => LATIN_1,
-            b"ISCII-DEV" => LATIN_1,
-            b"PT154" => LATIN_1,
-
-            // If all of the above fail, return an error ...
-            _ => {
-                let name = String::from_utf8_lossy(bytes);
-                return Err(Error::system_unsupported_encoding(name));
-            }
+        if let Some(encoding) = encoding_rs::Encoding::for_label_no_replacement(bytes) {
+            return Ok(Encoding::EncodingRs(encoding));
+        }
+        let label = match ALIASES.iter().find(|(n, _)| *n == normalized) {
+            Some(&(_, label)) => label.to_string(),
+            // e.g. ISO_8859-15 and iso885915
+            None => match normalized.strip_prefix("ISO8859") {
+                Some(part) => format!("iso-8859-{}", part),
+                None => return Err(Error::system_unsupported_encoding(name)),
+            },
         };
+        match encoding_rs::Encoding::for_label_no_replacement(label.as_bytes()) {
+            Some(encoding) => Ok(Encoding::EncodingRs(encoding)),
+            None => Err(Error::system_unsupported_encoding(name)),
+        }
+    }
+}
+
+/// Returns a codeset name in upper case and without the hyphens, underscores, dots and spaces
+/// that the C libraries spell it with in different ways (e.g. `ISO8859-15`, `ISO-8859-15` and
+/// `iso885915` as `ISO885915`).
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '-' | '_' | '.' | ' '))
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_bytes() {
+        let test_cases: &[(&str, &str)] = &[
+            // glibc's "C" locale and ASCII by other names
+            ("ANSI_X3.4-1968", "US-ASCII"),
+            ("US-ASCII", "US-ASCII"),
+            ("646", "US-ASCII"),
+            ("", "US-ASCII"),
+            // spellings of the same codeset
+            ("UTF-8", "UTF-8"),
+            ("utf8", "UTF-8"),
+            ("ISO-8859-15", "ISO-8859-15"),
+            ("ISO8859-15", "ISO-8859-15"),
+            ("ISO_8859-15", "ISO-8859-15"),
+            ("iso885915", "ISO-8859-15"),
+            ("ISO-8859-1", "windows-1252"),
+            ("ISO8859-5", "ISO-8859-5"),
+            ("ISO-8859-8-I", "ISO-8859-8-I"),
+            ("TIS-620", "windows-874"),
+            ("TIS620", "windows-874"),
+            ("KOI8-U", "KOI8-U"),
+            ("KOI8U", "KOI8-U"),
+            ("KOI8-RU", "KOI8-U"),
+            ("BIG5-HKSCS", "Big5"),
+            ("Big5HKSCS", "Big5"),
+            ("eucJP", "EUC-JP"),
+            ("eucCN", "gb18030"),
+            // close relatives
+            ("KOI8-T", "KOI8-R"),
+            ("PT154", "windows-1251"),
+            ("RK1048", "windows-1251"),
+            // ASCII-compatible codesets
+            ("ARMSCII-8", "ARMSCII-8"),
+            ("EUC-TW", "EUC-TW"),
+            ("eucTW", "EUC-TW"),
+            ("GEORGIAN-PS", "GEORGIAN-PS"),
+        ];
+        for &(codeset, name) in test_cases {
+            let encoding = Encoding::from_bytes(codeset.as_bytes()).unwrap();
+            assert_eq!(encoding.name(), name, "{}", codeset);
+        }
+
+        assert!(Encoding::from_bytes(b"EBCDIC-US").is_err());
+        assert!(Encoding::from_bytes(b"ISO-8859-99").is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let ascii = Encoding::from_bytes(b"ANSI_X3.4-1968").unwrap();
+        assert_eq!(ascii.decode(b",").unwrap(), ",");
+        assert!(ascii.decode(b"\xa0").is_err());
+
+        let rk1048 = Encoding::from_bytes(b"RK1048").unwrap();
+        assert_eq!(rk1048.decode(b"\xa0").unwrap(), "\u{a0}");
+
+        let koi8_t = Encoding::from_bytes(b"KOI8-T").unwrap();
+        assert_eq!(koi8_t.decode(b"\xd2\xd5\xc2").unwrap(), "руб");
+
+        let koi8_u = Encoding::from_bytes(b"KOI8-U").unwrap();
+        assert_eq!(koi8_u.decode(b"\x9a").unwrap(), "\u{a0}");
 
-        Ok(Encoding(encoding))
+        let euc_tw = Encoding::from_bytes(b"EUC-TW").unwrap();
+        assert_eq!(euc_tw.decode(b"NT$").unwrap(), "NT$");
+        assert!(euc_tw.decode(b"\xa1\xa1").is_err());
     }
 }
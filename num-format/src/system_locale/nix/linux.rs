@@ -12,50 +12,206 @@
 ))]
 
 use std::env;
+#[cfg(not(target_env = "gnu"))]
+use std::sync::Mutex;
 
-use libc::{c_char, c_void};
+use libc::{c_char, c_int, c_void};
 
 use crate::error::Error;
-use crate::system_locale::nix::{Encoding, Lconv, StaticCString, UTF_8};
+use crate::system_locale::nix::{Encoding, StaticCString, UTF_8};
 
 extern "C" {
+    #[cfg(not(target_env = "gnu"))]
     fn localeconv() -> *const libc::lconv;
-    fn nl_langinfo(item: libc::nl_item) -> *const c_char;
+    fn nl_langinfo_l(item: libc::nl_item, locale: *const c_void) -> *const c_char;
+    #[cfg(not(target_env = "gnu"))]
+    fn uselocale(locale: *const c_void) -> *const c_void;
 }
 
-pub(crate) fn get_encoding(_locale: *const c_void) -> Result<Encoding, Error> {
-    let encoding_ptr = unsafe { nl_langinfo(libc::CODESET) };
-    let encoding_static_c_string = StaticCString::new(encoding_ptr, *UTF_8, "nl_langinfo")?;
+#[cfg(not(target_env = "gnu"))]
+lazy_static! {
+    // Held while reading localeconv's buffer, which other threads' calls may overwrite
+    static ref LOCALECONV: Mutex<()> = Mutex::new(());
+}
+
+// glibc has no localeconv_l; localeconv reads the thread's locale into a buffer shared by all
+// threads. nl_langinfo_l instead reads each lconv field from a locale object, with these items,
+// which are private to glibc (see its <langinfo.h>); other C libraries number their items
+// differently, if they have them at all
+#[cfg(target_env = "gnu")]
+const GROUPING: libc::nl_item = 0x10002;
+#[cfg(target_env = "gnu")]
+const INT_CURR_SYMBOL: libc::nl_item = 0x40000;
+#[cfg(target_env = "gnu")]
+const CURRENCY_SYMBOL: libc::nl_item = 0x40001;
+#[cfg(target_env = "gnu")]
+const MON_DECIMAL_POINT: libc::nl_item = 0x40002;
+#[cfg(target_env = "gnu")]
+const MON_THOUSANDS_SEP: libc::nl_item = 0x40003;
+#[cfg(target_env = "gnu")]
+const MON_GROUPING: libc::nl_item = 0x40004;
+#[cfg(target_env = "gnu")]
+const POSITIVE_SIGN: libc::nl_item = 0x40005;
+#[cfg(target_env = "gnu")]
+const NEGATIVE_SIGN: libc::nl_item = 0x40006;
+#[cfg(target_env = "gnu")]
+const INT_FRAC_DIGITS: libc::nl_item = 0x40007;
+#[cfg(target_env = "gnu")]
+const FRAC_DIGITS: libc::nl_item = 0x40008;
+#[cfg(target_env = "gnu")]
+const P_CS_PRECEDES: libc::nl_item = 0x40009;
+#[cfg(target_env = "gnu")]
+const P_SEP_BY_SPACE: libc::nl_item = 0x4000a;
+#[cfg(target_env = "gnu")]
+const N_CS_PRECEDES: libc::nl_item = 0x4000b;
+#[cfg(target_env = "gnu")]
+const N_SEP_BY_SPACE: libc::nl_item = 0x4000c;
+#[cfg(target_env = "gnu")]
+const P_SIGN_POSN: libc::nl_item = 0x4000d;
+#[cfg(target_env = "gnu")]
+const N_SIGN_POSN: libc::nl_item = 0x4000e;
+#[cfg(target_env = "gnu")]
+const INT_P_CS_PRECEDES: libc::nl_item = 0x40010;
+#[cfg(target_env = "gnu")]
+const INT_P_SEP_BY_SPACE: libc::nl_item = 0x40011;
+#[cfg(target_env = "gnu")]
+const INT_N_CS_PRECEDES: libc::nl_item = 0x40012;
+#[cfg(target_env = "gnu")]
+const INT_N_SEP_BY_SPACE: libc::nl_item = 0x40013;
+#[cfg(target_env = "gnu")]
+const INT_P_SIGN_POSN: libc::nl_item = 0x40014;
+#[cfg(target_env = "gnu")]
+const INT_N_SIGN_POSN: libc::nl_item = 0x40015;
+
+/// Returns glibc's `_NL_LOCALE_NAME(category)` item, the name of a category of a locale object.
+#[cfg(target_env = "gnu")]
+fn locale_name_item(category: c_int) -> libc::nl_item {
+    (category << 16) | 0xffff
+}
+
+pub(crate) fn get_encoding(locale: *const c_void) -> Result<Encoding, Error> {
+    let encoding_ptr = unsafe { nl_langinfo_l(libc::CODESET, locale) };
+    let encoding_static_c_string = StaticCString::new(encoding_ptr, *UTF_8, "nl_langinfo_l")?;
     let encoding_string = encoding_static_c_string.to_string()?;
     let encoding = Encoding::from_bytes(encoding_string.as_bytes())?;
     Ok(encoding)
 }
 
-pub(crate) fn get_lconv(_locale: *const c_void, encoding: Encoding) -> Result<Lconv, Error> {
-    let lconv_ptr = unsafe { localeconv() };
-    if lconv_ptr.is_null() {
+#[cfg(target_env = "gnu")]
+pub(crate) fn get_lconv<T>(
+    locale: *const c_void,
+    encoding: Encoding,
+    read_lconv: fn(&libc::lconv, Encoding) -> Result<T, Error>,
+) -> Result<T, Error> {
+    let string = |item| unsafe { nl_langinfo_l(item, locale) } as *mut c_char;
+    // like localeconv, report a grouping of unspecified (-1 or CHAR_MAX) group sizes as empty
+    // and unspecified values as CHAR_MAX
+    let unspecified = |value: c_char| value == c_char::MAX || value.to_ne_bytes() == [0xff];
+    let empty: &'static [c_char] = &[0];
+    let grouping = |item| match unsafe { string(item).as_ref() } {
+        Some(&first) if unspecified(first) => empty.as_ptr() as *mut c_char,
+        _ => string(item),
+    };
+    let char = |item| match unsafe { string(item).as_ref() } {
+        Some(&value) if !unspecified(value) => value,
+        _ => c_char::MAX,
+    };
+    // the strings are owned by the locale object, which outlives the lconv
+    let lconv = libc::lconv {
+        decimal_point: string(libc::RADIXCHAR),
+        thousands_sep: string(libc::THOUSEP),
+        grouping: grouping(GROUPING),
+        int_curr_symbol: string(INT_CURR_SYMBOL),
+        currency_symbol: string(CURRENCY_SYMBOL),
+        mon_decimal_point: string(MON_DECIMAL_POINT),
+        mon_thousands_sep: string(MON_THOUSANDS_SEP),
+        mon_grouping: grouping(MON_GROUPING),
+        positive_sign: string(POSITIVE_SIGN),
+        negative_sign: string(NEGATIVE_SIGN),
+        int_frac_digits: char(INT_FRAC_DIGITS),
+        frac_digits: char(FRAC_DIGITS),
+        p_cs_precedes: char(P_CS_PRECEDES),
+        p_sep_by_space: char(P_SEP_BY_SPACE),
+        n_cs_precedes: char(N_CS_PRECEDES),
+        n_sep_by_space: char(N_SEP_BY_SPACE),
+        p_sign_posn: char(P_SIGN_POSN),
+        n_sign_posn: char(N_SIGN_POSN),
+        int_p_cs_precedes: char(INT_P_CS_PRECEDES),
+        int_p_sep_by_space: char(INT_P_SEP_BY_SPACE),
+        int_n_cs_precedes: char(INT_N_CS_PRECEDES),
+        int_n_sep_by_space: char(INT_N_SEP_BY_SPACE),
+        int_p_sign_posn: char(INT_P_SIGN_POSN),
+        int_n_sign_posn: char(INT_N_SIGN_POSN),
+    };
+    read_lconv(&lconv, encoding)
+}
+
+/// Reads the lconv of `locale` with `localeconv`, the only way other C libraries (e.g. musl) have,
+/// by switching the calling thread (and no other) to `locale` for the duration.
+#[cfg(not(target_env = "gnu"))]
+pub(crate) fn get_lconv<T>(
+    locale: *const c_void,
+    encoding: Encoding,
+    read_lconv: fn(&libc::lconv, Encoding) -> Result<T, Error>,
+) -> Result<T, Error> {
+    let _guard = LOCALECONV.lock().unwrap_or_else(|e| e.into_inner());
+    let initial = unsafe { uselocale(locale) };
+    if initial.is_null() {
         return Err(Error::system_invalid_return(
-            "localeconv_l",
-            "localeconv_l unexpectedly returned a null pointer.",
+            "uselocale",
+            "uselocale unexpectedly returned a null pointer.",
         ));
     }
-    let lconv: &libc::lconv = unsafe { lconv_ptr.as_ref() }.unwrap();
-    let lconv = Lconv::new(lconv, encoding)?;
-    Ok(lconv)
+    let lconv_ptr = unsafe { localeconv() };
+    let output = match unsafe { lconv_ptr.as_ref() } {
+        Some(lconv) => read_lconv(lconv, encoding),
+        None => Err(Error::system_invalid_return(
+            "localeconv",
+            "localeconv unexpectedly returned a null pointer.",
+        )),
+    };
+    let _ = unsafe { uselocale(initial) };
+    output
 }
 
-pub(crate) fn get_name(_locale: *const c_void, _encoding: Encoding) -> Result<String, Error> {
-    if let Ok(name) = env::var("LC_ALL") {
+#[cfg(target_env = "gnu")]
+pub(crate) fn get_name(
+    locale: *const c_void,
+    category: c_int,
+    encoding: Encoding,
+) -> Result<String, Error> {
+    let name_ptr = unsafe { nl_langinfo_l(locale_name_item(category), locale) };
+    let name = StaticCString::new(name_ptr, encoding, "nl_langinfo_l")?.to_string()?;
+    if !name.is_empty() {
         return Ok(name);
     }
-    if let Ok(name) = env::var("LC_NUMERIC") {
-        return Ok(name);
-    }
-    if let Ok(name) = env::var("LC_MONETARY") {
-        return Ok(name);
-    }
-    if let Ok(name) = env::var("LANG") {
-        return Ok(name);
+
+    // glibc before 2.26 does not know the name item
+    name_from_env(category)
+}
+
+#[cfg(not(target_env = "gnu"))]
+pub(crate) fn get_name(
+    _locale: *const c_void,
+    category: c_int,
+    _encoding: Encoding,
+) -> Result<String, Error> {
+    name_from_env(category)
+}
+
+/// Returns the name of `category` from the environment variables that newlocale read.
+fn name_from_env(category: c_int) -> Result<String, Error> {
+    let category_var = if category == libc::LC_MONETARY {
+        "LC_MONETARY"
+    } else {
+        "LC_NUMERIC"
+    };
+    for var in &["LC_ALL", category_var, "LANG"] {
+        if let Ok(name) = env::var(var) {
+            if !name.is_empty() {
+                return Ok(name);
+            }
+        }
     }
     Ok("C".to_string())
 }
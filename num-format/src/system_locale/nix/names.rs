@@ -0,0 +1,238 @@
+#![cfg(all(feature = "with-system-locale", unix))]
+
+use std::collections::HashSet;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::process::Command;
+
+use crate::error::Error;
+
+cfg_if! {
+    if #[cfg(any(
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "macos",
+        target_os = "openbsd",
+        target_os = "netbsd"
+    ))] {
+        /// The directory with a subdirectory of category files for each locale.
+        const LOCALE_DIR: &str = "/usr/share/locale";
+        /// The environment variable with more such directories, separated by colons.
+        const LOCALE_PATH_VAR: &str = "PATH_LOCALE";
+        /// The file with more locales, which is only read if `LOCALE_PATH_VAR` is not set.
+        const LOCALE_ARCHIVE: Option<&str> = None;
+    } else {
+        /// The directory with a subdirectory of category files for each locale.
+        const LOCALE_DIR: &str = "/usr/lib/locale";
+        /// The environment variable with more such directories, separated by colons.
+        const LOCALE_PATH_VAR: &str = "LOCPATH";
+        /// The file with more locales, which is only read if `LOCALE_PATH_VAR` is not set.
+        const LOCALE_ARCHIVE: Option<&str> = Some("/usr/lib/locale/locale-archive");
+    }
+}
+
+/// The first field of the header of glibc's locale archive.
+const ARCHIVE_MAGIC: u32 = 0xde02_0109;
+
+/// Returns the names of the locales the C library can load: those in the locale directories and,
+/// with glibc, in the locale archive, as well as "C" and "POSIX". Only if none of these can be
+/// read does it run `locale -a` instead.
+pub(crate) fn available_names() -> Result<HashSet<String>, Error> {
+    let mut names = HashSet::new();
+    let mut read_any = false;
+    let mut problems = Vec::new();
+
+    let locale_path = env::var(LOCALE_PATH_VAR)
+        .ok()
+        .filter(|locale_path| !locale_path.is_empty());
+
+    let dirs = locale_path
+        .iter()
+        .flat_map(|locale_path| locale_path.split(':'))
+        .filter(|dir| !dir.is_empty())
+        .chain(Some(LOCALE_DIR));
+    for dir in dirs {
+        match read_locale_dir(Path::new(dir)) {
+            Ok(dir_names) => {
+                names.extend(dir_names);
+                read_any = true;
+            }
+            Err(e) => problems.push(format!("{}: {}", dir, e)),
+        }
+    }
+
+    if let (None, Some(archive)) = (&locale_path, LOCALE_ARCHIVE) {
+        match File::open(archive).and_then(read_locale_archive) {
+            Ok(archive_names) => {
+                names.extend(archive_names);
+                read_any = true;
+            }
+            Err(e) => problems.push(format!("{}: {}", archive, e)),
+        }
+    }
+
+    if read_any {
+        names.insert("C".to_string());
+        names.insert("POSIX".to_string());
+        return Ok(names);
+    }
+
+    match run_locale_command() {
+        Ok(names) => Ok(names),
+        Err(e) => {
+            problems.push(format!("locale -a: {}", e));
+            Err(Error::system_no_locale_names(problems.join("; ")))
+        }
+    }
+}
+
+/// Returns the names of the subdirectories of `dir` that hold an `LC_NUMERIC` file.
+fn read_locale_dir(dir: &Path) -> io::Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if !entry.path().join("LC_NUMERIC").is_file() {
+            continue;
+        }
+        if let Ok(name) = entry.file_name().into_string() {
+            names.push(name);
+        }
+    }
+    Ok(names)
+}
+
+/// Returns the names of the locales in glibc's locale archive (see `locarchive.h` in glibc's
+/// sources), which starts with a header of native-endian `u32`s giving the positions of its
+/// tables. The name hash table has entries of three `u32`s: a hash, the position of a name in
+/// the string table and the position of its locale record (0 for an empty entry).
+fn read_locale_archive<R>(mut archive: R) -> io::Result<Vec<String>>
+where
+    R: Read + Seek,
+{
+    let invalid = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+
+    let header = read_u32s(&mut archive, 8)?;
+    if header[0] != ARCHIVE_MAGIC {
+        return Err(invalid("not a locale archive"));
+    }
+    let (namehash_offset, namehash_size) = (header[2], header[4]);
+    let (string_offset, string_used) = (header[5], header[6]);
+
+    archive.seek(SeekFrom::Start(u64::from(namehash_offset)))?;
+    let namehash = read_u32s(&mut archive, namehash_size as usize * 3)?;
+
+    archive.seek(SeekFrom::Start(u64::from(string_offset)))?;
+    let mut strings = vec![0; string_used as usize];
+    archive.read_exact(&mut strings)?;
+
+    let mut names = Vec::new();
+    for entry in namehash.chunks(3) {
+        let (name_offset, locrec_offset) = (entry[1], entry[2]);
+        if locrec_offset == 0 {
+            continue;
+        }
+        let name = name_offset
+            .checked_sub(string_offset)
+            .and_then(|start| strings.get(start as usize..))
+            .and_then(|rest| rest.split(|&b| b == 0).next())
+            .ok_or_else(|| invalid("name outside of the string table"))?;
+        let name = std::str::from_utf8(name).map_err(|_| invalid("name is not valid UTF-8"))?;
+        names.push(name.to_string());
+    }
+    Ok(names)
+}
+
+fn read_u32s<R>(reader: &mut R, n: usize) -> io::Result<Vec<u32>>
+where
+    R: Read,
+{
+    let mut bytes = vec![0; n * 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes
+        .chunks(4)
+        .map(|chunk| u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+/// Returns the names listed by the `locale -a` command, or what went wrong.
+fn run_locale_command() -> Result<HashSet<String>, String> {
+    let output = Command::new("locale")
+        .arg("-a")
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(format!("exited with {}", output.status));
+    }
+    let stdout = std::str::from_utf8(&output.stdout).map_err(|e| e.to_string())?;
+    Ok(stdout
+        .lines()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    /// Returns a locale archive with `names`, laid out as glibc lays it out, but without the
+    /// locale records themselves.
+    fn archive(names: &[&str]) -> Vec<u8> {
+        let namehash_offset = 56;
+        let namehash_size = names.len() as u32 + 2;
+        let string_offset = namehash_offset + namehash_size * 12;
+        let mut strings = Vec::new();
+        let mut namehash = vec![0; 6];
+        for name in names {
+            namehash.extend(&[1, string_offset + strings.len() as u32, 4096]);
+            strings.extend(name.as_bytes());
+            strings.push(0);
+        }
+        let string_used = strings.len() as u32;
+        let header = [
+            ARCHIVE_MAGIC,
+            0,
+            namehash_offset,
+            names.len() as u32,
+            namehash_size,
+            string_offset,
+            string_used,
+            string_used,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ];
+        let mut bytes = Vec::new();
+        for n in header.iter().chain(&namehash) {
+            bytes.extend(&n.to_ne_bytes());
+        }
+        bytes.extend(strings);
+        bytes
+    }
+
+    #[test]
+    fn test_read_locale_archive() {
+        let names = ["de_DE.utf8", "en_US.utf8", "ru_RU.koi8r"];
+        let archive_names = read_locale_archive(Cursor::new(archive(&names))).unwrap();
+        assert_eq!(archive_names, names);
+
+        let archive_names = read_locale_archive(Cursor::new(archive(&[]))).unwrap();
+        assert!(archive_names.is_empty());
+
+        let mut bytes = archive(&names);
+        bytes[0] ^= 0xff;
+        assert!(read_locale_archive(Cursor::new(bytes)).is_err());
+
+        let mut bytes = archive(&names);
+        bytes.truncate(bytes.len() - 5);
+        assert!(read_locale_archive(Cursor::new(bytes)).is_err());
+    }
+}
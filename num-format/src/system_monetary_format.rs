@@ -0,0 +1,500 @@
+#![cfg(all(feature = "with-system-locale", unix))]
+
+use libc::c_char;
+
+use crate::error::Error;
+use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::strings::{
+    DecString, DecimalStr, InfString, InfinityStr, MinString, MinusSignStr, NanStr, NanString,
+    PlusSignStr, PlusString, SepString, SeparatorStr,
+};
+use crate::system_locale::nix;
+
+/// The monetary format of a locale obtained from your operating system (its `LC_MONETARY`
+/// category), which writes amounts of money the way the C library's [`strfmon`] does. Implements
+/// [`Format`] with the monetary decimal point, separator and grouping, which can differ from those
+/// of [`SystemLocale`]. Only available on Unix-based systems (including macOS).
+///
+/// # Example
+/// ```rust
+/// use num_format::SystemMonetaryFormat;
+///
+/// fn main() {
+///     // In the "C" locale there is no currency symbol and no grouping
+///     let format = SystemMonetaryFormat::from_name("C").unwrap();
+///     assert_eq!(format.format(-1234.5), "-1234.50");
+///
+///     // In "de_DE.UTF-8", format.format(-1234.5) would be "-1.234,50 €", in "en_US.UTF-8"
+///     // "-$1,234.50" and format.format_international(-1234.5) "-USD 1,234.50"
+///     if let Ok(format) = SystemMonetaryFormat::from_name("en_US.UTF-8") {
+///         println!("{}", format.format(-1234.5));
+///     }
+/// }
+/// ```
+///
+/// [`Format`]: trait.Format.html
+/// [`strfmon`]: https://man7.org/linux/man-pages/man3/strfmon.3.html
+/// [`SystemLocale`]: struct.SystemLocale.html
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct SystemMonetaryFormat {
+    pub(crate) currency_symbol: String,
+    pub(crate) dec: DecString,
+    pub(crate) frac_digits: u8,
+    pub(crate) grp: Grouping,
+    pub(crate) inf: InfString,
+    pub(crate) int_curr_symbol: String,
+    pub(crate) int_frac_digits: u8,
+    pub(crate) int_negative: Placement,
+    pub(crate) int_positive: Placement,
+    pub(crate) min: MinString,
+    pub(crate) name: String,
+    pub(crate) nan: NanString,
+    pub(crate) negative: Placement,
+    pub(crate) plus: PlusString,
+    pub(crate) positive: Placement,
+    pub(crate) sep: SepString,
+}
+
+/// Where the currency symbol and sign of an amount go, from an lconv's `p_cs_precedes`,
+/// `p_sep_by_space` and `p_sign_posn` (or their `n_` and `int_` counterparts).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub(crate) struct Placement {
+    cs_precedes: bool,
+    sep_by_space: u8,
+    sign_posn: u8,
+}
+
+impl Placement {
+    /// Fills in unspecified values (`CHAR_MAX`) as glibc's `strfmon` does: the currency symbol
+    /// first, without a space, and the sign before both it and the amount.
+    pub(crate) fn new(cs_precedes: c_char, sep_by_space: c_char, sign_posn: c_char) -> Placement {
+        Placement {
+            cs_precedes: cs_precedes != 0,
+            sep_by_space: match sep_by_space {
+                1 => 1,
+                2 => 2,
+                _ => 0,
+            },
+            sign_posn: match sign_posn {
+                0 => 0,
+                2 => 2,
+                3 => 3,
+                4 => 4,
+                _ => 1,
+            },
+        }
+    }
+
+    /// Puts together a formatted amount, its currency symbol and its sign, as described for
+    /// `localeconv` by POSIX and written by glibc's `strfmon`.
+    fn arrange(&self, value: &str, symbol: &str, sign: &str, negative: bool) -> String {
+        // The space that follows the currency symbol or sign next to the amount (`sep_by_space`
+        // 1) or separates the sign from the currency symbol when they are next to each other (2)
+        let s1 = if self.sep_by_space == 1 { " " } else { "" };
+        let s2 = if self.sep_by_space == 2 { " " } else { "" };
+        match (self.cs_precedes, self.sign_posn) {
+            // strfmon only puts negative amounts in parentheses
+            (true, 0) if negative => ["(", symbol, s1, value, ")"].concat(),
+            (false, 0) if negative => ["(", value, s1, symbol, ")"].concat(),
+            (true, 0) => [symbol, s1, value].concat(),
+            (false, 0) => [value, s1, symbol].concat(),
+            (true, 2) => [symbol, s1, value, sign].concat(),
+            (true, 4) => [symbol, s2, sign, s1, value].concat(),
+            (true, _) => [sign, s2, symbol, s1, value].concat(),
+            (false, 1) => [sign, value, s1, symbol].concat(),
+            (false, 3) => [value, s1, sign, s2, symbol].concat(),
+            (false, _) => [value, s1, symbol, s2, sign].concat(),
+        }
+    }
+}
+
+impl SystemMonetaryFormat {
+    /// Same as [`default`].
+    ///
+    /// [`default`]: struct.SystemMonetaryFormat.html#method.default
+    pub fn new() -> Result<SystemMonetaryFormat, Error> {
+        SystemMonetaryFormat::default()
+    }
+
+    /// Constucts a [`SystemMonetaryFormat`] based on your operating system's default locale,
+    /// which is controlled by the `LC_ALL`, `LC_MONETARY` and `LANG` environment variables (see
+    /// [`SystemLocale::default`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the operating system returned something unexpected (such as a null
+    /// pointer), or if the locale groups digits in a way num-format does not support.
+    ///
+    /// [`SystemLocale::default`]: struct.SystemLocale.html#method.default
+    /// [`SystemMonetaryFormat`]: struct.SystemMonetaryFormat.html
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> Result<SystemMonetaryFormat, Error> {
+        nix::new_monetary(None)
+    }
+
+    /// Constucts a [`SystemMonetaryFormat`] from the provided locale name. For a list of locale
+    /// names available on your system, see [`SystemLocale::available_names`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name provided could not be parsed into a locale, if the
+    /// operating system returned something unexpected (such as a null pointer) or if the locale
+    /// groups digits in a way num-format does not support.
+    ///
+    /// [`SystemLocale::available_names`]: struct.SystemLocale.html#method.available_names
+    /// [`SystemMonetaryFormat`]: struct.SystemMonetaryFormat.html
+    pub fn from_name<S>(name: S) -> Result<SystemMonetaryFormat, Error>
+    where
+        S: Into<String>,
+    {
+        nix::new_monetary(Some(name.into()))
+    }
+
+    /// Returns `amount` rounded to [`frac_digits`] fraction digits and written with the
+    /// locale's monetary symbols, currency symbol and sign placement (e.g. `"-1.234,50 €"` for
+    /// -1234.5 in "de_DE.UTF-8"), like `strfmon`'s `"%n"`. Infinity and NaN are written with
+    /// the symbols of the closest CLDR locale (see [`SystemLocale::infinity`]).
+    ///
+    /// [`frac_digits`]: struct.SystemMonetaryFormat.html#method.frac_digits
+    /// [`SystemLocale::infinity`]: struct.SystemLocale.html#method.infinity
+    pub fn format(&self, amount: f64) -> String {
+        let placement = if amount < 0.0 {
+            &self.negative
+        } else {
+            &self.positive
+        };
+        self.write(amount, &self.currency_symbol, self.frac_digits, placement)
+    }
+
+    /// Returns `amount` rounded to [`international_frac_digits`] fraction digits and written
+    /// with the locale's international currency symbol (e.g. `"-USD 1,234.50"` for -1234.5 in
+    /// "en_US.UTF-8"), like `strfmon`'s `"%i"`.
+    ///
+    /// [`international_frac_digits`]: struct.SystemMonetaryFormat.html#method.international_frac_digits
+    pub fn format_international(&self, amount: f64) -> String {
+        let placement = if amount < 0.0 {
+            &self.int_negative
+        } else {
+            &self.int_positive
+        };
+        self.write(
+            amount,
+            &self.int_curr_symbol,
+            self.int_frac_digits,
+            placement,
+        )
+    }
+
+    /// Returns this locale's currency symbol (e.g. `"€"`), which is empty in the "C" locale.
+    pub fn currency_symbol(&self) -> &str {
+        &self.currency_symbol
+    }
+
+    /// Returns this locale's monetary decimal point.
+    pub fn decimal(&self) -> &str {
+        &self.dec
+    }
+
+    /// Returns the number of fraction digits this locale writes amounts with (2 if the locale
+    /// does not say, as in the "C" locale).
+    pub fn frac_digits(&self) -> u8 {
+        self.frac_digits
+    }
+
+    /// Returns this locale's monetary [`Grouping`].
+    ///
+    /// [`Grouping`]: enum.Grouping.html
+    pub fn grouping(&self) -> Grouping {
+        self.grp
+    }
+
+    /// Returns this locale's string representation of infinity (see [`SystemLocale::infinity`]).
+    ///
+    /// [`SystemLocale::infinity`]: struct.SystemLocale.html#method.infinity
+    pub fn infinity(&self) -> &str {
+        &self.inf
+    }
+
+    /// Returns this locale's ISO 4217 currency code (e.g. `"EUR"`), which is empty in the "C"
+    /// locale.
+    pub fn international_currency_symbol(&self) -> &str {
+        &self.int_curr_symbol
+    }
+
+    /// Returns the number of fraction digits this locale writes amounts with alongside its
+    /// international currency symbol (2 if the locale does not say).
+    pub fn international_frac_digits(&self) -> u8 {
+        self.int_frac_digits
+    }
+
+    /// Returns this locale's string representation of a minus sign for amounts (`"-"` if the
+    /// locale does not say, as in the "C" locale).
+    pub fn minus_sign(&self) -> &str {
+        &self.min
+    }
+
+    /// Returns this locale's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns this locale's string representation of NaN (see [`SystemLocale::nan`]).
+    ///
+    /// [`SystemLocale::nan`]: struct.SystemLocale.html#method.nan
+    pub fn nan(&self) -> &str {
+        &self.nan
+    }
+
+    /// Returns this locale's string representation of a plus sign for amounts (usually empty).
+    pub fn plus_sign(&self) -> &str {
+        &self.plus
+    }
+
+    /// Returns this locale's monetary thousands separator.
+    pub fn separator(&self) -> &str {
+        &self.sep
+    }
+
+    fn write(&self, amount: f64, symbol: &str, frac_digits: u8, placement: &Placement) -> String {
+        let negative = amount < 0.0;
+        let sign = if negative { &*self.min } else { &*self.plus };
+        let value = if amount.is_nan() {
+            self.nan.to_string()
+        } else if amount.is_infinite() {
+            self.inf.to_string()
+        } else {
+            let s = format!("{:.*}", usize::from(frac_digits), amount.abs());
+            let (integer, fraction) = s.split_once('.').unwrap_or((&s, ""));
+            let mut value = group(integer, self.grp, &self.sep);
+            if !fraction.is_empty() {
+                value.push_str(&self.dec);
+                value.push_str(fraction);
+            }
+            value
+        };
+        placement.arrange(&value, symbol, sign, negative)
+    }
+}
+
+/// Returns `digits` with `separator` between groups.
+fn group(digits: &str, grouping: Grouping, separator: &str) -> String {
+    // The size of the last group and of the others
+    let (last, others) = match grouping {
+        Grouping::Standard => (3, 3),
+        Grouping::Indian => (3, 2),
+        Grouping::Posix => (digits.len(), digits.len()),
+    };
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+    let mut size = last;
+    while end > 0 {
+        let start = end.saturating_sub(size);
+        groups.push(&digits[start..end]);
+        end = start;
+        size = others;
+    }
+    groups.reverse();
+    groups.join(separator)
+}
+
+impl std::str::FromStr for SystemMonetaryFormat {
+    type Err = Error;
+
+    /// Same as [`from_name`].
+    ///
+    /// [`from_name`]: struct.SystemMonetaryFormat.html#method.from_name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SystemMonetaryFormat::from_name(s)
+    }
+}
+
+impl Format for SystemMonetaryFormat {
+    #[inline(always)]
+    fn decimal(&self) -> DecimalStr<'_> {
+        DecimalStr::new(self.decimal()).unwrap()
+    }
+    #[inline(always)]
+    fn grouping(&self) -> Grouping {
+        self.grouping()
+    }
+    #[inline(always)]
+    fn infinity(&self) -> InfinityStr<'_> {
+        InfinityStr::new(self.infinity()).unwrap()
+    }
+    #[inline(always)]
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        MinusSignStr::new(self.minus_sign()).unwrap()
+    }
+    #[inline(always)]
+    fn nan(&self) -> NanStr<'_> {
+        NanStr::new(self.nan()).unwrap()
+    }
+    #[inline(always)]
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        PlusSignStr::new(self.plus_sign()).unwrap()
+    }
+    #[inline(always)]
+    fn separator(&self) -> SeparatorStr<'_> {
+        SeparatorStr::new(self.separator()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a format with the given currency symbols, symbols and placements (`[cs_precedes,
+    /// sep_by_space, sign_posn]` for positive and negative amounts), placed the same way for the
+    /// international currency symbol.
+    fn format(
+        symbols: (&str, &str),
+        (dec, sep, grp): (&str, &str, Grouping),
+        frac_digits: u8,
+        positive: [c_char; 3],
+        negative: [c_char; 3],
+    ) -> SystemMonetaryFormat {
+        let positive = Placement::new(positive[0], positive[1], positive[2]);
+        let negative = Placement::new(negative[0], negative[1], negative[2]);
+        SystemMonetaryFormat {
+            currency_symbol: symbols.0.to_string(),
+            dec: DecString::new(dec).unwrap(),
+            frac_digits,
+            grp,
+            inf: InfString::new("∞").unwrap(),
+            int_curr_symbol: symbols.1.to_string(),
+            int_frac_digits: frac_digits,
+            int_negative: negative,
+            int_positive: positive,
+            min: MinString::new("-").unwrap(),
+            name: "test".to_string(),
+            nan: NanString::new("NaN").unwrap(),
+            negative,
+            plus: PlusString::new("").unwrap(),
+            positive,
+            sep: SepString::new(sep).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        // What glibc's strfmon writes for 1234567.891 and -1234567.891 with "%n" and "%i"
+        let max = c_char::MAX;
+        let test_cases = [
+            (
+                // "C"
+                format(("", ""), (".", "", Grouping::Posix), 2, [max; 3], [max; 3]),
+                ["1234567.89", "-1234567.89", "1234567.89", "-1234567.89"],
+            ),
+            (
+                // "de_DE.UTF-8"
+                format(
+                    ("€", "EUR"),
+                    (",", ".", Grouping::Standard),
+                    2,
+                    [0, 1, 1],
+                    [0, 1, 1],
+                ),
+                [
+                    "1.234.567,89 €",
+                    "-1.234.567,89 €",
+                    "1.234.567,89 EUR",
+                    "-1.234.567,89 EUR",
+                ],
+            ),
+            (
+                // "en_IN.UTF-8", with the international placement of "en_US.UTF-8"
+                format(
+                    ("₹", "INR"),
+                    (".", ",", Grouping::Indian),
+                    2,
+                    [1, 0, 1],
+                    [1, 0, 1],
+                ),
+                [
+                    "₹12,34,567.89",
+                    "-₹12,34,567.89",
+                    "INR12,34,567.89",
+                    "-INR12,34,567.89",
+                ],
+            ),
+            (
+                // "nl_NL.UTF-8"
+                format(
+                    ("€", "EUR"),
+                    (",", ".", Grouping::Standard),
+                    2,
+                    [1, 1, 1],
+                    [1, 2, 4],
+                ),
+                [
+                    "€ 1.234.567,89",
+                    "€ -1.234.567,89",
+                    "EUR 1.234.567,89",
+                    "EUR -1.234.567,89",
+                ],
+            ),
+            (
+                // "ar_EG.UTF-8"
+                format(
+                    ("ج.م.", "EGP"),
+                    (".", ",", Grouping::Standard),
+                    3,
+                    [1, 1, 1],
+                    [1, 1, 2],
+                ),
+                [
+                    "ج.م. 1,234,567.891",
+                    "ج.م. 1,234,567.891-",
+                    "EGP 1,234,567.891",
+                    "EGP 1,234,567.891-",
+                ],
+            ),
+            (
+                // "ja_JP.UTF-8"
+                format(
+                    ("￥", "JPY"),
+                    (".", ",", Grouping::Standard),
+                    0,
+                    [1, 0, 4],
+                    [1, 0, 4],
+                ),
+                [
+                    "￥1,234,568",
+                    "￥-1,234,568",
+                    "JPY1,234,568",
+                    "JPY-1,234,568",
+                ],
+            ),
+            (
+                // Parentheses are only for negative amounts
+                format(
+                    ("$", "USD"),
+                    (".", ",", Grouping::Standard),
+                    2,
+                    [0, 2, 0],
+                    [0, 1, 0],
+                ),
+                [
+                    "1,234,567.89$",
+                    "(1,234,567.89 $)",
+                    "1,234,567.89USD",
+                    "(1,234,567.89 USD)",
+                ],
+            ),
+        ];
+        for (format, expected) in &test_cases {
+            assert_eq!(format.format(1234567.891), expected[0]);
+            assert_eq!(format.format(-1234567.891), expected[1]);
+            assert_eq!(format.format_international(1234567.891), expected[2]);
+            assert_eq!(format.format_international(-1234567.891), expected[3]);
+        }
+
+        let format = &test_cases[1].0;
+        assert_eq!(format.format(0.5), "0,50 €");
+        assert_eq!(format.format(f64::NEG_INFINITY), "-∞ €");
+        assert_eq!(format.format(f64::NAN), "NaN €");
+    }
+}
@@ -0,0 +1,296 @@
+use core::str::{self, FromStr};
+
+use crate::error::Error;
+use crate::format::Format;
+use crate::grouping::Grouping;
+use crate::locale::{Locale, Symbols};
+use crate::locale_tag::Tag;
+use crate::numbering_system::NumberingSystem;
+use crate::strings::{DecimalStr, InfinityStr, MinusSignStr, NanStr, PlusSignStr, SeparatorStr};
+#[cfg(feature = "std")]
+use crate::ToFormattedString;
+
+/// A [`Locale`] together with the Unicode extension keywords of the BCP 47 tag it was resolved
+/// from. Implements [`Format`].
+///
+/// The `-u-nu-` keyword chooses a [`NumberingSystem`], and the locale's CLDR symbols and grouping
+/// for that numbering system are used (e.g. `"\u{200e}-"` as the minus sign of `ar-u-nu-latn`,
+/// and `"\u{66b}"` as the decimal of `en-u-nu-arab`). The [`Format`] implementation can only
+/// supply symbols, so [`Buffer`] and the other writers still write ASCII digits; [`format`] writes
+/// the numbering system's own digits, and parsing accepts them. Numbering systems other than the
+/// decimal ones listed in [`NumberingSystem`] are ignored.
+///
+/// The `-u-cu-` keyword chooses a currency, which is only stored, and available from
+/// [`currency`]; it does not change how numbers are formatted. A POSIX `@euro` modifier (e.g.
+/// `de_DE@euro`) is read as `-u-cu-eur`.
+///
+/// # Example
+/// ```
+/// use num_format::{Buffer, NumberingSystem, TaggedLocale};
+///
+/// fn main() {
+///     let mut buf = Buffer::new();
+/// # // with NUM_FORMAT_LOCALES=en,eu, ar and hi cannot be looked up by name
+/// # #[cfg(locales_other)]
+/// # {
+///
+///     let locale = TaggedLocale::from_tag("ar-EG-u-nu-latn").unwrap();
+///     assert_eq!(locale.numbering_system(), Some(NumberingSystem::Latn));
+///     buf.write_formatted(&1_234_567, &locale);
+///     assert_eq!(buf.as_str(), "1,234,567");
+///
+///     let locale = TaggedLocale::from_tag("hi-IN-u-nu-deva-cu-inr").unwrap();
+///     assert_eq!(locale.numbering_system(), Some(NumberingSystem::Deva));
+///     assert_eq!(locale.currency(), Some("INR"));
+///     buf.write_formatted(&1_234_567, &locale);
+///     assert_eq!(buf.as_str(), "12,34,567");
+/// # }
+/// }
+/// ```
+///
+/// [`Buffer`]: struct.Buffer.html
+/// [`currency`]: struct.TaggedLocale.html#method.currency
+/// [`format`]: struct.TaggedLocale.html#method.format
+/// [`Format`]: trait.Format.html
+/// [`Locale`]: enum.Locale.html
+/// [`NumberingSystem`]: enum.NumberingSystem.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TaggedLocale {
+    currency: Option<[u8; 3]>,
+    locale: Locale,
+    numbering_system: Option<NumberingSystem>,
+}
+
+impl TaggedLocale {
+    /// Constructs a [`TaggedLocale`] from a BCP 47 language tag or POSIX locale name, resolving
+    /// the [`Locale`] as [`Locale::from_name`] does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag cannot be resolved to a [`Locale`].
+    ///
+    /// [`Locale`]: enum.Locale.html
+    /// [`Locale::from_name`]: enum.Locale.html#method.from_name
+    /// [`TaggedLocale`]: struct.TaggedLocale.html
+    #[cfg_attr(not(feature = "std"), allow(clippy::result_large_err))]
+    pub fn from_tag<S>(tag: S) -> Result<TaggedLocale, Error>
+    where
+        S: AsRef<str>,
+    {
+        let s = tag.as_ref();
+        let tag = Tag::parse(s).ok_or_else(|| Error::parse_locale(s))?;
+        let locale = Locale::from_exact_name(s)
+            .or_else(|| tag.resolve())
+            .ok_or_else(|| Error::parse_locale(s))?;
+        let currency = tag
+            .currency
+            .filter(|currency| {
+                currency.len() == 3 && currency.bytes().all(|b| b.is_ascii_alphabetic())
+            })
+            .map(|currency| {
+                let mut code = [0; 3];
+                code.copy_from_slice(currency.as_bytes());
+                code.make_ascii_uppercase();
+                code
+            });
+        let numbering_system = tag.numbering_system.and_then(NumberingSystem::from_name);
+        Ok(TaggedLocale {
+            currency,
+            locale,
+            numbering_system,
+        })
+    }
+
+    /// Returns `n` formatted with the locale's symbols and written in the digits of the
+    /// numbering system chosen with `-u-nu-` (ASCII digits if there is none).
+    ///
+    /// # Example
+    /// ```
+    /// use num_format::parsing::ParseFormatted;
+    /// use num_format::TaggedLocale;
+    ///
+    /// fn main() {
+    ///     let locale = TaggedLocale::from_tag("en-u-nu-deva").unwrap();
+    ///     let s = locale.format(&-1_234_567);
+    ///     assert_eq!(s, "-\u{967},\u{968}\u{969}\u{96a},\u{96b}\u{96c}\u{96d}");
+    ///     assert_eq!(s.parse_formatted::<_, i32>(&locale).unwrap(), -1_234_567);
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn format<N>(&self, n: &N) -> String
+    where
+        N: ToFormattedString,
+    {
+        let s = n.to_formatted_string(self);
+        match self.numbering_system {
+            Some(numbering_system) => numbering_system.transliterate(&s),
+            None => s,
+        }
+    }
+
+    /// Returns the ISO 4217 code (e.g. `"EUR"`) of the currency chosen with `-u-cu-`, if any.
+    pub fn currency(&self) -> Option<&str> {
+        self.currency
+            .as_ref()
+            .map(|code| str::from_utf8(code).unwrap())
+    }
+
+    /// Returns the underlying [`Locale`].
+    ///
+    /// [`Locale`]: enum.Locale.html
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Returns the [`NumberingSystem`] chosen with `-u-nu-`, if any.
+    ///
+    /// [`NumberingSystem`]: enum.NumberingSystem.html
+    pub fn numbering_system(&self) -> Option<NumberingSystem> {
+        self.numbering_system
+    }
+
+    /// Returns the locale's symbols in the requested numbering system.
+    fn symbols(&self) -> &'static Symbols {
+        match self.numbering_system {
+            Some(numbering_system) => self.locale.symbols_in(numbering_system),
+            None => self.locale.symbols(),
+        }
+    }
+}
+
+impl Format for TaggedLocale {
+    fn decimal(&self) -> DecimalStr<'_> {
+        DecimalStr::new(self.symbols().decimal).unwrap()
+    }
+
+    fn grouping(&self) -> Grouping {
+        self.symbols().grouping
+    }
+
+    fn infinity(&self) -> InfinityStr<'_> {
+        InfinityStr::new(self.symbols().infinity).unwrap()
+    }
+
+    fn minus_sign(&self) -> MinusSignStr<'_> {
+        MinusSignStr::new(self.symbols().minus_sign).unwrap()
+    }
+
+    fn nan(&self) -> NanStr<'_> {
+        NanStr::new(self.symbols().nan).unwrap()
+    }
+
+    fn plus_sign(&self) -> PlusSignStr<'_> {
+        PlusSignStr::new(self.symbols().plus_sign).unwrap()
+    }
+
+    fn separator(&self) -> SeparatorStr<'_> {
+        SeparatorStr::new(self.symbols().separator).unwrap()
+    }
+}
+
+impl From<Locale> for TaggedLocale {
+    fn from(locale: Locale) -> TaggedLocale {
+        TaggedLocale {
+            currency: None,
+            locale,
+            numbering_system: None,
+        }
+    }
+}
+
+impl FromStr for TaggedLocale {
+    type Err = Error;
+
+    /// Same as [`from_tag`].
+    ///
+    /// [`from_tag`]: struct.TaggedLocale.html#method.from_tag
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TaggedLocale::from_tag(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tagged_locale_keywords() {
+        let locale = TaggedLocale::from_tag("de-DE-u-cu-eur-nu-latn").unwrap();
+        assert_eq!(locale.locale(), Locale::de);
+        assert_eq!(locale.currency(), Some("EUR"));
+        assert_eq!(locale.numbering_system(), Some(NumberingSystem::Latn));
+
+        let locale = TaggedLocale::from_tag("de_AT.UTF-8@euro").unwrap();
+        assert_eq!(locale.locale(), Locale::de_AT);
+        assert_eq!(locale.currency(), Some("EUR"));
+
+        // other keywords (with multi-subtag types) and unsupported values are skipped
+        let locale = TaggedLocale::from_tag("fr-FR-u-ca-islamic-civil-nu-thai-x-cu-usd").unwrap();
+        assert_eq!(locale.locale(), Locale::fr);
+        assert_eq!(locale.currency(), None);
+        assert_eq!(locale.numbering_system(), Some(NumberingSystem::Thai));
+        let locale = TaggedLocale::from_tag("en-u-nu-hanidec-cu-euro").unwrap();
+        assert_eq!(locale.currency(), None);
+        assert_eq!(locale.numbering_system(), None);
+
+        assert_eq!(
+            "fr".parse::<TaggedLocale>().unwrap(),
+            TaggedLocale::from(Locale::fr)
+        );
+        assert!(TaggedLocale::from_tag("xx-u-nu-latn").is_err());
+        assert!(TaggedLocale::from_tag("en-u-nu-").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_tagged_locale_digits() {
+        use crate::parsing::ParseFormatted;
+
+        let locale = TaggedLocale::from_tag("de-u-nu-arab").unwrap();
+        let s = locale.format(&-1_234);
+        assert_eq!(s, "\u{61c}-\u{661}\u{66c}\u{662}\u{663}\u{664}");
+        assert_eq!(s.parse_formatted::<_, i32>(&locale).unwrap(), -1_234);
+
+        let locale = TaggedLocale::from_tag("de").unwrap();
+        assert_eq!(locale.format(&1_234_567), "1.234.567");
+        let locale = TaggedLocale::from_tag("de-u-nu-thai").unwrap();
+        let n: u64 = locale.format(&1_234_567).parse_formatted(&locale).unwrap();
+        assert_eq!(n, 1_234_567);
+    }
+
+    #[test]
+    fn test_tagged_locale_symbols() {
+        fn symbols(locale: &TaggedLocale) -> (&str, &str, &str) {
+            (
+                locale.decimal().into_str(),
+                locale.minus_sign().into_str(),
+                locale.separator().into_str(),
+            )
+        }
+        fn tagged(tag: &str) -> TaggedLocale {
+            TaggedLocale::from_tag(tag).unwrap()
+        }
+
+        assert_eq!(
+            symbols(&tagged("en-u-nu-arab")),
+            ("\u{66b}", "\u{61c}-", "\u{66c}")
+        );
+        assert_eq!(
+            symbols(&tagged("de-u-nu-arab")),
+            ("\u{66b}", "\u{61c}-", "\u{66c}")
+        );
+        assert_eq!(symbols(&tagged("de-u-nu-deva")), symbols(&tagged("de")));
+
+        #[cfg(locales_other)]
+        {
+            assert_eq!(symbols(&tagged("ar")), ("\u{66b}", "\u{61c}-", "\u{66c}"));
+            assert_eq!(symbols(&tagged("ar-u-nu-arab")), symbols(&tagged("ar")));
+            assert_eq!(symbols(&tagged("ar-u-nu-latn")), (".", "\u{200e}-", ","));
+            assert_eq!(symbols(&tagged("fa-u-nu-arabext")), symbols(&tagged("fa")));
+            assert_eq!(
+                symbols(&tagged("fa-u-nu-latn")),
+                (".", "\u{200e}\u{2212}", ",")
+            );
+        }
+    }
+}
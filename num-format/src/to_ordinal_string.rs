@@ -0,0 +1,45 @@
+#![cfg(feature = "std")]
+
+use crate::constants::MAX_BUF_LEN;
+use crate::integer::Integer;
+use crate::locale::Locale;
+
+/// Gives integers the [`to_ordinal_string`] method, which writes them as ordinals (e.g. "1st").
+///
+/// This trait is sealed; so you may not implement it on your own types.
+///
+/// To write ordinals without heap allocation (or in a `no_std` environment), see
+/// [`Locale::write_ordinal`].
+///
+/// [`Locale::write_ordinal`]: enum.Locale.html#method.write_ordinal
+/// [`to_ordinal_string`]: trait.ToOrdinalString.html#method.to_ordinal_string
+pub trait ToOrdinalString: Integer {
+    /// Returns a string representation of the number as an ordinal, formatted according to the
+    /// provided locale (e.g. "1,001st" for `Locale::en` and "1.001." for `Locale::de`).
+    ///
+    /// The number is formatted as [`to_formatted_string`] would and written into the locale's
+    /// ordinal pattern from CLDR's rule-based number formats, whose suffix may depend on the
+    /// number's [`ordinal_category`] (e.g. "1st", "2nd", "3rd" and "4th" in English). Locales
+    /// without a pattern of their own inherit their parent's, and ultimately CLDR's default of a
+    /// trailing ".". Negative numbers keep the locale's minus sign (e.g. "-1st").
+    ///
+    /// # Example
+    /// ```
+    /// use num_format::{Locale, ToOrdinalString};
+    ///
+    /// assert_eq!(1001.to_ordinal_string(&Locale::en), "1,001st");
+    /// assert_eq!(1001.to_ordinal_string(&Locale::de), "1.001.");
+    /// assert_eq!(2.to_ordinal_string(&Locale::fr), "2e");
+    /// assert_eq!(3.to_ordinal_string(&Locale::ja), "第3");
+    /// ```
+    ///
+    /// [`ordinal_category`]: enum.Locale.html#method.ordinal_category
+    /// [`to_formatted_string`]: trait.ToFormattedString.html#method.to_formatted_string
+    fn to_ordinal_string(&self, locale: &Locale) -> String {
+        let mut s = String::with_capacity(MAX_BUF_LEN);
+        let _ = locale.write_ordinal(&mut s, self);
+        s
+    }
+}
+
+impl<T> ToOrdinalString for T where T: Integer {}
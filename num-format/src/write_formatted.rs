@@ -72,8 +72,8 @@ mod any {
     impl WriteFormatted for process::ChildStdin { impl_for_io_write!(); }
     impl WriteFormatted for io::Cursor<Box<[u8]>> { impl_for_io_write!(); }
     impl WriteFormatted for io::Cursor<Vec<u8>> { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for io::Cursor<&'a mut [u8]> { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for io::Cursor<&'a mut Vec<u8>> { impl_for_io_write!(); }
+    impl WriteFormatted for io::Cursor<&mut [u8]> { impl_for_io_write!(); }
+    impl WriteFormatted for io::Cursor<&mut Vec<u8>> { impl_for_io_write!(); }
     impl WriteFormatted for fs::File { impl_for_io_write!(); }
     impl<W: io::Write> WriteFormatted for io::LineWriter<W> { impl_for_io_write!(); }
     impl WriteFormatted for io::Sink { impl_for_io_write!(); }
@@ -82,30 +82,30 @@ mod any {
     impl WriteFormatted for String { impl_for_fmt_write!(); }
     impl WriteFormatted for net::TcpStream { impl_for_io_write!(); }
     impl WriteFormatted for Vec<u8> { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for io::StderrLock<'a> { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for io::StdoutLock<'a> { impl_for_io_write!(); }
+    impl WriteFormatted for io::StderrLock<'_> { impl_for_io_write!(); }
+    impl WriteFormatted for io::StdoutLock<'_> { impl_for_io_write!(); }
 
-    impl<'a> WriteFormatted for &'a mut [u8] { impl_for_io_write!(); }
-    impl<'a, W: io::Write + ?Sized> WriteFormatted for &'a mut Box<W> { impl_for_io_write!(); }
-    impl<'a, W: io::Write> WriteFormatted for &'a mut io::BufWriter<W> { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut process::ChildStdin { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut io::Cursor<Box<[u8]>> { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut io::Cursor<Vec<u8>> { impl_for_io_write!(); }
-    impl<'a, 'b> WriteFormatted for &'a mut io::Cursor<&'b mut [u8]> { impl_for_io_write!(); }
-    impl<'a, 'b> WriteFormatted for &'a mut io::Cursor<&'b mut Vec<u8>> { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a fs::File { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut fs::File { impl_for_io_write!(); }
-    impl<'a, 'b> WriteFormatted for &'a mut fmt::Formatter<'b> { impl_for_fmt_write!(); }
-    impl<'a, W: io::Write> WriteFormatted for &'a mut io::LineWriter<W> { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut io::Sink { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut io::Stderr { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut io::Stdout { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut String { impl_for_fmt_write!(); }
-    impl<'a> WriteFormatted for &'a net::TcpStream { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut net::TcpStream { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut Vec<u8> { impl_for_io_write!(); }
-    impl<'a, 'b> WriteFormatted for &'a mut io::StderrLock<'b> { impl_for_io_write!(); }
-    impl<'a, 'b> WriteFormatted for &'a mut io::StdoutLock<'b> { impl_for_io_write!(); }
+    impl WriteFormatted for &mut [u8] { impl_for_io_write!(); }
+    impl<W: io::Write + ?Sized> WriteFormatted for &mut Box<W> { impl_for_io_write!(); }
+    impl<W: io::Write> WriteFormatted for &mut io::BufWriter<W> { impl_for_io_write!(); }
+    impl WriteFormatted for &mut process::ChildStdin { impl_for_io_write!(); }
+    impl WriteFormatted for &mut io::Cursor<Box<[u8]>> { impl_for_io_write!(); }
+    impl WriteFormatted for &mut io::Cursor<Vec<u8>> { impl_for_io_write!(); }
+    impl WriteFormatted for &mut io::Cursor<&mut [u8]> { impl_for_io_write!(); }
+    impl WriteFormatted for &mut io::Cursor<&mut Vec<u8>> { impl_for_io_write!(); }
+    impl WriteFormatted for &fs::File { impl_for_io_write!(); }
+    impl WriteFormatted for &mut fs::File { impl_for_io_write!(); }
+    impl WriteFormatted for &mut fmt::Formatter<'_> { impl_for_fmt_write!(); }
+    impl<W: io::Write> WriteFormatted for &mut io::LineWriter<W> { impl_for_io_write!(); }
+    impl WriteFormatted for &mut io::Sink { impl_for_io_write!(); }
+    impl WriteFormatted for &mut io::Stderr { impl_for_io_write!(); }
+    impl WriteFormatted for &mut io::Stdout { impl_for_io_write!(); }
+    impl WriteFormatted for &mut String { impl_for_fmt_write!(); }
+    impl WriteFormatted for &net::TcpStream { impl_for_io_write!(); }
+    impl WriteFormatted for &mut net::TcpStream { impl_for_io_write!(); }
+    impl WriteFormatted for &mut Vec<u8> { impl_for_io_write!(); }
+    impl WriteFormatted for &mut io::StderrLock<'_> { impl_for_io_write!(); }
+    impl WriteFormatted for &mut io::StdoutLock<'_> { impl_for_io_write!(); }
 }
 
 #[cfg(unix)]
@@ -116,6 +116,6 @@ mod unix {
     use super::*;
 
     impl WriteFormatted for UnixStream { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a UnixStream { impl_for_io_write!(); }
-    impl<'a> WriteFormatted for &'a mut UnixStream { impl_for_io_write!(); }
+    impl WriteFormatted for &UnixStream { impl_for_io_write!(); }
+    impl WriteFormatted for &mut UnixStream { impl_for_io_write!(); }
 }
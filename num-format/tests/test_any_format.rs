@@ -0,0 +1,78 @@
+mod common;
+
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use num_format::{AnyFormat, Buffer, CustomFormat, Format, Locale, TaggedLocale};
+
+use crate::common::POLICIES;
+
+const VALUES: [i64; 6] = [0, -1, 1000, -1_234_567, i64::MAX, i64::MIN];
+
+fn formatted<F>(n: i64, format: &F) -> String
+where
+    F: Format,
+{
+    let mut buf = Buffer::new();
+    buf.write_formatted(&n, format);
+    buf.as_str().to_string()
+}
+
+#[test]
+fn test_any_format() {
+    for locale in Locale::iter() {
+        let format = AnyFormat::from(locale);
+        for &n in &VALUES {
+            assert_eq!(formatted(n, &format), formatted(n, &locale));
+        }
+    }
+
+    for policy in POLICIES.iter() {
+        let format = AnyFormat::from(policy.clone());
+        for &n in &VALUES {
+            assert_eq!(formatted(n, &format), formatted(n, policy));
+        }
+    }
+
+    let tagged = TaggedLocale::from_tag("de-DE-u-nu-arab").unwrap();
+    let format = "de-DE-u-nu-arab".parse::<AnyFormat>().unwrap();
+    assert_eq!(format, AnyFormat::Tagged(tagged));
+    for &n in &VALUES {
+        assert_eq!(formatted(n, &format), formatted(n, &tagged));
+    }
+
+    assert!("not a locale".parse::<AnyFormat>().is_err());
+}
+
+#[test]
+fn test_dyn_format() {
+    let custom = CustomFormat::builder()
+        .minus_sign("🙌")
+        .separator("😀")
+        .build()
+        .unwrap();
+    check_dyn_format(Locale::en);
+    check_dyn_format(Locale::hi);
+    check_dyn_format(custom);
+}
+
+fn check_dyn_format<F>(format: F)
+where
+    F: Format + Clone + 'static,
+{
+    for &n in &VALUES {
+        let expected = formatted(n, &format);
+
+        let reference: &dyn Format = &format;
+        assert_eq!(formatted(n, &reference), expected);
+        assert_eq!(formatted(n, &&format), expected);
+
+        #[cfg(feature = "std")]
+        {
+            let boxed: Box<dyn Format> = Box::new(format.clone());
+            assert_eq!(formatted(n, &boxed), expected);
+            let arc: Arc<dyn Format> = Arc::new(format.clone());
+            assert_eq!(formatted(n, &arc), expected);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "std")]
+
+use num_format::{Grouping, Locale};
+
+#[test]
+fn test_locale_iter() {
+    // every variant exists, but some may not be available by name (see NUM_FORMAT_LOCALES)
+    #[cfg(locales_other)]
+    assert_eq!(Locale::iter().len(), Locale::available_names().len());
+    assert!(Locale::available_names().len() <= Locale::iter().len());
+    for (locale, name) in Locale::iter().zip(Locale::iter().skip(1)) {
+        assert!(locale < name);
+    }
+    for name in Locale::available_names() {
+        let locale = Locale::from_name(name).unwrap();
+        assert!(Locale::iter().any(|l| l == locale), "name: {}", name);
+    }
+}
+
+#[test]
+fn test_locale_subtags() {
+    for locale in Locale::iter() {
+        let mut name = locale.language().to_string();
+        for subtag in [locale.script(), locale.region(), locale.variant()]
+            .iter()
+            .flatten()
+        {
+            name.push('-');
+            name.push_str(subtag);
+        }
+        assert_eq!(name, locale.name());
+    }
+
+    let locale = Locale::sr_Latn_BA;
+    assert_eq!(locale.language(), "sr");
+    assert_eq!(locale.script(), Some("Latn"));
+    assert_eq!(locale.region(), Some("BA"));
+    assert_eq!(locale.variant(), None);
+
+    let locale = Locale::es_419;
+    assert_eq!(locale.language(), "es");
+    assert_eq!(locale.script(), None);
+    assert_eq!(locale.region(), Some("419"));
+
+    assert_eq!(Locale::en_US_POSIX.variant(), Some("POSIX"));
+}
+
+#[test]
+fn test_locale_display_names() {
+    let test_cases: &[(Locale, &str, &str)] = &[
+        (Locale::de, "German", "Deutsch"),
+        (Locale::de_AT, "Austrian German", "Österreichisches Deutsch"),
+        (Locale::fr_CA, "Canadian French", "français canadien"),
+        (
+            Locale::sr_Latn_BA,
+            "Serbian (Latin, Bosnia & Herzegovina)",
+            "srpski (latinica, Bosna i Hercegovina)",
+        ),
+        (
+            Locale::zh_Hant_HK,
+            "Traditional Chinese (Hong Kong SAR China)",
+            "繁體中文（中國香港特別行政區）",
+        ),
+        (
+            Locale::en_US_POSIX,
+            "American English (Computer)",
+            "American English (Computer)",
+        ),
+        (Locale::ja, "Japanese", "日本語"),
+    ];
+    for (locale, english, native) in test_cases {
+        assert_eq!(locale.english_name(), *english);
+        assert_eq!(locale.native_name(), *native);
+    }
+
+    for locale in Locale::iter() {
+        assert!(!locale.english_name().is_empty());
+        assert!(!locale.native_name().is_empty());
+    }
+}
+
+#[test]
+fn test_locale_queries() {
+    use Locale::*;
+
+    let commas = Locale::with_decimal(",").collect::<Vec<_>>();
+    assert!(commas.contains(&de));
+    assert!(commas.contains(&fr_CA));
+    assert!(!commas.contains(&en));
+    assert!(!commas.contains(&de_CH));
+
+    let indian = Locale::with_grouping(Grouping::Indian).collect::<Vec<_>>();
+    assert_eq!(
+        indian,
+        vec![
+            as_, bn, bn_IN, brx, ccp, ccp_IN, dz, en_IN, gu, hi, ks, ml, mr, ne, ne_IN, or, pa,
+            pa_Guru, ta, ta_LK, te,
+        ]
+    );
+    let posix = Locale::with_grouping(Grouping::Posix).collect::<Vec<_>>();
+    assert_eq!(posix, vec![en_US_POSIX]);
+
+    let apostrophes = Locale::with_separator("\u{2019}").collect::<Vec<_>>();
+    assert_eq!(
+        apostrophes,
+        vec![de_CH, de_LI, en_CH, gsw, gsw_FR, gsw_LI, it_CH, rm, wae]
+    );
+    assert_eq!(Locale::with_decimal("not a decimal").count(), 0);
+}
+
+#[test]
+fn test_locale_format_groups() {
+    assert!(Locale::en.has_same_format(&Locale::en_GB));
+    assert!(!Locale::en.has_same_format(&Locale::de));
+
+    let groups = Locale::format_groups();
+    assert_eq!(
+        groups.iter().map(Vec::len).sum::<usize>(),
+        Locale::iter().len()
+    );
+    for group in &groups {
+        let representative = group[0];
+        for locale in group {
+            assert!(locale.has_same_format(&representative));
+            assert_eq!(locale.representative(), representative);
+        }
+    }
+    for (i, a) in groups.iter().enumerate() {
+        for b in &groups[i + 1..] {
+            assert!(!a[0].has_same_format(&b[0]));
+        }
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+
+use std::env;
+
+use num_format::{ErrorKind, Locale, ToFormattedString};
+
+#[test]
+fn test_locale_from_env() {
+    // a single test, as the tests of a file run at once and these change the environment
+    let set = |lc_all: &str, lc_numeric: &str, lang: &str| {
+        for &(var, value) in &[
+            ("LC_ALL", lc_all),
+            ("LC_NUMERIC", lc_numeric),
+            ("LANG", lang),
+        ] {
+            if value.is_empty() {
+                env::remove_var(var);
+            } else {
+                env::set_var(var, value);
+            }
+        }
+    };
+
+    // LC_ALL overrides LC_NUMERIC, which overrides LANG
+    set("de_DE.UTF-8", "fr_FR.UTF-8", "en_IN.UTF-8");
+    assert_eq!(Locale::from_env().unwrap(), Locale::de);
+    set("", "fr_FR.UTF-8", "en_IN.UTF-8");
+    assert_eq!(Locale::from_env().unwrap(), Locale::fr);
+    set("", "", "en_IN.UTF-8");
+    let locale = Locale::from_env().unwrap();
+    assert_eq!(locale, Locale::en_IN);
+    assert_eq!(10000000.to_formatted_string(&locale), "1,00,00,000");
+
+    // empty variables count as unset
+    env::set_var("LC_ALL", "");
+    assert_eq!(Locale::from_env().unwrap(), Locale::en_IN);
+
+    // names with codesets and modifiers, and the "C" locale, which is also the default
+    set("sr_RS.UTF-8@latin", "", "");
+    #[cfg(locales_other)]
+    assert_eq!(Locale::from_env().unwrap(), Locale::sr_Latn);
+    set("", "C.UTF-8", "de_DE");
+    assert_eq!(Locale::from_env().unwrap(), Locale::en_US_POSIX);
+    set("", "", "");
+    assert_eq!(Locale::from_env().unwrap(), Locale::en_US_POSIX);
+
+    // a name that is not a locale is an error, rather than a fallback to the next variable
+    set("not a locale", "", "de_DE");
+    match Locale::from_env() {
+        Err(e) => match e.kind() {
+            ErrorKind::ParseLocale(s) => assert_eq!(s, "not a locale"),
+            kind => panic!("{:?}", kind),
+        },
+        Ok(locale) => panic!("{:?}", locale),
+    }
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "std")]
+
+use std::num::{NonZeroU32, NonZeroU8};
+
+use num_format::{Locale, PluralCategory, ToFormattedString, ToOrdinalString};
+
+#[test]
+fn test_ordinal_en() {
+    let test_cases: &[(u64, &str)] = &[
+        (0, "0th"),
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (22, "22nd"),
+        (23, "23rd"),
+        (101, "101st"),
+        (111, "111th"),
+        (1001, "1,001st"),
+        (1_000_000, "1,000,000th"),
+    ];
+    for (n, expected) in test_cases {
+        assert_eq!(&n.to_ordinal_string(&Locale::en), expected);
+    }
+}
+
+#[test]
+fn test_ordinal_locales() {
+    let test_cases: &[(Locale, i32, &str)] = &[
+        // Inherited from "root"
+        (Locale::de, 1001, "1.001."),
+        (Locale::de_AT, 2, "2."),
+        (Locale::cs, 3, "3."),
+        // Suffixes chosen by ordinal plural category
+        (Locale::fr, 1, "1er"),
+        (Locale::fr, 2, "2e"),
+        (Locale::fr_CA, 1, "1er"),
+        (Locale::sv, 2, "2:a"),
+        (Locale::sv, 3, "3:e"),
+        (Locale::hi, 100_000, "1,00,000वाँ"),
+        // Prefixes
+        (Locale::ja, 1001, "第1,001"),
+        (Locale::id, 3, "ke-3"),
+        // Suffixes chosen by other rule sets
+        (Locale::af, 2, "2de"),
+        (Locale::af, 21, "21ste"),
+        (Locale::af, 111, "111de"),
+        (Locale::bg, 2, "2-ри"),
+        (Locale::bg, 23, "23-ти"),
+        (Locale::az, 5, "5'inci"),
+        // Rules for specific numbers
+        (Locale::ms, 1, "No. 1"),
+        (Locale::ms, 2, "ke-2"),
+        (Locale::ee, 1, "1 tɔ"),
+        (Locale::ee, 2, "2 lia"),
+        // Inherited through parentLocales.json
+        (Locale::es_MX, 1, "1º."),
+        (Locale::en_IN, 100_000, "1,00,000th"),
+        // Negative numbers
+        (Locale::en, -1, "-1st"),
+        (Locale::sv, -2, "\u{2212}2:a"),
+    ];
+    for (locale, n, expected) in test_cases {
+        assert_eq!(
+            &n.to_ordinal_string(locale),
+            expected,
+            "{} in {}",
+            n,
+            locale.name()
+        );
+    }
+}
+
+#[test]
+fn test_ordinal_types() {
+    assert_eq!(1u8.to_ordinal_string(&Locale::en), "1st");
+    assert_eq!((-2i8).to_ordinal_string(&Locale::en), "-2nd");
+    assert_eq!(
+        NonZeroU8::new(3).unwrap().to_ordinal_string(&Locale::en),
+        "3rd"
+    );
+    assert_eq!(
+        NonZeroU32::new(1001)
+            .unwrap()
+            .to_ordinal_string(&Locale::de),
+        "1.001."
+    );
+    assert_eq!(
+        u128::MAX.to_ordinal_string(&Locale::en),
+        format!("{}th", u128::MAX.to_formatted_string(&Locale::en))
+    );
+    assert_eq!(
+        i128::MIN.to_ordinal_string(&Locale::en),
+        format!("{}th", i128::MIN.to_formatted_string(&Locale::en))
+    );
+}
+
+#[test]
+fn test_ordinal_category() {
+    let test_cases: &[(Locale, u32, PluralCategory)] = &[
+        (Locale::en, 1, PluralCategory::One),
+        (Locale::en, 11, PluralCategory::Other),
+        (Locale::en, 22, PluralCategory::Two),
+        (Locale::en, 103, PluralCategory::Few),
+        (Locale::de, 1, PluralCategory::Other),
+        (Locale::cy, 0, PluralCategory::Zero),
+        (Locale::kk, 10, PluralCategory::Many),
+        (Locale::kk, 0, PluralCategory::Other),
+        // Plural rules belong to the language, even where other data does not
+        (Locale::az_Cyrl, 40, PluralCategory::Many),
+    ];
+    for (locale, n, expected) in test_cases {
+        assert_eq!(
+            locale.ordinal_category(n),
+            *expected,
+            "{} in {}",
+            n,
+            locale.name()
+        );
+    }
+    assert_eq!(Locale::en.ordinal_category(&-21i64), PluralCategory::One);
+    assert_eq!(PluralCategory::Few.as_str(), "few");
+}
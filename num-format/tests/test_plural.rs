@@ -0,0 +1,151 @@
+#![cfg(feature = "std")]
+
+use std::num::NonZeroU16;
+
+use num_format::{Locale, PluralCategory, PluralOperands, ToPluralOperands};
+
+use PluralCategory::*;
+
+fn operands(s: &str) -> PluralOperands {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_plural_category_integers() {
+    let test_cases: &[(Locale, i64, PluralCategory)] = &[
+        (Locale::en, 0, Other),
+        (Locale::en, 1, One),
+        (Locale::en, -1, One),
+        (Locale::en, 2, Other),
+        (Locale::en, 21, Other),
+        (Locale::fr, 0, One),
+        (Locale::fr, 1, One),
+        (Locale::fr, 2, Other),
+        (Locale::fr, 1_000_000, Many),
+        (Locale::fr, 2_000_000, Many),
+        (Locale::fr, 1_000_001, Other),
+        (Locale::ru, 1, One),
+        (Locale::ru, 21, One),
+        (Locale::ru, 11, Many),
+        (Locale::ru, 22, Few),
+        (Locale::ru, 25, Many),
+        (Locale::ar, 0, Zero),
+        (Locale::ar, 2, Two),
+        (Locale::ar, 103, Few),
+        (Locale::ar, 111, Many),
+        (Locale::cy, 3, Few),
+        (Locale::cy, 6, Many),
+        (Locale::ja, 1, Other),
+        // "pt" puts 0 in "one", "pt-PT" has rules of its own and "pt-AO" has those of "pt"
+        (Locale::pt, 0, One),
+        (Locale::pt_PT, 0, Other),
+        (Locale::pt_AO, 0, One),
+    ];
+    for (locale, n, expected) in test_cases {
+        assert_eq!(
+            locale.plural_category(n),
+            *expected,
+            "{} in {}",
+            n,
+            locale.name()
+        );
+    }
+
+    // Every integer type
+    assert_eq!(Locale::ru.plural_category(&21u8), One);
+    assert_eq!(Locale::ru.plural_category(&-22i16), Few);
+    assert_eq!(Locale::ru.plural_category(&u128::MAX), Many);
+    assert_eq!(Locale::ru.plural_category(&i128::MIN), Many);
+    assert_eq!(
+        Locale::ru.plural_category(&NonZeroU16::new(31).unwrap()),
+        One
+    );
+    assert_eq!(Locale::en.plural_category(&1usize), One);
+}
+
+#[test]
+fn test_plural_category_decimals() {
+    let test_cases: &[(Locale, &str, PluralCategory)] = &[
+        // Fraction digits count even if they are zeros
+        (Locale::en, "1", One),
+        (Locale::en, "1.0", Other),
+        (Locale::en, "-1", One),
+        (Locale::fr, "1.5", One),
+        (Locale::fr, "1.99", One),
+        (Locale::fr, "2.0", Other),
+        (Locale::fr, "1000000.0", Other),
+        (Locale::ru, "1.5", Other),
+        (Locale::ru, "21.0", Other),
+        // Latvian tests the fraction digits
+        (Locale::lv, "0.1", One),
+        (Locale::lv, "0.10", Other),
+        (Locale::lv, "0.11", Zero),
+        (Locale::lv, "0.21", One),
+        (Locale::lv, "1.2", Other),
+        // Slovenian the number of fraction digits
+        (Locale::sl, "1.5", Few),
+        (Locale::sl, "101", One),
+        // Welsh compares `n`, which only integers equal
+        (Locale::cy, "1", One),
+        (Locale::cy, "1.0", One),
+        (Locale::cy, "1.5", Other),
+    ];
+    for (locale, s, expected) in test_cases {
+        assert_eq!(
+            locale.plural_category(&operands(s)),
+            *expected,
+            "{} in {}",
+            s,
+            locale.name()
+        );
+    }
+}
+
+#[test]
+fn test_plural_operands() {
+    assert_eq!(operands("1.50"), operands("+1.50"));
+    assert_eq!(operands("1.50"), operands("-1.50"));
+    assert_ne!(operands("1.50"), operands("1.5"));
+    assert_eq!(operands("007"), 7.to_plural_operands());
+    assert_eq!(operands("7."), 7.to_plural_operands());
+    assert_eq!(PluralOperands::from_f64(1.5, 2).unwrap(), operands("1.50"));
+    assert_eq!(PluralOperands::from_f64(-0.25, 1).unwrap(), operands("0.2"));
+    assert_eq!(
+        PluralOperands::from_f64(1e20, 0).unwrap(),
+        operands("100000000000000000000")
+    );
+    assert!(PluralOperands::from_f64(f64::NAN, 1).is_err());
+    assert!(PluralOperands::from_f64(f64::INFINITY, 1).is_err());
+    for s in &["", ".5", "1.2.3", "1,000", "1e3", "--1", " 1", "١"] {
+        assert!(s.parse::<PluralOperands>().is_err(), "{:?}", s);
+    }
+
+    // Numbers and fractions with any number of digits
+    let large = format!("{}1.{}1", "9".repeat(50), "0".repeat(50));
+    assert_eq!(Locale::ru.plural_category(&operands(&large)), Other);
+    let large = format!("{}1", "0".repeat(50));
+    assert_eq!(Locale::ru.plural_category(&operands(&large)), One);
+    let large = format!("1{}", "0".repeat(50));
+    assert_eq!(Locale::fr.plural_category(&operands(&large)), Many);
+    assert_eq!(Locale::en.plural_category(&operands(&large)), Other);
+}
+
+#[cfg(feature = "with-num-bigint")]
+#[test]
+fn test_plural_category_bigint() {
+    use num_bigint::{BigInt, BigUint};
+
+    let n: BigUint = "1000000000000000000000000000000000000000001"
+        .parse()
+        .unwrap();
+    assert_eq!(Locale::ru.plural_category(&n), One);
+    assert_eq!(Locale::en.plural_category(&n), Other);
+    let n: BigInt = "-1000000000000000000000000000000000000000000"
+        .parse()
+        .unwrap();
+    assert_eq!(Locale::fr.plural_category(&n), Many);
+    assert_eq!(Locale::ru.plural_category(&n), Many);
+    let n = BigInt::from(-22);
+    assert_eq!(Locale::ru.plural_category(&n), Few);
+    assert_eq!(Locale::en.plural_category(&BigUint::from(1u8)), One);
+}
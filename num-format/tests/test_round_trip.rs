@@ -0,0 +1,163 @@
+#![cfg(feature = "std")]
+
+use std::fmt::Debug;
+use std::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+use num_format::parsing::{FromFormattedStr, ParseFormatted};
+use num_format::{Buffer, Locale, ToFormattedStr, ToFormattedString, WriteFormatted};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+const SAMPLES_PER_LOCALE: usize = 64;
+
+/// Formats `n` with every API the crate offers, checks that they all agree, and then checks
+/// that parsing the result with [`ParseFormatted`] gives back `n`.
+fn round_trip<N>(n: N, locale: &Locale)
+where
+    N: ToFormattedStr + FromFormattedStr + Copy + Debug + PartialEq,
+{
+    // Buffer
+    let mut buf = Buffer::default();
+    let c = buf.write_formatted(&n, locale);
+    assert_eq!(c, buf.len(), "locale: {}, input: {:?}", locale.name(), n);
+
+    // ToFormattedString
+    let s = n.to_formatted_string(locale);
+    assert_eq!(buf.as_str(), s, "locale: {}, input: {:?}", locale.name(), n);
+
+    // WriteFormatted (fmt::Write)
+    let mut string = String::new();
+    let c = string.write_formatted(&n, locale).unwrap();
+    assert_eq!(
+        buf.as_str(),
+        string,
+        "locale: {}, input: {:?}",
+        locale.name(),
+        n
+    );
+    assert_eq!(c, string.len(), "locale: {}, input: {:?}", locale.name(), n);
+
+    // WriteFormatted (io::Write)
+    let mut vec = Vec::new();
+    let c = vec.write_formatted(&n, locale).unwrap();
+    assert_eq!(
+        buf.as_bytes(),
+        &vec[..],
+        "locale: {}, input: {:?}",
+        locale.name(),
+        n
+    );
+    assert_eq!(c, vec.len(), "locale: {}, input: {:?}", locale.name(), n);
+
+    // ParseFormatted
+    let parsed = s.parse_formatted::<_, N>(locale).unwrap_or_else(|e| {
+        panic!("locale: {}, input: {:?}, error: {}", locale.name(), s, e);
+    });
+    assert_eq!(n, parsed, "locale: {}, input: {:?}", locale.name(), s);
+}
+
+/// Returns uniformly random values with their magnitudes spread out, so that short numbers
+/// (which need few or no separators) are exercised as often as long ones.
+fn samples<N>(edge_cases: &[N]) -> Vec<N>
+where
+    N: Copy,
+    Standard: Distribution<N>,
+    N: std::ops::Shr<u32, Output = N>,
+{
+    let bits = (std::mem::size_of::<N>() * 8) as u32;
+    let mut rng = rand::thread_rng();
+    let mut vec = edge_cases.to_vec();
+    for _ in 0..SAMPLES_PER_LOCALE {
+        let n: N = rng.gen();
+        let shift = rng.gen_range(0..bits);
+        vec.push(n >> shift);
+    }
+    vec
+}
+
+macro_rules! test_round_trip {
+    ($name:ident, $type:ty) => {
+        #[test]
+        fn $name() {
+            for name in Locale::available_names() {
+                let locale = Locale::from_name(name).unwrap();
+                for n in samples::<$type>(&[0, 1, <$type>::MIN, <$type>::MAX]) {
+                    round_trip(n, &locale);
+                }
+            }
+        }
+    };
+}
+
+test_round_trip!(test_round_trip_u8, u8);
+test_round_trip!(test_round_trip_u16, u16);
+test_round_trip!(test_round_trip_u32, u32);
+test_round_trip!(test_round_trip_usize, usize);
+test_round_trip!(test_round_trip_u64, u64);
+test_round_trip!(test_round_trip_u128, u128);
+
+test_round_trip!(test_round_trip_i8, i8);
+test_round_trip!(test_round_trip_i16, i16);
+test_round_trip!(test_round_trip_i32, i32);
+test_round_trip!(test_round_trip_isize, isize);
+test_round_trip!(test_round_trip_i64, i64);
+test_round_trip!(test_round_trip_i128, i128);
+
+macro_rules! test_round_trip_non_zero {
+    ($name:ident, $type:ty, $related_type:ty) => {
+        #[test]
+        fn $name() {
+            for name in Locale::available_names() {
+                let locale = Locale::from_name(name).unwrap();
+                for n in samples::<$related_type>(&[1, <$related_type>::MAX]) {
+                    if let Some(n) = <$type>::new(n) {
+                        round_trip(n, &locale);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_round_trip_non_zero!(test_round_trip_non_zero_u8, NonZeroU8, u8);
+test_round_trip_non_zero!(test_round_trip_non_zero_u16, NonZeroU16, u16);
+test_round_trip_non_zero!(test_round_trip_non_zero_u32, NonZeroU32, u32);
+test_round_trip_non_zero!(test_round_trip_non_zero_usize, NonZeroUsize, usize);
+test_round_trip_non_zero!(test_round_trip_non_zero_u64, NonZeroU64, u64);
+test_round_trip_non_zero!(test_round_trip_non_zero_u128, NonZeroU128, u128);
+
+#[cfg(feature = "with-num-bigint")]
+#[test]
+fn test_round_trip_num_bigint() {
+    use num_bigint::{BigInt, BigUint};
+
+    let mut rng = rand::thread_rng();
+    for name in Locale::available_names() {
+        let locale = Locale::from_name(name).unwrap();
+        for _ in 0..SAMPLES_PER_LOCALE {
+            // Multiply two random values to reach magnitudes beyond u128::MAX
+            let n = BigInt::from(rng.gen::<i128>()) * BigInt::from(rng.gen::<u128>());
+            let n_unsigned = BigUint::from(rng.gen::<u128>()) * BigUint::from(rng.gen::<u128>());
+
+            let s = n.to_formatted_string(&locale);
+            let mut string = String::new();
+            let _ = string.write_formatted(&n, &locale).unwrap();
+            assert_eq!(s, string, "locale: {}, input: {:?}", name, n);
+            let parsed = s.parse_formatted::<_, BigInt>(&locale).unwrap();
+            assert_eq!(n, parsed, "locale: {}, input: {:?}", name, s);
+
+            let s = n_unsigned.to_formatted_string(&locale);
+            let mut vec = Vec::new();
+            let _ = vec.write_formatted(&n_unsigned, &locale).unwrap();
+            assert_eq!(
+                s.as_bytes(),
+                &vec[..],
+                "locale: {}, input: {:?}",
+                name,
+                n_unsigned
+            );
+            let parsed = s.parse_formatted::<_, BigUint>(&locale).unwrap();
+            assert_eq!(n_unsigned, parsed, "locale: {}, input: {:?}", name, s);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg(all(feature = "with-system-locale", any(unix, windows)))]
+
+use std::path::{Path, PathBuf};
+
+use num_format::{ErrorKind, Grouping, SystemLocale, ToFormattedString};
+
+fn locales() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("locales")
+}
+
+#[test]
+fn test_definition() {
+    // default comment and escape characters, symbolic names and <Uxxxx> escapes
+    let locale = SystemLocale::from_definition_dir(locales(), "yy_YY").unwrap();
+    assert_eq!(locale.name(), "yy_YY");
+    assert_eq!(locale.decimal(), ",");
+    assert_eq!(locale.separator(), "\u{a0}");
+    assert_eq!(locale.grouping(), Grouping::Indian);
+    assert_eq!(locale.minus_sign(), "-");
+    assert_eq!(locale.plus_sign(), "+");
+    assert_eq!(
+        (-12345678).to_formatted_string(&locale),
+        "-1\u{a0}23\u{a0}45\u{a0}678"
+    );
+
+    // copy directives, overrides, comments after keywords and continuation lines
+    let locale = SystemLocale::from_definition_file(locales().join("xx_XX")).unwrap();
+    assert_eq!(locale.name(), "xx_XX");
+    assert_eq!(locale.decimal(), ",");
+    assert_eq!(locale.separator(), "\u{202f}");
+    assert_eq!(locale.grouping(), Grouping::Indian);
+    assert_eq!(locale.minus_sign(), "\u{2212}");
+    assert_eq!(locale.plus_sign(), "");
+
+    // escaped characters, POSIX grouping and no LC_MONETARY
+    let locale = SystemLocale::from_definition_dir(locales(), "zz_ZZ").unwrap();
+    assert_eq!(locale.decimal(), ".");
+    assert_eq!(locale.separator(), "");
+    assert_eq!(locale.grouping(), Grouping::Posix);
+    assert_eq!(locale.minus_sign(), "");
+    assert_eq!(locale.plus_sign(), "");
+
+    // infinity and NaN come from the CLDR locale closest to the name
+    let locale = SystemLocale::from_definition_dir(locales(), "yy_YY").unwrap();
+    assert_eq!(locale.infinity(), "∞");
+    assert_eq!(locale.nan(), "NaN");
+}
+
+#[test]
+fn test_definition_errors() {
+    // the file itself, or the file it copies from, is missing
+    for &(name, missing) in &[
+        ("copy_missing", "no_such_locale"),
+        ("does_not_exist", "does_not_exist"),
+    ] {
+        match SystemLocale::from_definition_dir(locales(), name) {
+            Err(e) => match e.kind() {
+                ErrorKind::SystemDefinitionIo { path, .. } => assert!(path.ends_with(missing)),
+                kind => panic!("{}: {:?}", name, kind),
+            },
+            Ok(_) => panic!("{}", name),
+        }
+    }
+
+    for name in &["bad_grouping", "copy_loop", "no_end", "no_numeric"] {
+        match SystemLocale::from_definition_dir(locales(), name) {
+            Err(e) => match e.kind() {
+                ErrorKind::SystemInvalidDefinition(_) => {}
+                kind => panic!("{}: {:?}", name, kind),
+            },
+            Ok(_) => panic!("{}", name),
+        }
+    }
+
+    match SystemLocale::from_definition_dir(locales(), "unsupported_grouping") {
+        Err(e) => match e.kind() {
+            ErrorKind::SystemUnsupportedGrouping(bytes) => assert_eq!(bytes, &[4]),
+            kind => panic!("{:?}", kind),
+        },
+        Ok(_) => panic!(),
+    }
+}
@@ -1,12 +1,27 @@
 #![cfg(all(feature = "with-system-locale", unix))]
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::env;
+use std::process::Command;
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, Mutex};
+use std::thread;
 
-use num_format::SystemLocale;
+use lazy_static::lazy_static;
+use num_format::{
+    CachedSystemLocale, ErrorKind, Grouping, Locale, SystemLocale, SystemMonetaryFormat,
+    ToFormattedString,
+};
+
+lazy_static! {
+    // Held by the tests that set LC_ALL, so they do not change it under one another
+    static ref ENV: Mutex<()> = Mutex::new(());
+}
 
 #[test]
 fn test_unix() {
+    let _guard = ENV.lock().unwrap();
     let set = SystemLocale::available_names().unwrap();
     let mut vec = set.iter().map(|s| s.to_string()).collect::<Vec<String>>();
     vec.sort_by(|_, _| {
@@ -25,3 +40,159 @@ fn test_unix() {
         assert_eq!(locale1, locale2);
     }
 }
+
+#[test]
+fn test_unix_available_names() {
+    let names = SystemLocale::available_names().unwrap();
+    assert!(names.contains("C"));
+    assert!(names.contains("POSIX"));
+
+    // the names are read from the same places `locale -a` reads them, unless LOCPATH adds some
+    if cfg!(target_os = "linux") && env::var_os("LOCPATH").is_none() {
+        if let Ok(output) = Command::new("locale").arg("-a").output() {
+            let stdout = String::from_utf8(output.stdout).unwrap();
+            let expected = stdout
+                .lines()
+                .map(|s| s.to_string())
+                .collect::<HashSet<_>>();
+            assert_eq!(names, expected);
+        }
+    }
+}
+
+#[test]
+fn test_unix_cached() {
+    let _guard = ENV.lock().unwrap();
+    let mut names = SystemLocale::available_names()
+        .unwrap()
+        .into_iter()
+        .collect::<Vec<String>>();
+    names.sort();
+    let mut previous = CachedSystemLocale::get().unwrap();
+    for name in &names {
+        let locale = SystemLocale::from_name(name.as_str()).unwrap();
+        env::set_var("LC_ALL", name);
+
+        // the cached locale only changes with the environment when it is refreshed
+        assert_eq!(CachedSystemLocale::get().unwrap(), previous);
+        let refreshed = CachedSystemLocale::refresh().unwrap();
+        assert_eq!(*refreshed, locale);
+        assert_eq!(CachedSystemLocale::get().unwrap(), refreshed);
+        assert_eq!(
+            (-1234567).to_formatted_string(&refreshed),
+            (-1234567).to_formatted_string(&locale)
+        );
+        previous = refreshed;
+    }
+}
+
+#[test]
+fn test_unix_encodings() {
+    // whatever their codeset (e.g. ANSI_X3.4-1968 for glibc's "C" locale, KOI8-U or EUC-TW), the
+    // strings of every available locale are decoded, even those whose grouping is unsupported
+    for name in SystemLocale::available_names().unwrap() {
+        let results = [
+            SystemLocale::from_name(name.as_str()).map(|_| ()),
+            SystemMonetaryFormat::from_name(name.as_str()).map(|_| ()),
+        ];
+        for result in results {
+            if let Err(e) = result {
+                match e.kind() {
+                    ErrorKind::SystemUnsupportedGrouping(_) => {}
+                    kind => panic!("{}: {:?}", name, kind),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_unix_infinity_and_nan() {
+    // Unix-based systems have no infinity and NaN symbols; they come from the closest CLDR locale
+    for name in SystemLocale::available_names().unwrap() {
+        let locale = SystemLocale::from_name(name.as_str()).unwrap();
+        let cldr_locale = Locale::from_name(&name).unwrap_or(Locale::en);
+        assert_eq!(locale.infinity(), cldr_locale.infinity(), "{}", name);
+        assert_eq!(locale.nan(), cldr_locale.nan(), "{}", name);
+    }
+
+    let mut locale = SystemLocale::from_name("C").unwrap();
+    assert_eq!(locale.infinity(), Locale::en_US_POSIX.infinity());
+    locale.set_infinity("inf").unwrap();
+    locale.set_nan("nan").unwrap();
+    assert_eq!(locale.infinity(), "inf");
+    assert_eq!(locale.nan(), "nan");
+}
+
+#[test]
+fn test_unix_monetary_format() {
+    for name in SystemLocale::available_names().unwrap() {
+        let locale = SystemLocale::from_name(name.as_str()).unwrap();
+        let format = SystemMonetaryFormat::from_name(name.as_str()).unwrap();
+        assert_eq!(format.name(), locale.name());
+    }
+
+    let format = SystemMonetaryFormat::from_name("C").unwrap();
+    assert_eq!(format.currency_symbol(), "");
+    assert_eq!(format.international_currency_symbol(), "");
+    assert_eq!(format.frac_digits(), 2);
+    assert_eq!(format.grouping(), Grouping::Posix);
+    assert_eq!(format.format(1234.5), "1234.50");
+    assert_eq!(format.format(-1234.5), "-1234.50");
+    assert_eq!(format.format_international(-0.005), "-0.01");
+    assert_eq!(1234.to_formatted_string(&format), "1234");
+}
+
+#[test]
+fn test_unix_threads() {
+    // Constructing system locales reads locale objects of their own, so it gives the same
+    // results from many threads at once as from one, even while the default locale changes
+    let names = SystemLocale::available_names()
+        .unwrap()
+        .into_iter()
+        .collect::<Vec<String>>();
+    let _guard = ENV.lock().unwrap();
+    let expected = names
+        .iter()
+        .map(|name| {
+            let locale = SystemLocale::from_name(name.as_str()).unwrap();
+            let format = SystemMonetaryFormat::from_name(name.as_str()).unwrap();
+            (name.clone(), locale, format)
+        })
+        .collect::<Vec<_>>();
+    let expected = Arc::new(expected);
+    let done = Arc::new(AtomicBool::new(false));
+
+    let handles = (0..8)
+        .map(|i| {
+            let expected = Arc::clone(&expected);
+            let done = Arc::clone(&done);
+            thread::spawn(move || {
+                for j in 0.. {
+                    if j > 0 && done.load(atomic::Ordering::SeqCst) {
+                        break;
+                    }
+                    let (name, locale, format) = &expected[(i + j) % expected.len()];
+                    assert_eq!(&SystemLocale::from_name(name.as_str()).unwrap(), locale);
+                    assert_eq!(
+                        &SystemMonetaryFormat::from_name(name.as_str()).unwrap(),
+                        format
+                    );
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // meanwhile, change the default locale and read it back
+    for _ in 0..10 {
+        for (name, locale, format) in expected.iter() {
+            env::set_var("LC_ALL", name);
+            assert_eq!(&SystemLocale::default().unwrap(), locale);
+            assert_eq!(&SystemMonetaryFormat::default().unwrap(), format);
+        }
+    }
+    done.store(true, atomic::Ordering::SeqCst);
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}